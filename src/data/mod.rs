@@ -9,6 +9,8 @@ pub mod constants {
 
     pub const WEEKS_PER_YEAR: u32 = 52;
     pub const STARTING_YEAR: u32 = 1970;
+    /// The band's home country, as keyed in `markets.json` and `tax.json`.
+    pub const HOME_COUNTRY: &str = "united_kingdom";
 
     // Cost constants
     pub const SINGLE_RECORDING_COST: i32 = 100;
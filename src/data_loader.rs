@@ -1,15 +1,18 @@
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+use crate::game::genre::MusicGenre;
+use crate::game::player::LifestyleTier;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct TimelineData {
     pub timeline: HashMap<String, EraData>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EraData {
     pub era_name: String,
     pub dominant_genres: Vec<String>,
@@ -21,7 +24,7 @@ pub struct EraData {
     pub gig_pay_modifier: f32,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MarketConditionsData {
     pub overall_demand: u8,
     pub saturation: u8,
@@ -31,7 +34,7 @@ pub struct MarketConditionsData {
     pub record_sales_growth: f32,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IndustryTrendsData {
     pub album_vs_singles: f32,
     pub studio_quality_importance: u8,
@@ -40,7 +43,7 @@ pub struct IndustryTrendsData {
     pub fan_loyalty_factor: u8,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct RecordLabelsData {
     pub major_labels: Vec<RecordLabel>,
     pub independent_labels: Vec<RecordLabel>,
@@ -63,14 +66,14 @@ pub struct RecordLabel {
     pub reputation: String,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct LabelRequirements {
     pub major_label_interest_threshold: InterestThreshold,
     pub independent_label_interest_threshold: InterestThreshold,
     pub boutique_label_interest_threshold: InterestThreshold,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct InterestThreshold {
     pub fame: u8,
     pub albums: u8,
@@ -78,18 +81,18 @@ pub struct InterestThreshold {
     pub buzz: u8,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct MarketsData {
     pub markets: HashMap<String, CountryMarket>,
     pub market_modifiers: MarketModifiers,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CountryMarket {
     pub regions: HashMap<String, RegionMarket>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RegionMarket {
     pub name: String,
     pub major_cities: Vec<String>,
@@ -104,14 +107,14 @@ pub struct RegionMarket {
     pub economic_factors: EconomicFactors,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EconomicFactors {
     pub disposable_income: u8,
     pub unemployment_rate: f32,
     pub music_spending_ratio: f32,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct MarketModifiers {
     pub breakthrough_thresholds: HashMap<String, BreakthroughLevel>,
     pub genre_era_modifiers: HashMap<String, HashMap<String, f32>>,
@@ -120,14 +123,14 @@ pub struct MarketModifiers {
     pub touring_costs: HashMap<String, TouringCosts>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BreakthroughLevel {
     pub fame_required: u8,
     pub fan_base: u32,
     pub revenue_multiplier: f32,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EconomicCycleEffect {
     pub record_sales_modifier: f32,
     pub touring_revenue_modifier: f32,
@@ -143,10 +146,138 @@ pub struct EconomicCycleEffect {
 /// table, not data); these two modifiers are the extra logistics overhead of
 /// moving a bigger rig — previously loaded and never read at all. Not part
 /// of save state: `GameDataFiles` is reloaded fresh from disk every launch.
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// The two leg costs price the trips *between* stops on a routed tour:
+/// a hop to another region of the same country, or a flight abroad with
+/// the rig freighted after you.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TouringCosts {
     pub travel_cost_modifier: f32,
     pub equipment_cost_modifier: f32,
+    pub domestic_leg_cost: u32,
+    pub international_leg_cost: u32,
+}
+
+// ============================================================================
+// Income tax (`game/tax.rs`). `data/tax.json` holds each country's regimes,
+// keyed by the year they came in: the regime in force in a year is the
+// latest one at or before it. Rates and thresholds are in game dollars.
+// ============================================================================
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct TaxData {
+    /// Country key → first year in force → regime.
+    pub regimes: HashMap<String, HashMap<String, TaxRegime>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TaxRegime {
+    /// One line on the regime, for the finances screen.
+    pub note: String,
+    /// Profit taken tax-free before the first band.
+    pub allowance: u32,
+    /// What it costs to move there as a tax exile; nothing at home.
+    pub relocation_cost: u32,
+    /// Marginal rates on the profit above the allowance, lowest first.
+    pub bands: Vec<TaxBand>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct TaxBand {
+    pub from: u32,
+    pub rate: f32,
+}
+
+impl TaxRegime {
+    /// The tax on a year's profit.
+    pub fn tax_on(&self, profit: u32) -> u32 {
+        let taxable = profit.saturating_sub(self.allowance);
+        let tax: f32 = self
+            .bands
+            .iter()
+            .enumerate()
+            .map(|(i, band)| {
+                let to = self.bands.get(i + 1).map_or(u32::MAX, |next| next.from);
+                let slice = taxable.min(to).saturating_sub(band.from);
+                slice as f32 * band.rate
+            })
+            .sum();
+        tax.round() as u32
+    }
+
+    /// The rate on the last dollar of the highest band.
+    pub fn top_rate(&self) -> f32 {
+        self.bands.last().map_or(0.0, |band| band.rate)
+    }
+}
+
+impl TaxData {
+    /// The regime in force in a country in a year. Before a country's
+    /// earliest regime, that one applies.
+    pub fn regime(&self, country: &str, year: u32) -> Option<&TaxRegime> {
+        let regimes = self.regimes.get(country)?;
+        let mut years: Vec<u32> = regimes.keys().filter_map(|k| k.parse().ok()).collect();
+        years.sort_unstable();
+        let first = *years.first()?;
+        let year = years
+            .into_iter()
+            .filter(|&y| y <= year)
+            .max()
+            .unwrap_or(first);
+        regimes.get(&year.to_string())
+    }
+
+    /// Every country with a tax regime, sorted.
+    pub fn countries(&self) -> Vec<&str> {
+        let mut countries: Vec<&str> = self.regimes.keys().map(String::as_str).collect();
+        countries.sort_unstable();
+        countries
+    }
+
+    /// Fail fast on a malformed tax file: the home country present, and
+    /// every regime's bands starting at 0, rising, with rates in 0..=1.
+    pub fn validate(&self, home: &str) -> Result<(), String> {
+        if !self.regimes.contains_key(home) {
+            return Err(format!(
+                "tax.json: no regime for the home country '{}'",
+                home
+            ));
+        }
+        for (country, regimes) in &self.regimes {
+            for (year, regime) in regimes {
+                if year.parse::<u32>().is_err() {
+                    return Err(format!(
+                        "tax.json: {} has a regime keyed '{}', not a year",
+                        country, year
+                    ));
+                }
+                if regime.bands.first().is_none_or(|band| band.from != 0) {
+                    return Err(format!(
+                        "tax.json: {} {} must have a band from 0",
+                        country, year
+                    ));
+                }
+                if regime
+                    .bands
+                    .windows(2)
+                    .any(|pair| pair[0].from >= pair[1].from)
+                {
+                    return Err(format!("tax.json: {} {} bands must rise", country, year));
+                }
+                if regime
+                    .bands
+                    .iter()
+                    .any(|band| !(0.0..=1.0).contains(&band.rate))
+                {
+                    return Err(format!(
+                        "tax.json: {} {} has a rate outside 0-1",
+                        country, year
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -158,6 +289,16 @@ pub struct TouringCosts {
 
 /// One random incident: a weighted, condition-gated bundle of effect ranges
 /// plus its log line. Deserialized straight from `data/incidents.json`.
+///
+/// An incident with `choices` is a decision: its own `effects` and message
+/// land when it fires, then the game waits for the player to pick one of
+/// the choices (`Game::pending_incident`). Without choices it resolves on
+/// the spot, exactly as it always has.
+///
+/// Storylines chain through `follow_up`: when an incident (or one of its
+/// choices) lands, the named incident is scheduled that many weeks out and
+/// fires then instead of the week's random roll — if its conditions still
+/// hold. A `follow_up_only` incident never comes up at random.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Incident {
     pub id: String,
@@ -165,52 +306,157 @@ pub struct Incident {
     pub weight: u32,
     #[serde(default)]
     pub conditions: IncidentConditions,
+    #[serde(default)]
     pub effects: IncidentEffects,
     pub message: String,
+    #[serde(default)]
+    pub choices: Vec<IncidentChoice>,
+    /// Weeks after firing before this incident can come up again.
+    #[serde(default)]
+    pub cooldown_weeks: u32,
+    /// Fires at most once per career.
+    #[serde(default)]
+    pub once_per_career: bool,
+    #[serde(default)]
+    pub follow_up_only: bool,
+    #[serde(default)]
+    pub follow_up: Option<IncidentFollowUp>,
+}
+
+/// Schedule incident `incident` to fire `weeks` weeks from now.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct IncidentFollowUp {
+    pub incident: String,
+    pub weeks: u32,
+}
+
+/// One answer to a decision incident. A choice is offered only while its
+/// `conditions` hold and the player can pay its up-front `cost`; exactly one
+/// choice per incident is the unconditional, free `default` — what happens
+/// when nobody picks (the sim bots, or a player who just plays on).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IncidentChoice {
+    pub label: String,
+    #[serde(default)]
+    pub conditions: IncidentConditions,
+    #[serde(default)]
+    pub cost: u32,
+    #[serde(default)]
+    pub effects: IncidentEffects,
+    pub message: String,
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default)]
+    pub follow_up: Option<IncidentFollowUp>,
 }
 
 /// When an incident is eligible. Every field is optional (omit or `null` = no
-/// constraint). `on_tour` is deliberately absent — there is no persistent
-/// on-tour state this cycle (§F).
-#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+/// constraint); `min_*`/`max_*` bounds are inclusive. `eras` matches the
+/// timeline's era name, `genres` the band's genre by name or any of its
+/// market aliases (case-insensitive), and `lifestyles` the player's current
+/// tier — each any-of. `has_release` tests whether the band has put out
+/// anything yet; `on_tour` whether the week just played was spent on the
+/// road (`Game::on_tour`).
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
+#[serde(default)]
 pub struct IncidentConditions {
-    #[serde(default)]
     pub min_fame: Option<u8>,
-    #[serde(default)]
     pub max_fame: Option<u8>,
-    #[serde(default)]
     pub signed: Option<bool>,
+    pub has_release: Option<bool>,
+    pub min_year: Option<u32>,
+    pub max_year: Option<u32>,
+    pub eras: Option<Vec<String>>,
+    pub genres: Option<Vec<String>>,
+    pub lifestyles: Option<Vec<LifestyleTier>>,
+    pub min_stress: Option<u8>,
+    pub max_stress: Option<u8>,
+    pub min_happiness: Option<u8>,
+    pub max_happiness: Option<u8>,
+    pub min_creativity: Option<u8>,
+    pub max_creativity: Option<u8>,
+    pub min_health: Option<u8>,
+    pub max_health: Option<u8>,
+    pub min_money: Option<i32>,
+    pub max_money: Option<i32>,
+    pub on_tour: Option<bool>,
+}
+
+/// The slice of game state incident conditions are tested against, built
+/// by `Game::incident_context`. `Default` is a fresh unsigned nobody in an
+/// empty year — handy in tests with struct-update syntax.
+#[derive(Debug, Clone, Default)]
+pub struct IncidentContext {
+    pub fame: u8,
+    pub signed: bool,
+    pub has_release: bool,
+    pub year: u32,
+    pub era: String,
+    pub genre: MusicGenre,
+    pub lifestyle: LifestyleTier,
+    pub stress: u8,
+    pub happiness: u8,
+    pub creativity: u8,
+    pub health: u8,
+    pub money: i32,
+    pub on_tour: bool,
 }
 
 /// Inclusive `[lo, hi]` effect ranges, one per movable stat. An omitted field
 /// is a no-op (rolls 0, consuming no rng). Bars clamp 0–100; money is i32 and
 /// may go negative; fame gains are small and route through the comeback-aware
 /// path, losses saturate (see `events_apply.rs`).
+///
+/// Beyond the player: `band_skill` and the four reputation fields clamp
+/// 0–100; `member_skill`/`member_loyalty` land on one bandmate picked at
+/// random (named by `{member}` in the message); `release_stock` (copies)
+/// and `release_marketing` (buzz points) hit the newest release;
+/// `regional_fame` one region; `deal_cooldown` the weeks before labels call
+/// again; `unrecouped` the label's ledger; `gear_condition` one piece of the
+/// band's gear, picked at random. Release effects need the
+/// `has_release` condition and the ledger needs `signed`, so there is always
+/// something to hit (checked by `IncidentsData::validate`).
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
 pub struct IncidentEffects {
-    #[serde(default)]
     pub stress: Option<[i32; 2]>,
-    #[serde(default)]
     pub happiness: Option<[i32; 2]>,
-    #[serde(default)]
     pub creativity: Option<[i32; 2]>,
-    #[serde(default)]
     pub health: Option<[i32; 2]>,
-    #[serde(default)]
     pub money: Option<[i32; 2]>,
-    #[serde(default)]
     pub fame: Option<[i32; 2]>,
+    pub band_skill: Option<[i32; 2]>,
+    pub member_skill: Option<[i32; 2]>,
+    pub member_loyalty: Option<[i32; 2]>,
+    pub critical_acclaim: Option<[i32; 2]>,
+    pub commercial_success: Option<[i32; 2]>,
+    pub live_performance: Option<[i32; 2]>,
+    pub media_presence: Option<[i32; 2]>,
+    pub release_stock: Option<[i32; 2]>,
+    pub release_marketing: Option<[i32; 2]>,
+    pub regional_fame: Option<RegionalFameEffect>,
+    pub deal_cooldown: Option<[i32; 2]>,
+    pub unrecouped: Option<[i32; 2]>,
+    pub gear_condition: Option<[i32; 2]>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+/// Fame won or lost in one region, keyed `country:region` as in
+/// `markets.json` (e.g. `japan:tokyo`).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RegionalFameEffect {
+    pub region: String,
+    pub change: [i32; 2],
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct IncidentsData {
     pub incidents: Vec<Incident>,
 }
 
 impl IncidentEffects {
-    /// The six effect channels in the fixed roll order, paired with their name
+    /// Every effect channel in the fixed roll order, paired with its name
     /// for validation and iteration.
-    pub fn ranges(&self) -> [(&'static str, Option<[i32; 2]>); 6] {
+    pub fn ranges(&self) -> [(&'static str, Option<[i32; 2]>); 19] {
         [
             ("stress", self.stress),
             ("happiness", self.happiness),
@@ -218,32 +464,191 @@ impl IncidentEffects {
             ("health", self.health),
             ("money", self.money),
             ("fame", self.fame),
+            ("band_skill", self.band_skill),
+            ("member_skill", self.member_skill),
+            ("member_loyalty", self.member_loyalty),
+            ("critical_acclaim", self.critical_acclaim),
+            ("commercial_success", self.commercial_success),
+            ("live_performance", self.live_performance),
+            ("media_presence", self.media_presence),
+            ("release_stock", self.release_stock),
+            ("release_marketing", self.release_marketing),
+            (
+                "regional_fame",
+                self.regional_fame.as_ref().map(|effect| effect.change),
+            ),
+            ("deal_cooldown", self.deal_cooldown),
+            ("unrecouped", self.unrecouped),
+            ("gear_condition", self.gear_condition),
         ]
     }
+
+    /// Whether these effects single out a bandmate (and so fill `{member}`).
+    pub fn touches_member(&self) -> bool {
+        self.member_skill.is_some() || self.member_loyalty.is_some()
+    }
+
+    fn touches_release(&self) -> bool {
+        self.release_stock.is_some() || self.release_marketing.is_some()
+    }
+
+    /// Structural checks shared by an incident's and a choice's effects:
+    /// every range `lo ≤ hi`, a well-formed region key, a bandmate behind
+    /// any `{member}` in the message, and the condition that guarantees a
+    /// target for release and ledger effects. `what` names the owner for
+    /// the error.
+    fn validate(
+        &self,
+        what: &str,
+        message: &str,
+        conditions: &IncidentConditions,
+    ) -> Result<(), String> {
+        for (name, range) in self.ranges() {
+            if let Some([lo, hi]) = range
+                && lo > hi
+            {
+                return Err(format!(
+                    "incidents.json: {} effect '{}' has lo {} > hi {}",
+                    what, name, lo, hi
+                ));
+            }
+        }
+        if let Some(effect) = &self.regional_fame
+            && !effect
+                .region
+                .split_once(':')
+                .is_some_and(|(country, region)| !country.is_empty() && !region.is_empty())
+        {
+            return Err(format!(
+                "incidents.json: {} regional_fame region '{}' must be 'country:region'",
+                what, effect.region
+            ));
+        }
+        if message.contains("{member}") && !self.touches_member() {
+            return Err(format!(
+                "incidents.json: {} names {{member}} but no member effect picks one",
+                what
+            ));
+        }
+        if self.touches_release() && conditions.has_release != Some(true) {
+            return Err(format!(
+                "incidents.json: {} touches the newest release without has_release: true",
+                what
+            ));
+        }
+        if self.unrecouped.is_some() && conditions.signed != Some(true) {
+            return Err(format!(
+                "incidents.json: {} touches the label ledger without signed: true",
+                what
+            ));
+        }
+        Ok(())
+    }
 }
 
-impl Incident {
-    /// Whether this incident is eligible given the band's current fame and
-    /// whether it is signed to a label.
-    pub fn matches(&self, fame: u8, signed: bool) -> bool {
-        let c = &self.conditions;
-        if c.min_fame.is_some_and(|min| fame < min) {
-            return false;
+impl IncidentConditions {
+    /// Whether these conditions hold in the given game state.
+    pub fn matches(&self, ctx: &IncidentContext) -> bool {
+        fn within<T: PartialOrd>(value: T, min: Option<T>, max: Option<T>) -> bool {
+            min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
         }
-        if c.max_fame.is_some_and(|max| fame > max) {
-            return false;
+        within(ctx.fame, self.min_fame, self.max_fame)
+            && self.signed.is_none_or(|required| ctx.signed == required)
+            && self
+                .has_release
+                .is_none_or(|required| ctx.has_release == required)
+            && within(ctx.year, self.min_year, self.max_year)
+            && self
+                .eras
+                .as_ref()
+                .is_none_or(|eras| eras.iter().any(|era| era.eq_ignore_ascii_case(&ctx.era)))
+            && self.genres.as_ref().is_none_or(|genres| {
+                genres.iter().any(|genre| {
+                    genre.eq_ignore_ascii_case(ctx.genre.name())
+                        || ctx
+                            .genre
+                            .aliases()
+                            .iter()
+                            .any(|alias| genre.eq_ignore_ascii_case(alias))
+                })
+            })
+            && self
+                .lifestyles
+                .as_ref()
+                .is_none_or(|tiers| tiers.contains(&ctx.lifestyle))
+            && within(ctx.stress, self.min_stress, self.max_stress)
+            && within(ctx.happiness, self.min_happiness, self.max_happiness)
+            && within(ctx.creativity, self.min_creativity, self.max_creativity)
+            && within(ctx.health, self.min_health, self.max_health)
+            && within(ctx.money, self.min_money, self.max_money)
+            && self.on_tour.is_none_or(|required| ctx.on_tour == required)
+    }
+
+    /// No constraint at all — what a default choice must be.
+    fn is_unconditional(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The first `min_*`/`max_*` pair that can never hold (min > max), by
+    /// name, for validation.
+    fn inverted_bound(&self) -> Option<&'static str> {
+        fn inverted<T: PartialOrd>(min: Option<T>, max: Option<T>) -> bool {
+            matches!((min, max), (Some(min), Some(max)) if min > max)
         }
-        if c.signed.is_some_and(|required| signed != required) {
-            return false;
+        [
+            ("fame", inverted(self.min_fame, self.max_fame)),
+            ("year", inverted(self.min_year, self.max_year)),
+            ("stress", inverted(self.min_stress, self.max_stress)),
+            (
+                "happiness",
+                inverted(self.min_happiness, self.max_happiness),
+            ),
+            (
+                "creativity",
+                inverted(self.min_creativity, self.max_creativity),
+            ),
+            ("health", inverted(self.min_health, self.max_health)),
+            ("money", inverted(self.min_money, self.max_money)),
+        ]
+        .into_iter()
+        .find_map(|(name, bad)| bad.then_some(name))
+    }
+}
+
+impl Incident {
+    /// Whether this incident's conditions hold in the given game state.
+    pub fn matches(&self, ctx: &IncidentContext) -> bool {
+        self.conditions.matches(ctx)
+    }
+
+    /// Whether the once-per-career flag and cooldown allow this incident
+    /// in `week`, given the week it last fired (if ever).
+    pub fn off_cooldown(&self, last_fired: Option<u32>, week: u32) -> bool {
+        match last_fired {
+            None => true,
+            Some(_) if self.once_per_career => false,
+            Some(fired) => week >= fired.saturating_add(self.cooldown_weeks),
         }
-        true
+    }
+
+    /// The choice applied when nobody picks. Validation guarantees exactly
+    /// one on any incident with choices.
+    pub fn default_choice(&self) -> Option<usize> {
+        self.choices.iter().position(|choice| choice.default)
     }
 }
 
 impl IncidentsData {
     /// Fail-fast structural validation (§F loader contract): a non-empty pool,
-    /// every `weight` ≥ 1, every effect range `lo ≤ hi`, and unique ids.
-    pub fn validate(&self) -> Result<(), String> {
+    /// every `weight` ≥ 1, every effect range `lo ≤ hi`, every condition
+    /// bound `min ≤ max`, and unique ids. A decision incident needs exactly
+    /// one default choice, and that choice must be free and unconditional so
+    /// a headless run can always take it. Every follow-up names an incident
+    /// in the pool at least a week out, and every `follow_up_only` incident
+    /// is some follow-up's target. Effects beyond the player's bars must
+    /// have a target to land on (see `IncidentEffects`), and every
+    /// `regional_fame` effect must name a region in `markets`.
+    pub fn validate(&self, markets: &MarketsData) -> Result<(), String> {
         if self.incidents.is_empty() {
             return Err("incidents.json: the incident list is empty".to_string());
         }
@@ -255,37 +660,155 @@ impl IncidentsData {
                     inc.id
                 ));
             }
+        }
+        let check_follow_up = |inc: &Incident, follow_up: &Option<IncidentFollowUp>| {
+            let Some(follow_up) = follow_up else {
+                return Ok(());
+            };
+            if !seen.contains(follow_up.incident.as_str()) {
+                return Err(format!(
+                    "incidents.json: incident '{}' follows up with unknown incident '{}'",
+                    inc.id, follow_up.incident
+                ));
+            }
+            if follow_up.weeks < 1 {
+                return Err(format!(
+                    "incidents.json: incident '{}' schedules '{}' 0 weeks out (must be ≥ 1)",
+                    inc.id, follow_up.incident
+                ));
+            }
+            Ok(())
+        };
+        let check_region = |inc: &Incident, effects: &IncidentEffects| {
+            let Some(effect) = &effects.regional_fame else {
+                return Ok(());
+            };
+            let known = effect
+                .region
+                .split_once(':')
+                .is_some_and(|(country, region)| {
+                    markets
+                        .markets
+                        .get(country)
+                        .is_some_and(|market| market.regions.contains_key(region))
+                });
+            if !known {
+                return Err(format!(
+                    "incidents.json: incident '{}' names unknown region '{}'",
+                    inc.id, effect.region
+                ));
+            }
+            Ok(())
+        };
+        let targets: std::collections::HashSet<&str> = self
+            .incidents
+            .iter()
+            .flat_map(|inc| {
+                std::iter::once(&inc.follow_up).chain(inc.choices.iter().map(|c| &c.follow_up))
+            })
+            .filter_map(|follow_up| follow_up.as_ref().map(|f| f.incident.as_str()))
+            .collect();
+        for inc in &self.incidents {
+            if let Some(name) = inc.conditions.inverted_bound() {
+                return Err(format!(
+                    "incidents.json: incident '{}' condition has min_{} > max_{}",
+                    inc.id, name, name
+                ));
+            }
+            check_follow_up(inc, &inc.follow_up)?;
+            if inc.follow_up_only && !targets.contains(inc.id.as_str()) {
+                return Err(format!(
+                    "incidents.json: incident '{}' is follow_up_only but nothing schedules it",
+                    inc.id
+                ));
+            }
             if inc.weight < 1 {
                 return Err(format!(
                     "incidents.json: incident '{}' has weight {} (must be ≥ 1)",
                     inc.id, inc.weight
                 ));
             }
-            for (name, range) in inc.effects.ranges() {
-                if let Some([lo, hi]) = range
-                    && lo > hi
-                {
+            inc.effects.validate(
+                &format!("incident '{}'", inc.id),
+                &inc.message,
+                &inc.conditions,
+            )?;
+            check_region(inc, &inc.effects)?;
+            if inc.choices.is_empty() {
+                continue;
+            }
+            let defaults: Vec<&IncidentChoice> = inc.choices.iter().filter(|c| c.default).collect();
+            if defaults.len() != 1 {
+                return Err(format!(
+                    "incidents.json: incident '{}' has {} default choices (must be exactly 1)",
+                    inc.id,
+                    defaults.len()
+                ));
+            }
+            if defaults[0].cost > 0 || !defaults[0].conditions.is_unconditional() {
+                return Err(format!(
+                    "incidents.json: incident '{}' default choice '{}' must be free and unconditional",
+                    inc.id, defaults[0].label
+                ));
+            }
+            for choice in &inc.choices {
+                if choice.label.trim().is_empty() {
                     return Err(format!(
-                        "incidents.json: incident '{}' effect '{}' has lo {} > hi {}",
-                        inc.id, name, lo, hi
+                        "incidents.json: incident '{}' has a choice with no label",
+                        inc.id
                     ));
                 }
+                // A choice inherits the incident's gates: it is only ever
+                // offered once the incident itself has fired.
+                let gates = IncidentConditions {
+                    signed: choice.conditions.signed.or(inc.conditions.signed),
+                    has_release: choice.conditions.has_release.or(inc.conditions.has_release),
+                    ..IncidentConditions::default()
+                };
+                choice.effects.validate(
+                    &format!("incident '{}' choice '{}'", inc.id, choice.label),
+                    &choice.message,
+                    &gates,
+                )?;
+                check_region(inc, &choice.effects)?;
+                if let Some(name) = choice.conditions.inverted_bound() {
+                    return Err(format!(
+                        "incidents.json: incident '{}' choice '{}' condition has min_{} > max_{}",
+                        inc.id, choice.label, name, name
+                    ));
+                }
+                check_follow_up(inc, &choice.follow_up)?;
             }
         }
         Ok(())
     }
 
-    /// The incidents eligible for the current game state, in file order. The
-    /// weighted pick among these rolls on the action stream in `events.rs`.
-    pub fn eligible_incidents(&self, fame: u8, signed: bool) -> Vec<&Incident> {
+    /// The incidents eligible for a random roll in the current game state, in
+    /// file order: conditions hold, off cooldown (`last_fired` maps id → the
+    /// week it last fired), and not reserved for follow-ups. The weighted
+    /// pick among these rolls on the action stream in `events.rs`.
+    pub fn eligible_incidents(
+        &self,
+        ctx: &IncidentContext,
+        last_fired: &BTreeMap<String, u32>,
+        week: u32,
+    ) -> Vec<&Incident> {
         self.incidents
             .iter()
-            .filter(|inc| inc.matches(fame, signed))
+            .filter(|inc| {
+                !inc.follow_up_only
+                    && inc.matches(ctx)
+                    && inc.off_cooldown(last_fired.get(&inc.id).copied(), week)
+            })
             .collect()
     }
+
+    pub fn find(&self, id: &str) -> Option<&Incident> {
+        self.incidents.iter().find(|inc| inc.id == id)
+    }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct GameDataFiles {
     pub song_adjectives: Vec<String>,
     pub song_nouns: Vec<String>,
@@ -295,12 +818,14 @@ pub struct GameDataFiles {
     pub album_titles: Vec<String>,
     pub band_names: Vec<String>,
     pub band_member_names: Vec<String>,
+    pub manager_names: Vec<String>,
     pub venue_names: Vec<String>,
     pub city_names: Vec<String>,
     pub timeline_data: TimelineData,
     pub record_labels_data: RecordLabelsData,
     pub markets_data: MarketsData,
     pub incidents_data: IncidentsData,
+    pub tax_data: TaxData,
     /// Tracery grammars assembled from the word lists plus the editable
     /// pattern files. None only if grammar construction failed.
     pub band_name_grammar: Option<tracery::Grammar>,
@@ -351,21 +876,25 @@ impl GameDataFiles {
             album_titles: Self::load_text_file("data/album_titles.txt")?,
             band_names: Self::load_text_file("data/band_names.txt")?,
             band_member_names: Self::load_text_file("data/band_member_names.txt")?,
+            manager_names: Self::load_text_file("data/manager_names.txt")?,
             venue_names: Self::load_text_file("data/venue_names.txt")?,
             city_names: Self::load_text_file("data/city_names.txt")?,
             timeline_data: Self::load_json_file("data/timeline.json")?,
             record_labels_data: Self::load_json_file("data/record_labels.json")?,
             markets_data: Self::load_json_file("data/markets.json")?,
             incidents_data: Self::load_json_file("data/incidents.json")?,
+            tax_data: Self::load_json_file("data/tax.json")?,
             band_name_grammar: None,
             song_title_grammar: None,
         };
 
         // Fail fast on a malformed incident pool, in the style of the other
-        // loaders (§F): non-empty, weights ≥ 1, sane ranges, unique ids.
-        if let Err(msg) = files.incidents_data.validate() {
-            return Err(msg.into());
-        }
+        // loaders (§F): non-empty, weights ≥ 1, sane ranges, unique ids,
+        // known regions.
+        files.incidents_data.validate(&files.markets_data)?;
+        files
+            .tax_data
+            .validate(crate::data::constants::HOME_COUNTRY)?;
 
         let band_patterns =
             Self::load_pattern_file(BAND_NAME_PATTERNS_PATH, DEFAULT_BAND_NAME_PATTERNS)?;
@@ -505,6 +1034,10 @@ impl GameDataFiles {
         self.band_member_names[rng.gen_range(0..self.band_member_names.len())].clone()
     }
 
+    pub fn random_manager_name(&self, rng: &mut impl Rng) -> String {
+        self.manager_names[rng.gen_range(0..self.manager_names.len())].clone()
+    }
+
     pub fn get_timeline_data(&self) -> &TimelineData {
         &self.timeline_data
     }
@@ -523,12 +1056,14 @@ impl GameDataFiles {
             "data/album_titles.txt",
             "data/band_names.txt",
             "data/band_member_names.txt",
+            "data/manager_names.txt",
             "data/venue_names.txt",
             "data/city_names.txt",
             "data/timeline.json",
             "data/record_labels.json",
             "data/markets.json",
             "data/incidents.json",
+            "data/tax.json",
         ];
 
         for file in &required_files {
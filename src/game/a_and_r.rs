@@ -0,0 +1,306 @@
+//! A&R: what a label's creative freedom and artist development do while the
+//! band is signed. A label that gives little freedom leans on every record
+//! the band makes under it — how hard is the freedom it withholds, scaled by
+//! the deal's creative-control clause. When the band records, its A&R puts
+//! one demand on the record: drop the concept, change the genre to one the
+//! label sells, take its house producer, or let it sequence the album. The
+//! band answers before the week is out: comply and the record changes;
+//! defy and it goes out the band's way, but the label writes memos, won't
+//! count a defied album toward the contract, and shortens the term — the
+//! deal clock (`label_moves.rs`) does the rest. Leave it unanswered and the
+//! label's way stands. A label strong on artist development makes every
+//! week in the rehearsal room count for more.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::genre::MusicGenre;
+use super::music::ReleaseType;
+use super::world::find_label_with_tier;
+use super::*;
+use crate::data_loader::RecordLabel;
+
+/// What A&R wants changed on a record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LabelDemandKind {
+    /// Turn the concept album back into a collection of songs.
+    DropConcept,
+    /// Recut it as something the label knows how to sell.
+    ChangeGenre(MusicGenre),
+    /// Let the label's house producer have it.
+    HouseProducer,
+    /// Let the label sequence the album, the singles up front.
+    Tracklist,
+}
+
+impl LabelDemandKind {
+    /// The demand as the label's memo puts it.
+    pub fn memo(&self) -> String {
+        match self {
+            LabelDemandKind::DropConcept => {
+                "Nobody buys a concept. Lose the story and give us songs.".to_string()
+            }
+            LabelDemandKind::ChangeGenre(genre) => {
+                format!("We don't know how to sell this. Make it {}.", genre.name())
+            }
+            LabelDemandKind::HouseProducer => {
+                "It isn't finished. Our producer will take it from here.".to_string()
+            }
+            LabelDemandKind::Tracklist => {
+                "Singles first. We're resequencing the album.".to_string()
+            }
+        }
+    }
+
+    /// What complying does to the record, in a line.
+    pub fn if_complied(&self) -> String {
+        match self {
+            LabelDemandKind::DropConcept => format!(
+                "The record loses its story, and the band -{} creativity.",
+                AR_DROP_CONCEPT_CREATIVITY_COST
+            ),
+            LabelDemandKind::ChangeGenre(genre) => {
+                format!("The record goes out as {}.", genre.name())
+            }
+            LabelDemandKind::HouseProducer => format!(
+                "The producer pulls it toward {} quality; the ${} fee is added to what you owe the label.",
+                AR_PRODUCER_QUALITY, AR_PRODUCER_FEE
+            ),
+            LabelDemandKind::Tracklist => format!(
+                "+{} promo push, -{} quality.",
+                AR_TRACKLIST_PUSH, AR_TRACKLIST_QUALITY_COST
+            ),
+        }
+    }
+}
+
+/// A demand on a just-recorded release, waiting on the band's answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelDemand {
+    pub label_name: String,
+    pub release_id: u32,
+    pub release_name: String,
+    pub release_type: ReleaseType,
+    pub kind: LabelDemandKind,
+}
+
+/// The genres a label's specialties map to.
+fn label_genres(label: &RecordLabel) -> Vec<MusicGenre> {
+    label
+        .specialty_genres
+        .iter()
+        .flat_map(|specialty| {
+            let key = specialty.to_lowercase().replace([' ', '-'], "_");
+            MusicGenre::ALL.into_iter().filter(move |genre| {
+                genre.name().to_lowercase() == key || genre.aliases().contains(&key.as_str())
+            })
+        })
+        .collect()
+}
+
+impl Game {
+    /// How hard the band's label leans on its records: the creative freedom
+    /// it doesn't give, scaled by the deal's clause. 0 unsigned.
+    pub fn ar_pressure(&self) -> f32 {
+        let Some(deal) = self.band.current_deal() else {
+            return 0.0;
+        };
+        let Some((_, label)) = find_label_with_tier(&self.data_files, &deal.label_name) else {
+            return 0.0;
+        };
+        f32::from(100 - label.creative_freedom.min(100))
+            * AR_CLAUSE_PRESSURE[deal.creative_control as usize]
+    }
+
+    /// The band skill a week of rehearsal adds on top, from the label's
+    /// artist development. 0 unsigned.
+    pub fn development_skill_bonus(&self) -> u8 {
+        self.band
+            .current_deal()
+            .and_then(|deal| find_label_with_tier(&self.data_files, &deal.label_name))
+            .map_or(0, |(_, label)| {
+                label
+                    .artist_development
+                    .saturating_sub(AR_DEVELOPMENT_BASELINE)
+                    / AR_DEVELOPMENT_PER_SKILL
+            })
+    }
+
+    /// A&R hears the record the band just made under the deal and puts its
+    /// one demand on it, the most pressing first. Studio records only — a
+    /// live record is what the night was.
+    pub(super) fn ar_review(&mut self, concept: bool) {
+        let Some(deal) = self.band.current_deal() else {
+            return;
+        };
+        let Some((_, label)) = find_label_with_tier(&self.data_files, &deal.label_name) else {
+            return;
+        };
+        let Some(release) = self.just_released_music.last() else {
+            return;
+        };
+        let pressure = self.ar_pressure();
+        let album = release.release_type == ReleaseType::Album;
+        let genres = label_genres(label);
+        let kind = if album && concept && pressure >= AR_CONCEPT_MIN_PRESSURE {
+            LabelDemandKind::DropConcept
+        } else if pressure >= AR_GENRE_MIN_PRESSURE
+            && !genres.is_empty()
+            && !genres.contains(&self.band.genre)
+        {
+            LabelDemandKind::ChangeGenre(genres[0].clone())
+        } else if pressure >= AR_PRODUCER_MIN_PRESSURE
+            && release.release_quality < AR_PRODUCER_QUALITY
+        {
+            LabelDemandKind::HouseProducer
+        } else if album && pressure >= AR_TRACKLIST_MIN_PRESSURE {
+            LabelDemandKind::Tracklist
+        } else {
+            return;
+        };
+        let demand = LabelDemand {
+            label_name: deal.label_name.clone(),
+            release_id: release.id,
+            release_name: release.name.clone(),
+            release_type: release.release_type,
+            kind,
+        };
+        self.log(format!(
+            "📠 {} A&R on '{}': '{}'",
+            demand.label_name,
+            demand.release_name,
+            demand.kind.memo()
+        ));
+        self.pending_label_demand = Some(demand);
+    }
+
+    /// Answer A&R: comply and the record changes; defy and it goes out as
+    /// it is, at a cost to the deal. Instant.
+    pub(super) fn action_answer_label_demand(&mut self, comply: bool) -> Result<(), String> {
+        let demand = self
+            .pending_label_demand
+            .take()
+            .ok_or("A&R isn't asking for anything.")?;
+        if self
+            .band
+            .current_deal()
+            .is_none_or(|deal| deal.label_name != demand.label_name)
+        {
+            return Err(format!("You're not with {} any more.", demand.label_name));
+        }
+        if comply {
+            self.comply_with_label_demand(&demand);
+        } else {
+            self.defy_label_demand(&demand);
+        }
+        Ok(())
+    }
+
+    /// Time moves on and the label's way stands.
+    pub(super) fn resolve_label_demand_by_default(&mut self) {
+        let Some(demand) = self.pending_label_demand.take() else {
+            return;
+        };
+        if self
+            .band
+            .current_deal()
+            .is_some_and(|deal| deal.label_name == demand.label_name)
+        {
+            self.log(format!(
+                "📠 You never answered {}'s A&R — it does it its way.",
+                demand.label_name
+            ));
+            self.comply_with_label_demand(&demand);
+        }
+    }
+
+    fn comply_with_label_demand(&mut self, demand: &LabelDemand) {
+        let Some(release) = self
+            .just_released_music
+            .iter_mut()
+            .find(|release| release.id == demand.release_id)
+        else {
+            return;
+        };
+        match &demand.kind {
+            LabelDemandKind::DropConcept => {
+                self.player.creativity = self
+                    .player
+                    .creativity
+                    .saturating_sub(AR_DROP_CONCEPT_CREATIVITY_COST);
+            }
+            LabelDemandKind::ChangeGenre(genre) => release.genre = Some(genre.clone()),
+            LabelDemandKind::HouseProducer => {
+                release.release_quality = (u16::from(release.release_quality)
+                    + u16::from(AR_PRODUCER_QUALITY))
+                .div_ceil(2) as u8;
+            }
+            LabelDemandKind::Tracklist => {
+                release.marketing_level_achieved = release
+                    .marketing_level_achieved
+                    .saturating_add(AR_TRACKLIST_PUSH);
+                release.release_quality = release
+                    .release_quality
+                    .saturating_sub(AR_TRACKLIST_QUALITY_COST)
+                    .max(1);
+            }
+        }
+        if demand.kind == LabelDemandKind::HouseProducer
+            && let Some(deal) = self.band.record_deal.as_mut()
+        {
+            deal.unrecouped += AR_PRODUCER_FEE;
+        }
+        self.log(format!(
+            "🎚️ '{}' goes out {}'s way. {}",
+            demand.release_name,
+            demand.label_name,
+            demand.kind.if_complied()
+        ));
+    }
+
+    fn defy_label_demand(&mut self, demand: &LabelDemand) {
+        let album = demand.release_type == ReleaseType::Album;
+        if album
+            && let Some(release) = self
+                .just_released_music
+                .iter_mut()
+                .find(|release| release.id == demand.release_id)
+        {
+            release.outside_deal = true;
+        }
+        let Some(deal) = self.band.record_deal.as_mut() else {
+            return;
+        };
+        deal.defiances = deal.defiances.saturating_add(1);
+        let term_cut = deal.term_weeks > 0;
+        if term_cut {
+            deal.term_weeks = deal
+                .term_weeks
+                .saturating_sub(AR_DEFIANCE_TERM_CUT_WEEKS)
+                .max(1);
+        }
+        let term_end_week = deal.term_end_week();
+        self.log(format!(
+            "✊ '{}' goes out the way you made it.",
+            demand.release_name
+        ));
+        if album {
+            self.log(format!(
+                "📠 {}: 'That isn't the album we paid for. It doesn't count toward your contract.'",
+                demand.label_name
+            ));
+        }
+        if term_cut {
+            self.log(format!(
+                "📠 {}: 'Our patience has a term too.' The contract now runs out in week {}.",
+                demand.label_name, term_end_week
+            ));
+        }
+    }
+
+    /// Whether an album recorded now is a concept album: the band's
+    /// creativity is running high enough for a big idea.
+    pub(super) fn records_concept_album(&self) -> bool {
+        self.player.creativity >= CONCEPT_ALBUM_MIN_CREATIVITY
+    }
+}
@@ -9,7 +9,8 @@ use super::super::*;
 impl Game {
     /// The fee due at release under an indie distribution channel (design
     /// §E-3, M6) — `Ok(0)` while signed, since a label deal's `market_reach`
-    /// ignores channels entirely. Validates the fame gate here too, so a
+    /// ignores channels entirely. Validates the gate here too (fame, or
+    /// for the own label having founded it — `distribution_lock`), so a
     /// stale channel choice (fame slipped, or a pre-M6 default) can never
     /// charge — or silently apply — a channel the band no longer qualifies
     /// for. `pub` (not `pub(in crate::game)`): the picker UI previews this
@@ -18,12 +19,8 @@ impl Game {
         if self.band.current_deal().is_some() {
             return Ok(0);
         }
-        if !channel.is_available(self.band.fame) {
-            return Err(format!(
-                "{} needs {} fame — you're not there yet.",
-                channel.label(),
-                channel.fame_gate()
-            ));
+        if let Some(lock) = self.distribution_lock(channel) {
+            return Err(lock);
         }
         Ok(channel.fee())
     }
@@ -106,7 +103,7 @@ impl Game {
                 cost
             ));
         }
-        self.player.spend_money(cost);
+        self.post(LedgerCategory::Recording, -cost, "Re-press");
 
         let release = self
             .band
@@ -136,10 +133,11 @@ impl Game {
             ));
         }
         let spec = campaign_type.spec();
-        if !self.player.can_afford(spec.cost) {
+        let cost = self.marketing_cost(campaign_type);
+        if !self.player.can_afford(cost) {
             return Err(format!(
                 "Not enough money for a {} campaign. Need ${}.",
-                spec.name, spec.cost
+                spec.name, cost
             ));
         }
 
@@ -183,10 +181,14 @@ impl Game {
             .min(100) as u8;
         let release_name = release.name.clone();
 
-        self.player.spend_money(spec.cost);
+        self.post(
+            LedgerCategory::Marketing,
+            -cost,
+            format!("{} for '{}'", spec.name, release_name),
+        );
         self.log(format!(
             "📣 {} campaign launched for '{}' — ${}, runs {} weeks, +{} buzz.",
-            spec.name, release_name, spec.cost, spec.duration_weeks, spec.effectiveness_bonus
+            spec.name, release_name, cost, spec.duration_weeks, spec.effectiveness_bonus
         ));
         Ok(())
     }
@@ -235,9 +237,12 @@ impl Game {
             // legacy-policy deal (see `RecordDeal::term_weeks`).
             signed_week,
             term_weeks,
+            creative_control: offer.creative_control,
+            defiances: 0,
         };
         self.band.sign_deal(new_deal);
-        self.player.earn_money(advance);
+        self.bank_income(advance, LedgerCategory::Advances, label_name.as_str());
+        self.world.debit_label(&label_name, i64::from(advance));
         self.pending_deal_offers.clear();
         self.log(format!(
             "✍️ Signed with {}! ${} advance in the bank — you owe them {} album{} over {} weeks.",
@@ -247,9 +252,45 @@ impl Game {
             if albums_required == 1 { "" } else { "s" },
             term_weeks
         ));
+        self.log_label_mates(&label_name);
         Ok(())
     }
 
+    /// The scene acts the band knows from the road who already record for
+    /// a label it just signed with.
+    fn log_label_mates(&mut self, label_name: &str) {
+        let on_roster = |friend: bool| -> Vec<String> {
+            self.world
+                .bands
+                .iter()
+                .filter(|band| band.label.as_deref() == Some(label_name))
+                .filter(|band| {
+                    if friend {
+                        band.is_friend()
+                    } else {
+                        band.is_rival()
+                    }
+                })
+                .map(|band| band.name.clone())
+                .collect()
+        };
+        let (friends, rivals) = (on_roster(true), on_roster(false));
+        if !friends.is_empty() {
+            self.log(format!(
+                "🤝 Old friends on the {} roster: {} — they'll be glad to see you.",
+                label_name,
+                friends.join(", ")
+            ));
+        }
+        if !rivals.is_empty() {
+            self.log(format!(
+                "😠 {} record for {} too — expect a frosty label party.",
+                rivals.join(", "),
+                label_name
+            ));
+        }
+    }
+
     pub(in crate::game) fn action_reject_deal(
         &mut self,
         offer_index: usize,
@@ -262,9 +303,15 @@ impl Game {
         self.log(format!("🚫 Turned down {}'s offer.", offer.label_name));
 
         if let Some(poaching_band) = self.world.poach_rejected_deal(&offer.label_name, rng) {
+            let rival = self
+                .world
+                .scene_band(&poaching_band)
+                .is_some_and(|band| band.is_rival());
             self.log(format!(
-                "📰 NEWS: {} signed with {} after you turned them down!",
-                poaching_band, offer.label_name
+                "📰 NEWS: {}{} signed with {} after you turned them down!",
+                if rival { "Your rivals " } else { "" },
+                poaching_band,
+                offer.label_name
             ));
         }
         Ok(())
@@ -283,9 +330,14 @@ impl Game {
         if self.player.health < TOUR_HEALTH_GUARD {
             return Err("You're too unwell to head out on the road!".to_string());
         }
+        self.check_festival_clash(offer.weeks + 1)?;
         self.pending_support_offer = None;
 
-        self.player.earn_money(offer.pay);
+        self.bank_income(
+            offer.pay,
+            LedgerCategory::Shows,
+            format!("Supporting {}", offer.host_band),
+        );
         // A support run is touring: same weekly stress and wear as a headline tour.
         let weeks = offer.weeks as u8;
         self.player.stress = (self.player.stress
@@ -321,4 +373,195 @@ impl Game {
         self.log(format!("🚫 Passed on {}'s support slot.", offer.host_band));
         Ok(())
     }
+
+    /// Take on a pitching manager (FUTURE §9.4). One at a time — the rest
+    /// of the slate walks. Their commission starts with the next dollar in.
+    pub(in crate::game) fn action_hire_manager(
+        &mut self,
+        offer_index: usize,
+    ) -> Result<(), String> {
+        if let Some(manager) = &self.manager {
+            return Err(format!(
+                "{} already manages you — fire them first.",
+                manager.name
+            ));
+        }
+        if offer_index >= self.pending_manager_offers.len() {
+            return Err("Invalid manager offer selected.".to_string());
+        }
+        let offer = self.pending_manager_offers.remove(offer_index);
+        self.pending_manager_offers.clear();
+        let manager = offer.into_manager(self.week);
+        self.log(format!(
+            "🤝 {} is your manager now — {:.0}% of everything you earn.",
+            manager.name,
+            manager.cut * 100.0
+        ));
+        self.manager = Some(manager);
+        Ok(())
+    }
+
+    pub(in crate::game) fn action_reject_manager(
+        &mut self,
+        offer_index: usize,
+    ) -> Result<(), String> {
+        if offer_index >= self.pending_manager_offers.len() {
+            return Err("Invalid manager offer selected.".to_string());
+        }
+        let offer = self.pending_manager_offers.remove(offer_index);
+        self.log(format!("🚫 Sent {} packing.", offer.name));
+        Ok(())
+    }
+
+    /// Let the manager go (FUTURE §9.4): the settlement is owed whether or
+    /// not the bank balance can cover it, and nobody else calls until the
+    /// cooldown runs out. A skim nobody caught stays uncaught.
+    pub(in crate::game) fn action_fire_manager(&mut self) -> Result<(), String> {
+        let severance = self.manager_severance();
+        let Some(manager) = self.manager.take() else {
+            return Err("You don't have a manager to fire.".to_string());
+        };
+        self.post(
+            LedgerCategory::Manager,
+            -(severance as i32),
+            format!("Settling with {}", manager.name),
+        );
+        self.manager_cooldown = constants::MANAGER_COOLDOWN_WEEKS;
+        self.log(format!(
+            "✂️ Fired {} — ${} to settle their contract. Word gets around; other managers will keep their distance for a while.",
+            manager.name, severance
+        ));
+        Ok(())
+    }
+
+    /// Buy a piece of gear new. One of each; a vehicle waits on its rig's
+    /// fame gate, since nobody books a tour bus for an unknown band.
+    pub(in crate::game) fn action_buy_gear(&mut self, kind: GearKind) -> Result<(), String> {
+        if self.owns_gear(kind) {
+            return Err(format!(
+                "You already own a {}.",
+                kind.label().to_lowercase()
+            ));
+        }
+        if let Some(rig) = kind.rig()
+            && !self.rig_is_available(rig)
+        {
+            return Err(format!(
+                "A {} is no use until you can tour that way (fame {}).",
+                kind.label().to_lowercase(),
+                rig.fame_gate()
+            ));
+        }
+        let price = kind.price();
+        if !self.player.can_afford(price as i32) {
+            return Err(format!(
+                "A {} costs ${}.",
+                kind.label().to_lowercase(),
+                price
+            ));
+        }
+        self.post(LedgerCategory::Gear, -(price as i32), kind.label());
+        self.gear.push(GearItem::new(kind));
+        self.log(format!(
+            "🛒 Bought a {} for ${}.",
+            kind.label().to_lowercase(),
+            price
+        ));
+        Ok(())
+    }
+
+    /// Sell a piece of gear for a share of what it's worth. The band keeps
+    /// its instruments.
+    pub(in crate::game) fn action_sell_gear(&mut self, kind: GearKind) -> Result<(), String> {
+        if kind == GearKind::Instruments {
+            return Err("You can't play without instruments.".to_string());
+        }
+        let Some(index) = self.gear.iter().position(|item| item.kind == kind) else {
+            return Err(format!("You don't own a {}.", kind.label().to_lowercase()));
+        };
+        let item = self.gear.remove(index);
+        let resale = (item.value() as f32 * constants::GEAR_RESALE_SHARE).round() as u32;
+        self.post(LedgerCategory::Gear, resale as i32, kind.label());
+        self.log(format!(
+            "💵 Sold the {} for ${}.",
+            kind.label().to_lowercase(),
+            resale
+        ));
+        Ok(())
+    }
+
+    /// Service the whole kit back to mint condition.
+    pub(in crate::game) fn action_service_gear(&mut self) -> Result<(), String> {
+        let cost = self.gear_service_cost();
+        if cost == 0 {
+            return Err("Your gear is already in top shape.".to_string());
+        }
+        if !self.player.can_afford(cost as i32) {
+            return Err(format!("A full service costs ${}.", cost));
+        }
+        self.post(LedgerCategory::Gear, -(cost as i32), "Service");
+        for item in &mut self.gear {
+            item.condition = 100.0;
+        }
+        self.log(format!(
+            "🔧 The whole kit serviced for ${} — good as new.",
+            cost
+        ));
+        Ok(())
+    }
+
+    pub(in crate::game) fn action_hire_crew(&mut self, role: CrewRole) -> Result<(), String> {
+        if self.band.fame < role.fame_gate() {
+            return Err(format!(
+                "No {} will sign on with a band under {} fame.",
+                role.label().to_lowercase(),
+                role.fame_gate()
+            ));
+        }
+        if self.crew_count(role) >= role.max_hired() {
+            return Err(format!(
+                "You can't use another {}.",
+                role.label().to_lowercase()
+            ));
+        }
+        self.crew.push(role);
+        self.log(format!(
+            "🧳 Hired a {} — ${} a week.",
+            role.label().to_lowercase(),
+            role.wage()
+        ));
+        Ok(())
+    }
+
+    pub(in crate::game) fn action_fire_crew(&mut self, role: CrewRole) -> Result<(), String> {
+        let Some(index) = self.crew.iter().position(|&hired| hired == role) else {
+            return Err(format!("You don't have a {}.", role.label().to_lowercase()));
+        };
+        self.crew.remove(index);
+        self.log(format!("✂️ Let the {} go.", role.label().to_lowercase()));
+        Ok(())
+    }
+
+    pub(in crate::game) fn action_set_gear_insurance(
+        &mut self,
+        insured: bool,
+    ) -> Result<(), String> {
+        if self.gear_insured == insured {
+            return Err(if insured {
+                "Your gear is already insured.".to_string()
+            } else {
+                "Your gear isn't insured.".to_string()
+            });
+        }
+        self.gear_insured = insured;
+        if insured {
+            self.log(format!(
+                "🛡️ Gear insured — ${} a week at today's value.",
+                self.gear_insurance_premium()
+            ));
+        } else {
+            self.log("🛡️ Gear insurance cancelled.".to_string());
+        }
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@
 
 use crate::game::music::ReleaseType;
 use crate::game::shows::{self, ShowVerdict};
+use crate::game::world::{SceneBand, VenueStanding};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -84,6 +85,110 @@ impl TourRig {
     }
 }
 
+/// What a tour charges at the door. One tier per tour: it sets each
+/// show's price and, through `reception_attendance_factor`, how many people
+/// turn up — the merch table sells to whoever is in the room.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TicketTier {
+    Cheap,
+    #[default]
+    Standard,
+    Premium,
+}
+
+impl TicketTier {
+    /// In picker order, cheapest first.
+    pub const ALL: [TicketTier; 3] = [TicketTier::Cheap, TicketTier::Standard, TicketTier::Premium];
+
+    /// Index into the `TICKET_TIER_*` const tables and `ALL`.
+    pub fn ordinal(self) -> usize {
+        match self {
+            TicketTier::Cheap => 0,
+            TicketTier::Standard => 1,
+            TicketTier::Premium => 2,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TicketTier::Cheap => "Cheap seats",
+            TicketTier::Standard => "Standard",
+            TicketTier::Premium => "Premium",
+        }
+    }
+
+    pub fn price_mult(self) -> f32 {
+        TICKET_TIER_PRICE_MULT[self.ordinal()]
+    }
+
+    pub fn demand_mult(self) -> f32 {
+        TICKET_TIER_DEMAND_MULT[self.ordinal()]
+    }
+
+    /// A show's attendance factor at this price: the reception-driven
+    /// factor, scaled by how much the price puts people off (or draws
+    /// them in).
+    fn attendance_factor(self, reception: u8) -> f32 {
+        shows::reception_attendance_factor(reception) * self.demand_mult()
+    }
+}
+
+/// The terms a tour is booked on beyond its route and rig: the ticket
+/// tier, how much merch to order before leaving, whether to tape the shows
+/// for a live record, which scene band opens, and what the band plays. The
+/// default is the plain tour — standard tickets, no order, no tape, no
+/// opener, the usual set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TourTerms {
+    pub tickets: TicketTier,
+    pub merch_order: u32,
+    #[serde(default)]
+    pub recorded: bool,
+    /// The scene band opening every show, by name.
+    #[serde(default)]
+    pub opener: Option<String>,
+    /// The songs the band plays every night; empty is the usual set
+    /// (`setlist.rs`).
+    #[serde(default)]
+    pub setlist: Vec<SetlistSong>,
+}
+
+impl TourTerms {
+    /// The merch order sizes on offer, smallest first.
+    pub const MERCH_ORDERS: [u32; MERCH_ORDER_STEPS.len()] = MERCH_ORDER_STEPS;
+}
+
+/// How a promoter pays for a headline tour they book: a fixed fee whatever
+/// the door does, or a share of the net — the gross less the tour's costs.
+/// Either way the promoter carries the costs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PromoterDeal {
+    Guarantee(u32),
+    /// The band's share of the net, 0.0-1.0.
+    Percentage(f32),
+}
+
+impl PromoterDeal {
+    /// What the band takes home from a tour that grossed this much against
+    /// these costs. A percentage of a loss is nothing.
+    pub fn band_take(self, gross: u32, cost: i32) -> u32 {
+        match self {
+            PromoterDeal::Guarantee(fee) => fee,
+            PromoterDeal::Percentage(share) => {
+                let net = (i64::from(gross) - i64::from(cost)).max(0);
+                (net as f32 * share).round() as u32
+            }
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            PromoterDeal::Guarantee(fee) => format!("${} guaranteed", fee),
+            PromoterDeal::Percentage(share) => format!("{:.0}% of the net", share * 100.0),
+        }
+    }
+}
+
 /// The up-front quote a tour picker must show before booking (design §A):
 /// itemized cost, weeks, shows, and a projected gross range computed from
 /// the same formula the tour uses, at momentum 1.0, ± the reception spread
@@ -104,6 +209,52 @@ pub struct TourQuote {
     pub regional_fame_gain_max: u8,
 }
 
+/// One stop on a routed tour: a region (an index into `get_sorted_regions`)
+/// and how many weeks to play it. A plain tour is a route of one stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TourLeg {
+    pub region_index: usize,
+    pub weeks: u8,
+}
+
+/// The trip between two stops on a routed tour, priced from the rig's
+/// `touring_costs` (after any manager discount).
+#[derive(Debug, Clone)]
+pub struct TravelLeg {
+    pub from: String,
+    pub to: String,
+    pub international: bool,
+    pub cost: i32,
+}
+
+/// The up-front quote for a whole route, the same way `TourQuote` quotes a
+/// single region: one `TourQuote` per stop, the travel legs between them,
+/// and the totals `action_tour_route` will actually charge — merch order
+/// included, gross ranges priced at the route's ticket tier. Fame gain is
+/// the route's own — it follows the total weeks on the road, not the sum
+/// of the stops'.
+#[derive(Debug, Clone)]
+pub struct RouteQuote {
+    pub rig: TourRig,
+    pub terms: TourTerms,
+    /// The merch order's cost — already part of `cost`.
+    pub merch_cost: i32,
+    /// The mobile studio's fee when the tour is taped — already part of
+    /// `cost`.
+    pub recording_cost: i32,
+    /// The opener's fees — already part of `cost`. Their draw is already
+    /// in every stop's gross.
+    pub opener_cost: i32,
+    pub stops: Vec<TourQuote>,
+    pub travel: Vec<TravelLeg>,
+    pub weeks: u8,
+    pub shows: u32,
+    pub cost: i32,
+    pub gross_low: u32,
+    pub gross_high: u32,
+    pub fame_gain: u8,
+}
+
 /// The deterministic (no-rng) core of a tour booking: everything the quote
 /// and the actual booking must agree on. Shared by `quote_tour` (read-only,
 /// for the picker) and `action_go_on_tour` (which then layers per-show rng
@@ -114,7 +265,10 @@ struct TourPot {
     fame_req: u8,
     cost: i32,
     shows_total: u32,
-    synth_capacity: u32,
+    /// The region's typical room, before the rig's capacity multiplier —
+    /// what its venue roster is sized around.
+    typical_capacity: u32,
+    venue_density: u8,
     total_potential_gross: f32,
     fame_gain: u8,
     regional_fame_gain_base: u16,
@@ -122,6 +276,20 @@ struct TourPot {
     regional_fame_current: u8,
 }
 
+/// `TourPot` for a whole route: one pot per stop, plus the travel between
+/// them and the route-wide totals. Shared by `quote_route` and
+/// `action_tour_route` for the same reason `TourPot` is.
+struct RoutePot {
+    stops: Vec<(TourPot, u8)>,
+    travel: Vec<TravelLeg>,
+    weeks: u8,
+    merch_cost: i32,
+    recording_cost: i32,
+    opener_cost: i32,
+    cost: i32,
+    fame_gain: u8,
+}
+
 impl Game {
     pub fn get_sorted_regions(&self) -> Vec<(String, String, String, u32, u8, u8)> {
         let mut result = Vec::new();
@@ -216,8 +384,30 @@ impl Game {
         }
     }
 
-    /// A synthesized tour-stop venue's capacity, drawn from the region's
-    /// population and economic strength (design §B — Box office). [tune]
+    /// What one merch item sells for right now: the base price through the
+    /// economic cycle's `merchandise_modifier` (`markets.json`).
+    fn merch_unit_price(&self) -> f32 {
+        let cycle = self
+            .data_files
+            .markets_data
+            .market_modifiers
+            .economic_cycle_effects
+            .get(self.world.music_market.economic_state.markets_key())
+            .map_or(1.0, |effect| effect.merchandise_modifier);
+        MERCH_UNIT_PRICE * cycle
+    }
+
+    /// Sell one show's merch out of stock: (units sold, revenue). The
+    /// revenue is banked by the caller with the rest of the night's take.
+    fn sell_merch(&mut self, attendance: u32, verdict: ShowVerdict, unit_price: f32) -> (u32, u32) {
+        let units = shows::merch_units(attendance, self.band.fame, verdict, self.merch_stock);
+        self.merch_stock -= units;
+        (units, (units as f32 * unit_price).round() as u32)
+    }
+
+    /// A region's typical tour room, drawn from its population and
+    /// economic strength (design §B — Box office); its venue roster spreads
+    /// around this. [tune]
     fn synth_tour_venue_capacity(population: u32, economic_strength: u8) -> u32 {
         let raw = (population as f32 / TOUR_VENUE_CAPACITY_POP_DIVISOR)
             * (economic_strength as f32 / 100.0);
@@ -227,15 +417,33 @@ impl Game {
         ) as u32
     }
 
-    /// A synthesized tour-stop venue's name: flavor from the region/city
-    /// name lists, since a tour doesn't book from the home scene's fixed
-    /// five venues (design §B — Box office).
-    fn synth_tour_venue_name(&self, region_name: &str, rng: &mut impl Rng) -> String {
-        let venue_names = &self.data_files.venue_names;
-        let city_names = &self.data_files.city_names;
-        let venue = &venue_names[rng.gen_range(0..venue_names.len())];
-        let city = &city_names[rng.gen_range(0..city_names.len())];
-        format!("{venue} ({city}, {region_name})")
+    /// Pick tonight's room from a toured region's roster (building the
+    /// roster on the band's first visit): any venue that hasn't banned
+    /// them. A region where every room has banned them won't book them.
+    fn book_region_venue(
+        &mut self,
+        pot: &TourPot,
+        week: u32,
+        rng: &mut impl Rng,
+    ) -> Result<usize, String> {
+        let roster = self.world.region_venues(
+            &pot.regional_fame_key,
+            &pot.region_name,
+            pot.venue_density,
+            pot.typical_capacity,
+            &self.data_files,
+            rng,
+        );
+        let open: Vec<usize> = (0..roster.len())
+            .filter(|&i| !roster[i].is_banned(week))
+            .collect();
+        if open.is_empty() {
+            return Err(format!(
+                "Every room in {} has banned you — route around it.",
+                pot.region_name
+            ));
+        }
+        Ok(open[rng.gen_range(0..open.len())])
     }
 
     pub(in crate::game) fn action_play_gig(
@@ -252,13 +460,24 @@ impl Game {
         if venue_index >= self.world.venues.len() {
             return Err("Invalid venue selected.".to_string());
         }
+        if self.in_tax_exile() {
+            return Err(
+                "You're a tax exile — play a home show and the taxman has you.".to_string(),
+            );
+        }
         let venue = &self.world.venues[venue_index];
-        if venue.prestige > self.band.fame.saturating_add(20) {
+        if !self.venue_in_reach(venue) {
             return Err(format!(
                 "'{}' is out of your league! Get more famous first.",
                 venue.name
             ));
         }
+        if venue.is_banned(self.week) {
+            return Err(format!(
+                "'{}' won't book you again until week {}.",
+                venue.name, venue.banned_until
+            ));
+        }
 
         let era_modifier = self.timeline.get_gig_pay_modifier();
         let market_modifier = self.world.get_market_modifier();
@@ -268,21 +487,21 @@ impl Game {
 
         let base_ratio = ((self.band.fame as f32 + 10.0) / (venue.prestige as f32 + 10.0)).min(1.0);
 
-        let reception = shows::compute_reception(
-            &self.band,
-            self.player.stress,
-            self.player.health,
-            era_genre_modifier,
-            self.player.creativity,
-            rng,
-        );
+        let (reception, breakdown) = self.roll_show_reception(era_genre_modifier, None, rng);
+        self.wear_gear(1, None, 0);
+        let venue = &self.world.venues[venue_index];
         let verdict = ShowVerdict::from_reception(reception);
         let attendance_factor = shows::reception_attendance_factor(reception);
         let attendance_ratio = (base_ratio * attendance_factor).clamp(0.0, 1.0);
 
         let attendance = (venue.capacity as f32 * attendance_ratio) as u32;
-        let earnings =
-            (venue.base_payment as f32 * attendance_ratio * market_modifier * era_modifier) as u32;
+        // The promoter's opinion (and any return booking) moves the fee.
+        let earnings = (venue.base_payment as f32
+            * attendance_ratio
+            * market_modifier
+            * era_modifier
+            * venue.fee_mult(self.week)) as u32;
+        let invited = venue.has_invite(self.week);
 
         let base_fame_gain = if venue.capacity <= 200 {
             1
@@ -305,7 +524,12 @@ impl Game {
         let venue_name = venue.name.clone();
         let capacity = venue.capacity;
 
-        self.player.earn_money(earnings);
+        let merch_price = self.merch_unit_price();
+        let (merch_sold, merch) = self.sell_merch(attendance, verdict, merch_price);
+        self.bank_income(earnings, LedgerCategory::Shows, venue_name.as_str());
+        if merch > 0 {
+            self.bank_income(merch, LedgerCategory::Merch, venue_name.as_str());
+        }
         // Report the fame actually applied (comeback doubling, caps), not
         // the raw pre-multiplier gain.
         let fame_applied = self
@@ -324,6 +548,7 @@ impl Game {
                 attendance,
                 capacity,
                 take: earnings,
+                merch,
             }],
             fame_applied,
         ));
@@ -349,9 +574,348 @@ impl Game {
                 venue_name, verdict.label(), attendance, capacity, earnings
             ));
         }
+        if invited {
+            self.log(format!(
+                "📅 A return booking at '{}' — the promoter paid a little extra.",
+                venue_name
+            ));
+        }
+        match self.world.venues[venue_index].record_show(reception, verdict, self.week) {
+            VenueStanding::InvitedBack => self.log(format!(
+                "📅 '{}' wants you back — play there again within {} weeks for a better fee.",
+                venue_name, VENUE_INVITE_WEEKS
+            )),
+            VenueStanding::Banned => self.log(format!(
+                "🚫 '{}' has had enough of you — banned for {} weeks.",
+                venue_name, VENUE_BAN_WEEKS
+            )),
+            VenueStanding::Unchanged => {}
+        }
+        if merch_sold > 0 {
+            self.log(format!(
+                "👕 Sold {} item{} at the merch table for ${}.",
+                merch_sold,
+                if merch_sold == 1 { "" } else { "s" },
+                merch
+            ));
+        }
+        self.log_breakdowns(breakdown.as_slice());
+        Ok(())
+    }
+
+    pub(in crate::game) fn action_accept_festival(&mut self) -> Result<(), String> {
+        let Some(offer) = &mut self.pending_festival_offer else {
+            return Err("No festival has offered you a slot.".to_string());
+        };
+        if offer.booked {
+            return Err(format!("You're already booked for {}.", offer.festival));
+        }
+        offer.booked = true;
+        let (festival, week) = (offer.festival.clone(), offer.expires_week);
+        self.log(format!(
+            "🎪 Booked for {} — the set is in week {}.",
+            festival, week
+        ));
+        Ok(())
+    }
+
+    /// Refuse a commitment that would keep the band away through a booked
+    /// festival slot: `weeks` is how far it moves the calendar, the turn's
+    /// own week included. Tours and trips end before the day or not at all.
+    pub(in crate::game) fn check_festival_clash(&self, weeks: u32) -> Result<(), String> {
+        match &self.pending_festival_offer {
+            Some(offer) if offer.booked && self.week + weeks > offer.expires_week => Err(format!(
+                "You're booked to play {} in week {} — you'd still be away. Pull out first (F).",
+                offer.festival, offer.expires_week
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Turn down a festival offer, or pull out of a booked slot.
+    pub(in crate::game) fn action_decline_festival(&mut self) -> Result<(), String> {
+        let Some(offer) = self.pending_festival_offer.take() else {
+            return Err("No festival has offered you a slot.".to_string());
+        };
+        if offer.booked {
+            self.log(format!("🚫 Pulled out of {}.", offer.festival));
+        } else {
+            self.log(format!("🚫 Passed on {}.", offer.festival));
+        }
         Ok(())
     }
 
+    /// Play a booked festival slot on the day (`turn.rs`): one reception
+    /// roll, the fee, fame by billing, and regional fame across the host
+    /// country with a share everywhere else. A great set may go down as
+    /// legendary and make the news. A band too worn out to stand pulls out.
+    pub(in crate::game) fn play_festival(&mut self, offer: &FestivalOffer, rng: &mut impl Rng) {
+        if self.player.stress >= TOUR_STRESS_GUARD || self.player.health < TOUR_HEALTH_GUARD {
+            self.log(format!(
+                "🤒 In no state to play {} — you pulled out on the day.",
+                offer.festival
+            ));
+            return;
+        }
+        let billing = offer.billing;
+        let b = billing as usize;
+        let (capacity, host_country, host_region) = match self.world.festival(offer.festival_id) {
+            Some(festival) => (
+                festival.crowd(),
+                festival.country_key().to_string(),
+                festival.region_key.clone(),
+            ),
+            None => (50 * FESTIVAL_CROWD_PER_SIZE, String::new(), String::new()),
+        };
+
+        let era_genre_modifier = self
+            .data_files
+            .era_genre_modifier(self.timeline.get_current_year(), self.band.genre.aliases());
+        let (reception, breakdown) = self.roll_show_reception(era_genre_modifier, None, rng);
+        self.wear_gear(1, None, 0);
+        let verdict = ShowVerdict::from_reception(reception);
+        let watching = (capacity as f32
+            * FESTIVAL_BILLING_CROWD[b]
+            * shows::reception_attendance_factor(reception))
+        .min(capacity as f32) as u32;
+
+        let merch_price = self.merch_unit_price();
+        let (merch_sold, merch) = self.sell_merch(watching, verdict, merch_price);
+        self.bank_income(offer.pay, LedgerCategory::Shows, offer.festival.as_str());
+        if merch > 0 {
+            self.bank_income(merch, LedgerCategory::Merch, offer.festival.as_str());
+        }
+
+        // A rough set still puts the band in front of the crowd, for half
+        // the credit.
+        let halve = |gain: u8| {
+            if verdict == ShowVerdict::Rough {
+                gain / 2
+            } else {
+                gain
+            }
+        };
+        let mut fame_applied = self.band.gain_fame(halve(offer.fame_gain));
+        let home_gain = halve(FESTIVAL_BILLING_REGIONAL_FAME[b]);
+        let abroad_gain = (f32::from(home_gain) * FESTIVAL_ABROAD_SHARE).round() as u8;
+        for (country_key, region_key, ..) in self.get_sorted_regions() {
+            let gain = if country_key == host_country {
+                home_gain
+            } else {
+                abroad_gain
+            };
+            let entry = self
+                .regional_fame
+                .entry(format!("{}:{}", country_key, region_key))
+                .or_insert(0);
+            *entry = entry.saturating_add(gain).min(100);
+        }
+        if !host_region.is_empty() {
+            self.mark_region_visited(&host_region);
+        }
+
+        self.player.stress = (self.player.stress + FESTIVAL_STRESS_COST).min(constants::MAX_STRESS);
+        self.player.health = self.player.health.saturating_sub(FESTIVAL_HEALTH_COST);
+        self.apply_show_verdict_rewards(verdict);
+        self.idle_streak = 0;
+        self.decay_streak = 0;
+
+        if let Some(festival) = self
+            .world
+            .festivals
+            .iter_mut()
+            .find(|festival| festival.id == offer.festival_id)
+        {
+            match festival
+                .lineup
+                .iter()
+                .rposition(|slot| slot.billing == billing)
+            {
+                Some(i) => festival.lineup[i].act = self.band.name.clone(),
+                None => festival.lineup.push(crate::game::world::FestivalSlot {
+                    billing,
+                    act: self.band.name.clone(),
+                }),
+            }
+        }
+
+        self.log(format!(
+            "🎪 Played {} as the {} — a {} set for {} people, ${}, fame +{}, regional fame +{} around {} and +{} abroad.",
+            offer.festival,
+            billing.label(),
+            verdict.label(),
+            watching,
+            offer.pay,
+            fame_applied,
+            home_gain,
+            offer.region_name,
+            abroad_gain
+        ));
+        if merch_sold > 0 {
+            self.log(format!(
+                "👕 Sold {} item{} at the merch tent for ${}.",
+                merch_sold,
+                if merch_sold == 1 { "" } else { "s" },
+                merch
+            ));
+        }
+        self.log_breakdowns(breakdown.as_slice());
+
+        let legendary_chance = match verdict {
+            ShowVerdict::Great => FESTIVAL_LEGENDARY_CHANCE[b],
+            ShowVerdict::Transcendent => FESTIVAL_LEGENDARY_CHANCE[b] * 2.0,
+            ShowVerdict::Solid | ShowVerdict::Rough => 0.0,
+        };
+        if legendary_chance > 0.0 && rng.gen_bool(legendary_chance) {
+            let legend_fame = self.band.gain_fame(FESTIVAL_LEGENDARY_FAME);
+            fame_applied = fame_applied.saturating_add(legend_fame);
+            self.log(format!(
+                "📰 MUSIC NEWS: '{}' played a legendary {} set at {} — people will be talking about it for years (+{} fame).",
+                self.band.name,
+                billing.label(),
+                offer.festival,
+                legend_fame
+            ));
+        }
+
+        self.last_tour_report = Some(TourReport::from_rows(
+            vec![ShowReport {
+                week: self.week,
+                venue_name: offer.festival.clone(),
+                verdict: verdict.label().to_string(),
+                reception,
+                attendance: watching,
+                capacity,
+                take: offer.pay,
+                merch,
+            }],
+            fame_applied,
+        ));
+    }
+
+    /// Play the headline tour a promoter booked: their route, their rig,
+    /// their money. The promoter pays the bill and the band on the deal's
+    /// terms, then remembers every show — a rough run, or a tour that loses
+    /// them money, sours them on the band.
+    pub(in crate::game) fn action_accept_headline_tour(
+        &mut self,
+        rng: &mut impl Rng,
+    ) -> Result<(), String> {
+        let Some(offer) = self.pending_headline_offer.clone() else {
+            return Err("No promoter has offered you a tour.".to_string());
+        };
+        let (gross, cost) = self.play_route(
+            &offer.legs,
+            offer.rig,
+            TourTerms::default(),
+            Some((&offer.promoter, offer.deal)),
+            rng,
+        )?;
+        self.pending_headline_offer = None;
+
+        let verdicts: Vec<ShowVerdict> = self
+            .last_tour_report
+            .as_ref()
+            .map(|report| {
+                report
+                    .rows
+                    .iter()
+                    .map(|row| ShowVerdict::from_reception(row.reception))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let promoter_net =
+            i64::from(gross) - i64::from(cost) - i64::from(offer.deal.band_take(gross, cost));
+        let Some(promoter) = self
+            .world
+            .tour_promoters
+            .iter_mut()
+            .find(|promoter| promoter.name == offer.promoter)
+        else {
+            return Ok(());
+        };
+        let change = promoter.record_tour(&verdicts, promoter_net < 0);
+        let (name, makes_offers) = (promoter.name.clone(), promoter.makes_offers());
+        if promoter_net < 0 {
+            self.log(format!(
+                "📉 {} lost ${} on the tour.",
+                name,
+                promoter_net.unsigned_abs()
+            ));
+        }
+        if !makes_offers {
+            self.log(format!(
+                "🚫 {} won't be booking you again any time soon.",
+                name
+            ));
+        } else if change > 0 {
+            self.log(format!("🤝 {} would happily book you again.", name));
+        } else if change < 0 {
+            self.log(format!("😬 {} has cooled on you.", name));
+        }
+        Ok(())
+    }
+
+    pub(in crate::game) fn action_decline_headline_tour(&mut self) -> Result<(), String> {
+        let Some(offer) = self.pending_headline_offer.take() else {
+            return Err("No promoter has offered you a tour.".to_string());
+        };
+        self.log(format!("🚫 Passed on {}'s headline tour.", offer.promoter));
+        Ok(())
+    }
+
+    /// Roll one show's reception with the band's gear and crew (`gear.rs`):
+    /// working gear lifts it, a sound engineer narrows the variance, and a
+    /// worn kit may break down mid-set and cost the night some of it.
+    fn roll_show_reception(
+        &mut self,
+        era_genre_modifier: f32,
+        rig: Option<TourRig>,
+        rng: &mut impl Rng,
+    ) -> (u8, Option<GearKind>) {
+        let reception = shows::compute_reception(
+            &self.band,
+            self.player.stress,
+            self.player.health,
+            era_genre_modifier,
+            self.player.creativity,
+            self.reception_variance_mult(),
+            rng,
+        )
+        .saturating_add(self.gear_reception_bonus())
+        .min(100);
+        match self.roll_gear_breakdown(rig, rng) {
+            Some(kind) => (
+                reception.saturating_sub(GEAR_BREAKDOWN_RECEPTION),
+                Some(kind),
+            ),
+            None => (reception, None),
+        }
+    }
+
+    fn log_breakdowns(&mut self, breakdowns: &[GearKind]) {
+        let Some(first) = breakdowns.first() else {
+            return;
+        };
+        let repairs: u32 = breakdowns
+            .iter()
+            .map(|kind| (kind.price() as f32 * GEAR_BREAKDOWN_REPAIR_SHARE).round() as u32)
+            .sum();
+        if breakdowns.len() == 1 {
+            self.log(format!(
+                "🔧 The {} gave out mid-set — an emergency patch cost ${}.",
+                first.label().to_lowercase(),
+                repairs
+            ));
+        } else {
+            self.log(format!(
+                "🔧 Gear trouble all tour: {} breakdowns, ${} in emergency patches. Get the kit serviced.",
+                breakdowns.len(),
+                repairs
+            ));
+        }
+    }
+
     /// Fame gate for a rig: fame never re-prices a tour, it only decides
     /// which rigs are on offer (design §A).
     pub fn rig_is_available(&self, rig: TourRig) -> bool {
@@ -430,13 +994,15 @@ impl Game {
         // Cost formula (design §A): rig cost/week × weeks × country travel
         // mult × the rig's own travel/equipment modifiers — the dead
         // `markets.json` fields finally do their job, now keyed by rig
-        // instead of the old fame tier. Linear in weeks, by design.
-        let cost = (rig.cost_per_week() as f32
+        // instead of the old fame tier. Linear in weeks, by design. A
+        // manager's discount (FUTURE §9.4) comes off the whole bill.
+        let cost = (self.rig_cost_per_week(rig) as f32
             * weeks as f32
             * country_travel_mult
             * touring_costs.travel_cost_modifier
-            * touring_costs.equipment_cost_modifier)
-            .round() as i32;
+            * touring_costs.equipment_cost_modifier
+            * (1.0 - self.manager_tour_discount()))
+        .round() as i32;
 
         let regional_fame_key = format!("{}:{}", country_key, region_key);
         let regional_fame_current = *self.regional_fame.get(&regional_fame_key).unwrap_or(&0);
@@ -461,12 +1027,14 @@ impl Game {
         let total_potential_gross =
             (base_gross * era_modifier * market_modifier * rig.capacity_mult()).max(0.0);
 
-        // Same multiplier on the synthesized venue so reported attendance
-        // stays consistent with the bigger rooms the rig is playing (§A).
-        let synth_capacity = (Self::synth_tour_venue_capacity(*population, *economic_strength)
-            as f32
-            * rig.capacity_mult())
-        .round() as u32;
+        let typical_capacity = Self::synth_tour_venue_capacity(*population, *economic_strength);
+        let venue_density = self
+            .data_files
+            .markets_data
+            .markets
+            .get(country_key)
+            .and_then(|country| country.regions.get(region_key))
+            .map_or(0, |region| region.venue_density);
 
         let shows_total = weeks as u32 * SHOWS_PER_TOUR_WEEK;
 
@@ -482,7 +1050,8 @@ impl Game {
             fame_req: *fame_req,
             cost,
             shows_total,
-            synth_capacity,
+            typical_capacity,
+            venue_density,
             total_potential_gross,
             fame_gain,
             regional_fame_gain_base,
@@ -491,9 +1060,11 @@ impl Game {
         })
     }
 
-    /// The up-front quote (design §A): the tour picker must show this
-    /// before booking. Pure — no rng, no mutation — so the UI can recompute
-    /// it live as the player changes rig/length.
+    /// The up-front quote (design §A) for one region on plain terms. Pure —
+    /// no rng, no mutation. The picker quotes through `quote_route`, which
+    /// also prices tickets and merch; this single-region form is what the
+    /// sims and tests reason with.
+    #[cfg(test)]
     pub fn quote_tour(
         &self,
         region_index: usize,
@@ -501,20 +1072,25 @@ impl Game {
         weeks: u8,
     ) -> Result<TourQuote, String> {
         let pot = self.tour_pot(region_index, rig, weeks)?;
+        Ok(Self::quote_pot(&pot, rig, weeks, TicketTier::Standard))
+    }
 
-        // Projected gross range: the same whole-tour pot, at momentum 1.0,
-        // spread by the reception-driven attendance factor alone (§A).
-        let gross_low = (pot.total_potential_gross * RECEPTION_ATTENDANCE_MIN_FACTOR)
+    /// A stop's quote from its pot. Projected gross range: the same
+    /// whole-tour pot, at momentum 1.0, spread by the reception-driven
+    /// attendance factor alone (§A), at the tier's price and turnout.
+    fn quote_pot(pot: &TourPot, rig: TourRig, weeks: u8, tickets: TicketTier) -> TourQuote {
+        let price = tickets.price_mult();
+        let gross_low = (pot.total_potential_gross * price * tickets.attendance_factor(0))
             .max(0.0)
             .round() as u32;
-        let gross_high = (pot.total_potential_gross * RECEPTION_ATTENDANCE_MAX_FACTOR)
+        let gross_high = (pot.total_potential_gross * price * tickets.attendance_factor(100))
             .max(0.0)
             .round() as u32;
 
-        Ok(TourQuote {
+        TourQuote {
             rig,
             weeks,
-            region_name: pot.region_name,
+            region_name: pot.region_name.clone(),
             shows: pot.shows_total,
             cost: pot.cost,
             gross_low,
@@ -524,6 +1100,262 @@ impl Game {
             regional_fame_gain_max: (pot.regional_fame_gain_base
                 + TOUR_REGIONAL_FAME_GAIN_RNG_SPREAD as u16)
                 .min(u8::MAX as u16) as u8,
+        }
+    }
+
+    /// The route gates on top of each stop's own: at most
+    /// `TOUR_ROUTE_MAX_STOPS` stops and `TOUR_ROUTE_MAX_WEEKS` weeks, no
+    /// region twice, and more than one stop only from
+    /// `TOUR_ROUTE_FAME_GATE` fame. Then every stop is potted exactly as a
+    /// single-region tour would be, and a travel leg is priced between
+    /// each pair: a domestic hop within a country, a flight between them.
+    fn route_pot(
+        &self,
+        legs: &[TourLeg],
+        rig: TourRig,
+        terms: &TourTerms,
+    ) -> Result<RoutePot, String> {
+        if legs.is_empty() {
+            return Err("A tour needs at least one stop.".to_string());
+        }
+        if legs.len() > TOUR_ROUTE_MAX_STOPS {
+            return Err(format!(
+                "A tour can take in at most {} regions.",
+                TOUR_ROUTE_MAX_STOPS
+            ));
+        }
+        if legs.len() > 1 && self.band.fame < TOUR_ROUTE_FAME_GATE {
+            return Err(format!(
+                "Routing a tour through several regions needs at least {} fame.",
+                TOUR_ROUTE_FAME_GATE
+            ));
+        }
+        self.check_setlist(&terms.setlist)?;
+        let opener = terms
+            .opener
+            .as_deref()
+            .map(|name| self.check_opener(name))
+            .transpose()?;
+        let mut stops = legs
+            .iter()
+            .map(|leg| Ok((self.tour_pot(leg.region_index, rig, leg.weeks)?, leg.weeks)))
+            .collect::<Result<Vec<_>, String>>()?;
+        if self.in_tax_exile() && stops.iter().any(|(pot, _)| pot.country_key == HOME_COUNTRY) {
+            return Err("You're a tax exile — the tour can't come home this year.".to_string());
+        }
+        let weeks: u32 = legs.iter().map(|leg| u32::from(leg.weeks)).sum();
+        if weeks > u32::from(TOUR_ROUTE_MAX_WEEKS) {
+            return Err(format!(
+                "A tour can run at most {} weeks.",
+                TOUR_ROUTE_MAX_WEEKS
+            ));
+        }
+        for (i, leg) in legs.iter().enumerate() {
+            if legs[..i]
+                .iter()
+                .any(|earlier| earlier.region_index == leg.region_index)
+            {
+                return Err("The route already plays that region.".to_string());
+            }
+        }
+
+        let touring_costs = self
+            .data_files
+            .markets_data
+            .market_modifiers
+            .touring_costs
+            .get(rig.markets_key())
+            .ok_or_else(|| "Touring cost data not found for this rig.".to_string())?;
+        let discount = 1.0 - self.manager_tour_discount();
+        let travel: Vec<TravelLeg> = stops
+            .windows(2)
+            .map(|pair| {
+                let (from, to) = (&pair[0].0, &pair[1].0);
+                let international = from.country_key != to.country_key;
+                let fare = if international {
+                    touring_costs.international_leg_cost
+                } else {
+                    touring_costs.domestic_leg_cost
+                };
+                TravelLeg {
+                    from: from.region_name.clone(),
+                    to: to.region_name.clone(),
+                    international,
+                    cost: (fare as f32 * discount).round() as i32,
+                }
+            })
+            .collect();
+
+        let merch_cost = (terms.merch_order as f32 * MERCH_UNIT_COST as f32).round() as i32;
+        let recording_cost = if terms.recorded {
+            let shows: u32 = stops.iter().map(|(pot, _)| pot.shows_total).sum();
+            LIVE_RECORDING_FEE_BASE + LIVE_RECORDING_FEE_PER_SHOW * shows as i32
+        } else {
+            0
+        };
+        // An opener's draw lifts every stop's gross; their fee is a share
+        // of what the stops would have grossed without them.
+        let opener_cost = match opener {
+            Some(opener) => {
+                let draw = 1.0 + f32::from(opener.heat()) / 100.0 * OPENER_DRAW_MAX;
+                let mut fee = 0.0;
+                for (pot, _) in &mut stops {
+                    fee += pot.total_potential_gross * opener.opener_fee_share();
+                    pot.total_potential_gross *= draw;
+                }
+                fee.round() as i32
+            }
+            None => 0,
+        };
+        let cost = stops.iter().map(|(pot, _)| pot.cost).sum::<i32>()
+            + travel.iter().map(|leg| leg.cost).sum::<i32>()
+            + merch_cost
+            + recording_cost
+            + opener_cost;
+        // The route's fame follows total weeks on the road, on the same
+        // sublinear curve as a single tour — so one stop quotes exactly as
+        // `tour_pot` does, and splitting a tour never games the curve.
+        let fame_gain =
+            (TOUR_FAME_GAIN_BASE * (weeks as f32).powf(TOUR_FAME_WEEKS_EXPONENT)).round() as u8;
+
+        Ok(RoutePot {
+            stops,
+            travel,
+            weeks: weeks as u8,
+            merch_cost,
+            recording_cost,
+            opener_cost,
+            cost,
+            fame_gain,
+        })
+    }
+
+    /// What the setlist did: a set too short for the night, and the songs
+    /// `road_test_setlist` played into shape.
+    fn log_setlist(&mut self, setlist: &[SetlistSong], (improved, gain): (u32, u8)) {
+        if setlist.is_empty() {
+            return;
+        }
+        if setlist.len() < SETLIST_MIN_SONGS {
+            self.log(format!(
+                "⏱️ A {}-song set left the crowds wanting — play at least {}.",
+                setlist.len(),
+                SETLIST_MIN_SONGS
+            ));
+        }
+        if improved > 0 {
+            self.log(format!(
+                "🎼 Road-tested {} new song{} — songwriting +{} each.",
+                improved,
+                if improved == 1 { "" } else { "s" },
+                gain
+            ));
+        }
+    }
+
+    /// The opener's share of the tour: fame for every week in front of the
+    /// band's crowds and more for a good run, momentum unless it went
+    /// badly, and rapport by how it went.
+    fn credit_opener(&mut self, name: &str, weeks: u8, verdict: ShowVerdict) {
+        let Some(opener) = self.world.scene_band_mut(name) else {
+            return;
+        };
+        let gain = OPENER_FAME_PER_WEEK
+            .saturating_mul(weeks)
+            .saturating_add(OPENER_FAME_BONUS[verdict as usize]);
+        opener.fame = opener.fame.saturating_add(gain).min(100);
+        opener.peak_fame = opener.peak_fame.max(opener.fame);
+        if verdict != ShowVerdict::Rough {
+            opener.momentum = (opener.momentum + 1).min(3);
+        }
+        let was_friend = opener.is_friend();
+        opener.rapport = (opener.rapport + OPENER_RAPPORT_DELTA[verdict as usize])
+            .clamp(-SCENE_RAPPORT_MAX, SCENE_RAPPORT_MAX);
+        let line = if opener.is_rival() {
+            format!(
+                "😠 {} blame you for a rough run — they won't forget it (fame +{}).",
+                opener.name, gain
+            )
+        } else if opener.is_friend() && !was_friend {
+            format!(
+                "🤝 {} opened every night and loved it — friends for life (fame +{}).",
+                opener.name, gain
+            )
+        } else {
+            format!("🎸 {} opened every night — fame +{}.", opener.name, gain)
+        };
+        self.log(line);
+    }
+
+    /// The scene bands the picker offers as openers, hottest first: at most
+    /// `OPENER_SHORTLIST` of those `check_opener` would accept.
+    pub fn opener_candidates(&self) -> Vec<&SceneBand> {
+        if self.band.fame < OPENER_MIN_FAME {
+            return Vec::new();
+        }
+        let mut candidates: Vec<&SceneBand> = self
+            .world
+            .bands
+            .iter()
+            .filter(|band| band.fame < self.band.fame && !band.is_rival())
+            .collect();
+        candidates.sort_by_key(|band| std::cmp::Reverse(band.heat()));
+        candidates.truncate(OPENER_SHORTLIST);
+        candidates
+    }
+
+    /// A scene band the band can take out as its opener: only once it's
+    /// famous itself, only a smaller act, and never a rival.
+    fn check_opener(&self, name: &str) -> Result<&SceneBand, String> {
+        if self.band.fame < OPENER_MIN_FAME {
+            return Err(format!(
+                "Bringing an opener along needs at least {} fame.",
+                OPENER_MIN_FAME
+            ));
+        }
+        let opener = self
+            .world
+            .scene_band(name)
+            .ok_or_else(|| format!("{} aren't around any more.", name))?;
+        if opener.fame >= self.band.fame {
+            return Err(format!("{} are too big to open for you.", opener.name));
+        }
+        if opener.is_rival() {
+            return Err(format!("{} won't open for you.", opener.name));
+        }
+        Ok(opener)
+    }
+
+    /// The up-front quote for a routed tour — every stop quoted as
+    /// `quote_tour` would, plus travel, merch and totals. Pure, like
+    /// `quote_tour`.
+    pub fn quote_route(
+        &self,
+        legs: &[TourLeg],
+        rig: TourRig,
+        terms: TourTerms,
+    ) -> Result<RouteQuote, String> {
+        let route = self.route_pot(legs, rig, &terms)?;
+        self.check_festival_clash(u32::from(route.weeks) + 1)?;
+        let stops: Vec<TourQuote> = route
+            .stops
+            .iter()
+            .map(|(pot, weeks)| Self::quote_pot(pot, rig, *weeks, terms.tickets))
+            .collect();
+        Ok(RouteQuote {
+            rig,
+            terms,
+            merch_cost: route.merch_cost,
+            recording_cost: route.recording_cost,
+            opener_cost: route.opener_cost,
+            weeks: route.weeks,
+            shows: stops.iter().map(|stop| stop.shows).sum(),
+            cost: route.cost,
+            gross_low: stops.iter().map(|stop| stop.gross_low).sum(),
+            gross_high: stops.iter().map(|stop| stop.gross_high).sum(),
+            fame_gain: route.fame_gain,
+            stops,
+            travel: route.travel,
         })
     }
 
@@ -534,6 +1366,42 @@ impl Game {
         weeks: u8,
         rng: &mut impl Rng,
     ) -> Result<(), String> {
+        self.action_tour_route(
+            &[TourLeg {
+                region_index,
+                weeks,
+            }],
+            rig,
+            TourTerms::default(),
+            rng,
+        )
+    }
+
+    /// Play a routed tour stop by stop. Word of mouth carries across the
+    /// whole route; each stop builds regional fame where it played; the
+    /// bill is every stop, the travel between them and the merch order,
+    /// paid up front.
+    pub(in crate::game) fn action_tour_route(
+        &mut self,
+        legs: &[TourLeg],
+        rig: TourRig,
+        terms: TourTerms,
+        rng: &mut impl Rng,
+    ) -> Result<(), String> {
+        self.play_route(legs, rig, terms, None, rng).map(|_| ())
+    }
+
+    /// The tour itself, self-funded or — with `backing` — booked by a
+    /// promoter, who pays the bill and the band on the deal's terms.
+    /// Returns the gross and the bill, for the promoter's books.
+    fn play_route(
+        &mut self,
+        legs: &[TourLeg],
+        rig: TourRig,
+        terms: TourTerms,
+        backing: Option<(&str, PromoterDeal)>,
+        rng: &mut impl Rng,
+    ) -> Result<(u32, i32), String> {
         if self.player.stress >= TOUR_STRESS_GUARD {
             return Err("You're too stressed out to go on tour!".to_string());
         }
@@ -541,66 +1409,117 @@ impl Game {
             return Err("You're too unwell to go on tour!".to_string());
         }
 
-        let pot = self.tour_pot(region_index, rig, weeks)?;
+        let route = self.route_pot(legs, rig, &terms)?;
+        self.check_festival_clash(u32::from(route.weeks) + 1)?;
 
-        if !self.player.can_afford(pot.cost) {
+        if backing.is_none() && !self.player.can_afford(route.cost) {
             return Err(format!(
                 "You need at least ${} to finance this tour!",
-                pot.cost
+                route.cost
             ));
         }
+        // Only rooms the band has already played can have banned it, so a
+        // refused tour leaves regions it never reached unbuilt.
+        for (pot, _) in &route.stops {
+            if self
+                .world
+                .regional_venues
+                .get(&pot.regional_fame_key)
+                .is_some_and(|roster| roster.iter().all(|venue| venue.is_banned(self.week)))
+            {
+                return Err(format!(
+                    "Every room in {} has banned you — route around it.",
+                    pot.region_name
+                ));
+            }
+        }
 
-        let region_name = pot.region_name.clone();
         let era_genre_modifier = self
             .data_files
             .era_genre_modifier(self.timeline.get_current_year(), self.band.genre.aliases());
 
-        let base_fill_ratio =
-            ((self.band.fame as f32 + 10.0) / (pot.fame_req as f32 + 10.0)).min(1.0);
-
-        let per_show_share = pot.total_potential_gross / pot.shows_total as f32;
-
         let mut momentum = MOMENTUM_START;
-        let mut rows: Vec<ShowReport> = Vec::with_capacity(pot.shows_total as usize);
+        let shows_total: u32 = route.stops.iter().map(|(pot, _)| pot.shows_total).sum();
+        let mut rows: Vec<ShowReport> = Vec::with_capacity(shows_total as usize);
         let mut gross_sum: u32 = 0;
+        let mut shows_played: u32 = 0;
+        let merch_price = self.merch_unit_price();
+        self.merch_stock = self.merch_stock.saturating_add(terms.merch_order);
+        let mut merch_sold: u32 = 0;
+        let mut merch_sum: u32 = 0;
+        let mut bans: Vec<String> = Vec::new();
+        let mut breakdowns: Vec<GearKind> = Vec::new();
+        let set_shift = self.setlist_reception_shift(&terms.setlist);
+
+        for (pot, _) in &route.stops {
+            let base_fill_ratio =
+                ((self.band.fame as f32 + 10.0) / (pot.fame_req as f32 + 10.0)).min(1.0);
+            let per_show_share = pot.total_potential_gross / pot.shows_total as f32;
+
+            for _ in 0..pot.shows_total {
+                let show_week = self.week + shows_played / SHOWS_PER_TOUR_WEEK;
+                // Rooms banning the band mid-tour can cancel the rest of a
+                // region's dates.
+                let Ok(venue_index) = self.book_region_venue(pot, show_week, rng) else {
+                    break;
+                };
+                let venue = &self.world.regional_venues[&pot.regional_fame_key][venue_index];
+                let venue_name = format!("{} ({})", venue.name, venue.location);
+                // The rig decides how much of the room it can use (§A).
+                let capacity = (venue.capacity as f32 * rig.capacity_mult()).round() as u32;
+                // The promoter's opinion (and any return booking) moves the
+                // take, as it does a single gig's fee.
+                let fee_mult = venue.fee_mult(show_week);
+
+                let (reception, breakdown) =
+                    self.roll_show_reception(era_genre_modifier, Some(rig), rng);
+                let reception = (f32::from(reception) + set_shift).round().clamp(0.0, 100.0) as u8;
+                self.wear_gear(1, Some(rig), 0);
+                breakdowns.extend(breakdown);
+                let verdict = ShowVerdict::from_reception(reception);
+                let attendance_factor = terms.tickets.attendance_factor(reception);
+                // Word of mouth: momentum carries from show to show, so the
+                // same night's own reception affects only its own attendance
+                // fill, not its own take. Money is centered on the per-show
+                // share regardless of venue size (design §B).
+                let money_multiplier = attendance_factor * momentum;
+
+                let fill_ratio = (base_fill_ratio * money_multiplier).clamp(0.0, 1.0);
+                let attendance = (capacity as f32 * fill_ratio).round() as u32;
+                let take =
+                    (per_show_share * money_multiplier * terms.tickets.price_mult() * fee_mult)
+                        .max(0.0)
+                        .round() as u32;
+                let merch = self.sell_merch(attendance, verdict, merch_price);
+                merch_sum = merch_sum.saturating_add(merch.1);
+                merch_sold += merch.0;
+
+                self.apply_show_verdict_rewards(verdict);
+                momentum = shows::apply_momentum_delta(momentum, verdict);
+                if let Some(venue) = self
+                    .world
+                    .regional_venues
+                    .get_mut(&pot.regional_fame_key)
+                    .and_then(|roster| roster.get_mut(venue_index))
+                    && venue.record_show(reception, verdict, show_week) == VenueStanding::Banned
+                {
+                    bans.push(venue.name.clone());
+                }
 
-        for show_idx in 0..pot.shows_total {
-            let venue_name = self.synth_tour_venue_name(&region_name, rng);
-
-            let reception = shows::compute_reception(
-                &self.band,
-                self.player.stress,
-                self.player.health,
-                era_genre_modifier,
-                self.player.creativity,
-                rng,
-            );
-            let verdict = ShowVerdict::from_reception(reception);
-            let attendance_factor = shows::reception_attendance_factor(reception);
-            // Word of mouth: momentum carries from show to show, so the
-            // same night's own reception affects only its own attendance
-            // fill, not its own take. Money is centered on the per-show
-            // share regardless of venue size (design §B).
-            let money_multiplier = attendance_factor * momentum;
-
-            let fill_ratio = (base_fill_ratio * money_multiplier).clamp(0.0, 1.0);
-            let attendance = (pot.synth_capacity as f32 * fill_ratio).round() as u32;
-            let take = (per_show_share * money_multiplier).max(0.0).round() as u32;
-
-            self.apply_show_verdict_rewards(verdict);
-            momentum = shows::apply_momentum_delta(momentum, verdict);
-
-            gross_sum = gross_sum.saturating_add(take);
-
-            rows.push(ShowReport {
-                week: self.week + show_idx / SHOWS_PER_TOUR_WEEK,
-                venue_name,
-                verdict: verdict.label().to_string(),
-                reception,
-                attendance,
-                capacity: pot.synth_capacity,
-                take,
-            });
+                gross_sum = gross_sum.saturating_add(take);
+
+                rows.push(ShowReport {
+                    week: show_week,
+                    venue_name,
+                    verdict: verdict.label().to_string(),
+                    reception,
+                    attendance,
+                    capacity,
+                    take,
+                    merch: merch.1,
+                });
+                shows_played += 1;
+            }
         }
 
         // Cap the fame gain against the live ceiling and apply it before
@@ -608,7 +1527,7 @@ impl Game {
         // both show the fame actually applied to the band — comeback
         // doubling and caps included, not the pre-cap request.
         let live_cap = self.live_fame_cap();
-        let fame_gain = pot.fame_gain.min(live_cap.saturating_sub(self.band.fame));
+        let fame_gain = route.fame_gain.min(live_cap.saturating_sub(self.band.fame));
         let fame_applied = self.band.gain_fame_capped(fame_gain, live_cap);
         let report = TourReport::from_rows(rows, fame_applied);
         if report.went_very_well() {
@@ -620,50 +1539,146 @@ impl Game {
 
         // Touring wears harder than a night at home (§A): the rig's own
         // wear table, replacing the flat cost-per-week the headline tour
-        // used to charge every rig alike.
+        // used to charge every rig alike — plus the jet lag of every
+        // flight on a routed tour.
+        // A tour manager keeps the road saner; an owned vehicle takes its
+        // own beating.
         let (rig_health_cost, rig_stress_cost) = rig.wear_per_week();
-        let tour_stress_cost = rig_stress_cost.saturating_mul(weeks);
-        let tour_health_cost = rig_health_cost.saturating_mul(weeks);
-        self.player.stress = (self.player.stress + tour_stress_cost).min(constants::MAX_STRESS);
+        let rig_stress_cost = if self.crew_count(CrewRole::TourManager) > 0 {
+            rig_stress_cost.saturating_sub(CREW_TOUR_MANAGER_STRESS_RELIEF)
+        } else {
+            rig_stress_cost
+        };
+        let flights = route.travel.iter().filter(|leg| leg.international).count() as u8;
+        let tour_stress_cost = rig_stress_cost
+            .saturating_mul(route.weeks)
+            .saturating_add(TOUR_FLIGHT_STRESS.saturating_mul(flights));
+        self.wear_gear(0, Some(rig), u32::from(route.weeks));
+        let tour_health_cost = rig_health_cost.saturating_mul(route.weeks);
+        self.player.stress =
+            (self.player.stress.saturating_add(tour_stress_cost)).min(constants::MAX_STRESS);
         self.player.health = self.player.health.saturating_sub(tour_health_cost);
 
-        self.player.spend_money(pot.cost);
-        self.player.earn_money(gross_sum);
-
-        let regional_fame_gain = pot.regional_fame_gain_base
-            + rng.gen_range(0..=TOUR_REGIONAL_FAME_GAIN_RNG_SPREAD as u16);
-        let new_regional_fame =
-            (pot.regional_fame_current as u16 + regional_fame_gain).min(100) as u8;
-        // The 100-cap can eat part of the rolled gain — report the delta
-        // that actually landed, not the roll.
-        let regional_fame_applied = new_regional_fame - pot.regional_fame_current;
-        self.regional_fame
-            .insert(pot.regional_fame_key.clone(), new_regional_fame);
+        let band_take = match backing {
+            Some((_, deal)) => deal.band_take(gross_sum, route.cost),
+            None => {
+                self.post(LedgerCategory::Touring, -route.cost, "Tour costs");
+                gross_sum
+            }
+        };
+        self.bank_income(band_take, LedgerCategory::Shows, "Tour");
+        if merch_sum > 0 {
+            self.bank_income(merch_sum, LedgerCategory::Merch, "Tour");
+        }
 
-        self.week += weeks as u32;
+        let mut regional_lines = Vec::with_capacity(route.stops.len());
+        for (pot, _) in &route.stops {
+            let regional_fame_gain = pot.regional_fame_gain_base
+                + rng.gen_range(0..=TOUR_REGIONAL_FAME_GAIN_RNG_SPREAD as u16);
+            let new_regional_fame =
+                (pot.regional_fame_current as u16 + regional_fame_gain).min(100) as u8;
+            // The 100-cap can eat part of the rolled gain — report the delta
+            // that actually landed, not the roll.
+            let regional_fame_applied = new_regional_fame - pot.regional_fame_current;
+            self.regional_fame
+                .insert(pot.regional_fame_key.clone(), new_regional_fame);
+            self.mark_region_visited(&pot.regional_fame_key);
+            regional_lines.push((
+                pot.region_name.clone(),
+                new_regional_fame,
+                regional_fame_applied,
+            ));
+        }
 
+        self.week += route.weeks as u32;
+
+        let stops = route
+            .stops
+            .iter()
+            .map(|(pot, _)| {
+                format!(
+                    "{} ({})",
+                    pot.region_name,
+                    pot.country_key.replace("_", " ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" → ");
+        let regional = match regional_lines.as_slice() {
+            [(_, level, applied)] => format!("{}% (+{})", level, applied),
+            many => many
+                .iter()
+                .map(|(name, level, applied)| format!("{} {}% (+{})", name, level, applied))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        let travel_cost: i32 = route.travel.iter().map(|leg| leg.cost).sum();
+        let costs = if route.travel.is_empty() {
+            format!("${}", route.cost)
+        } else {
+            format!("${} (${} of it travel)", route.cost, travel_cost)
+        };
         let avg_verdict = ShowVerdict::from_reception(report.avg_reception);
+        let money = match backing {
+            Some((promoter, deal)) => format!(
+                "grossed ${} for {} — your {} paid ${}",
+                gross_sum,
+                promoter,
+                deal.label(),
+                band_take
+            ),
+            None => format!("grossed ${} against {} in costs", gross_sum, costs),
+        };
         self.log(format!(
-            "🚌 {} tour of {} ({}), {} weeks: {} shows, avg reception {} ({}) — grossed ${} against ${} in costs, fame +{}, regional fame {}% (+{}). Press R for the tour report.",
+            "🚌 {} tour of {}, {} weeks: {} shows, avg reception {} ({}) — {}, fame +{}, regional fame {}. Press R for the tour report.",
             rig.label(),
-            region_name,
-            pot.country_key.replace("_", " "),
-            weeks,
-            pot.shows_total,
+            stops,
+            route.weeks,
+            shows_played,
             report.avg_reception,
             avg_verdict.label(),
-            gross_sum,
-            pot.cost,
+            money,
             fame_applied,
-            new_regional_fame,
-            regional_fame_applied
+            regional
         ));
+        if !bans.is_empty() {
+            self.log(format!(
+                "🚫 Word travels: {} won't book you again for a while.",
+                bans.join(", ")
+            ));
+        }
+        self.log_breakdowns(&breakdowns);
+        if merch_sold > 0 || terms.merch_order > 0 {
+            self.log(format!(
+                "👕 The merch table sold {} item{} for ${} — {} left in stock.",
+                merch_sold,
+                if merch_sold == 1 { "" } else { "s" },
+                merch_sum,
+                self.merch_stock
+            ));
+        }
         if report.went_very_well() {
             self.log("🌟 The tour went very well — spirits (and inspiration) are high.");
         }
+        if terms.recorded {
+            if self.live_tapes.is_some() {
+                self.log("🎚️ The new tapes replace the ones you never released.");
+            }
+            self.log(format!(
+                "🎚️ {} shows on tape — release the best of them as a live record (A).",
+                report.rows.len()
+            ));
+            self.live_tapes = Some(report.clone());
+        }
+        if let Some(opener) = &terms.opener {
+            self.credit_opener(opener, route.weeks, avg_verdict);
+        }
+        // Only the dates actually played road-test the new songs.
+        let road_tested = self.road_test_setlist(&terms.setlist, shows_played);
+        self.log_setlist(&terms.setlist, road_tested);
 
         self.last_tour_report = Some(report);
 
-        Ok(())
+        Ok((gross_sum, route.cost))
     }
 }
@@ -10,7 +10,8 @@ use rand::Rng;
 
 use super::*;
 
-pub use live::{TourQuote, TourRig};
+pub use live::{PromoterDeal, RouteQuote, TicketTier, TourLeg, TourRig, TourTerms};
+pub use rest::Vacation;
 
 impl Game {
     pub(super) fn execute_action(
@@ -24,16 +25,29 @@ impl Game {
             GameAction::Practice => self.action_practice(),
             GameAction::RecordSingle { pressing } => self.action_record_single(pressing, rng),
             GameAction::RecordAlbum { pressing } => self.action_record_album(pressing, rng),
+            GameAction::ReleaseLive { format, pressing } => {
+                self.action_release_live(format, pressing)
+            }
             GameAction::Gig(venue_index) => self.action_play_gig(venue_index, rng),
             GameAction::GoOnTour(region_index, rig, weeks) => {
                 self.action_go_on_tour(region_index, rig, weeks, rng)
             }
-            GameAction::TakeBreak => self.action_take_break(),
+            GameAction::GoOnTourRoute(legs, rig, terms) => {
+                self.action_tour_route(&legs, rig, terms, rng)
+            }
+            GameAction::TakeVacation(vacation) => self.action_take_vacation(vacation),
             GameAction::VisitDoctor => self.action_visit_doctor(),
             GameAction::AcceptDeal(index) => self.action_accept_deal(index),
             GameAction::RejectDeal(index) => self.action_reject_deal(index, rng),
+            GameAction::CounterDeal(index, counter) => {
+                self.action_counter_deal(index, counter, rng)
+            }
             GameAction::AcceptSupportTour => self.action_accept_support_tour(rng),
             GameAction::DeclineSupportTour => self.action_decline_support_tour(),
+            GameAction::AcceptFestival => self.action_accept_festival(),
+            GameAction::DeclineFestival => self.action_decline_festival(),
+            GameAction::AcceptHeadlineTour => self.action_accept_headline_tour(rng),
+            GameAction::DeclineHeadlineTour => self.action_decline_headline_tour(),
             GameAction::StartMarketingCampaign(release_id, campaign_type) => {
                 self.action_start_marketing_campaign(release_id, campaign_type)
             }
@@ -42,6 +56,35 @@ impl Game {
                 release_id,
                 pressing,
             } => self.action_re_press(release_id, pressing),
+            GameAction::BuyBackMaster(release_id) => self.action_buy_back_master(release_id),
+            GameAction::SellMaster(release_id) => self.action_sell_master(release_id),
+            GameAction::ReRecord {
+                release_id,
+                pressing,
+            } => self.action_rerecord(release_id, pressing, rng),
+            GameAction::RenegotiateTakeover => self.action_renegotiate_takeover(),
+            GameAction::KeyManExit => self.action_key_man_exit(),
+            GameAction::HireManager(index) => self.action_hire_manager(index),
+            GameAction::RejectManager(index) => self.action_reject_manager(index),
+            GameAction::FireManager => self.action_fire_manager(),
+            GameAction::BuyGear(kind) => self.action_buy_gear(kind),
+            GameAction::SellGear(kind) => self.action_sell_gear(kind),
+            GameAction::ServiceGear => self.action_service_gear(),
+            GameAction::HireCrew(role) => self.action_hire_crew(role),
+            GameAction::FireCrew(role) => self.action_fire_crew(role),
+            GameAction::SetGearInsurance(insured) => self.action_set_gear_insurance(insured),
+            GameAction::TakeLoan(amount) => self.action_take_loan(amount),
+            GameAction::RepayLoan(amount) => self.action_repay_loan(amount),
+            GameAction::DrawLabelAdvance(amount) => self.action_draw_label_advance(amount),
+            GameAction::SetAccountant(hired) => self.action_set_accountant(hired),
+            GameAction::GoIntoTaxExile(country) => self.action_go_into_tax_exile(&country),
+            GameAction::BuyInvestment(kind) => self.action_buy_investment(kind),
+            GameAction::SellInvestment(kind) => self.action_sell_investment(kind),
+            GameAction::FoundLabel => self.action_found_label(),
+            GameAction::SignToLabel(band) => self.action_sign_to_label(&band),
+            GameAction::DropFromLabel(band) => self.action_drop_from_label(&band),
+            GameAction::AnswerLabelDemand(comply) => self.action_answer_label_demand(comply),
+            GameAction::ResolveIncident(index) => self.resolve_incident_choice(index, rng),
             GameAction::Quit => {
                 self.game_over = true;
                 Ok(())
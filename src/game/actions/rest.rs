@@ -1,9 +1,93 @@
 //! Player weekly actions (split by concern). Methods remain on `Game`.
 
+use serde::{Deserialize, Serialize};
+
 use super::super::constants::{self, *};
 use super::super::player::LifestyleTier;
 use super::super::*;
 
+/// Where the player goes to recover — the original 1989 game's escalating
+/// menu, cheap weekend to world cruise (FUTURE §9.3). Every trip costs
+/// money and calendar; the longer ones also cost fame, through nothing more
+/// than the quiet weeks idle decay already charges for.
+///
+/// Not persisted, but `GameAction` derives `Serialize`/`Deserialize` as a
+/// whole, so this must too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Vacation {
+    SeasideWeekend,
+    CountryRetreat,
+    MediterraneanHoliday,
+    WorldCruise,
+}
+
+impl Vacation {
+    /// In picker order, cheapest to grandest.
+    pub const ALL: [Vacation; 4] = [
+        Vacation::SeasideWeekend,
+        Vacation::CountryRetreat,
+        Vacation::MediterraneanHoliday,
+        Vacation::WorldCruise,
+    ];
+
+    /// Index into the `VACATION_*` const tables — also this trip's
+    /// position in `ALL`, for picker navigation.
+    pub fn ordinal(self) -> usize {
+        match self {
+            Vacation::SeasideWeekend => 0,
+            Vacation::CountryRetreat => 1,
+            Vacation::MediterraneanHoliday => 2,
+            Vacation::WorldCruise => 3,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Vacation::SeasideWeekend => "Seaside weekend",
+            Vacation::CountryRetreat => "Country retreat",
+            Vacation::MediterraneanHoliday => "Mediterranean holiday",
+            Vacation::WorldCruise => "World cruise",
+        }
+    }
+
+    fn emoji(self) -> &'static str {
+        match self {
+            Vacation::SeasideWeekend => "🏖️",
+            Vacation::CountryRetreat => "🌳",
+            Vacation::MediterraneanHoliday => "☀️",
+            Vacation::WorldCruise => "🛳️",
+        }
+    }
+
+    pub fn cost(self) -> u32 {
+        VACATION_COST[self.ordinal()]
+    }
+
+    pub fn weeks(self) -> u32 {
+        VACATION_WEEKS[self.ordinal()]
+    }
+
+    pub fn stress_relief(self) -> u8 {
+        VACATION_STRESS_RELIEF[self.ordinal()]
+    }
+
+    pub fn happiness_gain(self) -> u8 {
+        VACATION_HAPPINESS_GAIN[self.ordinal()]
+    }
+
+    pub fn creativity_gain(self) -> u8 {
+        VACATION_CREATIVITY_GAIN[self.ordinal()]
+    }
+
+    pub fn health_gain(self) -> u8 {
+        VACATION_HEALTH_GAIN[self.ordinal()]
+    }
+
+    pub fn addiction_relief(self) -> u8 {
+        VACATION_ADDICTION_RELIEF[self.ordinal()]
+    }
+}
+
 impl Game {
     pub(in crate::game) fn action_laze_around(&mut self) -> Result<(), String> {
         // v0.7 §B: a nicer place to crash adds to the rest-healing bonus.
@@ -26,30 +110,56 @@ impl Game {
         Ok(())
     }
 
-    pub(in crate::game) fn action_take_break(&mut self) -> Result<(), String> {
+    /// Go away for a while (FUTURE §9.3). Paid up front; the trip's weeks
+    /// pass in one turn, so idle fame decay applies to every one of them
+    /// exactly as it would to that many quiet weeks at home.
+    pub(in crate::game) fn action_take_vacation(
+        &mut self,
+        vacation: Vacation,
+    ) -> Result<(), String> {
+        let cost = vacation.cost() as i32;
+        if !self.player.can_afford(cost) {
+            return Err(format!(
+                "A {} costs ${} — you can't afford to get away.",
+                vacation.label().to_lowercase(),
+                cost
+            ));
+        }
+        self.check_festival_clash(vacation.weeks())?;
+        self.post(LedgerCategory::Health, -cost, vacation.label());
+
         // v0.7 §B: the lifestyle's rest-healing bonus tops up the health gain.
         let bonus = self.player.lifestyle.rest_healing_bonus();
-        self.player.stress = 0;
+        self.player.stress = self.player.stress.saturating_sub(vacation.stress_relief());
         self.player.happiness = self
             .player
             .happiness
-            .saturating_add(BREAK_HAPPINESS_GAIN)
+            .saturating_add(vacation.happiness_gain())
             .min(constants::MAX_HAPPINESS);
         self.player.creativity = self
             .player
             .creativity
-            .saturating_add(BREAK_CREATIVITY_GAIN)
+            .saturating_add(vacation.creativity_gain())
             .min(constants::MAX_CREATIVITY);
         self.player.health = self
             .player
             .health
-            .saturating_add(BREAK_HEALTH_GAIN)
+            .saturating_add(vacation.health_gain())
             .saturating_add(bonus)
             .min(constants::MAX_HEALTH);
-        self.week += BREAK_WEEKS - 1;
+        let relief = vacation.addiction_relief();
+        self.player.drug_addiction = self.player.drug_addiction.saturating_sub(relief);
+        self.player.alcohol_addiction = self.player.alcohol_addiction.saturating_sub(relief);
+
+        // The turn itself adds the final week.
+        self.week += vacation.weeks() - 1;
         self.log(format!(
-            "🏖️ You disappeared for {} weeks — fully recharged and healthier for it.",
-            BREAK_WEEKS
+            "{} {} — {} week{} away, ${} well spent.",
+            vacation.emoji(),
+            vacation.label(),
+            vacation.weeks(),
+            if vacation.weeks() == 1 { "" } else { "s" },
+            cost
         ));
         Ok(())
     }
@@ -61,7 +171,11 @@ impl Game {
                 constants::DOCTOR_VISIT_COST
             ));
         }
-        self.player.spend_money(constants::DOCTOR_VISIT_COST);
+        self.post(
+            LedgerCategory::Health,
+            -constants::DOCTOR_VISIT_COST,
+            "Doctor",
+        );
         self.player.health = (self.player.health + 20).min(constants::MAX_HEALTH);
         self.log(format!(
             "🩺 The doctor patched you up (+20 health, -${}).",
@@ -90,7 +204,11 @@ impl Game {
                         cost
                     ));
                 }
-                self.player.spend_money(cost);
+                self.post(
+                    LedgerCategory::Living,
+                    -cost,
+                    format!("Move to a {}", tier.label()),
+                );
                 self.player.lifestyle = tier;
                 self.player.happiness = self
                     .player
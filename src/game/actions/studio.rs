@@ -149,7 +149,9 @@ impl Game {
             return Err("You're too stressed to focus on rehearsal!".to_string());
         }
         self.player.stress = (self.player.stress + PRACTICE_STRESS_COST).min(constants::MAX_STRESS);
-        self.band.skill = (self.band.skill + 2).min(constants::MAX_SKILL);
+        // A label strong on artist development makes the week count for more.
+        self.band.skill =
+            (self.band.skill + 2 + self.development_skill_bonus()).min(constants::MAX_SKILL);
         // L12: rehearsal is also where individual musicianship grows —
         // `average_member_skill()` feeds live reception (design §B) and,
         // unlike `band.skill` above, was never written after band creation.
@@ -212,7 +214,7 @@ impl Game {
         if selected_songs.is_empty() {
             return Err("Failed to select a song for the single.".to_string());
         }
-        self.player.spend_money(cost);
+        self.post(LedgerCategory::Recording, -cost, "Single");
 
         let release_quality = self.calculate_release_quality(avg_song_quality, rng);
         let release_name = format!("Single: {}", selected_songs[0].name);
@@ -243,6 +245,10 @@ impl Game {
             // Freeze the label's reach on the release so its catalog tail
             // survives the deal ending (§E-3); `None` for an indie release.
             label_market_reach: self.band.current_deal().map(|deal| deal.market_reach),
+            live: None,
+            outside_deal: false,
+            master_owner: Some(self.new_master_owner()),
+            rerecording_of: None,
         };
         let name = new_release.name.clone();
         self.just_released_music.push(new_release);
@@ -267,6 +273,7 @@ impl Game {
             ));
         }
         self.apply_label_promo();
+        self.ar_review(false);
 
         // Apply stress cost (§A)
         self.player.stress = (self.player.stress + RECORD_STRESS_COST).min(constants::MAX_STRESS);
@@ -326,9 +333,10 @@ impl Game {
         if selected_songs.len() < constants::MIN_ALBUM_SONGS as usize {
             return Err("Not enough songs selected for an album.".to_string());
         }
-        self.player.spend_money(cost);
+        self.post(LedgerCategory::Recording, -cost, "Album");
 
         let release_quality = self.calculate_release_quality(avg_song_quality, rng);
+        let concept = self.records_concept_album();
         let release_name = self.data_files.random_album_title(rng);
         let signed = self.band.current_deal().is_some();
 
@@ -357,6 +365,10 @@ impl Game {
             // Freeze the label's reach on the release so its catalog tail
             // survives the deal ending (§E-3); `None` for an indie release.
             label_market_reach: self.band.current_deal().map(|deal| deal.market_reach),
+            live: None,
+            outside_deal: false,
+            master_owner: Some(self.new_master_owner()),
+            rerecording_of: None,
         };
         let name = new_release.name.clone();
         self.just_released_music.push(new_release);
@@ -380,7 +392,14 @@ impl Game {
                 name
             ));
         }
+        if concept {
+            self.log(format!(
+                "🎭 Creativity running high, '{}' comes out a concept album.",
+                name
+            ));
+        }
         self.apply_label_promo();
+        self.ar_review(concept);
 
         if self.timeline.is_album_era() {
             self.band.fame = (self.band.fame + 3).min(constants::MAX_FAME);
@@ -394,4 +413,170 @@ impl Game {
 
         Ok(())
     }
+
+    /// What mixing the tapes costs, before any pressing or distribution.
+    pub fn live_release_cost(&self, format: music::LiveFormat) -> i32 {
+        let base = match format {
+            music::LiveFormat::Album => LIVE_MIXING_COST,
+            music::LiveFormat::ConcertFilm => LIVE_MIXING_COST + LIVE_FILM_COST,
+        };
+        (base as f32 * self.timeline.get_recording_cost_modifier()) as i32
+    }
+
+    /// A live record's quality from the tapes on hand: the best shows'
+    /// receptions, weighted against the catalogue the band played. No rng —
+    /// what the release screen previews is what the record gets.
+    pub fn live_release_quality(&self, format: music::LiveFormat) -> Result<u8, String> {
+        let Some(tapes) = &self.live_tapes else {
+            return Err("There are no tapes — book a tour with recording on first.".to_string());
+        };
+        if tapes.rows.len() < LIVE_MIN_SHOWS {
+            return Err(format!(
+                "The tapes need at least {} shows to make a record.",
+                LIVE_MIN_SHOWS
+            ));
+        }
+        let catalogue = self.catalogue_song_quality().ok_or_else(|| {
+            "You need a studio record out before there's a set worth taping.".to_string()
+        })?;
+
+        let mut receptions: Vec<u8> = tapes.rows.iter().map(|row| row.reception).collect();
+        receptions.sort_unstable_by(|a, b| b.cmp(a));
+        receptions.truncate(LIVE_BEST_SHOWS);
+        let performance =
+            receptions.iter().map(|&r| f32::from(r)).sum::<f32>() / receptions.len() as f32;
+        let bonus = match format {
+            music::LiveFormat::Album => 0,
+            music::LiveFormat::ConcertFilm => LIVE_FILM_QUALITY_BONUS,
+        };
+        let quality = performance * LIVE_PERFORMANCE_WEIGHT
+            + f32::from(catalogue) * (1.0 - LIVE_PERFORMANCE_WEIGHT)
+            + f32::from(bonus);
+        Ok(quality.round().clamp(1.0, 100.0) as u8)
+    }
+
+    /// The average song quality across the band's studio releases.
+    fn catalogue_song_quality(&self) -> Option<u8> {
+        let studio: Vec<u32> = self
+            .band
+            .singles_released
+            .iter()
+            .chain(&self.band.albums_released)
+            .filter(|release| release.live.is_none())
+            .map(|release| u32::from(release.songs_involved_quality_avg))
+            .collect();
+        if studio.is_empty() {
+            return None;
+        }
+        Some((studio.iter().sum::<u32>() / studio.len() as u32) as u8)
+    }
+
+    /// Release the taped tour as a live record. Sells and charts as an
+    /// album, but only counts toward a deal's albums when the label rates
+    /// it highly enough to take it as one.
+    pub(in crate::game) fn action_release_live(
+        &mut self,
+        format: music::LiveFormat,
+        pressing: Option<usize>,
+    ) -> Result<(), String> {
+        if format == music::LiveFormat::ConcertFilm && !self.timeline.is_video_era() {
+            return Err(
+                "Nobody's showing concert films yet — that waits on music video.".to_string(),
+            );
+        }
+        let release_quality = self.live_release_quality(format)?;
+        let mixing_cost = self.live_release_cost(format);
+        let (copies, pressing_cost) = self.plan_pressing(&music::ReleaseType::Album, pressing)?;
+        let distribution_channel = self.current_distribution_channel;
+        let distribution_fee = self.plan_distribution(distribution_channel)?;
+        let cost = mixing_cost + pressing_cost + distribution_fee;
+        if !self.player.can_afford(cost) {
+            return Err(format!(
+                "The {} costs ${} — ${} to mix, plus pressing and distribution.",
+                format.label().to_lowercase(),
+                cost,
+                mixing_cost
+            ));
+        }
+        let Some(tapes) = self.live_tapes.take() else {
+            return Err("There are no tapes to release.".to_string());
+        };
+        self.post(LedgerCategory::Recording, -cost, format.label());
+
+        let headline = tapes
+            .rows
+            .iter()
+            .max_by_key(|row| row.reception)
+            .map(|row| row.venue_name.clone())
+            .unwrap_or_default();
+        let release_name = match format {
+            music::LiveFormat::Album => format!("Live at {}", headline),
+            music::LiveFormat::ConcertFilm => format!("Live at {} (Concert Film)", headline),
+        };
+        let signed = self.band.current_deal().is_some();
+        let deal_credit = self
+            .band
+            .current_deal()
+            .filter(|deal| deal.albums_owed())
+            .map(|deal| {
+                (
+                    deal.label_name.clone(),
+                    release_quality >= LIVE_DEAL_CREDIT_QUALITY,
+                )
+            });
+
+        let new_release = music::Release {
+            id: self.next_release_id,
+            name: release_name.clone(),
+            release_type: music::ReleaseType::Album,
+            release_quality,
+            week_released: self.week,
+            songs_involved_quality_avg: self.catalogue_song_quality().unwrap_or(0),
+            active_marketing: Vec::new(),
+            marketing_level_achieved: 0,
+            initial_sales_score: 0,
+            total_income_generated: 0,
+            genre: Some(self.band.genre.clone()),
+            copies_pressed: copies,
+            copies_sold: 0,
+            peak_chart_position: None,
+            singles_cut: 0,
+            certified: 0,
+            distribution_channel: if signed {
+                None
+            } else {
+                Some(distribution_channel)
+            },
+            label_market_reach: self.band.current_deal().map(|deal| deal.market_reach),
+            live: Some(format),
+            outside_deal: !deal_credit.as_ref().is_some_and(|(_, counts)| *counts),
+            master_owner: Some(self.new_master_owner()),
+            rerecording_of: None,
+        };
+        self.just_released_music.push(new_release);
+        self.next_release_id += 1;
+        self.log(format!(
+            "🎚️ Mixed the best of {} shows into the {} '{}' for ${} — quality {}, out in {} weeks.",
+            tapes.rows.len().min(LIVE_BEST_SHOWS),
+            format.label().to_lowercase(),
+            release_name,
+            cost,
+            release_quality,
+            INITIAL_SALES_WINDOW_WEEKS
+        ));
+        match deal_credit {
+            Some((label, true)) => self.log(format!(
+                "🤝 {} will take it as one of the albums you owe them.",
+                label
+            )),
+            Some((label, false)) => self.log(format!(
+                "📝 {} won't count a live record toward your deal.",
+                label
+            )),
+            None => {}
+        }
+        self.apply_label_promo();
+
+        Ok(())
+    }
 }
@@ -94,6 +94,53 @@ pub struct RecordDeal {
     /// `term_served` / `term_expired`.
     #[serde(default)]
     pub term_weeks: u16,
+    /// Who has the final say on the records (negotiated, `negotiation.rs`).
+    /// Pre-negotiation deals load as `Shared`.
+    #[serde(default)]
+    pub creative_control: CreativeControl,
+    /// A&R demands the band has defied under this deal (`a_and_r.rs`).
+    #[serde(default)]
+    pub defiances: u8,
+}
+
+/// A deal's creative-control clause. The more say the band keeps, the less
+/// the label invests in pushing records it didn't shape.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CreativeControl {
+    /// The label's A&R picks the singles and signs off the mixes.
+    LabelLed,
+    #[default]
+    Shared,
+    /// The band delivers finished records; the label just sells them.
+    BandLed,
+}
+
+impl CreativeControl {
+    /// In order of how much say the band keeps; also the index into the
+    /// per-clause tables in `constants.rs`.
+    pub const ALL: [CreativeControl; 3] = [
+        CreativeControl::LabelLed,
+        CreativeControl::Shared,
+        CreativeControl::BandLed,
+    ];
+
+    /// The clause a label writes into its opening offer, by how much
+    /// freedom it's known for giving.
+    pub fn offered_by(creative_freedom: u8) -> Self {
+        match creative_freedom {
+            0..=39 => CreativeControl::LabelLed,
+            40..=74 => CreativeControl::Shared,
+            _ => CreativeControl::BandLed,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CreativeControl::LabelLed => "Label-led",
+            CreativeControl::Shared => "Shared",
+            CreativeControl::BandLed => "Band-led",
+        }
+    }
 }
 
 fn default_market_reach() -> u8 {
@@ -0,0 +1,267 @@
+//! The back catalogue as an asset. Every release carries its master's
+//! owner: the label the band was signed to when it was recorded, paying
+//! the deal's royalty, or the band itself. A master a label holds can be
+//! bought back — for whatever the band still owes that label plus a
+//! premium — or competed with by re-recording its songs; a master the band
+//! holds can be sold to a label for a lump sum, giving up its tail for
+//! good. The weekly money for each copy follows the owner
+//! (`economy::process_music_releases_and_marketing`).
+
+use rand::Rng;
+
+use super::constants::*;
+use super::music::{MasterOwner, Release};
+use super::*;
+
+impl Release {
+    /// What the master is worth to whoever holds it: a share of everything
+    /// it has grossed so far, never less than the floor.
+    pub fn master_value(&self) -> u32 {
+        let per_copy = if self.label_market_reach.is_some() {
+            LABEL_INCOME_PER_COPY
+        } else {
+            INDIE_INCOME_PER_COPY
+        };
+        let gross = self.copies_sold * per_copy / SALES_INCOME_DIVISOR;
+        ((gross as f32 * MASTER_VALUE_SHARE_OF_GROSS) as u32).max(MASTER_VALUE_FLOOR)
+    }
+}
+
+impl Game {
+    /// Who owns a master recorded today: the label the band is signed to,
+    /// on the deal's royalty, or the band itself.
+    pub(super) fn new_master_owner(&self) -> MasterOwner {
+        match self.band.current_deal() {
+            Some(deal) => MasterOwner::Label {
+                name: deal.label_name.clone(),
+                royalty_rate: deal.royalty_rate,
+            },
+            None => MasterOwner::Band,
+        }
+    }
+
+    /// Every release already out in the shops, albums first.
+    pub fn catalogue(&self) -> impl Iterator<Item = &Release> {
+        self.band
+            .albums_released
+            .iter()
+            .chain(&self.band.singles_released)
+    }
+
+    /// A release already out in the shops, by id.
+    pub fn catalogue_release(&self, release_id: u32) -> Option<&Release> {
+        self.catalogue().find(|release| release.id == release_id)
+    }
+
+    fn catalogue_release_mut(&mut self, release_id: u32) -> Option<&mut Release> {
+        self.band
+            .albums_released
+            .iter_mut()
+            .chain(&mut self.band.singles_released)
+            .find(|release| release.id == release_id)
+    }
+
+    /// What the label holding a master asks to sell it back: what the band
+    /// still owes it, and the premium on top. `None` when the band owns the
+    /// master already.
+    pub fn buyback_price(&self, release: &Release) -> Option<(u32, u32)> {
+        let label = release.master_label()?;
+        let owed = self
+            .band
+            .current_deal()
+            .filter(|deal| deal.label_name == label)
+            .map_or(0, |deal| deal.unrecouped.max(0) as u32);
+        let premium = (release.master_value() as f32 * MASTER_BUYBACK_PREMIUM) as u32;
+        Some((owed, premium))
+    }
+
+    /// Who would buy a master the band owns, and for how much. `None` when
+    /// the band doesn't own it or no label has the money to spare.
+    pub fn master_sale_offer(&self, release: &Release) -> Option<(String, u32)> {
+        if !release.band_owns_master() {
+            return None;
+        }
+        let price = (release.master_value() as f32 * MASTER_SALE_SHARE) as u32;
+        let buyer = self.world.catalogue_buyer(&self.data_files, price)?;
+        Some((buyer, price))
+    }
+
+    /// Whether the band can re-record a release right now, and why not.
+    pub fn check_rerecord(&self, release: &Release) -> Result<(), String> {
+        let Some(label) = release.master_label() else {
+            return Err(format!(
+                "You own '{}' already — there's nothing to win back.",
+                release.name
+            ));
+        };
+        if release.live.is_some() {
+            return Err("A live record can't be re-recorded — that night's gone.".to_string());
+        }
+        let already = self
+            .catalogue()
+            .chain(&self.just_released_music)
+            .any(|other| other.rerecording_of == Some(release.id));
+        if already {
+            return Err(format!("You've already re-recorded '{}'.", release.name));
+        }
+        if self
+            .band
+            .current_deal()
+            .is_some_and(|deal| deal.label_name == label)
+        {
+            return Err(format!(
+                "{} won't let you compete with its own record while you're on its roster.",
+                label
+            ));
+        }
+        Ok(())
+    }
+
+    /// Buy a master back from the label that holds it. The price clears
+    /// whatever the band still owes that label. Instant.
+    pub(super) fn action_buy_back_master(&mut self, release_id: u32) -> Result<(), String> {
+        let release = self
+            .catalogue_release(release_id)
+            .ok_or_else(|| format!("Release with ID {} not found.", release_id))?;
+        let (owed, premium) = self
+            .buyback_price(release)
+            .ok_or_else(|| format!("You already own '{}'.", release.name))?;
+        let label = release.master_label().unwrap_or_default().to_string();
+        let name = release.name.clone();
+        let price = owed + premium;
+        if !self.player.can_afford(price as i32) {
+            return Err(format!(
+                "{} wants ${} for the master of '{}'.",
+                label, price, name
+            ));
+        }
+        self.post(
+            LedgerCategory::Masters,
+            -(price as i32),
+            format!("Bought back '{}'", name),
+        );
+        if owed > 0
+            && let Some(deal) = self.band.record_deal.as_mut()
+        {
+            deal.unrecouped = 0;
+        }
+        self.world.credit_label(&label, i64::from(price));
+        if let Some(release) = self.catalogue_release_mut(release_id) {
+            release.master_owner = Some(MasterOwner::Band);
+        }
+        let cleared = if owed > 0 {
+            format!(", clearing the ${} you still owed", owed)
+        } else {
+            String::new()
+        };
+        self.log(format!(
+            "🔑 Bought the master of '{}' back from {} for ${}{} — every copy pays you in full now.",
+            name, label, price, cleared
+        ));
+        Ok(())
+    }
+
+    /// Sell a master the band owns for a lump sum. Its sales belong to the
+    /// buyer from then on. Instant.
+    pub(super) fn action_sell_master(&mut self, release_id: u32) -> Result<(), String> {
+        let release = self
+            .catalogue_release(release_id)
+            .ok_or_else(|| format!("Release with ID {} not found.", release_id))?;
+        if !release.band_owns_master() {
+            return Err(format!("You don't own the master of '{}'.", release.name));
+        }
+        let name = release.name.clone();
+        let (buyer, price) = self
+            .master_sale_offer(release)
+            .ok_or("No label has the money to buy a catalogue right now.")?;
+        self.bank_income(price, LedgerCategory::Masters, format!("Sold '{}'", name));
+        self.world.debit_label(&buyer, i64::from(price));
+        if let Some(release) = self.catalogue_release_mut(release_id) {
+            release.master_owner = Some(MasterOwner::Label {
+                name: buyer.clone(),
+                royalty_rate: 0.0,
+            });
+        }
+        self.log(format!(
+            "💼 Sold the master of '{}' to {} for ${} — its sales are theirs from now on.",
+            name, buyer, price
+        ));
+        Ok(())
+    }
+
+    /// Cut the songs of a release the band doesn't own again, as a new
+    /// record under whoever the band is with now. It costs a fresh session
+    /// and pressing like any record, pulls in the fans who know the songs,
+    /// and takes half the original's tail once it's out.
+    pub(super) fn action_rerecord(
+        &mut self,
+        release_id: u32,
+        pressing: Option<usize>,
+        rng: &mut impl Rng,
+    ) -> Result<(), String> {
+        if self.player.stress >= STUDIO_STRESS_BLOCK {
+            return Err("You're too stressed to record quality music!".to_string());
+        }
+        let original = self
+            .catalogue_release(release_id)
+            .ok_or_else(|| format!("Release with ID {} not found.", release_id))?;
+        self.check_rerecord(original)?;
+        let release_type = original.release_type;
+        let song_quality = original.songs_involved_quality_avg;
+        let name = format!("{} (Re-recorded)", original.name);
+        let owner = original.master_label().unwrap_or_default().to_string();
+
+        let recording_cost = self.recording_cost(&release_type);
+        let (copies, pressing_cost) = self.plan_pressing(&release_type, pressing)?;
+        let distribution_channel = self.current_distribution_channel;
+        let distribution_fee = self.plan_distribution(distribution_channel)?;
+        let cost = recording_cost + pressing_cost + distribution_fee;
+        if !self.player.can_afford(cost) {
+            return Err(format!(
+                "Re-recording '{}' costs ${} with pressing and distribution.",
+                name, cost
+            ));
+        }
+        self.post(LedgerCategory::Recording, -cost, name.as_str());
+
+        let signed = self.band.current_deal().is_some();
+        let new_release = music::Release {
+            id: self.next_release_id,
+            name: name.clone(),
+            release_type,
+            release_quality: self.calculate_release_quality(song_quality, rng),
+            week_released: self.week,
+            songs_involved_quality_avg: song_quality,
+            active_marketing: Vec::new(),
+            marketing_level_achieved: 0,
+            initial_sales_score: 0,
+            total_income_generated: 0,
+            genre: Some(self.band.genre.clone()),
+            copies_pressed: copies,
+            copies_sold: 0,
+            peak_chart_position: None,
+            singles_cut: 0,
+            certified: 0,
+            distribution_channel: if signed {
+                None
+            } else {
+                Some(distribution_channel)
+            },
+            label_market_reach: self.band.current_deal().map(|deal| deal.market_reach),
+            live: None,
+            // Old songs aren't the new album a deal asks for.
+            outside_deal: true,
+            master_owner: Some(self.new_master_owner()),
+            rerecording_of: Some(release_id),
+        };
+        self.just_released_music.push(new_release);
+        self.next_release_id += 1;
+        self.log(format!(
+            "🎙️ Re-recorded '{}' for ${} to take on {}'s master — out in {} weeks.",
+            name, cost, owner, INITIAL_SALES_WINDOW_WEEKS
+        ));
+        self.apply_label_promo();
+        self.player.stress = (self.player.stress + RECORD_STRESS_COST).min(MAX_STRESS);
+        Ok(())
+    }
+}
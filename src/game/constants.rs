@@ -148,13 +148,13 @@ pub(super) const PLAYER_MARKET_IMPACT_DEMAND_BONUS: u8 = 1;
 // records word of mouth stalls. Gigs and tours raise fame no further than
 // the smaller of the venue's ceiling and the catalog cap.
 pub(super) const VENUE_FAME_HEADROOM: u8 = 15;
+// A venue books the band once its prestige is within this much of their fame
+// (before any manager's pull — see `MANAGER_VENUE_REACH_MAX`).
+pub(super) const GIG_VENUE_REACH: u8 = 20;
 pub(super) const LIVE_FAME_BASE_CAP: u8 = 35;
 pub(super) const LIVE_FAME_PER_SINGLE: u8 = 6;
 pub(super) const LIVE_FAME_PER_ALBUM: u8 = 12;
 
-// A break clears the calendar for this many weeks (a full reset action).
-pub const BREAK_WEEKS: u32 = 4;
-
 // ============================================================================
 // Fame gravity (v0.6 design §C — fully decided; only the comeback multiplier
 // is [tune]). Fame is earned slowly and defended by staying in the picture;
@@ -253,13 +253,76 @@ pub(super) const MAX_HAPPINESS: u8 = 100;
 pub(super) const MAX_CREATIVITY: u8 = 100;
 
 // Rest actions (`actions/rest.rs`): laze trades stress for a little
-// creativity; a real break resets stress and gives happiness/creativity/
-// health a proper boost.
+// creativity; a vacation (below) is the real, paid-for recovery.
 pub(super) const LAZE_STRESS_RELIEF: u8 = 15;
 pub(super) const LAZE_CREATIVITY_GAIN: u8 = 3;
-pub(super) const BREAK_HAPPINESS_GAIN: u8 = 10;
-pub(super) const BREAK_CREATIVITY_GAIN: u8 = 10;
-pub(super) const BREAK_HEALTH_GAIN: u8 = 30;
+
+// ============================================================================
+// Vacations (FUTURE §9.3): the flat Take a Break became the original game's
+// escalating menu, cheap weekend to world cruise. Tables are index-aligned
+// with `Vacation::ALL` (`actions/rest.rs`): Seaside weekend, Country retreat,
+// Mediterranean holiday, World cruise. No fame cost is tuned here — a long
+// trip is simply that many quiet weeks for `update_public_visibility`. [tune]
+// ============================================================================
+
+/// Up-front price of each trip. [tune]
+pub(super) const VACATION_COST: [u32; 4] = [50, 400, 2_000, 12_000];
+/// Weeks away, the turn's own week included. `pub`: the sim lab bounds a
+/// single turn's calendar jump by the longest trip.
+pub const VACATION_WEEKS: [u32; 4] = [1, 2, 4, 10];
+/// Stress shed; 100 means a full reset. [tune]
+pub(super) const VACATION_STRESS_RELIEF: [u8; 4] = [20, 50, 100, 100];
+/// Happiness gained; the cruise's 100 means "back to the top". [tune]
+pub(super) const VACATION_HAPPINESS_GAIN: [u8; 4] = [5, 12, 25, 100];
+pub(super) const VACATION_CREATIVITY_GAIN: [u8; 4] = [3, 8, 15, 25];
+/// Health recovered before the lifestyle's rest bonus; 100 means full. [tune]
+pub(super) const VACATION_HEALTH_GAIN: [u8; 4] = [5, 30, 100, 100];
+/// Taken off both (dormant, §9.1) addiction bars — only the long trips get
+/// you far enough from the scene to dry out. [tune]
+pub(super) const VACATION_ADDICTION_RELIEF: [u8; 4] = [0, 0, 5, 20];
+
+// ============================================================================
+// The manager (FUTURE §9.4): the deal-offer stream applied to a person.
+// Managers scout on the same buzz scale labels do (`world/managers.rs`) and
+// take a cut of every dollar that reaches the player (`manager.rs`). Every
+// benefit scales linearly with `skill` (0-100); honesty is rolled at
+// generation and never shown. [tune] throughout.
+// ============================================================================
+
+/// Buzz (`GameWorld::band_buzz`) before anyone wants to manage you — below
+/// the independent-label threshold, so a manager can arrive ahead of a deal.
+pub(super) const MANAGER_OFFER_MIN_BUZZ: u8 = 8;
+/// Weeks between scouting cycles.
+pub(super) const MANAGER_SCOUT_INTERVAL_WEEKS: u32 = 4;
+/// Chance per scouting cycle that anyone calls at all.
+pub(super) const MANAGER_OFFER_CHANCE: f64 = 0.35;
+pub(super) const MANAGER_MAX_OFFERS: usize = 2;
+/// Same beat as a label's offer (`DEAL_OFFER_LIFETIME_WEEKS`).
+pub(super) const MANAGER_OFFER_LIFETIME_WEEKS: u32 = 4;
+/// Commission bounds: the cheapest hustler to the priciest operator.
+pub(super) const MANAGER_CUT_MIN: f32 = 0.10;
+pub(super) const MANAGER_CUT_MAX: f32 = 0.25;
+/// Extra prestige headroom on top of `GIG_VENUE_REACH` at skill 100 — about
+/// one venue tier early.
+pub(super) const MANAGER_VENUE_REACH_MAX: u8 = 25;
+/// Royalty points added to every fresh deal offer: MIN at skill 0, MAX at 100.
+pub(super) const MANAGER_ROYALTY_BUMP_MIN: f32 = 0.02;
+pub(super) const MANAGER_ROYALTY_BUMP_MAX: f32 = 0.05;
+/// Marketing and tour-cost discounts at skill 100.
+pub(super) const MANAGER_MARKETING_DISCOUNT_MAX: f32 = 0.25;
+pub(super) const MANAGER_TOUR_DISCOUNT_MAX: f32 = 0.30;
+/// Honesty below this skims; the slice grows toward `MANAGER_SKIM_MAX` of
+/// gross income as honesty falls to 0.
+pub(super) const MANAGER_HONEST_THRESHOLD: u8 = 50;
+pub(super) const MANAGER_SKIM_MAX: f32 = 0.15;
+/// Weekly chance a skim comes to light, once there's been one.
+pub(super) const MANAGER_DISCOVERY_CHANCE: f64 = 0.04;
+pub(super) const MANAGER_DISCOVERY_HAPPINESS_HIT: u8 = 25;
+pub(super) const MANAGER_DISCOVERY_STRESS_HIT: u8 = 15;
+/// Firing settlement: this many weeks of the manager's average take.
+pub(super) const MANAGER_SEVERANCE_WEEKS: u32 = 8;
+/// Weeks after a manager leaves before anyone else will call.
+pub(super) const MANAGER_COOLDOWN_WEEKS: u32 = 12;
 
 // New-player defaults (`player.rs`) — the original 1989 game's starting
 // mood/imagination, not maxed like health/energy.
@@ -296,6 +359,10 @@ pub(super) const RECORDING_STRESS_PENALTY: i8 = 10;
 // per-week chance one fires, rolled on the action stream in `events.rs`.
 // [tune] — §F raises cadence from 30% every-other-week to 35% weekly.
 pub(super) const INCIDENT_WEEKLY_CHANCE_PERCENT: u32 = 35;
+// A positive `release_marketing` incident effect lands as a free press
+// campaign this many weeks long, so the weekly marketing recompute keeps it.
+// [tune]
+pub(super) const INCIDENT_PRESS_WEEKS: u32 = 4;
 
 // ============================================================================
 // L3: per-show engine (docs/DESIGN-v0.6-life-cycle.md §B — `shows.rs`).
@@ -402,7 +469,7 @@ pub(super) const LIFESTYLE_STRESS_RELEASE_BONUS: [u8; 5] = [0, 1, 2, 3, 4];
 /// (event/incident losses still can), by tier. [tune]
 pub(super) const LIFESTYLE_HAPPINESS_FLOOR: [u8; 5] = [0, 5, 10, 15, 20];
 /// Added to the health/stress recovery of rest-type actions (`LazeAround`,
-/// `TakeBreak`), by tier. [tune]
+/// `TakeVacation`), by tier. [tune]
 pub(super) const LIFESTYLE_REST_HEALING_BONUS: [u8; 5] = [0, 1, 2, 3, 4];
 
 /// Fame at/above which living at Squat or Shared flat draws tabloid
@@ -485,6 +552,327 @@ pub(super) const TOUR_FAME_WEEKS_EXPONENT: f32 = 0.7;
 pub(super) const TOUR_REGIONAL_FAME_GAIN_BASE: f32 = 7.0;
 pub(super) const TOUR_REGIONAL_FAME_GAIN_RNG_SPREAD: u8 = 5;
 
+/// Routed tours: several regions booked as one tour, with travel legs
+/// between the stops priced per rig in `markets.json`'s `touring_costs`.
+/// Each stop keeps the single-region length gates; the route as a whole is
+/// capped here, and anything past one stop needs some fame first. [tune]
+pub(super) const TOUR_ROUTE_MAX_STOPS: usize = 4;
+pub(super) const TOUR_ROUTE_MAX_WEEKS: u8 = 10;
+pub(super) const TOUR_ROUTE_FAME_GATE: u8 = 40;
+/// Extra stress per international flight on a routed tour. [tune]
+pub(super) const TOUR_FLIGHT_STRESS: u8 = 3;
+
+/// Ticket price tiers (`TicketTier`, `actions/live.rs`), index-aligned:
+/// cheap, standard, premium. The price multiplies each show's take; the
+/// demand multiplier scales `reception_attendance_factor`, so cheap seats
+/// pack the room (and sell more merch) for less at the door, and premium
+/// seats earn a little more from fewer people. Standard is exactly the
+/// untiered tour. [tune]
+pub(super) const TICKET_TIER_PRICE_MULT: [f32; 3] = [0.7, 1.0, 1.45];
+pub(super) const TICKET_TIER_DEMAND_MULT: [f32; 3] = [1.25, 1.0, 0.75];
+
+/// Merchandise: ordered up front with a tour booking, sold per show at
+/// tours and gigs while stock lasts, leftovers carried to the next show.
+/// What a unit costs to make and what it sells for before the economic
+/// cycle's `merchandise_modifier` (`markets.json`). [tune]
+pub(super) const MERCH_UNIT_COST: u32 = 4;
+pub(super) const MERCH_UNIT_PRICE: f32 = 10.0;
+/// The order sizes the booking picker cycles through.
+pub(super) const MERCH_ORDER_STEPS: [u32; 7] = [0, 100, 250, 500, 1_000, 2_500, 5_000];
+/// Share of the crowd buying something: a base plus a little per point of
+/// fame, then scaled by the night's verdict (rough, solid, great,
+/// transcendent). [tune]
+pub(super) const MERCH_BUY_RATE_BASE: f32 = 0.03;
+pub(super) const MERCH_BUY_RATE_PER_FAME: f32 = 0.0007;
+pub(super) const MERCH_VERDICT_MULT: [f32; 4] = [0.5, 1.0, 1.5, 2.0];
+
+// ============================================================================
+// The venue roster (`world/venues.rs`). Home venues open and close as the
+// years turn, each remembers how the band's shows there went, and its
+// promoter's opinion of the band moves the fee, earns return bookings, and
+// — after enough bad nights — gets the band banned. Touring regions keep
+// rosters of their own, sized by `venue_density` in `markets.json`. [tune]
+// unless noted.
+// ============================================================================
+
+/// The five home-venue tiers worldgen has always built, smallest first:
+/// capacity, prestige, and base payment. New venues are cut from the same
+/// templates, with some spread.
+pub(super) const VENUE_TIER_CAPACITY: [u32; 5] = [50, 200, 500, 2_000, 20_000];
+pub(super) const VENUE_TIER_PRESTIGE: [u8; 5] = [10, 25, 40, 70, 95];
+pub(super) const VENUE_TIER_PAYMENT: [u32; 5] = [100, 300, 800, 3_000, 15_000];
+
+/// Yearly chance each home venue closes, replaced by a new room of its tier.
+pub(super) const VENUE_YEARLY_CLOSE_CHANCE: f64 = 0.12;
+/// The home roster grows with the era's `touring_market` (0-100): this
+/// many venues at minimum, one more per step of touring market.
+pub(super) const VENUE_ROSTER_BASE: usize = 5;
+pub(super) const VENUE_ROSTER_TOURING_STEP: u8 = 25;
+/// New venues that can open in one year.
+pub(super) const VENUE_MAX_OPENINGS_PER_YEAR: usize = 2;
+
+/// A promoter starts neutral; each show moves the opinion by verdict
+/// (rough, solid, great, transcendent).
+pub(super) const VENUE_PROMOTER_START: u8 = 50;
+pub(super) const VENUE_PROMOTER_DELTA: [i8; 4] = [-12, 2, 5, 8];
+/// The fee swings up to this share either way with the promoter's opinion
+/// (0 → ×0.8, 50 → ×1.0, 100 → ×1.2).
+pub(super) const VENUE_PROMOTER_FEE_SWING: f32 = 0.2;
+/// A great night earns an invitation back: the next gig there within this
+/// many weeks pays the return-booking bonus.
+pub(super) const VENUE_INVITE_WEEKS: u32 = 8;
+pub(super) const VENUE_INVITE_FEE_BONUS: f32 = 1.25;
+/// A promoter whose opinion sinks below this bans the band for a while,
+/// then takes them back grudgingly.
+pub(super) const VENUE_BAN_PROMOTER: u8 = 15;
+pub(super) const VENUE_BAN_WEEKS: u32 = 26;
+pub(super) const VENUE_PROMOTER_AFTER_BAN: u8 = 30;
+
+/// A touring region's roster: this many venues plus one per step of its
+/// `venue_density`; each venue's size spreads around the region's typical
+/// room.
+pub(super) const VENUE_REGION_ROSTER_MIN: usize = 3;
+pub(super) const VENUE_REGION_DENSITY_STEP: u8 = 15;
+pub(super) const VENUE_REGION_CAPACITY_SPREAD: f32 = 0.5;
+
+// ============================================================================
+// Festivals (`world/festivals.rs`). Each new year publishes a summer
+// calendar sized by the era's touring market; scene bands fill the bills,
+// and a famous enough band gets offered a slot a few weeks ahead. Billing
+// tables are index-aligned with `FestivalBilling::ALL` (opener, support,
+// sub-headliner, headliner). [tune] unless noted.
+// ============================================================================
+
+/// Festivals per year: this many, plus one per step of `touring_market`.
+pub(super) const FESTIVAL_BASE_COUNT: usize = 2;
+pub(super) const FESTIVAL_TOURING_STEP: u8 = 30;
+/// The festival season, as weeks of the year (June through August).
+pub(super) const FESTIVAL_SEASON_START_WEEK: u32 = 22;
+pub(super) const FESTIVAL_SEASON_END_WEEK: u32 = 35;
+/// A festival's size (0-100) is a base roll plus a share of the touring
+/// market; the crowd is size × this many people.
+pub(super) const FESTIVAL_SIZE_ROLL: (u8, u8) = (20, 70);
+pub(super) const FESTIVAL_SIZE_TOURING_DIVISOR: u8 = 4;
+pub(super) const FESTIVAL_CROWD_PER_SIZE: u32 = 1_000;
+
+/// Slots on each bill, and how far below the festival's size each billing's
+/// fame gate sits (the headliner needs fame ≥ size).
+pub(super) const FESTIVAL_BILLING_SLOTS: [usize; 4] = [4, 3, 2, 1];
+pub(super) const FESTIVAL_BILLING_FAME_BELOW: [u8; 4] = [45, 30, 15, 0];
+/// Fee at a size-50 festival (scaled by size and the era's gig pay), fame
+/// gained, and the regional fame gained across the host country.
+pub(super) const FESTIVAL_BILLING_PAY: [u32; 4] = [300, 1_000, 3_000, 10_000];
+pub(super) const FESTIVAL_BILLING_FAME: [u8; 4] = [3, 5, 8, 12];
+pub(super) const FESTIVAL_BILLING_REGIONAL_FAME: [u8; 4] = [8, 12, 16, 22];
+/// The share of the crowd actually watching your set — what the merch
+/// table sells to.
+pub(super) const FESTIVAL_BILLING_CROWD: [f32; 4] = [0.15, 0.3, 0.6, 1.0];
+/// Word travels: every region outside the host country gains this share of
+/// the host's regional fame.
+pub(super) const FESTIVAL_ABROAD_SHARE: f32 = 0.25;
+
+/// Offers arrive at most this many weeks ahead, and expire on the day.
+pub(super) const FESTIVAL_OFFER_LEAD_WEEKS: u32 = 4;
+/// Weekly chance an eligible festival makes its offer.
+pub(super) const FESTIVAL_OFFER_CHANCE: f64 = 0.4;
+
+/// A festival weekend's cost to the band.
+pub(super) const FESTIVAL_STRESS_COST: u8 = 15;
+pub(super) const FESTIVAL_HEALTH_COST: u8 = 3;
+
+/// Chance a great set goes down as legendary, by billing; doubled for a
+/// transcendent one. A legendary set makes the news and adds fame.
+pub(super) const FESTIVAL_LEGENDARY_CHANCE: [f64; 4] = [0.05, 0.1, 0.15, 0.25];
+pub(super) const FESTIVAL_LEGENDARY_FAME: u8 = 5;
+
+// ============================================================================
+// Headline tour offers (`world/promoters.rs`). Once the band can sell out
+// rooms on its own, tour promoters offer to book a fixed route and carry its
+// costs, paying the band a guaranteed fee or a share of the door. Each
+// promoter remembers how the shows they backed went. [tune] unless noted.
+// ============================================================================
+
+/// The promoters working the touring circuit.
+pub(super) const TOUR_PROMOTER_COUNT: usize = 5;
+/// A promoter starts neutral; each show they back moves the opinion by
+/// verdict (rough, solid, great, transcendent), and a tour that loses them
+/// money costs a little more on top.
+pub(super) const TOUR_PROMOTER_START: u8 = 50;
+pub(super) const TOUR_PROMOTER_SHOW_DELTA: [i8; 4] = [-4, 0, 1, 2];
+pub(super) const TOUR_PROMOTER_LOSS_PENALTY: u8 = 10;
+/// A promoter whose opinion is below this stops making offers.
+pub(super) const TOUR_PROMOTER_OFFER_MIN_OPINION: u8 = 30;
+/// The deal swings up to this share either way with the promoter's opinion
+/// (0 → ×0.85, 50 → ×1.0, 100 → ×1.15).
+pub(super) const TOUR_PROMOTER_DEAL_SWING: f32 = 0.15;
+
+/// Fame needed before promoters call, and the weekly chance one does.
+pub(super) const HEADLINE_OFFER_MIN_FAME: u8 = 55;
+pub(super) const HEADLINE_OFFER_CHANCE: f64 = 0.05;
+/// Offers stay open a month, like a label's.
+pub(super) const HEADLINE_OFFER_LIFETIME_WEEKS: u32 = 4;
+/// The route on offer: this many stops, each this many weeks.
+pub(super) const HEADLINE_OFFER_STOPS: (usize, usize) = (2, 3);
+pub(super) const HEADLINE_OFFER_STOP_WEEKS: (u8, u8) = (1, 2);
+/// Chance the offer is a guarantee rather than a percentage deal.
+pub(super) const HEADLINE_GUARANTEE_CHANCE: f64 = 0.5;
+/// A guarantee is this share of the route's projected net (the middle of
+/// the quote's gross, less its costs); a percentage deal pays a share of
+/// the actual net rolled from this range. Either is before the promoter's
+/// opinion. A route the promoter can't expect to profit on isn't offered.
+pub(super) const HEADLINE_GUARANTEE_SHARE: f32 = 0.5;
+pub(super) const HEADLINE_PERCENTAGE_RANGE: (f32, f32) = (0.6, 0.75);
+
+// ============================================================================
+// Openers (`TourTerms::opener`). A famous band can take a smaller scene act
+// out as its opening act: a hot opener draws a bigger crowd but wants a fee
+// per show, and the tour builds the opener's fame and momentum. Each scene
+// band remembers how the tours it opened went — friends return the favour,
+// rivals hold a grudge. [tune] unless noted.
+// ============================================================================
+
+/// Fame the band needs before it can bring an opener along.
+pub(super) const OPENER_MIN_FAME: u8 = 40;
+/// How many of the hottest eligible acts the tour picker offers.
+pub(super) const OPENER_SHORTLIST: usize = 8;
+/// The lift a fully hot opener (heat 100) puts on every stop's gross. Heat
+/// is the opener's fame plus `OPENER_MOMENTUM_HEAT` per point of momentum.
+pub(super) const OPENER_DRAW_MAX: f32 = 0.3;
+pub(super) const OPENER_MOMENTUM_HEAT: i16 = 5;
+/// The opener's fee: this share of every stop's projected gross for a
+/// fame-100 act, less for smaller ones. Set against the draw, an opener pays
+/// its way on fame alone only just — momentum is what makes one worth it.
+pub(super) const OPENER_FEE_SHARE: f32 = 0.25;
+/// The opener's fame gain per tour week, plus a bonus by the tour's average
+/// verdict (rough, solid, great, transcendent).
+pub(super) const OPENER_FAME_PER_WEEK: u8 = 1;
+pub(super) const OPENER_FAME_BONUS: [u8; 4] = [0, 1, 2, 4];
+/// How the tour moves the opener's rapport with the band, by the same
+/// verdict. Rapport stays within ±`SCENE_RAPPORT_MAX`.
+pub(super) const OPENER_RAPPORT_DELTA: [i8; 4] = [-3, 2, 3, 4];
+pub(super) const SCENE_RAPPORT_MAX: i8 = 10;
+/// A scene band at or above this rapport is a friend: it hosts the band's
+/// support slots first and won't poach a deal the band turns down. At or
+/// below the rival mark it won't open for the band or invite it out, and
+/// is first in line for a rejected deal.
+pub(super) const SCENE_FRIEND_RAPPORT: i8 = 3;
+pub(super) const SCENE_RIVAL_RAPPORT: i8 = -3;
+
+// ============================================================================
+// Gear and crew (`gear.rs`). The band owns its gear, which wears with every
+// show and breaks down more the worse it gets; an owned vehicle cuts the
+// hire on its rig. Crew are paid by the week. Gear tables are index-aligned
+// with `GearKind::ALL` (instruments, backline, PA system, van, tour bus,
+// truck); crew tables with `CrewRole::ALL` (tour manager, sound engineer,
+// roadie). [tune] unless noted.
+// ============================================================================
+
+/// What each piece of gear costs new. Vehicles run ~30 weeks of hire.
+pub(super) const GEAR_PRICE: [u32; 6] = [800, 1_500, 4_000, 3_600, 12_000, 36_000];
+/// Reception added by gear in working order (condition at or above
+/// `GEAR_BREAKDOWN_CONDITION`). Vehicles don't play.
+pub(super) const GEAR_RECEPTION_BONUS: [u8; 6] = [0, 2, 3, 0, 0, 0];
+/// The instruments every band starts with, already played in.
+pub(super) const GEAR_STARTING_CONDITION: f32 = 80.0;
+/// An owned vehicle saves this share of its rig's weekly hire.
+pub(super) const GEAR_OWNED_RIG_SAVING: f32 = 0.6;
+/// Condition every piece of playing gear loses per show, plus the rig's
+/// health wear per week (`TourRig::wear_per_week`) over this divisor — a
+/// rough ride shakes the gear too. An owned vehicle loses its own wear per
+/// tour week.
+pub(super) const GEAR_WEAR_PER_SHOW: f32 = 0.5;
+pub(super) const GEAR_WEAR_RIG_DIVISOR: f32 = 4.0;
+pub(super) const GEAR_VEHICLE_WEAR_PER_WEEK: f32 = 3.0;
+/// Below this condition gear can break down, up to the max chance per show
+/// at condition 0.
+pub(super) const GEAR_BREAKDOWN_CONDITION: f32 = 50.0;
+pub(super) const GEAR_BREAKDOWN_MAX_CHANCE: f32 = 0.3;
+/// A breakdown costs the show this much reception, and an emergency patch
+/// — a share of the item's price — brings it back up to the threshold.
+pub(super) const GEAR_BREAKDOWN_RECEPTION: u8 = 15;
+pub(super) const GEAR_BREAKDOWN_REPAIR_SHARE: f32 = 0.1;
+/// A full service restores condition at this share of the price per
+/// condition point lost (of 100).
+pub(super) const GEAR_SERVICE_COST_SHARE: f32 = 0.5;
+/// Selling gear returns this share of its condition-scaled price.
+pub(super) const GEAR_RESALE_SHARE: f32 = 0.5;
+/// Insurance: a weekly premium on the gear's value; a covered gear
+/// incident costs only the excess share of the claim — the repair bill up
+/// to what a service would charge, or the value of gear that's gone.
+pub(super) const GEAR_INSURANCE_WEEKLY_RATE: f32 = 0.005;
+pub(super) const GEAR_INSURANCE_EXCESS_SHARE: f32 = 0.2;
+
+/// Weekly wages, the fame a band needs before each role will sign on, and
+/// how many can be hired.
+pub(super) const CREW_WAGE: [u32; 3] = [250, 150, 60];
+pub(super) const CREW_FAME_GATE: [u8; 3] = [30, 20, 0];
+pub(super) const CREW_MAX_HIRED: [usize; 3] = [1, 1, 3];
+/// A sound engineer narrows the reception variance to this share.
+pub(super) const CREW_SOUND_VARIANCE_MULT: f32 = 0.5;
+/// A tour manager scales breakdown risk by this and takes stress off
+/// every tour week.
+pub(super) const CREW_TOUR_MANAGER_BREAKDOWN_MULT: f32 = 0.6;
+pub(super) const CREW_TOUR_MANAGER_STRESS_RELIEF: u8 = 2;
+/// Each roadie scales breakdown risk and gear wear by these.
+pub(super) const CREW_ROADIE_BREAKDOWN_MULT: f32 = 0.85;
+pub(super) const CREW_ROADIE_WEAR_MULT: f32 = 0.8;
+
+// ============================================================================
+// Live records (`actions/studio.rs`). A tour booked with recording on leaves
+// its tapes behind; the best shows on them can be mixed into a live album,
+// or a concert film in a video era. Quality comes from the receptions and
+// the catalogue, not from unreleased songs. [tune] unless noted.
+// ============================================================================
+
+/// The mobile studio: a flat booking fee plus a charge per show taped.
+pub(super) const LIVE_RECORDING_FEE_BASE: i32 = 500;
+pub(super) const LIVE_RECORDING_FEE_PER_SHOW: i32 = 60;
+/// Shows a recording needs before there's a record in it.
+pub(super) const LIVE_MIN_SHOWS: usize = 3;
+/// How many of the best shows make the record.
+pub(super) const LIVE_BEST_SHOWS: usize = 8;
+/// Share of a live record's quality from the shows; the rest is the
+/// catalogue the band played.
+pub(super) const LIVE_PERFORMANCE_WEIGHT: f32 = 0.6;
+/// Mixing the tapes, before the era's recording-cost modifier.
+pub(super) const LIVE_MIXING_COST: i32 = 1_500;
+/// On top of the mix: cutting the footage into a film.
+pub(super) const LIVE_FILM_COST: i32 = 4_000;
+pub(super) const LIVE_FILM_QUALITY_BONUS: u8 = 5;
+/// An era's `media_influence` from which a concert film has somewhere to
+/// play (1980 on, when music video arrives).
+pub(super) const LIVE_FILM_MEDIA_INFLUENCE: u8 = 80;
+/// The quality a label wants before a live record counts as a contract
+/// album.
+pub(super) const LIVE_DEAL_CREDIT_QUALITY: u8 = 75;
+
+// ============================================================================
+// Setlists (`setlist.rs`). A tour can carry a setlist drawn from the
+// catalogue and the unreleased songs. Hits the crowd knows lift every
+// show's reception, unreleased songs cost a little of it but tighten up on
+// the road, and a set too short to fill the night sinks it. An empty
+// setlist is the band's usual set and moves nothing. [tune] unless noted.
+// ============================================================================
+
+/// The longest set a band can play, and the shortest that fills a night;
+/// every song short of it costs each show this much reception.
+pub const SETLIST_MAX_SONGS: usize = 16;
+pub const SETLIST_MIN_SONGS: usize = 8;
+pub(super) const SETLIST_SHORT_SONG_PENALTY: f32 = 3.0;
+/// Reception a set of nothing but fully familiar hits adds to every show.
+/// A release's familiarity is its peak chart placement (weighted by
+/// `SETLIST_CHART_WEIGHT`) and its sales, full at `SETLIST_SALES_FAMILIAR`
+/// copies.
+pub(super) const SETLIST_FAMILIARITY_MAX: f32 = 10.0;
+pub(super) const SETLIST_CHART_WEIGHT: f32 = 0.6;
+pub(super) const SETLIST_SALES_FAMILIAR: u32 = 100_000;
+/// Reception each unreleased song on the set costs every show.
+pub(super) const SETLIST_NEW_SONG_COST: f32 = 1.0;
+/// An unreleased song gains a point of songwriting quality every this many
+/// shows it's played, at most `SETLIST_ROAD_TEST_MAX_GAIN` a tour.
+pub(super) const SETLIST_ROAD_TEST_SHOWS_PER_POINT: u32 = 2;
+pub(super) const SETLIST_ROAD_TEST_MAX_GAIN: u8 = 10;
+
 // ============================================================================
 // M5: Label recoupment + label auto-repress
 // (docs/DESIGN-v0.7-money-cycle.md §E-2 and the §E-1 label half). The label's
@@ -581,24 +969,289 @@ pub(super) const DEAL_MEMO_IDLE_WEEKS: u32 = 4;
 pub(super) const DEAL_MEMO_DEADLINE_WINDOW_WEEKS: u32 = 12;
 pub(super) const DEAL_MEMO_DEADLINE_STRESS_PER_WEEK: u8 = 3;
 
+// ============================================================================
+// Contract negotiation (`negotiation.rs`): a counter costs the label a
+// "greed" score summed over the terms it improves; the label's appetite is
+// a base plus the band's buzz. Within appetite the label signs the
+// counter, within `NEGOTIATION_HALFWAY_MULTIPLE` of it the label meets
+// halfway, and past that it holds firm or walks. [tune] throughout.
+// ============================================================================
+
+/// Counters a label hears on one offer before it calls it final. `pub`:
+/// the counter screen shows how many are left.
+pub const NEGOTIATION_MAX_COUNTERS: u8 = 2;
+/// Greed for asking another whole top-of-range advance, at full financial
+/// power; a label with none minds twice as much.
+pub(super) const NEGOTIATION_ADVANCE_WEIGHT: f32 = 0.6;
+/// Greed per royalty percentage point.
+pub(super) const NEGOTIATION_ROYALTY_POINT_COST: f32 = 0.06;
+/// Greed per album knocked off the commitment.
+pub(super) const NEGOTIATION_ALBUM_COST: f32 = 0.15;
+/// Greed per year knocked off the term.
+pub(super) const NEGOTIATION_TERM_YEAR_COST: f32 = 0.2;
+/// Greed per step of creative control, at no creative freedom; a label
+/// known for full freedom gives it up for nothing.
+pub(super) const NEGOTIATION_CONTROL_STEP_COST: f32 = 0.3;
+/// What any label gives, and what full buzz adds on top.
+pub(super) const NEGOTIATION_BASE_APPETITE: f32 = 0.1;
+pub(super) const NEGOTIATION_BUZZ_APPETITE: f32 = 0.6;
+/// The label's mood on the day scales its appetite.
+pub(super) const NEGOTIATION_APPETITE_ROLL: (f32, f32) = (0.8, 1.2);
+pub(super) const NEGOTIATION_HALFWAY_MULTIPLE: f32 = 2.0;
+/// Bounds on a counter, whatever the label's mood.
+pub(super) const NEGOTIATION_MAX_ROYALTY: f32 = 0.5;
+pub(super) const NEGOTIATION_MAX_ALBUMS: u8 = 6;
+pub(super) const NEGOTIATION_MIN_TERM_WEEKS: u16 = 26;
+pub(super) const NEGOTIATION_MAX_TERM_WEEKS: u16 = 208;
+/// The label's promo push per release (`apply_label_promo`) by
+/// creative-control clause, index-aligned with `CreativeControl::ALL`:
+/// label-led, shared, band-led.
+pub(super) const LABEL_PROMO_CONTROL_SCALE: [f32; 3] = [1.2, 1.0, 0.7];
+
+// ============================================================================
+// A&R (`a_and_r.rs`): how hard a label leans on the records it puts out,
+// what each demand does to a record, what defying one costs the deal, and
+// what a label's artist development adds to rehearsal. [tune] throughout.
+// ============================================================================
+
+/// A&R pressure per point of creative freedom the label doesn't give, by
+/// creative-control clause, index-aligned with `CreativeControl::ALL`: a
+/// label-led deal doubles it, a band-led one leaves the band alone.
+pub(super) const AR_CLAUSE_PRESSURE: [f32; 3] = [2.0, 1.0, 0.0];
+/// The pressure at which A&R turns down a concept album, asks for another
+/// genre, pushes its producer on a record and re-sequences an album.
+pub(super) const AR_CONCEPT_MIN_PRESSURE: f32 = 20.0;
+pub(super) const AR_GENRE_MIN_PRESSURE: f32 = 25.0;
+pub(super) const AR_PRODUCER_MIN_PRESSURE: f32 = 30.0;
+pub(super) const AR_TRACKLIST_MIN_PRESSURE: f32 = 35.0;
+/// An album recorded with creativity this high is a concept album; giving
+/// up the idea at A&R's say-so costs the band this much creativity.
+pub(super) const CONCEPT_ALBUM_MIN_CREATIVITY: u8 = 75;
+pub(super) const AR_DROP_CONCEPT_CREATIVITY_COST: u8 = 15;
+/// A&R's producer goes after any record under this quality and pulls it
+/// halfway there, for a fee that joins the recoupment ledger.
+pub(super) const AR_PRODUCER_QUALITY: u8 = 70;
+pub(super) const AR_PRODUCER_FEE: i32 = 3_000;
+/// A&R's running order: the singles up front buy a bigger push and cost
+/// the album some of its shape.
+pub(super) const AR_TRACKLIST_PUSH: u8 = 10;
+pub(super) const AR_TRACKLIST_QUALITY_COST: u8 = 3;
+/// Every defied demand takes this much off the deal's term. [ui]
+pub const AR_DEFIANCE_TERM_CUT_WEEKS: u16 = 13;
+/// Artist development above the baseline adds a point of band skill to a
+/// week of rehearsal per this many points.
+pub(super) const AR_DEVELOPMENT_BASELINE: u8 = 60;
+pub(super) const AR_DEVELOPMENT_PER_SKILL: u8 = 15;
+
+// ============================================================================
+// Labels as going concerns (`world/labels.rs`): each label opens in its
+// founding year with cash by financial power, earns off its roster's fame
+// every week and takes a cut of every record it puts out, and pays overhead
+// by size plus upkeep for each act it carries. [tune] throughout.
+// ============================================================================
+
+pub(super) const LABEL_START_CASH_PER_POWER: i64 = 5_000;
+pub(super) const LABEL_OVERHEAD_PER_POWER: i64 = 30;
+/// An act costs its label this a week, against what its fame earns.
+pub(super) const LABEL_UPKEEP_PER_ACT: i64 = 300;
+/// Weekly income per point of roster fame, and per point of market reach
+/// from the records the label already owns, before the market modifier.
+pub(super) const LABEL_CATALOGUE_PER_FAME: i64 = 15;
+pub(super) const LABEL_BACK_CATALOGUE_PER_REACH: i64 = 60;
+/// A scene release's take per point of sales score, at full market reach.
+pub(super) const LABEL_TAKE_PER_SCORE: f32 = 10.0;
+/// Cash past this (per point of financial power) is profit: the owners
+/// take this share of the excess out every week.
+pub(super) const LABEL_CASH_CEILING_PER_POWER: i64 = 8_000;
+pub(super) const LABEL_PROFIT_PAYOUT_SHARE: f32 = 0.05;
+/// A record charting this high on any board counts as a hit.
+pub(super) const LABEL_HIT_MAX_POSITION: usize = 20;
+/// Financial power per act the label has room for.
+pub(super) const LABEL_POWER_PER_ROSTER_SLOT: usize = 3;
+/// How much of its willingness a label with a full roster loses; it
+/// signs nobody at `1 / LABEL_FULL_ROSTER_RELUCTANCE` times full.
+pub(super) const LABEL_FULL_ROSTER_RELUCTANCE: f32 = 0.75;
+/// Weeks of overhead in the bank before a label signs without a worry.
+pub(super) const LABEL_COMFORTABLE_RUNWAY_WEEKS: f32 = 52.0;
+/// Weeks of overhead a label keeps back before it pays any advance.
+pub(super) const LABEL_ADVANCE_RESERVE_WEEKS: i64 = 26;
+/// Below this willingness (0-100) a label signs nobody.
+pub(super) const LABEL_SIGNING_MIN_WILLINGNESS: u8 = 10;
+/// Weeks in a row in the red before a label folds.
+pub(super) const LABEL_FOLD_WEEKS_IN_RED: u32 = 26;
+
+// ============================================================================
+// Label mergers (`world/labels.rs`) and what a takeover means for a band
+// signed to the label that was bought (`takeover.rs`). [tune] throughout.
+// ============================================================================
+
+/// A label this many weeks in the red is up for sale, and a bigger label
+/// buys it out with this chance a week.
+pub(super) const LABEL_DISTRESSED_WEEKS_IN_RED: u32 = 8;
+pub(super) const LABEL_DISTRESSED_BUYOUT_CHANCE: f64 = 0.05;
+/// Weekly chance a healthy label sells out to a bigger one anyway.
+pub(super) const LABEL_BUYOUT_CHANCE: f64 = 0.0002;
+/// What a buyer pays the owners per point of the target's financial
+/// power, on top of taking on its balance, debts and all.
+pub(super) const LABEL_BUYOUT_PRICE_PER_POWER: i64 = 2_000;
+/// How far a moved contract's royalty goes toward the buyer's own rate.
+pub(super) const TAKEOVER_ROYALTY_SHIFT: f32 = 0.5;
+/// Weeks the band has to answer a takeover before the new terms stand.
+pub(super) const TAKEOVER_DECISION_WEEKS: u32 = 8;
+
+// ============================================================================
+// The books (`ledger.rs`): labels account for royalties once a quarter.
+// ============================================================================
+
+pub(super) const WEEKS_PER_QUARTER: u32 = 13;
+pub(super) const QUARTERS_PER_YEAR: u32 = 4;
+
+// ============================================================================
+// Debt (`debt.rs`): the bank lends against fame and the masters the band
+// owns and takes its repayment every week; a label fronts its acts cash
+// against future royalties; and a band that can't pay goes through
+// bankruptcy. [tune] throughout.
+// ============================================================================
+
+/// Credit the bank extends per point of fame.
+pub(super) const LOAN_LIMIT_PER_FAME: u32 = 150;
+/// Share of the value of the masters the band owns the bank lends against.
+pub(super) const LOAN_MASTER_COLLATERAL_SHARE: f32 = 0.5;
+/// Yearly interest for the most famous borrower; an unknown pays the risk
+/// premium on top, scaling down as fame rises.
+pub(super) const LOAN_BASE_YEARLY_RATE: f32 = 0.10;
+pub(super) const LOAN_RISK_PREMIUM: f32 = 0.15;
+/// Every borrowing resets the schedule to pay the whole balance off over
+/// this many weeks.
+pub(super) const LOAN_TERM_WEEKS: u32 = 104;
+/// Added to the balance each week a repayment bounces.
+pub(super) const LOAN_LATE_FEE: u32 = 50;
+/// Bounced repayments in a row before the bank calls the loan in.
+pub(super) const LOAN_DEFAULT_MISSED_PAYMENTS: u32 = 4;
+/// Below this fame, being in the red at all means bankruptcy: nobody is
+/// coming to bail out a band nobody knows.
+pub(super) const BANKRUPTCY_FAME: u8 = 10;
+/// Weeks before a bankruptcy is discharged. Go broke again before then and
+/// it's over.
+pub(super) const BANKRUPTCY_DISCHARGE_WEEKS: u32 = 52;
+pub(super) const BANKRUPTCY_HAPPINESS: u8 = 20;
+/// What a label will front an act against future royalties, per point of
+/// its reach — counting what the act already owes it.
+pub(super) const LABEL_CASH_ADVANCE_PER_REACH: u32 = 100;
+/// How much one press of a key on the finances screen borrows, repays or
+/// draws. [ui]
+pub const MONEY_STEP: u32 = 500;
+
+// ============================================================================
+// Income tax (`tax.rs`): the rates live in `data/tax.json`. [tune]
+// ============================================================================
+
+/// Weeks abroad in a tax year that make the band resident where it went.
+pub(super) const TAX_RESIDENCY_WEEKS: u32 = 26;
+/// How long a tax exile lasts.
+pub(super) const TAX_EXILE_WEEKS: u32 = 52;
+/// The accountant's weekly retainer. [ui]
+pub const ACCOUNTANT_FEE_PER_WEEK: u32 = 100;
+/// Share of the year's profit an accountant finds in deductions.
+pub(super) const ACCOUNTANT_DEDUCTION_SHARE: f32 = 0.2;
+
+// ============================================================================
+// Investments (`investments.rs`): where the money goes once the band has
+// more than it can spend. Tables are index-aligned with
+// `InvestmentKind::ALL` (property, studio, imprint, blue chips, record
+// shares). [tune] throughout.
+// ============================================================================
+
+/// What buying in costs: the whole asset, or one more stake in a market.
+pub(super) const INVESTMENT_PRICE: [u32; 5] = [40_000, 120_000, 50_000, 10_000, 10_000];
+/// Share of its value lost in selling — estate agents, the few buyers for
+/// a studio, an imprint that's only a name. The liquidity of each.
+pub(super) const INVESTMENT_SALE_COST_SHARE: [f32; 5] = [0.08, 0.3, 0.75, 0.01, 0.02];
+/// Weekly swing in value either side of its drift. The risk of each.
+pub(super) const INVESTMENT_WEEKLY_VOLATILITY: [f32; 5] = [0.004, 0.0, 0.0, 0.02, 0.035];
+/// Weekly drift of property and blue chips in a recession, a stagnant
+/// economy, a growing one and a boom.
+pub(super) const PROPERTY_WEEKLY_DRIFT: [f32; 4] = [-0.003, 0.0, 0.0015, 0.003];
+pub(super) const STOCKS_WEEKLY_DRIFT: [f32; 4] = [-0.005, 0.0, 0.002, 0.005];
+/// Record shares ride the era's `record_sales_growth`, geared up: a 20%
+/// growth era lifts them 20% × this a year.
+pub(super) const RECORD_SHARES_GEARING: f32 = 2.0;
+/// Rent from the tenants, a share of the property's value a week.
+pub(super) const PROPERTY_RENT_WEEKLY_YIELD: f32 = 0.001;
+/// The studio's running costs, and what it knocks off every session.
+pub(super) const STUDIO_UPKEEP_PER_WEEK: u32 = 400;
+pub(super) const OWN_STUDIO_RECORDING_SAVING: f32 = 0.6;
+/// A studio's value ages by this share a week.
+pub(super) const STUDIO_WEEKLY_DEPRECIATION: f32 = 0.001;
+/// The imprint's office, and its weekly shot at a hit from one of the
+/// unknowns it puts out — scaled by the market — and what a hit takes.
+pub(super) const IMPRINT_OVERHEAD_PER_WEEK: u32 = 500;
+pub(super) const IMPRINT_HIT_CHANCE: f64 = 0.03;
+pub(super) const IMPRINT_HIT_TAKINGS: (u32, u32) = (8_000, 30_000);
+
+// ============================================================================
+// The band's own label (`own_label.rs`): founding it, signing scene bands
+// to it, and what it earns. Its acts' records spread like an independent
+// label's, and it earns off them the way the other labels do
+// (`LABEL_TAKE_PER_SCORE`, `LABEL_CATALOGUE_PER_FAME`,
+// `LABEL_UPKEEP_PER_ACT`). [tune] throughout.
+// ============================================================================
+
+/// Fame the band needs, and what it costs, to found a label. [ui]
+pub const OWN_LABEL_MIN_FAME: u8 = 50;
+pub const OWN_LABEL_FOUNDING_COST: u32 = 20_000;
+/// The label's office, before its acts.
+pub(super) const OWN_LABEL_OVERHEAD_PER_WEEK: u32 = 250;
+/// The market reach its acts' records go out with.
+pub(super) const OWN_LABEL_MARKET_REACH: u8 = 60;
+/// How many acts it can carry. [ui]
+pub const OWN_LABEL_MAX_ACTS: usize = 6;
+/// What signing an act costs up front, per point of its fame.
+pub(super) const OWN_LABEL_ADVANCE_PER_FAME: u32 = 100;
+
+// ============================================================================
+// Master ownership and the back catalogue (`catalogue.rs`): what a master
+// is worth, what a label asks to sell one back and what a buyer pays for
+// one, and how a re-recording splits a song's audience with the original.
+// [tune] throughout.
+// ============================================================================
+
+/// A master is worth this share of everything it has grossed so far —
+/// the tail ahead of it shrinks, so the past is the best guide there is.
+pub(super) const MASTER_VALUE_SHARE_OF_GROSS: f32 = 0.5;
+/// Nobody trades a master for less, however little it has sold.
+pub(super) const MASTER_VALUE_FLOOR: u32 = 250;
+/// A label selling a master back asks this multiple of its worth, on top
+/// of whatever the band still owes it.
+pub(super) const MASTER_BUYBACK_PREMIUM: f32 = 1.5;
+/// A catalogue buyer pays this share of a master's worth.
+pub(super) const MASTER_SALE_SHARE: f32 = 0.75;
+/// The share of its tail an original keeps once a re-recording is out.
+pub(super) const MASTER_RERECORD_ORIGINAL_SHARE: f32 = 0.5;
+/// Fans who know the songs: a re-recording's sales score adds this share
+/// of the original's first-run score.
+pub(super) const MASTER_RERECORD_FAN_PULL: f32 = 0.3;
+
 // ============================================================================
 // M6: indie re-press + indie distribution tiers
 // (docs/DESIGN-v0.7-money-cycle.md §E-1 indie half, §E-3). Distribution
 // channel tables are index-aligned with `DistributionChannel::ALL`
 // (`music.rs`): Mail order & gigs, Regional distributor, National
-// distributor. Re-pressing itself reuses `PRESSING_TIERS` and
+// distributor, the band's own label. Re-pressing itself reuses `PRESSING_TIERS` and
 // `pressing_cost` (M1/pre-existing) — nothing new to tune there.
 // ============================================================================
 
 /// Fame required to select each channel (design §E-3 table). Only the
 /// National gate is meaningfully tunable; Mail order/Regional are ungated
-/// by design (`0`). [tune]
-pub(super) const DISTRIBUTION_CHANNEL_FAME_GATE: [u8; 3] = [0, 0, 35];
-/// Fee charged at each release under this channel (design §E-3 table). [tune]
-pub(super) const DISTRIBUTION_CHANNEL_FEE: [i32; 3] = [0, 400, 1_500];
+/// by design (`0`), and the own label is gated on founding it instead
+/// (`OWN_LABEL_MIN_FAME`). [tune]
+pub(super) const DISTRIBUTION_CHANNEL_FAME_GATE: [u8; 4] = [0, 0, 35, 0];
+/// Fee charged at each release under this channel (design §E-3 table). The
+/// own label's office covers its own distribution. [tune]
+pub(super) const DISTRIBUTION_CHANNEL_FEE: [i32; 4] = [0, 400, 1_500, 0];
 /// Reach floor: effective indie reach is `max(floor, current fame formula)`
 /// (design §E-3 table). [tune]
-pub(super) const DISTRIBUTION_CHANNEL_REACH_FLOOR: [f32; 3] = [0.15, 0.30, 0.50];
+pub(super) const DISTRIBUTION_CHANNEL_REACH_FLOOR: [f32; 4] = [0.15, 0.30, 0.50, 0.75];
 
 /// A release counts as eligible for an indie re-press once cumulative sales
 /// reach this fraction of the pressed run — "sold out (or low on stock)"
@@ -632,6 +1285,22 @@ pub(super) const LOCAL_PRESENCE: f32 = 1.0;
 /// abroad and sells only at home behind the UK floor. [tune]
 pub(super) const REGIONAL_FAME_PRESENCE_DIVISOR: f32 = 100.0;
 
+// ============================================================================
+// Regional fame decay (`regional.rs`): a region the band hasn't played
+// forgets it, alongside the national fade in `update_public_visibility`. A
+// tour stop or a festival set restarts a region's clock; a record on the
+// country's chart holds every region in it. [tune] throughout.
+// ============================================================================
+
+/// Weeks a region remembers the band after its last show there.
+pub(super) const REGIONAL_FAME_GRACE_WEEKS: u32 = 26;
+pub(super) const REGIONAL_FAME_DECAY_PER_WEEK: u8 = 1;
+/// A region that once knew the band never quite forgets it.
+pub(super) const REGIONAL_FAME_FLOOR: u8 = 5;
+/// The fade makes the news each time a region drops below a multiple of
+/// this.
+pub(super) const REGIONAL_FAME_NEWS_STEP: u8 = 10;
+
 // Determinism salts — stream construction lives in `rng.rs`.
 // ACTION_STREAM_SALT keeps the action stream uncorrelated with the world
 // stream (π's fractional bits: arbitrary, fixed forever).
@@ -5,17 +5,28 @@ use rand::SeedableRng;
 use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
 
-use crate::data_loader::GameDataFiles;
-use crate::game::actions::TourRig;
+use crate::data_loader::{GameDataFiles, Incident};
+use crate::game::a_and_r::LabelDemand;
+use crate::game::actions::{PromoterDeal, TourLeg, TourRig, TourTerms, Vacation};
 use crate::game::band::{self, Band};
 use crate::game::constants;
+use crate::game::debt::Loan;
 use crate::game::events::EventManager;
+use crate::game::gear::{self, CrewRole, GearItem, GearKind};
 use crate::game::genre;
-use crate::game::music::{DistributionChannel, MarketingCampaignType, Release};
+use crate::game::investments::{Investment, InvestmentKind};
+use crate::game::ledger::{LedgerEntry, RoyaltyStatement};
+use crate::game::music::{DistributionChannel, LiveFormat, MarketingCampaignType, Release};
+use crate::game::negotiation::DealCounter;
+use crate::game::own_label::OwnLabel;
 use crate::game::player::{LifestyleTier, Player};
 use crate::game::shows::TourReport;
+use crate::game::takeover::LabelTakeover;
+use crate::game::tax::{TaxAssessment, TaxExile};
 use crate::game::timeline::MusicTimeline;
-use crate::game::world::{GameWorld, PotentialDealOffer};
+use crate::game::world::{
+    FestivalBilling, GameWorld, Manager, PotentialDealOffer, PotentialManagerOffer,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GameAction {
@@ -28,17 +39,40 @@ pub enum GameAction {
     RecordAlbum {
         pressing: Option<usize>,
     },
+    /// Mix the best of the taped tour (`Game::live_tapes`) into a live
+    /// record.
+    ReleaseLive {
+        format: LiveFormat,
+        pressing: Option<usize>,
+    },
     Gig(usize),
     /// Region index, chosen rig, and tour length in weeks — all explicit
     /// player choices, quoted before booking (design §A, M1). Fame never
     /// selects any of these; it only gates which are available.
     GoOnTour(usize, TourRig, u8),
-    TakeBreak,
+    /// A routed tour: several regions in order, one rig for the whole run,
+    /// travel between the stops, booked on the given ticket tier and merch
+    /// order. Quoted by `Game::quote_route`.
+    GoOnTourRoute(Vec<TourLeg>, TourRig, TourTerms),
+    /// A paid trip away from the grind (FUTURE §9.3) — the tier is the
+    /// player's choice from the vacation picker.
+    TakeVacation(Vacation),
     VisitDoctor,
     AcceptDeal(usize),
     RejectDeal(usize),
+    /// Counter a pending deal offer with edited terms (`negotiation.rs`).
+    /// Instant, like answering it.
+    CounterDeal(usize, DealCounter),
     AcceptSupportTour,
     DeclineSupportTour,
+    /// Book the festival slot on offer; the set is played on the day.
+    /// Instant. Declining a booked slot pulls out of it.
+    AcceptFestival,
+    DeclineFestival,
+    /// Play the headline tour a promoter has booked — their route, their
+    /// money. Declining is instant.
+    AcceptHeadlineTour,
+    DeclineHeadlineTour,
     StartMarketingCampaign(u32, MarketingCampaignType), // release_id, campaign_type
     /// Move to a different lifestyle tier — always the player's call,
     /// instant, no week consumed (v0.7 design §B).
@@ -51,6 +85,60 @@ pub enum GameAction {
         release_id: u32,
         pressing: Option<usize>,
     },
+    /// The back catalogue (`catalogue.rs`): buy a label's master of a
+    /// release back, or sell one the band owns — both instant — or
+    /// re-record a release the band doesn't own, which takes a week in the
+    /// studio like any record.
+    BuyBackMaster(u32),
+    SellMaster(u32),
+    ReRecord {
+        release_id: u32,
+        pressing: Option<usize>,
+    },
+    /// Answer a takeover of the band's label (`takeover.rs`): ask the buyer
+    /// for the old terms back, or walk under the key-man clause. Both
+    /// instant.
+    RenegotiateTakeover,
+    KeyManExit,
+    /// Take on a pitching manager (FUTURE §9.4). Instant, like signing a
+    /// deal.
+    HireManager(usize),
+    RejectManager(usize),
+    /// Let the current manager go and pay their settlement. Instant.
+    FireManager,
+    /// Gear and crew (`gear.rs`). All instant: buy or sell a piece of gear,
+    /// service the whole kit, hire or let go one crew member of a role, and
+    /// switch the gear insurance on or off.
+    BuyGear(GearKind),
+    SellGear(GearKind),
+    ServiceGear,
+    HireCrew(CrewRole),
+    FireCrew(CrewRole),
+    SetGearInsurance(bool),
+    /// Debt (`debt.rs`). All instant: borrow from the bank, pay the loan
+    /// down early, or draw cash from the label against future royalties.
+    TakeLoan(u32),
+    RepayLoan(u32),
+    DrawLabelAdvance(u32),
+    /// Tax (`tax.rs`). Both instant: take on or let go the accountant, and
+    /// move abroad for a year (a country key from `tax.json`).
+    SetAccountant(bool),
+    GoIntoTaxExile(String),
+    /// Investments (`investments.rs`). Both instant: buy in (one more stake,
+    /// for a market) or sell the whole holding.
+    BuyInvestment(InvestmentKind),
+    SellInvestment(InvestmentKind),
+    /// The band's own label (`own_label.rs`). All instant: found it, sign
+    /// a scene band to it, or let an act go.
+    FoundLabel,
+    SignToLabel(String),
+    DropFromLabel(String),
+    /// Answer the A&R demand on the record just made (`a_and_r.rs`):
+    /// comply (`true`) or defy. Instant.
+    AnswerLabelDemand(bool),
+    /// Answer the pending decision incident with the choice at this index.
+    /// Instant — the decision belongs to the week that raised it.
+    ResolveIncident(usize),
     Quit,
 }
 
@@ -65,13 +153,42 @@ pub struct SupportTourOffer {
     pub expires_week: u32,
 }
 
+/// A slot on a festival bill, offered a few weeks before the day and
+/// played on it once booked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FestivalOffer {
+    /// `Festival::id` in the world's calendar.
+    pub festival_id: u32,
+    pub festival: String,
+    pub region_name: String,
+    pub billing: FestivalBilling,
+    pub pay: u32,
+    pub fame_gain: u8,
+    /// The festival's week: the offer lapses, or the booked set is played.
+    pub expires_week: u32,
+    #[serde(default)]
+    pub booked: bool,
+}
+
+/// A promoter's offer to book a headline tour: a fixed route on their rig,
+/// the costs on them, the band paid on the deal's terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadlineTourOffer {
+    /// `TourPromoter::name` on the world's circuit.
+    pub promoter: String,
+    pub legs: Vec<TourLeg>,
+    pub rig: TourRig,
+    pub deal: PromoterDeal,
+    pub expires_week: u32,
+}
+
 /// The one deliberate use of ambient entropy: choosing a world seed when
 /// ROCKER_SEED doesn't dictate one. Every roll after this derives from it.
 fn default_seed() -> u64 {
     rand::random::<u64>()
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Game {
     #[serde(default = "default_seed")]
     pub world_seed: u64,
@@ -86,7 +203,90 @@ pub struct Game {
     #[serde(default)]
     pub pending_support_offer: Option<SupportTourOffer>,
     #[serde(default)]
+    pub pending_festival_offer: Option<FestivalOffer>,
+    #[serde(default)]
+    pub pending_headline_offer: Option<HeadlineTourOffer>,
+    /// The band's manager, if one is hired (FUTURE §9.4).
+    #[serde(default)]
+    pub manager: Option<Manager>,
+    #[serde(default)]
+    pub pending_manager_offers: Vec<PotentialManagerOffer>,
+    /// Weeks until managers call again after the last one left — fired or
+    /// caught skimming.
+    #[serde(default)]
+    pub manager_cooldown: u32,
+    /// Weeks since managers last went scouting; they scout again every
+    /// `MANAGER_SCOUT_INTERVAL_WEEKS`, however long the turns in between.
+    #[serde(default)]
+    pub manager_scout_weeks: u32,
+    /// A decision incident waiting on the player's answer (its choices are
+    /// on the incident itself). Settled by its default choice if the player
+    /// moves on without answering.
+    #[serde(default)]
+    pub pending_incident: Option<Incident>,
+    /// A buyout of the band's label, waiting on the band's answer
+    /// (`takeover.rs`).
+    #[serde(default)]
+    pub pending_takeover: Option<LabelTakeover>,
+    /// A&R's demand on the record just made, waiting on the band's answer
+    /// (`a_and_r.rs`).
+    #[serde(default)]
+    pub pending_label_demand: Option<LabelDemand>,
+    /// Every transaction in and out of the player's account (`ledger.rs`).
+    #[serde(default)]
+    pub ledger: Vec<LedgerEntry>,
+    /// Royalty statements the labels have sent, oldest first, and the ones
+    /// still adding up this quarter's sales.
+    #[serde(default)]
+    pub royalty_statements: Vec<RoyaltyStatement>,
+    #[serde(default)]
+    pub open_royalty_statements: Vec<RoyaltyStatement>,
+    /// The band's bank loan, if it has one (`debt.rs`).
+    #[serde(default)]
+    pub loan: Option<Loan>,
+    /// The week an ongoing bankruptcy is discharged.
+    #[serde(default)]
+    pub bankrupt_until: Option<u32>,
+    /// Tax (`tax.rs`): whether an accountant is on the books, every year
+    /// spent abroad, and every year's bill.
+    #[serde(default)]
+    pub accountant: bool,
+    #[serde(default)]
+    pub tax_exiles: Vec<TaxExile>,
+    #[serde(default)]
+    pub tax_assessments: Vec<TaxAssessment>,
+    /// What the band has put its money into (`investments.rs`).
+    #[serde(default)]
+    pub investments: Vec<Investment>,
+    /// The band's own label, once founded (`own_label.rs`).
+    #[serde(default)]
+    pub own_label: Option<OwnLabel>,
+    /// Whether the week just played was spent on the road (a headline or
+    /// support tour). Set each week-consuming turn; incident conditions
+    /// read it.
+    #[serde(default)]
+    pub on_tour: bool,
+    #[serde(default)]
     pub regional_fame: std::collections::HashMap<String, u8>,
+    /// The week the band last played each `regional_fame` region; its fame
+    /// starts to fade once it's been neglected long enough (`regional.rs`).
+    #[serde(default)]
+    pub regional_last_visit: std::collections::HashMap<String, u32>,
+    /// Copies sold in each sales territory, keyed by its `regional_fame`
+    /// country.
+    #[serde(default)]
+    pub copies_by_country: std::collections::HashMap<String, u32>,
+    /// Merch ordered with a tour and not yet sold — it sells at every show,
+    /// tour or gig, until it runs out.
+    #[serde(default)]
+    pub merch_stock: u32,
+    /// The band's own gear (`gear.rs`) and the crew on the payroll.
+    #[serde(default = "gear::starting_gear")]
+    pub gear: Vec<GearItem>,
+    #[serde(default)]
+    pub crew: Vec<CrewRole>,
+    #[serde(default)]
+    pub gear_insured: bool,
     /// Consecutive weeks with no public activity (no shows, nothing on sale).
     #[serde(default)]
     pub idle_streak: u32,
@@ -113,6 +313,10 @@ pub struct Game {
     /// would. Serde-defaulted so old saves load with no report on hand.
     #[serde(default)]
     pub last_tour_report: Option<TourReport>,
+    /// A taped tour's report, waiting to be released as a live record.
+    /// Cleared by the release; a newer taped tour replaces it.
+    #[serde(default)]
+    pub live_tapes: Option<TourReport>,
     /// Messages produced while processing the last turn, drained by the UI.
     #[serde(skip)]
     pub turn_log: Vec<String>,
@@ -169,7 +373,33 @@ impl Game {
             data_files,
             pending_deal_offers: Vec::new(),
             pending_support_offer: None,
+            pending_festival_offer: None,
+            pending_headline_offer: None,
+            manager: None,
+            pending_manager_offers: Vec::new(),
+            manager_cooldown: 0,
+            manager_scout_weeks: 1,
+            pending_incident: None,
+            pending_takeover: None,
+            pending_label_demand: None,
+            ledger: Vec::new(),
+            royalty_statements: Vec::new(),
+            open_royalty_statements: Vec::new(),
+            loan: None,
+            bankrupt_until: None,
+            accountant: false,
+            tax_exiles: Vec::new(),
+            tax_assessments: Vec::new(),
+            investments: Vec::new(),
+            own_label: None,
+            on_tour: false,
             regional_fame: std::collections::HashMap::new(),
+            regional_last_visit: std::collections::HashMap::new(),
+            copies_by_country: std::collections::HashMap::new(),
+            merch_stock: 0,
+            gear: gear::starting_gear(),
+            crew: Vec::new(),
+            gear_insured: false,
             idle_streak: 0,
             decay_streak: 0,
             genre_trend_reported: 0,
@@ -180,6 +410,7 @@ impl Game {
             next_release_id: 0,
             just_released_music: Vec::new(),
             last_tour_report: None,
+            live_tapes: None,
             turn_log,
             rockstar_achieved: false,
             current_distribution_channel: DistributionChannel::default(),
@@ -252,6 +483,7 @@ impl Game {
         // charts — the old flat top-10 seeds the Local board, then stays
         // empty (design §C, `GameWorld::migrate_legacy_charts`).
         loaded_game.world.migrate_legacy_charts();
+        loaded_game.sync_band_label();
 
         Ok(loaded_game)
     }
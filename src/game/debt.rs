@@ -0,0 +1,370 @@
+//! Debt. The bank lends against the band's fame and the masters it owns,
+//! at a rate that falls the better known the band is, and takes a fixed
+//! repayment out of the account every week until the balance is cleared. A
+//! repayment the account can't cover bounces and costs a late fee; bounce
+//! too many in a row and the bank calls the loan in. A signed band can also
+//! ask its label for cash up front, which joins what it owes the label
+//! before royalties reach it.
+//!
+//! A band that can't pay its way goes bankrupt instead of going under: the
+//! receiver seizes the home, everything but the instruments and the masters
+//! the band owns, pays the bank out of what they fetch and writes the rest
+//! off. Nobody lends to an undischarged bankrupt, and going broke again
+//! before the discharge ends the career.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::gear::GearKind;
+use super::music::MasterOwner;
+use super::player::LifestyleTier;
+use super::world::find_label_with_tier;
+use super::*;
+
+/// The band's bank loan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Loan {
+    /// What's still owed, interest and fees included.
+    pub balance: u32,
+    pub yearly_rate: f32,
+    /// Taken out of the account every week.
+    pub weekly_payment: u32,
+    /// Repayments bounced in a row.
+    pub missed_payments: u32,
+}
+
+impl Loan {
+    fn weekly_rate(&self) -> f32 {
+        self.yearly_rate / WEEKS_PER_YEAR as f32
+    }
+
+    /// Reset the schedule to pay the balance off over a full term.
+    fn reschedule(&mut self) {
+        let rate = self.weekly_rate();
+        let payment = if rate > 0.0 {
+            self.balance as f32 * rate / (1.0 - (1.0 + rate).powi(-(LOAN_TERM_WEEKS as i32)))
+        } else {
+            self.balance as f32 / LOAN_TERM_WEEKS as f32
+        };
+        self.weekly_payment = (payment.ceil() as u32).max(1);
+    }
+}
+
+impl Game {
+    /// Whether the band is an undischarged bankrupt.
+    pub fn is_bankrupt(&self) -> bool {
+        self.bankrupt_until.is_some()
+    }
+
+    /// The most the bank will have out to the band at once: so much per
+    /// point of fame, and a share of the value of the masters it owns.
+    /// Nothing while the band is bankrupt.
+    pub fn credit_limit(&self) -> u32 {
+        if self.is_bankrupt() {
+            return 0;
+        }
+        let masters: u32 = self
+            .catalogue()
+            .filter(|release| release.band_owns_master())
+            .map(|release| release.master_value())
+            .sum();
+        u32::from(self.band.fame) * LOAN_LIMIT_PER_FAME
+            + (masters as f32 * LOAN_MASTER_COLLATERAL_SHARE) as u32
+    }
+
+    /// What the band can still borrow.
+    pub fn credit_available(&self) -> u32 {
+        let owed = self.loan.as_ref().map_or(0, |loan| loan.balance);
+        self.credit_limit().saturating_sub(owed)
+    }
+
+    /// The yearly rate the bank charges the band today.
+    pub fn loan_rate(&self) -> f32 {
+        LOAN_BASE_YEARLY_RATE + LOAN_RISK_PREMIUM * (1.0 - f32::from(self.band.fame) / 100.0)
+    }
+
+    /// What the band's label will still front it against future royalties:
+    /// its limit for an act of the deal's reach, less what the band already
+    /// owes it, and no more than the label can spare. Zero unsigned.
+    pub fn label_cash_advance_available(&self) -> u32 {
+        let Some(deal) = self.band.current_deal() else {
+            return 0;
+        };
+        let Some((_, label)) = find_label_with_tier(&self.data_files, &deal.label_name) else {
+            return 0;
+        };
+        let limit = u32::from(deal.market_reach) * LABEL_CASH_ADVANCE_PER_REACH;
+        limit
+            .saturating_sub(deal.unrecouped.max(0) as u32)
+            .min(self.world.label_advance_budget(label))
+    }
+
+    /// Borrow from the bank. A top-up joins the balance at today's rate
+    /// and the whole of it goes back on a fresh schedule. Instant.
+    pub(super) fn action_take_loan(&mut self, amount: u32) -> Result<(), String> {
+        if self.is_bankrupt() {
+            return Err("No bank will lend to an undischarged bankrupt.".to_string());
+        }
+        let available = self.credit_available();
+        if amount == 0 || amount > available {
+            return Err(format!(
+                "The bank will only lend you another ${}.",
+                available
+            ));
+        }
+        let yearly_rate = self.loan_rate();
+        let loan = self.loan.get_or_insert(Loan {
+            balance: 0,
+            yearly_rate,
+            weekly_payment: 0,
+            missed_payments: 0,
+        });
+        loan.balance += amount;
+        loan.yearly_rate = yearly_rate;
+        loan.reschedule();
+        let (balance, payment) = (loan.balance, loan.weekly_payment);
+        self.post(LedgerCategory::Loans, amount as i32, "Bank loan");
+        self.log(format!(
+            "🏦 Borrowed ${} at {:.1}% a year — ${} owed, ${} a week for {} weeks.",
+            amount,
+            yearly_rate * 100.0,
+            balance,
+            payment,
+            LOAN_TERM_WEEKS
+        ));
+        Ok(())
+    }
+
+    /// Pay the loan down early. Instant.
+    pub(super) fn action_repay_loan(&mut self, amount: u32) -> Result<(), String> {
+        let Some(balance) = self.loan.as_ref().map(|loan| loan.balance) else {
+            return Err("You don't owe the bank anything.".to_string());
+        };
+        let amount = amount.min(balance);
+        if amount == 0 || !self.player.can_afford(amount as i32) {
+            return Err(format!("You haven't got ${} to pay back.", amount));
+        }
+        self.post(LedgerCategory::Loans, -(amount as i32), "Early repayment");
+        self.pay_down_loan(amount);
+        if let Some(loan) = &self.loan {
+            let balance = loan.balance;
+            self.log(format!(
+                "🏦 Paid ${} off the loan — ${} still owed.",
+                amount, balance
+            ));
+        }
+        Ok(())
+    }
+
+    /// Take a payment off the balance, clearing the loan once it's paid.
+    fn pay_down_loan(&mut self, amount: u32) {
+        let Some(loan) = self.loan.as_mut() else {
+            return;
+        };
+        loan.balance = loan.balance.saturating_sub(amount);
+        loan.missed_payments = 0;
+        if loan.balance == 0 {
+            self.loan = None;
+            self.log("🏦 The bank loan is paid off.".to_string());
+        }
+    }
+
+    /// Ask the label for cash against future royalties. It joins what the
+    /// band owes the label. Instant.
+    pub(super) fn action_draw_label_advance(&mut self, amount: u32) -> Result<(), String> {
+        let Some(label) = self.band.current_deal().map(|deal| deal.label_name.clone()) else {
+            return Err("Only a label can front you money against royalties.".to_string());
+        };
+        let available = self.label_cash_advance_available();
+        if amount == 0 || amount > available {
+            return Err(format!(
+                "{} will only front you another ${}.",
+                label, available
+            ));
+        }
+        let Some(deal) = self.band.record_deal.as_mut() else {
+            return Err("Only a label can front you money against royalties.".to_string());
+        };
+        deal.unrecouped += amount as i32;
+        let owed = deal.unrecouped;
+        self.world.debit_label(&label, i64::from(amount));
+        self.bank_income(
+            amount,
+            LedgerCategory::Advances,
+            format!("Cash from {}", label),
+        );
+        self.log(format!(
+            "💸 {} fronts you ${} against future royalties — ${} to recoup now.",
+            label, amount, owed
+        ));
+        Ok(())
+    }
+
+    /// The bank's week: interest on the balance, then the repayment, which
+    /// bounces if the account can't cover it. A bankruptcy that has run its
+    /// course is discharged.
+    pub(super) fn loan_weekly_repayment(&mut self, weeks_elapsed: u32) {
+        if self.bankrupt_until.is_some_and(|until| self.week >= until) {
+            self.bankrupt_until = None;
+            self.log(
+                "📜 Your bankruptcy is discharged — the banks will talk to you again.".to_string(),
+            );
+        }
+        for _ in 0..weeks_elapsed {
+            let Some(loan) = self.loan.as_mut() else {
+                return;
+            };
+            loan.balance += (loan.balance as f32 * loan.weekly_rate()).round() as u32;
+            let payment = loan.weekly_payment.min(loan.balance);
+            if self.player.can_afford(payment as i32) {
+                self.post(LedgerCategory::Loans, -(payment as i32), "Loan repayment");
+                self.pay_down_loan(payment);
+            } else {
+                loan.balance += LOAN_LATE_FEE;
+                loan.missed_payments += 1;
+                let missed = loan.missed_payments;
+                self.log(format!(
+                    "🏦 The loan repayment bounced — that's {} in a row, and a ${} late fee.",
+                    missed, LOAN_LATE_FEE
+                ));
+            }
+        }
+    }
+
+    /// Whether the band can no longer pay its way: in the red with nobody
+    /// knowing who it is, or a loan the bank has given up on.
+    fn is_insolvent(&self) -> bool {
+        (self.player.money < 0 && self.band.fame < BANKRUPTCY_FAME)
+            || self
+                .loan
+                .as_ref()
+                .is_some_and(|loan| loan.missed_payments >= LOAN_DEFAULT_MISSED_PAYMENTS)
+    }
+
+    /// An insolvent band goes bankrupt; one that's already bankrupt is
+    /// finished.
+    pub(super) fn check_insolvency(&mut self) {
+        if !self.is_insolvent() {
+            return;
+        }
+        if self.is_bankrupt() {
+            self.game_over = true;
+        } else {
+            self.declare_bankruptcy();
+        }
+    }
+
+    /// The receiver moves in: the home goes, then every piece of gear but
+    /// the instruments, every master the band owns and every investment is
+    /// sold. What they
+    /// fetch pays the bank first, then the overdraft; anything still owed
+    /// is written off and anything left over is the band's.
+    fn declare_bankruptcy(&mut self) {
+        self.log(format!(
+            "⚖️ {} is declared bankrupt. The receiver moves in.",
+            self.band.name
+        ));
+        if self.player.lifestyle != LifestyleTier::Squat {
+            self.log(format!(
+                "🏚️ The {} goes back to the landlord — it's the squat for you.",
+                self.player.lifestyle.label()
+            ));
+            self.player.lifestyle = LifestyleTier::Squat;
+        }
+
+        let seized: Vec<_> = self
+            .gear
+            .iter()
+            .filter(|item| item.kind != GearKind::Instruments)
+            .cloned()
+            .collect();
+        self.gear.retain(|item| item.kind == GearKind::Instruments);
+        for item in seized {
+            let resale = (item.value() as f32 * GEAR_RESALE_SHARE).round() as i32;
+            self.post(
+                LedgerCategory::Gear,
+                resale,
+                format!("Seized: {}", item.kind.label()),
+            );
+            self.log(format!(
+                "📦 The {} goes under the hammer for ${}.",
+                item.kind.label().to_lowercase(),
+                resale
+            ));
+        }
+
+        // A record still in its launch window is the band's as much as the
+        // back catalogue is.
+        let owned: Vec<u32> = self
+            .catalogue()
+            .chain(&self.just_released_music)
+            .filter(|release| release.band_owns_master())
+            .map(|release| release.id)
+            .collect();
+        for release_id in owned {
+            let Some(release) = self
+                .catalogue()
+                .chain(&self.just_released_music)
+                .find(|release| release.id == release_id)
+            else {
+                continue;
+            };
+            let name = release.name.clone();
+            let Some((buyer, price)) = self.master_sale_offer(release) else {
+                continue;
+            };
+            self.post(
+                LedgerCategory::Masters,
+                price as i32,
+                format!("Seized: '{}'", name),
+            );
+            self.world.debit_label(&buyer, i64::from(price));
+            if let Some(release) = self
+                .band
+                .albums_released
+                .iter_mut()
+                .chain(&mut self.band.singles_released)
+                .chain(&mut self.just_released_music)
+                .find(|release| release.id == release_id)
+            {
+                release.master_owner = Some(MasterOwner::Label {
+                    name: buyer.clone(),
+                    royalty_rate: 0.0,
+                });
+            }
+            self.log(format!(
+                "💼 The receiver sells the master of '{}' to {} for ${}.",
+                name, buyer, price
+            ));
+        }
+        self.seize_investments();
+        self.close_own_label();
+
+        self.crew.clear();
+        self.gear_insured = false;
+        if let Some(loan) = self.loan.take() {
+            let paid = loan.balance.min(self.player.money.max(0) as u32);
+            self.post(
+                LedgerCategory::Loans,
+                -(paid as i32),
+                "Loan settled by the receiver",
+            );
+            self.log(format!(
+                "🏦 The bank recovers ${} of the ${} it was owed.",
+                paid, loan.balance
+            ));
+        }
+        if self.player.money < 0 {
+            let written_off = -self.player.money;
+            self.post(LedgerCategory::Loans, written_off, "Debts written off");
+            self.log(format!("🧾 ${} of debts written off.", written_off));
+        }
+        self.player.weeks_broke = 0;
+        self.player.happiness = self.player.happiness.saturating_sub(BANKRUPTCY_HAPPINESS);
+        self.bankrupt_until = Some(self.week + BANKRUPTCY_DISCHARGE_WEEKS);
+        self.log(format!(
+            "📜 Discharge in {} weeks. Go broke again before then and it's over.",
+            BANKRUPTCY_DISCHARGE_WEEKS
+        ));
+    }
+}
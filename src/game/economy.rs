@@ -1,7 +1,7 @@
 //! The money pipeline: recording and pressing costs, sales scoring,
 //! and the weekly release/catalog payout.
 
-use crate::game::music::{DistributionChannel, Release, ReleaseType};
+use crate::game::music::{DistributionChannel, MasterOwner, Release, ReleaseType};
 
 use super::constants::{self, *};
 use super::*;
@@ -36,7 +36,15 @@ impl Game {
             })
             .unwrap_or(1.0);
 
-        let base_score = quality_score + marketing_score + fame_score;
+        // A re-recording brings along the fans who know the songs.
+        let fan_pull = release
+            .rerecording_of
+            .and_then(|id| self.catalogue_release(id))
+            .map_or(0.0, |original| {
+                original.initial_sales_score as f32 * MASTER_RERECORD_FAN_PULL
+            });
+
+        let base_score = quality_score + marketing_score + fame_score + fan_pull;
         (base_score * era_sales_modifier * genre_modifier * era_genre_modifier).max(0.0) as u32
     }
 
@@ -83,7 +91,7 @@ impl Game {
     /// four `markets.json` countries. Never called for `Local` (a UK subset,
     /// scored at full presence) or `Worldwide` (derived), which are not sales
     /// territories — see `ChartRegion::TERRITORIES`.
-    fn territory_country(region: world::ChartRegion) -> &'static str {
+    pub(super) fn territory_country(region: world::ChartRegion) -> &'static str {
         match region {
             world::ChartRegion::Uk => "united_kingdom",
             world::ChartRegion::Europe => "europe",
@@ -117,7 +125,7 @@ impl Game {
     /// callers compute it once and pass it in; only the regional-fame factor
     /// varies by territory. Used to scale both the chart submission and the
     /// demand sum for each of the four `ChartRegion::TERRITORIES`.
-    fn territory_presence(&self, region: world::ChartRegion, reach: f32) -> f32 {
+    pub(super) fn territory_presence(&self, region: world::ChartRegion, reach: f32) -> f32 {
         if matches!(region, world::ChartRegion::Uk) {
             // Home market: the act's distribution reach carries it directly.
             // A label's `market_reach` (or a bought indie channel) sells you
@@ -138,7 +146,12 @@ impl Game {
             ReleaseType::Single => constants::SINGLE_RECORDING_COST,
             ReleaseType::Album => constants::ALBUM_RECORDING_BASE_COST,
         };
-        (base as f32 * self.timeline.get_recording_cost_modifier()) as i32
+        let studio = if self.owns_studio() {
+            1.0 - OWN_STUDIO_RECORDING_SAVING
+        } else {
+            1.0
+        };
+        (base as f32 * self.timeline.get_recording_cost_modifier() * studio) as i32
     }
 
     /// What a pressing run of `copies` costs to buy yourself.
@@ -178,7 +191,11 @@ impl Game {
         let Some(deal) = self.band.current_deal() else {
             return;
         };
-        let push = (deal.market_reach / 2).clamp(10, 45);
+        // The less say the label has over a record, the less it spends
+        // pushing it.
+        let push = ((deal.market_reach / 2).clamp(10, 45) as f32
+            * LABEL_PROMO_CONTROL_SCALE[deal.creative_control as usize])
+            .round() as u8;
         let label_name = deal.label_name.clone();
         // M5 (§E-2): the label's outlay on this release joins the recoupment
         // ledger — the pressing run (the same run `plan_pressing` hands a
@@ -521,13 +538,51 @@ impl Game {
 
                 let (income, units_sold, sold_out) =
                     self.calculate_release_outcome(sales_score, &release);
+                // The label keeps what the royalty doesn't pay the band.
+                let statement_label = self.band.current_deal().map(|deal| {
+                    let gross = units_sold * LABEL_INCOME_PER_COPY / SALES_INCOME_DIVISOR;
+                    (deal.label_name.clone(), gross)
+                });
+                if let Some((label_name, gross)) = &statement_label {
+                    let hit = best_position.is_some_and(|pos| pos <= LABEL_HIT_MAX_POSITION);
+                    self.world.credit_label_release(
+                        label_name,
+                        i64::from(gross.saturating_sub(income)),
+                        hit,
+                    );
+                }
                 release.total_income_generated += income;
                 release.copies_sold = units_sold;
+                let presences = world::ChartRegion::TERRITORIES
+                    .map(|territory| self.territory_presence(territory, reach));
+                self.credit_territory_sales(units_sold, presences);
                 // M5 (§E-2): royalties recoup the label's ledger before the
                 // player is paid. `total_income_generated` stays the record's
                 // gross earning; only what reaches the bank is netted here.
                 let to_player = self.apply_recoupment(income);
-                self.player.earn_money(to_player);
+                match &statement_label {
+                    Some((label_name, gross)) => {
+                        self.add_to_royalty_statement(
+                            label_name,
+                            units_sold,
+                            *gross,
+                            income,
+                            income - to_player,
+                        );
+                        self.bank_income(
+                            to_player,
+                            LedgerCategory::Royalties,
+                            release.name.as_str(),
+                        );
+                    }
+                    None => {
+                        self.bank_income(
+                            to_player,
+                            LedgerCategory::RecordSales,
+                            release.name.as_str(),
+                        );
+                    }
+                }
 
                 // Check for certification milestones (§D). The 🏆 lines are
                 // held back like the 🏭 restock below: the award's mechanics
@@ -612,8 +667,14 @@ impl Game {
                     // M9 (design §E-4): free agency comes at the LATER of
                     // all albums delivered and the term served — an early
                     // finish keeps the band signed (and the recoupment
-                    // ledger alive) until the clock runs out too.
-                    match self.band.fulfill_album_obligation(current_week) {
+                    // ledger alive) until the clock runs out too. A live
+                    // record the label didn't accept delivers nothing.
+                    let outcome = if release.outside_deal {
+                        band::DealCompletionOutcome::StillActive
+                    } else {
+                        self.band.fulfill_album_obligation(current_week)
+                    };
+                    match outcome {
                         band::DealCompletionOutcome::FreeAgent { label_name } => {
                             self.log(format!(
                                 "🤝 That album completes your deal with {} — you're a free agent again!",
@@ -686,6 +747,28 @@ impl Game {
         // mutable borrow of `self.band`), so M5's recoupment paydown and the
         // player payout both happen once, after the loop.
         let mut catalog_gross_this_week: u32 = 0;
+        // Royalties off masters another label holds: that label owes the
+        // band nothing against them, so they skip the recoupment ledger.
+        let mut catalog_unrecoupable_this_week: u32 = 0;
+        // Each owning label's share of the week's catalogue takings.
+        let mut label_takings: Vec<(String, u32)> = Vec::new();
+        // Each label's week for its royalty statement (copies, gross,
+        // royalty), and what the band's own masters earned it outright.
+        let mut statement_lines: Vec<(String, u32, u32, u32)> = Vec::new();
+        let mut own_sales_this_week: u32 = 0;
+        let deal_label = self.band.current_deal().map(|deal| deal.label_name.clone());
+        // Originals whose re-recording is out split their audience with it.
+        let rerecorded: std::collections::HashSet<u32> = self
+            .band
+            .albums_released
+            .iter()
+            .chain(&self.band.singles_released)
+            .filter_map(|release| release.rerecording_of)
+            .collect();
+
+        // Tail sales with the territory presences that drove them, credited
+        // to `copies_by_country` after the loop (same borrow).
+        let mut tail_territory_sales: Vec<(u32, [f32; 4])> = Vec::new();
 
         // Collect certifications to apply after the loop (to avoid borrow checker issues).
         let mut certifications_to_award: Vec<(String, u8, u8, u32)> = Vec::new();
@@ -751,20 +834,21 @@ impl Game {
                         // Mirrors `territory_presence`: the UK home market
                         // rides the act's reach directly; the three foreign
                         // territories scale by regional fame (touring).
-                        let presence_sum: f32 = territory_fame
-                            .iter()
-                            .map(|&(region, fame_factor)| {
-                                if matches!(region, world::ChartRegion::Uk) {
-                                    reach
-                                } else {
-                                    reach * fame_factor
-                                }
-                            })
-                            .sum();
-                        let wanted = (ongoing_sales_score as f32
-                            * presence_sum
-                            * UNITS_PER_SCORE_POINT) as u32
-                            / TAIL_UNITS_DIVISOR;
+                        let presences = territory_fame.map(|(region, fame_factor)| {
+                            if matches!(region, world::ChartRegion::Uk) {
+                                reach
+                            } else {
+                                reach * fame_factor
+                            }
+                        });
+                        let presence_sum: f32 = presences.iter().sum();
+                        let mut wanted =
+                            (ongoing_sales_score as f32 * presence_sum * UNITS_PER_SCORE_POINT)
+                                as u32
+                                / TAIL_UNITS_DIVISOR;
+                        if rerecorded.contains(&release.id) {
+                            wanted = (wanted as f32 * MASTER_RERECORD_ORIGINAL_SHARE) as u32;
+                        }
                         let mut units = wanted;
                         let mut stock_capped = false;
                         if release.copies_pressed > 0 {
@@ -788,16 +872,64 @@ impl Game {
                             continue;
                         }
                         release.copies_sold += units;
-                        let gross = units * income_per_copy;
+                        tail_territory_sales.push((units, presences));
+                        // Whoever owns the master decides what a copy pays
+                        // the band; a pre-ownership release pays the way the
+                        // live deal does, as it always did.
+                        let (per_copy, rate) = match &release.master_owner {
+                            None => (income_per_copy, royalty_rate),
+                            Some(MasterOwner::Band) if release.label_market_reach.is_some() => {
+                                (LABEL_INCOME_PER_COPY, None)
+                            }
+                            Some(MasterOwner::Band) => (INDIE_INCOME_PER_COPY, None),
+                            Some(MasterOwner::Label { royalty_rate, .. }) => {
+                                (LABEL_INCOME_PER_COPY, Some(*royalty_rate))
+                            }
+                        };
+                        let gross = units * per_copy;
                         // M7 (§F): same SALES_INCOME_DIVISOR as the first-run
                         // payout — the copy bump feeds certification, not the
                         // tail's cash.
-                        let ongoing_income = match royalty_rate {
+                        let ongoing_income = match rate {
                             Some(rate) => (gross as f32 * rate) as u32 / SALES_INCOME_DIVISOR,
                             None => gross / SALES_INCOME_DIVISOR,
                         };
                         release.total_income_generated += ongoing_income;
-                        catalog_gross_this_week += ongoing_income;
+                        match release.master_label() {
+                            Some(label) => {
+                                label_takings.push((
+                                    label.to_string(),
+                                    (gross / SALES_INCOME_DIVISOR).saturating_sub(ongoing_income),
+                                ));
+                                statement_lines.push((
+                                    label.to_string(),
+                                    units,
+                                    gross / SALES_INCOME_DIVISOR,
+                                    ongoing_income,
+                                ));
+                                if deal_label.as_deref() == Some(label) {
+                                    catalog_gross_this_week += ongoing_income;
+                                } else {
+                                    catalog_unrecoupable_this_week += ongoing_income;
+                                }
+                            }
+                            None if release.master_owner.is_none() => {
+                                catalog_gross_this_week += ongoing_income;
+                                match &deal_label {
+                                    Some(label) => statement_lines.push((
+                                        label.clone(),
+                                        units,
+                                        gross / SALES_INCOME_DIVISOR,
+                                        ongoing_income,
+                                    )),
+                                    None => own_sales_this_week += ongoing_income,
+                                }
+                            }
+                            None => {
+                                catalog_unrecoupable_this_week += ongoing_income;
+                                own_sales_this_week += ongoing_income;
+                            }
+                        }
 
                         // Check for certification milestones after each tail sale (§D).
                         // We collect these and apply them after the loop to avoid borrow checker issues.
@@ -822,6 +954,10 @@ impl Game {
             }
         }
 
+        for (units, presences) in tail_territory_sales {
+            self.credit_territory_sales(units, presences);
+        }
+
         // Apply all collected certifications. On the tail path the 🏆 lines
         // log right away — the sales that earned them happened in earlier
         // weeks, so there is no same-pass cause line to wait for.
@@ -857,9 +993,30 @@ impl Game {
         // whole pool passes through, so the trickle line reads as it always
         // did; while in the red, $0 reaches the bank and the recoup line below
         // carries the news instead.
-        let catalog_to_player = self.apply_recoupment(catalog_gross_this_week);
+        for (label, take) in label_takings {
+            self.world.credit_label(&label, i64::from(take));
+        }
+        for (label, copies, gross, royalties) in statement_lines {
+            self.add_to_royalty_statement(&label, copies, gross, royalties, 0);
+        }
+        let recouped_to_player = self.apply_recoupment(catalog_gross_this_week);
+        if let Some(label) = &deal_label {
+            let recouped = catalog_gross_this_week - recouped_to_player;
+            self.add_to_royalty_statement(label, 0, 0, 0, recouped);
+        }
+        let catalog_to_player = recouped_to_player + catalog_unrecoupable_this_week;
         if catalog_to_player > 0 {
-            self.player.earn_money(catalog_to_player);
+            let royalties = catalog_to_player.saturating_sub(own_sales_this_week);
+            if royalties > 0 {
+                self.bank_income(royalties, LedgerCategory::Royalties, "Catalogue");
+            }
+            if own_sales_this_week > 0 {
+                self.bank_income(
+                    own_sales_this_week,
+                    LedgerCategory::RecordSales,
+                    "Catalogue",
+                );
+            }
             self.log(format!(
                 "💵 Catalog royalties trickle in: ${}.",
                 catalog_to_player
@@ -5,6 +5,8 @@
 //! one. Application is in `events_apply.rs`. All rolls draw on the action
 //! stream (design §F/§G).
 
+use std::collections::BTreeMap;
+
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -12,14 +14,49 @@ use crate::data_loader::Incident;
 
 use super::constants::INCIDENT_WEEKLY_CHANCE_PERCENT;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EventManager {
     pub last_event_week: u32,
+    /// Incident id → the week it last fired, for cooldowns and
+    /// once-per-career incidents.
+    #[serde(default)]
+    pub last_fired: BTreeMap<String, u32>,
+    /// Follow-ups waiting for their week, in the order they were scheduled.
+    #[serde(default)]
+    pub scheduled: Vec<ScheduledIncident>,
+}
+
+/// A chained incident due to fire in (or after) `week`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledIncident {
+    pub id: String,
+    pub week: u32,
 }
 
 impl EventManager {
     pub fn new() -> Self {
-        Self { last_event_week: 0 }
+        Self {
+            last_event_week: 0,
+            last_fired: BTreeMap::new(),
+            scheduled: Vec::new(),
+        }
+    }
+
+    /// Book incident `id` to fire `weeks` weeks after `current_week`.
+    pub fn schedule(&mut self, id: &str, current_week: u32, weeks: u32) {
+        self.scheduled.push(ScheduledIncident {
+            id: id.to_string(),
+            week: current_week + weeks,
+        });
+    }
+
+    /// Take the earliest-scheduled follow-up that has come due, if any.
+    pub fn take_due(&mut self, current_week: u32) -> Option<ScheduledIncident> {
+        let index = self
+            .scheduled
+            .iter()
+            .position(|due| due.week <= current_week)?;
+        Some(self.scheduled.remove(index))
     }
 
     /// Incidents are eligible **every** week now (design §F — cadence up; was
@@ -30,8 +67,7 @@ impl EventManager {
 
     /// Roll this week's incident gate on the action stream. Returns whether an
     /// incident should fire; the caller then picks and applies one. Marks the
-    /// week so the weekly cadence holds. `last_event_week` is the only
-    /// serialized state — the shape is unchanged from before the JSON move.
+    /// week so the weekly cadence holds.
     pub fn try_trigger_event(&mut self, current_week: u32, rng: &mut impl Rng) -> bool {
         if !self.should_process_events(current_week) {
             return false;
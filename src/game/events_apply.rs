@@ -4,29 +4,53 @@
 
 use rand::Rng;
 
-use crate::data_loader::Incident;
+use crate::data_loader::{Incident, IncidentChoice, IncidentContext, IncidentEffects};
 
 use super::constants;
+use super::gear::GearLoss;
+use super::music::{ActiveMarketingCampaign, MarketingCampaignType, Release};
 use super::*;
 
 impl Game {
-    /// Roll the weekly incident gate; if it fires, pick a weighted incident
-    /// from those eligible for the current fame/signed state and apply it.
+    /// This week's incident, if any. A follow-up that has come due fires in
+    /// place of the random roll (skipping it, so no gate is drawn); one whose
+    /// conditions no longer hold — or which is on cooldown — quietly drops
+    /// out of the storyline. Otherwise roll the weekly gate and, if it fires,
+    /// pick a weighted incident from those eligible for the current state.
     /// Gate, selection, and effect rolls all draw on the action stream
     /// (design §F/§G). The single entry point `turn.rs` calls.
     pub(super) fn maybe_trigger_incident(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+        if !self.events.should_process_events(self.week) {
+            return Ok(());
+        }
+        let ctx = self.incident_context();
+        while let Some(due) = self.events.take_due(self.week) {
+            let follow_up = self
+                .data_files
+                .incidents_data
+                .find(&due.id)
+                .filter(|inc| {
+                    inc.matches(&ctx)
+                        && inc.off_cooldown(self.events.last_fired.get(&inc.id).copied(), self.week)
+                })
+                .cloned();
+            if let Some(incident) = follow_up {
+                self.events.last_event_week = self.week;
+                self.apply_incident(&incident, rng);
+                return Ok(());
+            }
+        }
         if !self.events.try_trigger_event(self.week, rng) {
             return Ok(());
         }
-        let fame = self.band.fame;
-        let signed = self.band.has_record_deal();
         // Clone the chosen incident out of `data_files` so applying its
         // effects (which mutates `self`) doesn't hold a borrow of it.
         let chosen = {
-            let eligible = self
-                .data_files
-                .incidents_data
-                .eligible_incidents(fame, signed);
+            let eligible = self.data_files.incidents_data.eligible_incidents(
+                &ctx,
+                &self.events.last_fired,
+                self.week,
+            );
             events::weighted_pick(&eligible, rng).cloned()
         };
         if let Some(incident) = chosen {
@@ -35,14 +59,125 @@ impl Game {
         Ok(())
     }
 
-    /// Apply one incident's effect ranges to the four bars, money, and fame.
-    /// Ranges roll inclusively on the action stream in a fixed field order;
-    /// bars clamp to 0–100, money may go negative, and fame *gains* route
-    /// through the comeback-aware `gain_fame` while *losses* saturate — never
-    /// through `gain_fame`. Then the incident's message hits the log.
-    /// (design §A/§F)
+    /// The game state incident and choice conditions are tested against.
+    pub fn incident_context(&self) -> IncidentContext {
+        IncidentContext {
+            fame: self.band.fame,
+            signed: self.band.has_record_deal(),
+            has_release: self.newest_release().is_some(),
+            year: self.timeline.get_current_year(),
+            era: self.timeline.get_current_era().era_name.clone(),
+            genre: self.band.genre.clone(),
+            lifestyle: self.player.lifestyle,
+            stress: self.player.stress,
+            happiness: self.player.happiness,
+            creativity: self.player.creativity,
+            health: self.player.health,
+            money: self.player.money,
+            on_tour: self.on_tour,
+        }
+    }
+
+    /// Apply one incident: its own effects and message land now, and an
+    /// incident with choices is then parked as `pending_incident` until the
+    /// player (or the headless default) answers it. Firing starts its
+    /// cooldown and books its follow-up, if it has one. (design §A/§F)
     pub(super) fn apply_incident(&mut self, incident: &Incident, rng: &mut impl Rng) {
-        let e = &incident.effects;
+        self.events
+            .last_fired
+            .insert(incident.id.clone(), self.week);
+        if let Some(follow_up) = &incident.follow_up {
+            self.events
+                .schedule(&follow_up.incident, self.week, follow_up.weeks);
+        }
+        let (member, gear_claim) = self.apply_incident_effects(&incident.effects, rng);
+        self.log(name_member(&incident.message, member));
+        self.settle_gear_insurance(gear_claim);
+        if !incident.choices.is_empty() {
+            self.pending_incident = Some(incident.clone());
+        }
+    }
+
+    /// Whether a choice on the pending decision can be taken right now: its
+    /// conditions hold and the player can pay its cost. `pub`: the decision
+    /// modal greys out the rest.
+    pub fn incident_choice_available(&self, choice: &IncidentChoice) -> bool {
+        choice.conditions.matches(&self.incident_context())
+            && self.player.can_afford(choice.cost as i32)
+    }
+
+    /// Answer the pending decision with the chosen option: pay its cost,
+    /// roll its effects on the action stream, and log its outcome.
+    pub(super) fn resolve_incident_choice(
+        &mut self,
+        choice_index: usize,
+        rng: &mut impl Rng,
+    ) -> Result<(), String> {
+        let Some(incident) = &self.pending_incident else {
+            return Err("There's no decision waiting on you.".to_string());
+        };
+        let Some(choice) = incident.choices.get(choice_index).cloned() else {
+            return Err("Invalid choice selected.".to_string());
+        };
+        if !self.incident_choice_available(&choice) {
+            return Err(if self.player.can_afford(choice.cost as i32) {
+                format!("'{}' isn't an option for you right now.", choice.label)
+            } else {
+                format!(
+                    "'{}' costs ${} — you can't afford it.",
+                    choice.label, choice.cost
+                )
+            });
+        }
+        self.pending_incident = None;
+        self.post(
+            LedgerCategory::Incidents,
+            -(choice.cost as i32),
+            choice.label.clone(),
+        );
+        if let Some(follow_up) = &choice.follow_up {
+            self.events
+                .schedule(&follow_up.incident, self.week, follow_up.weeks);
+        }
+        let (member, gear_claim) = self.apply_incident_effects(&choice.effects, rng);
+        self.log(name_member(&choice.message, member));
+        self.settle_gear_insurance(gear_claim);
+        Ok(())
+    }
+
+    /// Settle a decision nobody answered with its default choice — how the
+    /// sim bots (and a player who just plays on) get through one. Called
+    /// before a week-consuming action, so time never moves past an open
+    /// question.
+    pub(super) fn resolve_pending_incident_by_default(&mut self, rng: &mut impl Rng) {
+        let Some(default) = self
+            .pending_incident
+            .as_ref()
+            .and_then(Incident::default_choice)
+        else {
+            self.pending_incident = None;
+            return;
+        };
+        // The default is free and unconditional (load-time validation), so
+        // this cannot be refused.
+        let _ = self.resolve_incident_choice(default, rng);
+    }
+
+    /// Roll a set of effect ranges onto the four bars, money, and fame, then
+    /// onto the band, its newest release, a region, the label, and the
+    /// gear. Ranges
+    /// roll inclusively on the action stream in `IncidentEffects::ranges`
+    /// order; bars clamp to 0–100, money may go negative, and fame *gains*
+    /// route through the comeback-aware `gain_fame` while *losses* saturate —
+    /// never through `gain_fame`. An effect with nothing to land on (no
+    /// members, no release, no deal) rolls nothing. Returns the bandmate a
+    /// member effect picked, for the message, and the gear insurance claim
+    /// (0 unless the effects damaged gear), settled after it.
+    fn apply_incident_effects(
+        &mut self,
+        e: &IncidentEffects,
+        rng: &mut impl Rng,
+    ) -> (Option<String>, i32) {
         apply_bar(
             &mut self.player.stress,
             roll_range(e.stress, rng),
@@ -64,7 +199,8 @@ impl Game {
             constants::MAX_HEALTH,
         );
 
-        self.player.money += roll_range(e.money, rng);
+        let money = roll_range(e.money, rng);
+        self.post(LedgerCategory::Incidents, money, "Incident");
 
         let fame_delta = roll_range(e.fame, rng);
         if fame_delta > 0 {
@@ -73,7 +209,105 @@ impl Game {
             self.band.fame = self.band.fame.saturating_sub((-fame_delta) as u8);
         }
 
-        self.log(incident.message.clone());
+        apply_bar(&mut self.band.skill, roll_range(e.band_skill, rng), 100);
+
+        let mut member_name = None;
+        if e.touches_member() && !self.band.members.is_empty() {
+            let index = rng.gen_range(0..self.band.members.len());
+            let member = &mut self.band.members[index];
+            apply_bar(&mut member.skill, roll_range(e.member_skill, rng), 100);
+            apply_bar(&mut member.loyalty, roll_range(e.member_loyalty, rng), 100);
+            member_name = Some(member.name.clone());
+        }
+
+        let reputation = &mut self.band.reputation;
+        apply_bar(
+            &mut reputation.critical_acclaim,
+            roll_range(e.critical_acclaim, rng),
+            100,
+        );
+        apply_bar(
+            &mut reputation.commercial_success,
+            roll_range(e.commercial_success, rng),
+            100,
+        );
+        apply_bar(
+            &mut reputation.live_performance,
+            roll_range(e.live_performance, rng),
+            100,
+        );
+        apply_bar(
+            &mut reputation.media_presence,
+            roll_range(e.media_presence, rng),
+            100,
+        );
+
+        let week = self.week;
+        if let Some(release) = self.newest_release_mut() {
+            // Stock can't fall below what's already sold; an uncapped legacy
+            // release (0 pressed) has no stock to touch.
+            let stock_delta = roll_range(e.release_stock, rng);
+            if release.copies_pressed > 0 {
+                release.copies_pressed = (i64::from(release.copies_pressed)
+                    + i64::from(stock_delta))
+                .clamp(i64::from(release.copies_sold), i64::from(u32::MAX))
+                    as u32;
+            }
+            let buzz_delta = roll_range(e.release_marketing, rng);
+            nudge_marketing(release, buzz_delta, week);
+        }
+
+        if let Some(effect) = &e.regional_fame {
+            let delta = roll_range(Some(effect.change), rng);
+            let fame = self.regional_fame.entry(effect.region.clone()).or_insert(0);
+            apply_bar(fame, delta, 100);
+        }
+
+        let cooldown_delta = roll_range(e.deal_cooldown, rng);
+        self.band.deal_cooldown = (i32::from(self.band.deal_cooldown) + cooldown_delta)
+            .clamp(0, i32::from(u16::MAX)) as u16;
+
+        if let Some(deal) = &mut self.band.record_deal {
+            let owed_delta = roll_range(e.unrecouped, rng);
+            deal.unrecouped = deal.unrecouped.saturating_add(owed_delta).max(0);
+        }
+
+        // Damaged gear makes the effects a claim: the repair bill, up to
+        // what a service would charge to undo the damage — or, for gear
+        // that's gone, what it was worth. Never both, so a claim can't
+        // leave the band better off than the incident found it.
+        let mut gear_claim = 0;
+        if e.gear_condition.is_some() && !self.gear.is_empty() {
+            let delta = roll_range(e.gear_condition, rng);
+            gear_claim = match self.damage_gear(delta, rng) {
+                Some(GearLoss::Damaged { service_cost }) if money < 0 => {
+                    (-money).min(service_cost as i32)
+                }
+                Some(GearLoss::Damaged { service_cost }) => service_cost as i32,
+                Some(GearLoss::WrittenOff { value }) => value as i32,
+                None => 0,
+            };
+        }
+
+        (member_name, gear_claim)
+    }
+
+    /// The band's most recent release — the one still in its launch window
+    /// if there is one, else the latest album or single out.
+    fn newest_release(&self) -> Option<&Release> {
+        self.just_released_music
+            .iter()
+            .chain(self.band.albums_released.iter())
+            .chain(self.band.singles_released.iter())
+            .max_by_key(|release| (release.week_released, release.id))
+    }
+
+    fn newest_release_mut(&mut self) -> Option<&mut Release> {
+        self.just_released_music
+            .iter_mut()
+            .chain(self.band.albums_released.iter_mut())
+            .chain(self.band.singles_released.iter_mut())
+            .max_by_key(|release| (release.week_released, release.id))
     }
 
     pub(super) fn apply_historical_event(
@@ -85,7 +319,7 @@ impl Game {
             event if event.contains("Beatles") => {
                 if self.band.dominant_genres_match(&["Rock", "Folk Rock"]) {
                     self.band.gain_fame(5);
-                    self.player.money += 200;
+                    self.post(LedgerCategory::Incidents, 200, "Music news");
                 }
             }
             event if event.contains("MTV") => {
@@ -93,7 +327,7 @@ impl Game {
                     if self.band.reputation.media_presence > 60 {
                         self.band.gain_fame(10);
                         let earnings = rng.gen_range(1000..3000);
-                        self.player.money += earnings;
+                        self.post(LedgerCategory::Incidents, earnings, "Music news");
                     } else {
                         self.band.fame = self.band.fame.saturating_sub(5);
                     }
@@ -103,7 +337,7 @@ impl Game {
                 if self.band.dominant_genres_match(&["Grunge", "Alternative"]) {
                     self.band.gain_fame(12);
                     let major_earnings = rng.gen_range(2000..5000);
-                    self.player.money += major_earnings;
+                    self.post(LedgerCategory::Incidents, major_earnings, "Music news");
                 } else if self
                     .band
                     .dominant_genres_match(&["Hair Metal", "Pop Metal"])
@@ -115,7 +349,10 @@ impl Game {
                 0 => {
                     self.band.gain_fame(1);
                 }
-                1 => self.player.money += rng.gen_range(50..200),
+                1 => {
+                    let money = rng.gen_range(50..200);
+                    self.post(LedgerCategory::Incidents, money, "Music news");
+                }
                 _ => {
                     self.band.reputation.critical_acclaim =
                         (self.band.reputation.critical_acclaim + 1).min(100)
@@ -136,6 +373,44 @@ fn roll_range(range: Option<[i32; 2]>, rng: &mut impl Rng) -> i32 {
     }
 }
 
+/// Fill `{member}` in an incident message with the bandmate its effects
+/// picked.
+fn name_member(message: &str, member: Option<String>) -> String {
+    match member {
+        Some(name) => message.replace("{member}", &name),
+        None => message.replace("{member}", "someone in the band"),
+    }
+}
+
+/// Move a release's buzz now, and book it so it stays moved: the weekly
+/// marketing pass rebuilds the level from running campaigns, so a gain also
+/// lands as a free press campaign (`INCIDENT_PRESS_WEEKS` long) and a loss
+/// eats into the running campaigns, biggest first.
+fn nudge_marketing(release: &mut Release, delta: i32, week: u32) {
+    apply_bar(&mut release.marketing_level_achieved, delta, 100);
+    if delta > 0 {
+        release.active_marketing.push(ActiveMarketingCampaign {
+            campaign_type: MarketingCampaignType::BasicPress,
+            start_week: week,
+            end_week: week + constants::INCIDENT_PRESS_WEEKS,
+            effectiveness_bonus: delta.min(100) as u8,
+        });
+    } else if delta < 0 {
+        let mut loss = delta.unsigned_abs();
+        release
+            .active_marketing
+            .sort_by_key(|campaign| std::cmp::Reverse(campaign.effectiveness_bonus));
+        for campaign in &mut release.active_marketing {
+            let cut = loss.min(u32::from(campaign.effectiveness_bonus));
+            campaign.effectiveness_bonus -= cut as u8;
+            loss -= cut;
+        }
+        release
+            .active_marketing
+            .retain(|campaign| campaign.effectiveness_bonus > 0);
+    }
+}
+
 /// Nudge a 0–`max` bar by a signed delta, clamping at both ends.
 fn apply_bar(bar: &mut u8, delta: i32, max: u8) {
     let updated = i32::from(*bar) + delta;
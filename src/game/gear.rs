@@ -0,0 +1,361 @@
+//! Gear and crew: what the band owns and who it pays to haul it. Gear
+//! wears with every show and breaks down more the worse its condition; an
+//! owned vehicle cuts the hire on its tour rig; insurance softens gear
+//! incidents. Crew draw a weekly wage and steady the shows — a sound
+//! engineer evens out the reception, a tour manager and roadies keep the
+//! gear working. Buying, servicing, hiring, and the insurance switch are
+//! instant actions (`actions/business.rs`); this module is the model and
+//! the week-to-week economics.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GearKind {
+    Instruments,
+    Backline,
+    PaSystem,
+    Van,
+    Bus,
+    Truck,
+}
+
+impl GearKind {
+    /// In shop order: playing gear, then vehicles smallest first.
+    pub const ALL: [GearKind; 6] = [
+        GearKind::Instruments,
+        GearKind::Backline,
+        GearKind::PaSystem,
+        GearKind::Van,
+        GearKind::Bus,
+        GearKind::Truck,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GearKind::Instruments => "Instruments",
+            GearKind::Backline => "Backline (amps & kit)",
+            GearKind::PaSystem => "PA system",
+            GearKind::Van => "Van",
+            GearKind::Bus => "Tour bus",
+            GearKind::Truck => "Truck",
+        }
+    }
+
+    pub fn price(self) -> u32 {
+        GEAR_PRICE[self as usize]
+    }
+
+    /// The tour rig this vehicle is, if it is one.
+    pub fn rig(self) -> Option<TourRig> {
+        match self {
+            GearKind::Van => Some(TourRig::Van),
+            GearKind::Bus => Some(TourRig::Bus),
+            GearKind::Truck => Some(TourRig::Truck),
+            GearKind::Instruments | GearKind::Backline | GearKind::PaSystem => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GearItem {
+    pub kind: GearKind,
+    /// 0-100: wears with every show, restored by a service.
+    pub condition: f32,
+}
+
+impl GearItem {
+    pub fn new(kind: GearKind) -> Self {
+        Self {
+            kind,
+            condition: 100.0,
+        }
+    }
+
+    /// The price scaled by condition — what it's worth, not what it fetches.
+    pub fn value(&self) -> u32 {
+        (self.kind.price() as f32 * self.condition / 100.0).round() as u32
+    }
+
+    pub fn service_cost(&self) -> u32 {
+        (self.kind.price() as f32 * (100.0 - self.condition) / 100.0 * GEAR_SERVICE_COST_SHARE)
+            .round() as u32
+    }
+
+    fn working(&self) -> bool {
+        self.condition >= GEAR_BREAKDOWN_CONDITION
+    }
+}
+
+/// What an incident did to the piece of gear it hit, priced for the
+/// insurance claim: what servicing the damage out costs, or what the item
+/// was worth if it's gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GearLoss {
+    Damaged { service_cost: u32 },
+    WrittenOff { value: u32 },
+}
+
+/// A new band's kit: its own instruments, already played in. Also what an
+/// old save without an inventory loads with.
+pub(super) fn starting_gear() -> Vec<GearItem> {
+    vec![GearItem {
+        kind: GearKind::Instruments,
+        condition: GEAR_STARTING_CONDITION,
+    }]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrewRole {
+    TourManager,
+    SoundEngineer,
+    Roadie,
+}
+
+impl CrewRole {
+    pub const ALL: [CrewRole; 3] = [
+        CrewRole::TourManager,
+        CrewRole::SoundEngineer,
+        CrewRole::Roadie,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CrewRole::TourManager => "Tour manager",
+            CrewRole::SoundEngineer => "Sound engineer",
+            CrewRole::Roadie => "Roadie",
+        }
+    }
+
+    pub fn wage(self) -> u32 {
+        CREW_WAGE[self as usize]
+    }
+
+    pub fn fame_gate(self) -> u8 {
+        CREW_FAME_GATE[self as usize]
+    }
+
+    pub fn max_hired(self) -> usize {
+        CREW_MAX_HIRED[self as usize]
+    }
+}
+
+impl Game {
+    pub fn owns_gear(&self, kind: GearKind) -> bool {
+        self.gear.iter().any(|item| item.kind == kind)
+    }
+
+    pub fn crew_count(&self, role: CrewRole) -> usize {
+        self.crew.iter().filter(|&&hired| hired == role).count()
+    }
+
+    pub fn crew_wages(&self) -> u32 {
+        self.crew.iter().map(|role| role.wage()).sum()
+    }
+
+    pub fn gear_value(&self) -> u32 {
+        self.gear.iter().map(GearItem::value).sum()
+    }
+
+    /// This week's insurance premium; 0 while uninsured.
+    pub fn gear_insurance_premium(&self) -> u32 {
+        if self.gear_insured {
+            (self.gear_value() as f32 * GEAR_INSURANCE_WEEKLY_RATE).round() as u32
+        } else {
+            0
+        }
+    }
+
+    pub fn gear_service_cost(&self) -> u32 {
+        self.gear.iter().map(GearItem::service_cost).sum()
+    }
+
+    /// A rig's weekly hire, less the saving when the band owns the vehicle.
+    /// `pub`: the rig picker quotes this.
+    pub fn rig_cost_per_week(&self, rig: TourRig) -> u32 {
+        let owned = self.gear.iter().any(|item| item.kind.rig() == Some(rig));
+        if owned {
+            (rig.cost_per_week() as f32 * (1.0 - GEAR_OWNED_RIG_SAVING)).round() as u32
+        } else {
+            rig.cost_per_week()
+        }
+    }
+
+    /// How much of the usual reception variance a show gets.
+    pub(super) fn reception_variance_mult(&self) -> f32 {
+        if self.crew_count(CrewRole::SoundEngineer) > 0 {
+            CREW_SOUND_VARIANCE_MULT
+        } else {
+            1.0
+        }
+    }
+
+    /// Reception added by the gear in working order.
+    pub(super) fn gear_reception_bonus(&self) -> u8 {
+        self.gear
+            .iter()
+            .filter(|item| item.working())
+            .map(|item| GEAR_RECEPTION_BONUS[item.kind as usize])
+            .sum()
+    }
+
+    fn crew_breakdown_mult(&self) -> f32 {
+        let manager = if self.crew_count(CrewRole::TourManager) > 0 {
+            CREW_TOUR_MANAGER_BREAKDOWN_MULT
+        } else {
+            1.0
+        };
+        manager * CREW_ROADIE_BREAKDOWN_MULT.powi(self.crew_count(CrewRole::Roadie) as i32)
+    }
+
+    /// Whether an item is in play at a show: the playing gear always, a
+    /// vehicle only on a tour in its rig.
+    fn in_play(item: &GearItem, rig: Option<TourRig>) -> bool {
+        match item.kind.rig() {
+            None => true,
+            Some(vehicle) => rig == Some(vehicle),
+        }
+    }
+
+    /// The chance one show suffers a breakdown: each worn item in play adds
+    /// its share, scaled down by the crew.
+    pub(super) fn gear_breakdown_chance(&self, rig: Option<TourRig>) -> f32 {
+        let risk: f32 = self
+            .gear
+            .iter()
+            .filter(|item| Self::in_play(item, rig) && !item.working())
+            .map(|item| {
+                (GEAR_BREAKDOWN_CONDITION - item.condition) / GEAR_BREAKDOWN_CONDITION
+                    * GEAR_BREAKDOWN_MAX_CHANCE
+            })
+            .sum();
+        (risk * self.crew_breakdown_mult()).min(1.0)
+    }
+
+    /// Roll for a breakdown at one show. Draws only while some gear in play
+    /// is worn past the threshold, so a band that keeps its kit serviced
+    /// never shifts the action stream. The worst item is the one that
+    /// fails; an emergency patch, paid on the spot, gets it back to the
+    /// threshold.
+    pub(super) fn roll_gear_breakdown(
+        &mut self,
+        rig: Option<TourRig>,
+        rng: &mut impl Rng,
+    ) -> Option<GearKind> {
+        let chance = self.gear_breakdown_chance(rig);
+        if chance <= 0.0 || !rng.gen_bool(f64::from(chance)) {
+            return None;
+        }
+        let item = self
+            .gear
+            .iter_mut()
+            .filter(|item| Self::in_play(item, rig))
+            .min_by(|a, b| a.condition.total_cmp(&b.condition))?;
+        item.condition = GEAR_BREAKDOWN_CONDITION;
+        let kind = item.kind;
+        let repair = (kind.price() as f32 * GEAR_BREAKDOWN_REPAIR_SHARE).round() as i32;
+        self.post(
+            LedgerCategory::Gear,
+            -repair,
+            format!("{} repair", kind.label()),
+        );
+        Some(kind)
+    }
+
+    /// Wear the gear for a run of shows: the playing gear per show (rougher
+    /// rigs shake it harder), an owned vehicle per week on the road.
+    /// Roadies slow both.
+    pub(super) fn wear_gear(&mut self, shows: u32, rig: Option<TourRig>, weeks: u32) {
+        let roadies = CREW_ROADIE_WEAR_MULT.powi(self.crew_count(CrewRole::Roadie) as i32);
+        let rig_wear = rig.map_or(0.0, |rig| {
+            f32::from(rig.wear_per_week().0) / GEAR_WEAR_RIG_DIVISOR
+        });
+        let per_show = (GEAR_WEAR_PER_SHOW + rig_wear) * roadies;
+        for item in &mut self.gear {
+            let wear = match item.kind.rig() {
+                None => per_show * shows as f32,
+                Some(vehicle) if rig == Some(vehicle) => {
+                    GEAR_VEHICLE_WEAR_PER_WEEK * weeks as f32 * roadies
+                }
+                Some(_) => 0.0,
+            };
+            item.condition = (item.condition - wear).max(0.0);
+        }
+    }
+
+    /// The crew's wages and the insurance premium for the weeks that
+    /// passed, charged in full like the rent. A band that can't make
+    /// payroll loses its crew.
+    pub(super) fn gear_weekly_upkeep(&mut self, weeks_elapsed: u32) {
+        if weeks_elapsed == 0 {
+            return;
+        }
+        let weekly = self.crew_wages() + self.gear_insurance_premium();
+        if weekly == 0 {
+            return;
+        }
+        let (wages, premium) = (self.crew_wages(), self.gear_insurance_premium());
+        self.post(
+            LedgerCategory::Crew,
+            -((wages * weeks_elapsed) as i32),
+            "Wages",
+        );
+        self.post(
+            LedgerCategory::Gear,
+            -((premium * weeks_elapsed) as i32),
+            "Insurance premium",
+        );
+        if self.player.money < 0 && !self.crew.is_empty() {
+            self.crew.clear();
+            self.log("🧳 Payroll bounced — the crew packed up and walked.".to_string());
+        }
+    }
+
+    /// A gear incident with the gear insured: the insurer pays all but the
+    /// excess of the claim (see `apply_incident_effects`).
+    pub(super) fn settle_gear_insurance(&mut self, loss: i32) {
+        if !self.gear_insured || loss <= 0 {
+            return;
+        }
+        let covered = (loss as f32 * (1.0 - GEAR_INSURANCE_EXCESS_SHARE)).round() as i32;
+        self.post(LedgerCategory::Gear, covered, "Insurance claim");
+        self.log(format!(
+            "🛡️ Insurance covered ${} of it — you paid the ${} excess.",
+            covered,
+            loss - covered
+        ));
+    }
+
+    /// Damage one piece of gear (an incident's `gear_condition`). Gear that
+    /// hits zero is gone for good — except the instruments, which the band
+    /// replaces with whatever junk it can borrow. Returns what the damage
+    /// cost, for the insurance claim; `None` if nothing was hit.
+    pub(super) fn damage_gear(&mut self, delta: i32, rng: &mut impl Rng) -> Option<GearLoss> {
+        if self.gear.is_empty() || delta >= 0 {
+            return None;
+        }
+        let index = rng.gen_range(0..self.gear.len());
+        let item = &mut self.gear[index];
+        let (value_before, service_before) = (item.value(), item.service_cost());
+        item.condition = (item.condition + delta as f32).max(0.0);
+        let damaged = GearLoss::Damaged {
+            service_cost: item.service_cost().saturating_sub(service_before),
+        };
+        if item.kind == GearKind::Instruments && item.condition <= 0.0 {
+            self.log("💸 Your instruments are gone — you're playing on borrowed junk.".to_string());
+        } else if item.condition <= 0.0 {
+            let lost = self.gear.remove(index);
+            self.log(format!(
+                "💸 Lost for good: your {}.",
+                lost.kind.label().to_lowercase()
+            ));
+            return Some(GearLoss::WrittenOff {
+                value: value_before,
+            });
+        }
+        Some(damaged)
+    }
+}
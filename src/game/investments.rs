@@ -0,0 +1,312 @@
+//! Investments: where a band's money goes once it has more than the
+//! mansion can spend. Property pays rent and follows the economy; a studio
+//! of its own cuts every recording bill; a vanity imprint costs an office
+//! and now and then turns up a hit; blue chips follow the economy and
+//! record-company shares the era's record sales. Each has its own risk —
+//! how far its value swings in a week — and liquidity — what selling it
+//! loses. Buying in and selling up are instant; the returns come in week
+//! by week with the rest of the upkeep.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvestmentKind {
+    Property,
+    Studio,
+    Imprint,
+    BlueChips,
+    RecordShares,
+}
+
+impl InvestmentKind {
+    /// In shop order: the things the band owns outright, then the markets.
+    pub const ALL: [InvestmentKind; 5] = [
+        InvestmentKind::Property,
+        InvestmentKind::Studio,
+        InvestmentKind::Imprint,
+        InvestmentKind::BlueChips,
+        InvestmentKind::RecordShares,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            InvestmentKind::Property => "Property",
+            InvestmentKind::Studio => "Your own studio",
+            InvestmentKind::Imprint => "Vanity imprint",
+            InvestmentKind::BlueChips => "Blue-chip shares",
+            InvestmentKind::RecordShares => "Record-company shares",
+        }
+    }
+
+    /// What it does for the band, in a line.
+    pub fn blurb(self) -> &'static str {
+        match self {
+            InvestmentKind::Property => "Pays rent every week; its value follows the economy.",
+            InvestmentKind::Studio => "Cuts every recording bill, but costs its upkeep and ages.",
+            InvestmentKind::Imprint => {
+                "Your name on other people's records: an office to pay and the odd hit."
+            }
+            InvestmentKind::BlueChips => "Rise in a boom and fall in a recession.",
+            InvestmentKind::RecordShares => "Ride the era's record sales, up and down, geared.",
+        }
+    }
+
+    pub fn price(self) -> u32 {
+        INVESTMENT_PRICE[self as usize]
+    }
+
+    /// A market takes stake after stake; the rest are bought once.
+    pub fn is_market(self) -> bool {
+        matches!(
+            self,
+            InvestmentKind::BlueChips | InvestmentKind::RecordShares
+        )
+    }
+
+    /// Share of the value that selling up keeps.
+    pub fn liquidity(self) -> f32 {
+        1.0 - INVESTMENT_SALE_COST_SHARE[self as usize]
+    }
+
+    pub fn volatility(self) -> f32 {
+        INVESTMENT_WEEKLY_VOLATILITY[self as usize]
+    }
+
+    /// The risk, as the screen names it.
+    pub fn risk_label(self) -> &'static str {
+        match self {
+            InvestmentKind::Studio => "none",
+            InvestmentKind::Imprint => "long shot",
+            _ if self.volatility() < 0.01 => "low",
+            _ if self.volatility() < 0.03 => "medium",
+            _ => "high",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Investment {
+    pub kind: InvestmentKind,
+    /// Everything put in, stake by stake.
+    pub paid: u32,
+    /// What it's worth today, before the cost of selling.
+    pub value: u32,
+    pub bought_week: u32,
+}
+
+impl Investment {
+    /// What selling it today would bring in.
+    pub fn sale_value(&self) -> u32 {
+        (self.value as f32 * self.kind.liquidity()).round() as u32
+    }
+}
+
+impl Game {
+    pub fn investment(&self, kind: InvestmentKind) -> Option<&Investment> {
+        self.investments
+            .iter()
+            .find(|investment| investment.kind == kind)
+    }
+
+    pub fn owns_studio(&self) -> bool {
+        self.investment(InvestmentKind::Studio).is_some()
+    }
+
+    pub fn investments_value(&self) -> u32 {
+        self.investments
+            .iter()
+            .map(|investment| investment.value)
+            .sum()
+    }
+
+    /// The running costs the investments charge every week.
+    pub fn investments_upkeep(&self) -> u32 {
+        self.investments
+            .iter()
+            .map(|investment| match investment.kind {
+                InvestmentKind::Studio => STUDIO_UPKEEP_PER_WEEK,
+                InvestmentKind::Imprint => IMPRINT_OVERHEAD_PER_WEEK,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// How far an investment's value drifts in a week, before its swing.
+    fn investment_drift(&self, kind: InvestmentKind) -> f32 {
+        let economy = self.world.music_market.economic_state as usize;
+        match kind {
+            InvestmentKind::Property => PROPERTY_WEEKLY_DRIFT[economy],
+            InvestmentKind::Studio => -STUDIO_WEEKLY_DEPRECIATION,
+            InvestmentKind::Imprint => 0.0,
+            InvestmentKind::BlueChips => STOCKS_WEEKLY_DRIFT[economy],
+            InvestmentKind::RecordShares => {
+                self.timeline
+                    .get_current_era()
+                    .market_conditions
+                    .record_sales_growth
+                    / 100.0
+                    * RECORD_SHARES_GEARING
+                    / WEEKS_PER_YEAR as f32
+            }
+        }
+    }
+
+    /// The weeks' returns: values move, property pays its rent, the studio
+    /// and the imprint their running costs, and the imprint rolls for a
+    /// hit. Draws only while the band holds something that swings or
+    /// gambles, so a band with nothing invested never shifts the action
+    /// stream.
+    pub(super) fn investments_weekly_update(&mut self, weeks_elapsed: u32, rng: &mut impl Rng) {
+        if self.investments.is_empty() {
+            return;
+        }
+        let market = self.world.get_market_modifier();
+        for _ in 0..weeks_elapsed {
+            let drifts: Vec<f32> = self
+                .investments
+                .iter()
+                .map(|investment| self.investment_drift(investment.kind))
+                .collect();
+            for (investment, drift) in self.investments.iter_mut().zip(drifts) {
+                let volatility = investment.kind.volatility();
+                let swing = if volatility > 0.0 {
+                    rng.gen_range(-volatility..=volatility)
+                } else {
+                    0.0
+                };
+                investment.value = (investment.value as f32 * (1.0 + drift + swing))
+                    .max(0.0)
+                    .round() as u32;
+            }
+
+            if let Some(property) = self.investment(InvestmentKind::Property) {
+                let rent = (property.value as f32 * PROPERTY_RENT_WEEKLY_YIELD).round() as i32;
+                self.post(LedgerCategory::Investments, rent, "Rent from the tenants");
+            }
+            if self.owns_studio() {
+                self.post(
+                    LedgerCategory::Recording,
+                    -(STUDIO_UPKEEP_PER_WEEK as i32),
+                    "Studio upkeep",
+                );
+            }
+            if self.investment(InvestmentKind::Imprint).is_some() {
+                self.post(
+                    LedgerCategory::Investments,
+                    -(IMPRINT_OVERHEAD_PER_WEEK as i32),
+                    "Imprint office",
+                );
+                let chance = (IMPRINT_HIT_CHANCE * f64::from(market)).min(1.0);
+                if rng.gen_bool(chance) {
+                    let takings = rng.gen_range(IMPRINT_HIT_TAKINGS.0..=IMPRINT_HIT_TAKINGS.1);
+                    self.post(LedgerCategory::Investments, takings as i32, "Imprint hit");
+                    self.log(format!(
+                        "💿 One of the unknowns on your imprint has a hit — ${} through the door.",
+                        takings
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Buy in: the whole asset, or one more stake in a market. Instant.
+    pub(super) fn action_buy_investment(&mut self, kind: InvestmentKind) -> Result<(), String> {
+        if self.is_bankrupt() {
+            return Err("The receiver won't let a bankrupt invest.".to_string());
+        }
+        if !kind.is_market() && self.investment(kind).is_some() {
+            return Err(format!("You already own {}.", kind.label().to_lowercase()));
+        }
+        let price = kind.price();
+        if !self.player.can_afford(price as i32) {
+            return Err(format!("{} costs ${}.", kind.label(), price));
+        }
+        self.post(LedgerCategory::Investments, -(price as i32), kind.label());
+        let week = self.week;
+        match self
+            .investments
+            .iter_mut()
+            .find(|investment| investment.kind == kind)
+        {
+            Some(holding) => {
+                holding.paid += price;
+                holding.value += price;
+            }
+            None => self.investments.push(Investment {
+                kind,
+                paid: price,
+                value: price,
+                bought_week: week,
+            }),
+        }
+        self.log(match kind {
+            InvestmentKind::Property => {
+                format!("🏘️ Bought a house to let for ${}.", price)
+            }
+            InvestmentKind::Studio => format!(
+                "🎛️ The band has a studio of its own — ${}, and every session from now on costs less.",
+                price
+            ),
+            InvestmentKind::Imprint => format!(
+                "💿 Launched a vanity imprint for ${} — the band's name on somebody else's records.",
+                price
+            ),
+            InvestmentKind::BlueChips | InvestmentKind::RecordShares => {
+                format!("📈 Put ${} into {}.", price, kind.label().to_lowercase())
+            }
+        });
+        Ok(())
+    }
+
+    /// Sell up — the whole holding, at its value less what selling costs.
+    /// Instant.
+    pub(super) fn action_sell_investment(&mut self, kind: InvestmentKind) -> Result<(), String> {
+        let Some(index) = self
+            .investments
+            .iter()
+            .position(|investment| investment.kind == kind)
+        else {
+            return Err(format!("You don't own {}.", kind.label().to_lowercase()));
+        };
+        let investment = self.investments.remove(index);
+        let proceeds = investment.sale_value();
+        self.post(
+            LedgerCategory::Investments,
+            proceeds as i32,
+            format!("Sold: {}", kind.label()),
+        );
+        let verdict = if proceeds >= investment.paid {
+            format!("${} up on what you put in", proceeds - investment.paid)
+        } else {
+            format!("${} down on what you put in", investment.paid - proceeds)
+        };
+        self.log(format!(
+            "💼 Sold {} for ${} — {}.",
+            kind.label().to_lowercase(),
+            proceeds,
+            verdict
+        ));
+        Ok(())
+    }
+
+    /// The receiver's sale: everything goes at what it fetches.
+    pub(super) fn seize_investments(&mut self) {
+        for investment in std::mem::take(&mut self.investments) {
+            let proceeds = investment.sale_value();
+            self.post(
+                LedgerCategory::Investments,
+                proceeds as i32,
+                format!("Seized: {}", investment.kind.label()),
+            );
+            self.log(format!(
+                "📦 {} goes to the receiver for ${}.",
+                investment.kind.label(),
+                proceeds
+            ));
+        }
+    }
+}
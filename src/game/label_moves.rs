@@ -13,6 +13,16 @@ use super::constants::{
 use super::music::ReleaseType;
 use super::*;
 
+/// A studio album with singles left in it, whose master the label holds —
+/// a label can't cut singles from a record it doesn't own. A
+/// pre-ownership album (`master_owner: None`) is fair game, as it always
+/// was.
+fn label_may_cut(album: &music::Release, label_name: &str) -> bool {
+    album.live.is_none()
+        && album.singles_cut < LABEL_CUT_MAX_PER_ALBUM
+        && (album.master_owner.is_none() || album.master_label() == Some(label_name))
+}
+
 impl Game {
     /// Check if the label should cut a single from an unreleased album.
     ///
@@ -40,7 +50,7 @@ impl Game {
 
         // Condition 2: find an eligible album (un-singled tracks, most recent first).
         // We need to check if there's an eligible album first.
-        let has_eligible = self.label_has_cuttable_album();
+        let has_eligible = self.label_has_cuttable_album(&label_name);
 
         if !has_eligible {
             return;
@@ -80,12 +90,13 @@ impl Game {
             .albums_released
             .iter_mut()
             .rev()
-            .find(|album| album.singles_cut < LABEL_CUT_MAX_PER_ALBUM)
+            .find(|album| label_may_cut(album, &label_name))
             .expect("has_eligible guarantees this");
 
         let album_name = album.name.clone();
         let album_quality = album.release_quality;
         let album_genre = album.genre.clone();
+        let album_owner = album.master_owner.clone();
 
         // Increment the album's cut counter.
         album.singles_cut += 1;
@@ -119,6 +130,12 @@ impl Game {
             // reach on the release so its tail survives the deal ending.
             distribution_channel: None,
             label_market_reach: Some(deal_market_reach),
+            live: None,
+            outside_deal: false,
+            // The single is the album's recording: whoever holds that
+            // master holds this one.
+            master_owner: album_owner,
+            rerecording_of: None,
         };
 
         self.just_released_music.push(new_release);
@@ -146,14 +163,15 @@ impl Game {
             .any(|release| release.week_released > cutoff_week)
     }
 
-    /// Whether some released album still has un-singled tracks (fewer than
-    /// `LABEL_CUT_MAX_PER_ALBUM` cuts) — the single-cut mechanic's own
-    /// eligibility check, shared with the "cut a single" memo (§E-5).
-    fn label_has_cuttable_album(&self) -> bool {
+    /// Whether some released studio album still has un-singled tracks
+    /// (fewer than `LABEL_CUT_MAX_PER_ALBUM` cuts) — the single-cut
+    /// mechanic's own eligibility check, shared with the "cut a single"
+    /// memo (§E-5). Live records are never cut.
+    fn label_has_cuttable_album(&self, label_name: &str) -> bool {
         self.band
             .albums_released
             .iter()
-            .any(|album| album.singles_cut < LABEL_CUT_MAX_PER_ALBUM)
+            .any(|album| label_may_cut(album, label_name))
     }
 
     /// The weekly deal-clock check (design §E-4/§E-5): breach, the
@@ -166,6 +184,31 @@ impl Game {
         // deal — it's what eventually lets new offers through again.
         self.band.tick_deal_cooldown();
 
+        // A label that was bought rather than folded hands the contract on.
+        self.label_takeover_check();
+
+        // A label that folds takes the contract down with it: the band
+        // walks free and whatever it still owed dies with the label.
+        if let Some(deal) = self.band.current_deal()
+            && self
+                .world
+                .label(&deal.label_name)
+                .is_some_and(|label| label.folded)
+        {
+            let deal = self.band.record_deal.take().expect("checked Some above");
+            self.log(format!(
+                "💀 {} has gone under — your contract goes with it. You're a free agent.",
+                deal.label_name
+            ));
+            if deal.unrecouped > 0 {
+                self.log(format!(
+                    "🗑️ The ${} you still owed {} is nobody's to collect now.",
+                    deal.unrecouped, deal.label_name
+                ));
+            }
+            return;
+        }
+
         // Breach: the term's clock, independent of any release this week.
         if let Some(breach) = self.band.check_term_breach(self.week) {
             self.log(format!(
@@ -227,7 +270,8 @@ impl Game {
     /// Label memos (design §E-5): the label asks before it takes. Each
     /// condition is checked independently; at most one memo message logs
     /// per week (priority: deadline pressure, then the cut-single nudge,
-    /// then the write-songs nudge). The deadline's stress bite applies
+    /// then the write-songs nudge, then a reminder of every A&R demand the
+    /// band defied, `a_and_r.rs`). The deadline's stress bite applies
     /// whenever the condition holds, whether or not the memo message
     /// itself rolled — "the deadline is real pressure, not flavor".
     fn label_memo_check(&mut self, rng: &mut impl Rng, term_end_week: u32) {
@@ -239,6 +283,7 @@ impl Game {
         let label_name = deal.label_name.clone();
         let albums_owed = deal.albums_owed();
         let albums_remaining = deal.albums_required.saturating_sub(deal.albums_delivered);
+        let defiances = deal.defiances;
         // Only a *real* term has a deadline. A legacy deal (`term_weeks == 0`)
         // has `term_end_week == signed_week`, so `weeks_left` would saturate
         // to 0 and read as "past deadline" — phantom pressure on a deal that
@@ -260,7 +305,7 @@ impl Game {
         let no_progress =
             self.band.unreleased_songs.is_empty() && self.idle_streak >= DEAL_MEMO_IDLE_WEEKS;
         let material_idle =
-            self.label_has_cuttable_album() && self.idle_streak >= DEAL_MEMO_IDLE_WEEKS;
+            self.label_has_cuttable_album(&label_name) && self.idle_streak >= DEAL_MEMO_IDLE_WEEKS;
 
         // One memo max per week (priority: deadline, then cut-single, then
         // write-songs); each condition still rolls independently.
@@ -282,6 +327,13 @@ impl Game {
                 "📠 {}: 'We need songs on tape. Write.'",
                 label_name
             ));
+        } else if defiances > 0 && rng.gen_bool(DEAL_MEMO_CHANCE) {
+            self.log(format!(
+                "📠 {}: 'A&R hasn't forgotten the {} time{} you went over its head.'",
+                label_name,
+                defiances,
+                if defiances == 1 { "" } else { "s" }
+            ));
         }
     }
 }
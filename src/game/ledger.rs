@@ -0,0 +1,297 @@
+//! The books. Every dollar that moves in or out of the player's account is
+//! posted as a dated, categorised transaction, so the finance screen can
+//! add a year up by where the money came from and where it went. Labels
+//! account to the band each quarter: a royalty statement per label with the
+//! copies and gross its masters sold, the royalty that earned the band, how
+//! much of it paid down the advance, and what was left to pay out.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::*;
+
+/// Where a transaction's money came from or went. One category covers both
+/// directions — gear is bought and sold, masters bought back and sold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LedgerCategory {
+    /// Copies of records the band owns outright.
+    RecordSales,
+    /// What a label pays on the records it holds, after recoupment.
+    Royalties,
+    Advances,
+    /// Borrowing from the bank and paying it back.
+    Loans,
+    Shows,
+    Merch,
+    /// Buying masters back and selling them.
+    Masters,
+    Recording,
+    Touring,
+    Marketing,
+    /// Rent and the lifestyle it pays for.
+    Living,
+    /// The doctor, rehab and holidays.
+    Health,
+    Gear,
+    Crew,
+    /// Commission and the cost of letting a manager go.
+    Manager,
+    /// Windfalls and mishaps off the incident and event decks.
+    Incidents,
+    /// The yearly tax bill and the accountant's retainer.
+    Tax,
+    /// Buying and selling investments, and what they pay and cost to run.
+    Investments,
+    /// Running the band's own label: founding it, advances, its office,
+    /// and its take on its acts' records.
+    Label,
+}
+
+impl LedgerCategory {
+    pub const ALL: [LedgerCategory; 19] = [
+        LedgerCategory::RecordSales,
+        LedgerCategory::Royalties,
+        LedgerCategory::Advances,
+        LedgerCategory::Loans,
+        LedgerCategory::Shows,
+        LedgerCategory::Merch,
+        LedgerCategory::Masters,
+        LedgerCategory::Recording,
+        LedgerCategory::Touring,
+        LedgerCategory::Marketing,
+        LedgerCategory::Living,
+        LedgerCategory::Health,
+        LedgerCategory::Gear,
+        LedgerCategory::Crew,
+        LedgerCategory::Manager,
+        LedgerCategory::Incidents,
+        LedgerCategory::Tax,
+        LedgerCategory::Investments,
+        LedgerCategory::Label,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LedgerCategory::RecordSales => "Record sales",
+            LedgerCategory::Royalties => "Royalties",
+            LedgerCategory::Advances => "Advances",
+            LedgerCategory::Loans => "Loans",
+            LedgerCategory::Shows => "Shows",
+            LedgerCategory::Merch => "Merch",
+            LedgerCategory::Masters => "Masters",
+            LedgerCategory::Recording => "Recording",
+            LedgerCategory::Touring => "Touring",
+            LedgerCategory::Marketing => "Marketing",
+            LedgerCategory::Living => "Living",
+            LedgerCategory::Health => "Health",
+            LedgerCategory::Gear => "Gear",
+            LedgerCategory::Crew => "Crew",
+            LedgerCategory::Manager => "Manager",
+            LedgerCategory::Incidents => "Incidents",
+            LedgerCategory::Tax => "Tax",
+            LedgerCategory::Investments => "Investments",
+            LedgerCategory::Label => "Your label",
+        }
+    }
+
+    /// Whether the category counts toward the year's taxable profit:
+    /// everything but borrowing, the rent, the doctor and the tax itself.
+    /// Investments are taxed on their cash: what goes in comes off the
+    /// year it's spent, and what comes out is taxed the year it's banked.
+    pub fn taxable(self) -> bool {
+        !matches!(
+            self,
+            LedgerCategory::Loans
+                | LedgerCategory::Living
+                | LedgerCategory::Health
+                | LedgerCategory::Tax
+        )
+    }
+}
+
+/// One transaction: positive money in, negative money out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub week: u32,
+    pub year: u32,
+    pub category: LedgerCategory,
+    pub amount: i32,
+    pub note: String,
+}
+
+/// A label's account of one quarter's sales of the masters it holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoyaltyStatement {
+    pub label: String,
+    /// Quarters since the game began: 0 is the first quarter of 1970.
+    pub quarter: u32,
+    pub copies: u32,
+    /// What the copies grossed the label.
+    pub gross: u32,
+    /// The band's royalty on that gross, before recoupment.
+    pub royalties: u32,
+    /// The part of the royalties kept back against the advance.
+    pub recouped: u32,
+}
+
+impl RoyaltyStatement {
+    /// The royalty rate the quarter's sales worked out at.
+    pub fn rate(&self) -> f32 {
+        if self.gross == 0 {
+            0.0
+        } else {
+            self.royalties as f32 / self.gross as f32
+        }
+    }
+
+    /// What reached the band.
+    pub fn net(&self) -> u32 {
+        self.royalties.saturating_sub(self.recouped)
+    }
+
+    /// The year the statement's quarter falls in.
+    pub fn year(&self) -> u32 {
+        STARTING_YEAR + self.quarter / QUARTERS_PER_YEAR
+    }
+
+    /// The quarter as a statement heads it, e.g. "Q3 1972".
+    pub fn period(&self) -> String {
+        format!("Q{} {}", self.quarter % QUARTERS_PER_YEAR + 1, self.year())
+    }
+}
+
+/// A year of the books, category by category: (money in, money out).
+pub struct YearSummary {
+    pub by_category: Vec<(LedgerCategory, u32, u32)>,
+}
+
+impl YearSummary {
+    pub fn income(&self) -> u32 {
+        self.by_category.iter().map(|&(_, income, _)| income).sum()
+    }
+
+    pub fn expenses(&self) -> u32 {
+        self.by_category.iter().map(|&(_, _, out)| out).sum()
+    }
+}
+
+impl Game {
+    /// Move money in or out of the account and post it to the books. Goes
+    /// through whatever the balance is — callers check affordability first
+    /// where the player has a choice.
+    pub(super) fn post(&mut self, category: LedgerCategory, amount: i32, note: impl Into<String>) {
+        if amount == 0 {
+            return;
+        }
+        self.player.money += amount;
+        self.ledger.push(LedgerEntry {
+            week: self.week,
+            year: self.timeline.get_current_year(),
+            category,
+            amount,
+            note: note.into(),
+        });
+    }
+
+    /// The quarter the current week falls in.
+    fn current_quarter(&self) -> u32 {
+        self.week.saturating_sub(1) / WEEKS_PER_QUARTER
+    }
+
+    /// Add a week's sales of a label's masters to its open statement for
+    /// this quarter.
+    pub(super) fn add_to_royalty_statement(
+        &mut self,
+        label: &str,
+        copies: u32,
+        gross: u32,
+        royalties: u32,
+        recouped: u32,
+    ) {
+        if copies == 0 && royalties == 0 && recouped == 0 {
+            return;
+        }
+        let quarter = self.current_quarter();
+        let index = match self
+            .open_royalty_statements
+            .iter()
+            .position(|statement| statement.label == label && statement.quarter == quarter)
+        {
+            Some(index) => index,
+            None => {
+                self.open_royalty_statements.push(RoyaltyStatement {
+                    label: label.to_string(),
+                    quarter,
+                    copies: 0,
+                    gross: 0,
+                    royalties: 0,
+                    recouped: 0,
+                });
+                self.open_royalty_statements.len() - 1
+            }
+        };
+        let statement = &mut self.open_royalty_statements[index];
+        statement.copies += copies;
+        statement.gross += gross;
+        statement.royalties += royalties;
+        statement.recouped += recouped;
+    }
+
+    /// Once a quarter closes, every label that sold the band's records in
+    /// it sends its statement.
+    pub(super) fn issue_royalty_statements(&mut self) {
+        let quarter = self.current_quarter();
+        let (due, open): (Vec<_>, Vec<_>) = std::mem::take(&mut self.open_royalty_statements)
+            .into_iter()
+            .partition(|statement| statement.quarter < quarter);
+        self.open_royalty_statements = open;
+        for statement in due {
+            let recouped = if statement.recouped > 0 {
+                format!(", ${} against the advance", statement.recouped)
+            } else {
+                String::new()
+            };
+            self.log(format!(
+                "🧾 {}'s statement for {}: {} copies grossing ${}, {:.0}% royalty = ${}{} — ${} to you.",
+                statement.label,
+                statement.period(),
+                statement.copies,
+                statement.gross,
+                statement.rate() * 100.0,
+                statement.royalties,
+                recouped,
+                statement.net()
+            ));
+            self.royalty_statements.push(statement);
+        }
+    }
+
+    /// The books for one year, by category.
+    pub fn year_summary(&self, year: u32) -> YearSummary {
+        let by_category = LedgerCategory::ALL
+            .into_iter()
+            .filter_map(|category| {
+                let (income, out) = self
+                    .ledger
+                    .iter()
+                    .filter(|entry| entry.year == year && entry.category == category)
+                    .fold((0u32, 0u32), |(income, out), entry| {
+                        if entry.amount > 0 {
+                            (income + entry.amount as u32, out)
+                        } else {
+                            (income, out + entry.amount.unsigned_abs())
+                        }
+                    });
+                (income > 0 || out > 0).then_some((category, income, out))
+            })
+            .collect();
+        YearSummary { by_category }
+    }
+
+    /// Every year the books have anything in, oldest first.
+    pub fn ledger_years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.ledger.iter().map(|entry| entry.year).collect();
+        years.dedup();
+        years
+    }
+}
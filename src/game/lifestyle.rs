@@ -85,7 +85,7 @@ impl Game {
     fn apply_lifestyle_upkeep(&mut self) {
         let upkeep = self.player.lifestyle.upkeep_per_week();
         if upkeep > 0 {
-            self.player.money -= upkeep as i32;
+            self.post(LedgerCategory::Living, -(upkeep as i32), "Rent");
         }
     }
 
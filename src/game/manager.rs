@@ -0,0 +1,184 @@
+//! The manager (FUTURE §9.4): the one person standing between the player
+//! and the industry. A manager takes a cut of every dollar that reaches the
+//! player (`bank_income`), and in return books bigger rooms sooner, talks
+//! labels up, and gets promotion and touring cheaper — all scaled by
+//! `skill`. A low-honesty manager also skims, silently, until the weekly
+//! discovery roll catches them. Hiring and firing are instant actions
+//! (`actions/business.rs`); this module is the week-to-week economics.
+
+use rand::Rng;
+
+use super::constants::{self, *};
+use super::music::MarketingCampaignType;
+use super::world::Venue;
+use super::*;
+
+impl Game {
+    /// The manager's skill as a 0.0–1.0 multiplier on every benefit; 0.0
+    /// with nobody hired.
+    fn manager_skill_factor(&self) -> f32 {
+        self.manager
+            .as_ref()
+            .map_or(0.0, |manager| f32::from(manager.skill) / 100.0)
+    }
+
+    /// Whether a venue will book the band: prestige within
+    /// `GIG_VENUE_REACH` of fame, plus a skilled manager's pull of up to
+    /// about one prestige tier. `pub`: the venue picker greys out the rest.
+    pub fn venue_in_reach(&self, venue: &Venue) -> bool {
+        let pull = (f32::from(MANAGER_VENUE_REACH_MAX) * self.manager_skill_factor()).round() as u8;
+        venue.prestige
+            <= self
+                .band
+                .fame
+                .saturating_add(GIG_VENUE_REACH)
+                .saturating_add(pull)
+    }
+
+    /// What a campaign actually costs after the manager's discount. `pub`:
+    /// the marketing picker shows this price, not the list price.
+    pub fn marketing_cost(&self, campaign_type: MarketingCampaignType) -> i32 {
+        let discount = MANAGER_MARKETING_DISCOUNT_MAX * self.manager_skill_factor();
+        (campaign_type.spec().cost as f32 * (1.0 - discount)).round() as i32
+    }
+
+    /// Share knocked off a tour's travel and rig bill (`tour_pot`).
+    pub(super) fn manager_tour_discount(&self) -> f32 {
+        MANAGER_TOUR_DISCOUNT_MAX * self.manager_skill_factor()
+    }
+
+    /// Royalty points a manager talks onto every fresh deal offer; zero
+    /// with nobody hired.
+    pub(super) fn manager_royalty_bump(&self) -> f32 {
+        if self.manager.is_none() {
+            return 0.0;
+        }
+        MANAGER_ROYALTY_BUMP_MIN
+            + (MANAGER_ROYALTY_BUMP_MAX - MANAGER_ROYALTY_BUMP_MIN) * self.manager_skill_factor()
+    }
+
+    /// The settlement firing the current manager would cost:
+    /// `MANAGER_SEVERANCE_WEEKS` of their average weekly commission.
+    pub fn manager_severance(&self) -> u32 {
+        let Some(manager) = &self.manager else {
+            return 0;
+        };
+        let weeks = self.week.saturating_sub(manager.hired_week).max(1);
+        manager.commission_paid / weeks * MANAGER_SEVERANCE_WEEKS
+    }
+
+    /// Bank income through the manager, if there is one: their commission
+    /// comes off the top, and a dishonest manager quietly takes a slice on
+    /// top of that. Returns what actually reached the player. With nobody
+    /// hired the whole amount is posted to `category`.
+    pub(super) fn bank_income(
+        &mut self,
+        gross: u32,
+        category: LedgerCategory,
+        note: impl Into<String>,
+    ) -> u32 {
+        let Some(manager) = &mut self.manager else {
+            self.post(category, gross as i32, note);
+            return gross;
+        };
+        let commission = (gross as f32 * manager.cut).round() as u32;
+        let skim_rate = if manager.honesty < MANAGER_HONEST_THRESHOLD {
+            MANAGER_SKIM_MAX * f32::from(MANAGER_HONEST_THRESHOLD - manager.honesty)
+                / f32::from(MANAGER_HONEST_THRESHOLD)
+        } else {
+            0.0
+        };
+        let skim =
+            ((gross as f32 * skim_rate).round() as u32).min(gross.saturating_sub(commission));
+        manager.commission_paid = manager.commission_paid.saturating_add(commission);
+        manager.skimmed = manager.skimmed.saturating_add(skim);
+        let net = gross.saturating_sub(commission).saturating_sub(skim);
+        // The books show what the manager reports: the skim never appears.
+        let note = note.into();
+        self.post(category, (gross - skim) as i32, note.as_str());
+        self.post(
+            LedgerCategory::Manager,
+            -(commission as i32),
+            format!("Commission: {}", note),
+        );
+        net
+    }
+
+    /// The manager's week: the post-firing cooldown runs down, stale pitches
+    /// lapse, a skimming manager risks discovery once for every week that
+    /// passed, and — with nobody hired — new pitches arrive on the labels'
+    /// four-week beat. Runs once per turn that consumes time, after the deal
+    /// stream (`turn.rs`), so its rolls come last on the action stream.
+    pub(super) fn manager_weekly_check(&mut self, weeks_elapsed: u32, rng: &mut impl Rng) {
+        self.manager_cooldown = self.manager_cooldown.saturating_sub(weeks_elapsed);
+        self.manager_scout_weeks += weeks_elapsed;
+        let scouting = self.manager_scout_weeks >= MANAGER_SCOUT_INTERVAL_WEEKS;
+        self.manager_scout_weeks %= MANAGER_SCOUT_INTERVAL_WEEKS;
+        self.expire_stale_manager_offers();
+
+        if self.manager.as_ref().is_some_and(|m| m.skimmed > 0) {
+            let unnoticed = (1.0 - MANAGER_DISCOVERY_CHANCE).powi(weeks_elapsed as i32);
+            if rng.gen_bool(1.0 - unnoticed) {
+                self.expose_manager_skim();
+            }
+            return;
+        }
+
+        if scouting
+            && self.manager.is_none()
+            && self.manager_cooldown == 0
+            && self.pending_manager_offers.is_empty()
+        {
+            let mut offers = self
+                .world
+                .generate_manager_offers(&self.band, &self.data_files, rng);
+            for offer in &mut offers {
+                offer.expires_week = Some(self.week + MANAGER_OFFER_LIFETIME_WEEKS);
+            }
+            if !offers.is_empty() {
+                let n = offers.len();
+                self.pending_manager_offers = offers;
+                self.log(format!(
+                    "💼 {} manager{} want{} to represent you — press G to hear them out.",
+                    n,
+                    if n == 1 { "" } else { "s" },
+                    if n == 1 { "s" } else { "" }
+                ));
+            }
+        }
+    }
+
+    fn expire_stale_manager_offers(&mut self) {
+        let week = self.week;
+        let offers = std::mem::take(&mut self.pending_manager_offers);
+        let (expired, live): (Vec<_>, Vec<_>) = offers
+            .into_iter()
+            .partition(|offer| offer.expires_week.is_some_and(|deadline| week >= deadline));
+        self.pending_manager_offers = live;
+        for offer in expired {
+            self.log(format!(
+                "📪 {} has found another act to manage.",
+                offer.name
+            ));
+        }
+    }
+
+    /// The skim comes to light: the money is gone for good, the manager is
+    /// out with no settlement, the tabloids have a field day, and it hurts.
+    fn expose_manager_skim(&mut self) {
+        let Some(manager) = self.manager.take() else {
+            return;
+        };
+        self.manager_cooldown = MANAGER_COOLDOWN_WEEKS;
+        self.player.happiness = self
+            .player
+            .happiness
+            .saturating_sub(MANAGER_DISCOVERY_HAPPINESS_HIT);
+        self.player.stress =
+            (self.player.stress + MANAGER_DISCOVERY_STRESS_HIT).min(constants::MAX_STRESS);
+        self.log(format!(
+            "🗞️ SCANDAL: {} has been skimming {}'s money — ${} gone on top of their cut. You threw them out; the money isn't coming back.",
+            manager.name, self.band.name, manager.skimmed
+        ));
+    }
+}
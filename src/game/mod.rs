@@ -3,22 +3,35 @@
 //! Exposes `Game` as the primary state machine, `GameAction` as the input
 //! command set, and submodules for simulation subsystems (band, player, world, etc.).
 
+mod a_and_r;
 mod actions;
 pub mod band;
+mod catalogue;
 mod constants;
 pub mod core;
+mod debt;
 mod economy;
 pub mod events;
 mod events_apply;
+mod gear;
 pub mod genre;
+mod investments;
 mod label_moves;
+mod ledger;
 mod lifestyle;
+mod manager;
 pub mod music;
+mod negotiation;
+mod own_label;
 pub mod player;
+mod regional;
 mod rng;
+mod setlist;
 mod shows;
 #[cfg(test)]
 mod sim; // Track D balance lab: bot-driven career sims, tests only.
+mod takeover;
+mod tax;
 pub mod timeline;
 mod turn;
 pub mod world;
@@ -26,10 +39,17 @@ pub mod world;
 #[cfg(test)]
 mod tests;
 
-pub use actions::{TourQuote, TourRig};
+pub use actions::{PromoterDeal, RouteQuote, TicketTier, TourLeg, TourRig, TourTerms, Vacation};
 pub use constants::{
-    BREAK_WEEKS, GIG_HEALTH_GUARD, GIG_STRESS_GUARD, PRESSING_TIERS, STUDIO_STRESS_BLOCK,
+    ACCOUNTANT_FEE_PER_WEEK, AR_DEFIANCE_TERM_CUT_WEEKS, GIG_HEALTH_GUARD, GIG_STRESS_GUARD,
+    MONEY_STEP, NEGOTIATION_MAX_COUNTERS, OWN_LABEL_FOUNDING_COST, OWN_LABEL_MAX_ACTS,
+    OWN_LABEL_MIN_FAME, PRESSING_TIERS, SETLIST_MAX_SONGS, SETLIST_MIN_SONGS, STUDIO_STRESS_BLOCK,
     TOUR_HEALTH_GUARD, TOUR_STRESS_GUARD,
 };
-pub use core::{Game, GameAction, SupportTourOffer};
+pub use core::{FestivalOffer, Game, GameAction, HeadlineTourOffer, SupportTourOffer};
+pub use gear::{CrewRole, GearItem, GearKind};
+pub use investments::InvestmentKind;
+pub use ledger::LedgerCategory;
+pub use negotiation::DealCounter;
+pub use setlist::SetlistSong;
 pub use shows::{ShowReport, TourReport};
@@ -81,12 +81,14 @@ impl MarketingCampaignType {
 /// Indie distribution channels (design §E-3, M6): releasing while unsigned
 /// buys reach through one of these, fee due at release. A label deal
 /// ignores channels entirely — its own `market_reach` always wins
-/// (`economy::distribution_multiplier`).
+/// (`economy::distribution_multiplier`). The band's own label
+/// (`own_label.rs`) is the last and widest, open only once it's founded.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DistributionChannel {
     MailOrder,
     Regional,
     National,
+    OwnLabel,
 }
 
 impl Default for DistributionChannel {
@@ -100,10 +102,11 @@ impl Default for DistributionChannel {
 
 impl DistributionChannel {
     /// In picker order, smallest reach to biggest.
-    pub const ALL: [DistributionChannel; 3] = [
+    pub const ALL: [DistributionChannel; 4] = [
         DistributionChannel::MailOrder,
         DistributionChannel::Regional,
         DistributionChannel::National,
+        DistributionChannel::OwnLabel,
     ];
 
     /// Index into the `DISTRIBUTION_CHANNEL_*` const tables — also this
@@ -113,6 +116,7 @@ impl DistributionChannel {
             DistributionChannel::MailOrder => 0,
             DistributionChannel::Regional => 1,
             DistributionChannel::National => 2,
+            DistributionChannel::OwnLabel => 3,
         }
     }
 
@@ -121,6 +125,7 @@ impl DistributionChannel {
             DistributionChannel::MailOrder => "Mail order & gigs",
             DistributionChannel::Regional => "Regional distributor",
             DistributionChannel::National => "National distributor",
+            DistributionChannel::OwnLabel => "Your own label",
         }
     }
 
@@ -145,6 +150,24 @@ impl DistributionChannel {
     }
 }
 
+/// What a recorded tour's tapes are released as: a live album always, a
+/// concert film once the era has the screens for it
+/// (`Timeline::is_video_era`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiveFormat {
+    Album,
+    ConcertFilm,
+}
+
+impl LiveFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            LiveFormat::Album => "Live album",
+            LiveFormat::ConcertFilm => "Concert film",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActiveMarketingCampaign {
     pub campaign_type: MarketingCampaignType,
@@ -206,4 +229,49 @@ pub struct Release {
     /// `distribution_channel: None`.
     #[serde(default)]
     pub label_market_reach: Option<u8>,
+    /// `Some` for a live record cut from a recorded tour's tapes. Charts
+    /// and sells like any album; the label never cuts singles from it.
+    #[serde(default)]
+    pub live: Option<LiveFormat>,
+    /// Doesn't count toward a deal's `albums_required` — a live record the
+    /// label wouldn't accept as a contract album. `false` on every studio
+    /// release and pre-live saves.
+    #[serde(default)]
+    pub outside_deal: bool,
+    /// Who owns the master, stamped at record time: the signing label,
+    /// or the band itself for an indie release. `None` on pre-ownership
+    /// saves — such a release reads as the band's but keeps paying the
+    /// way the live deal pays, exactly as it always did (the same fallback
+    /// `label_market_reach` uses).
+    #[serde(default)]
+    pub master_owner: Option<MasterOwner>,
+    /// `Some(id)` for a re-recording of the band's release `id`, cut to
+    /// win back sales from a master the band doesn't own.
+    #[serde(default)]
+    pub rerecording_of: Option<u32>,
+}
+
+impl Release {
+    /// The label holding the master, if a label holds it.
+    pub fn master_label(&self) -> Option<&str> {
+        match &self.master_owner {
+            Some(MasterOwner::Label { name, .. }) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Whether the band owns the master outright.
+    pub fn band_owns_master(&self) -> bool {
+        self.master_label().is_none()
+    }
+}
+
+/// Who owns a release's master recording, and so who its sales pay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MasterOwner {
+    /// The band: every copy's takings are its own.
+    Band,
+    /// A label, paying the band `royalty_rate` of each copy's takings. A
+    /// master the band sold outright pays it nothing.
+    Label { name: String, royalty_rate: f32 },
 }
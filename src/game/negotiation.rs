@@ -0,0 +1,230 @@
+//! Contract negotiation: a counter to a pending deal offer instead of
+//! taking it or leaving it. The player asks for a bigger advance, a better
+//! royalty, fewer albums, a shorter term or more creative control; the
+//! label weighs what the ask costs it against how much it wants the band.
+//! A rich label minds a bigger advance less, a label known for creative
+//! freedom gives control up easily, and the hotter the band's buzz the
+//! more it will give. Ask within that and it signs your terms; ask a
+//! little more and it meets you halfway; ask far too much and it may walk.
+//! Every roll is on the action stream.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::band::CreativeControl;
+use super::constants::*;
+use super::world::PotentialDealOffer;
+use super::*;
+
+/// The terms the player counters with — the offer's own terms, edited.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DealCounter {
+    pub advance: u32,
+    pub royalty_rate: f32,
+    pub albums_required: u8,
+    pub term_weeks: u16,
+    pub creative_control: CreativeControl,
+}
+
+impl DealCounter {
+    /// Start a counter from the offer on the table.
+    pub fn from_offer(offer: &PotentialDealOffer) -> Self {
+        Self {
+            advance: offer.advance,
+            royalty_rate: offer.royalty_rate,
+            albums_required: offer.albums_required,
+            term_weeks: offer.term_weeks,
+            creative_control: offer.creative_control,
+        }
+    }
+}
+
+/// How the label answered a counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterResponse {
+    Accepted,
+    MetHalfway,
+    HeldFirm,
+    WalkedAway,
+}
+
+/// What a counter costs the label, in the same units as its appetite:
+/// each term the player improves adds to it, each one given back takes
+/// off.
+pub(super) fn counter_greed(offer: &PotentialDealOffer, counter: &DealCounter) -> f32 {
+    let label = &offer.original_label_data;
+    let top_advance = label.advance_range[1].max(1) as f32;
+    let advance = (counter.advance as f32 - offer.advance as f32) / top_advance
+        * NEGOTIATION_ADVANCE_WEIGHT
+        * (2.0 - f32::from(label.financial_power) / 100.0);
+    let royalty =
+        (counter.royalty_rate - offer.royalty_rate) * 100.0 * NEGOTIATION_ROYALTY_POINT_COST;
+    let albums = (f32::from(offer.albums_required) - f32::from(counter.albums_required))
+        * NEGOTIATION_ALBUM_COST;
+    let term = (f32::from(offer.term_weeks) - f32::from(counter.term_weeks)) / 52.0
+        * NEGOTIATION_TERM_YEAR_COST;
+    let control = (counter.creative_control as i32 - offer.creative_control as i32) as f32
+        * NEGOTIATION_CONTROL_STEP_COST
+        * (1.0 - f32::from(label.creative_freedom) / 100.0);
+    advance + royalty + albums + term + control
+}
+
+impl Game {
+    /// How much a label will give to sign the band: a little for anyone,
+    /// more the hotter the band's buzz.
+    fn label_appetite(&self) -> f32 {
+        NEGOTIATION_BASE_APPETITE
+            + f32::from(self.world.band_buzz(&self.band)) / 100.0 * NEGOTIATION_BUZZ_APPETITE
+    }
+
+    /// Counters the label will hear; anything past the bounds is turned
+    /// back before it reaches the table.
+    fn check_counter(offer: &PotentialDealOffer, counter: &DealCounter) -> Result<(), String> {
+        if offer.counters >= NEGOTIATION_MAX_COUNTERS {
+            return Err(format!(
+                "{} says that's their final offer.",
+                offer.label_name
+            ));
+        }
+        if counter.advance > offer.original_label_data.advance_range[1] * 2 {
+            return Err("No label pays an advance like that.".to_string());
+        }
+        if !(0.0..=NEGOTIATION_MAX_ROYALTY).contains(&counter.royalty_rate) {
+            return Err(format!(
+                "A royalty runs from 0% to {:.0}%.",
+                NEGOTIATION_MAX_ROYALTY * 100.0
+            ));
+        }
+        if !(1..=NEGOTIATION_MAX_ALBUMS).contains(&counter.albums_required) {
+            return Err(format!(
+                "A deal is for 1 to {} albums.",
+                NEGOTIATION_MAX_ALBUMS
+            ));
+        }
+        // An offer without a term (one saved before terms) has none to
+        // negotiate.
+        if offer.term_weeks == 0 {
+            if counter.term_weeks != 0 {
+                return Err("This offer has no term to negotiate.".to_string());
+            }
+        } else if !(NEGOTIATION_MIN_TERM_WEEKS..=NEGOTIATION_MAX_TERM_WEEKS)
+            .contains(&counter.term_weeks)
+        {
+            return Err(format!(
+                "A deal runs {} to {} weeks.",
+                NEGOTIATION_MIN_TERM_WEEKS, NEGOTIATION_MAX_TERM_WEEKS
+            ));
+        }
+        Ok(())
+    }
+
+    /// Put a counter to the label behind a pending offer. Instant, like
+    /// answering an offer.
+    pub(super) fn action_counter_deal(
+        &mut self,
+        offer_index: usize,
+        counter: DealCounter,
+        rng: &mut impl Rng,
+    ) -> Result<(), String> {
+        let offer = self
+            .pending_deal_offers
+            .get(offer_index)
+            .ok_or("Invalid deal offer selected.")?;
+        Self::check_counter(offer, &counter)?;
+
+        let greed = counter_greed(offer, &counter);
+        let appetite = self.label_appetite()
+            * rng.gen_range(NEGOTIATION_APPETITE_ROLL.0..=NEGOTIATION_APPETITE_ROLL.1);
+        let response = if greed <= appetite {
+            CounterResponse::Accepted
+        } else if greed <= appetite * NEGOTIATION_HALFWAY_MULTIPLE {
+            CounterResponse::MetHalfway
+        } else {
+            // Past halfway, the further over the more likely they walk.
+            let walk_chance = ((greed / appetite - NEGOTIATION_HALFWAY_MULTIPLE)
+                / NEGOTIATION_HALFWAY_MULTIPLE)
+                .clamp(0.0, 1.0);
+            if rng.gen_bool(f64::from(walk_chance)) {
+                CounterResponse::WalkedAway
+            } else {
+                CounterResponse::HeldFirm
+            }
+        };
+
+        let label_name = offer.label_name.clone();
+        match response {
+            CounterResponse::WalkedAway => {
+                self.pending_deal_offers.remove(offer_index);
+                self.log(format!(
+                    "🚪 {} walked away from the table — your counter asked too much.",
+                    label_name
+                ));
+            }
+            CounterResponse::HeldFirm => {
+                self.pending_deal_offers[offer_index].counters += 1;
+                self.log(format!(
+                    "🧱 {} won't budge — the offer stands as it was.",
+                    label_name
+                ));
+            }
+            CounterResponse::Accepted | CounterResponse::MetHalfway => {
+                let offer = &mut self.pending_deal_offers[offer_index];
+                let settled = if response == CounterResponse::Accepted {
+                    counter
+                } else {
+                    meet_halfway(offer, &counter)
+                };
+                offer.advance = settled.advance;
+                offer.royalty_rate = settled.royalty_rate;
+                offer.albums_required = settled.albums_required;
+                offer.term_weeks = settled.term_weeks;
+                offer.creative_control = settled.creative_control;
+                offer.counters += 1;
+                let terms = format!(
+                    "${} advance, {:.1}% royalty, {} album{} over {} weeks, {} control",
+                    settled.advance,
+                    settled.royalty_rate * 100.0,
+                    settled.albums_required,
+                    if settled.albums_required == 1 {
+                        ""
+                    } else {
+                        "s"
+                    },
+                    settled.term_weeks,
+                    settled.creative_control.label().to_lowercase()
+                );
+                if response == CounterResponse::Accepted {
+                    self.log(format!(
+                        "🤝 {} agreed to your terms: {}. Accept to sign.",
+                        label_name, terms
+                    ));
+                } else {
+                    self.log(format!("↔️ {} met you halfway: {}.", label_name, terms));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The terms halfway between the offer and the counter — rounded the
+/// label's way.
+fn meet_halfway(offer: &PotentialDealOffer, counter: &DealCounter) -> DealCounter {
+    let halfway = |from: f32, to: f32| from + (to - from) / 2.0;
+    let control = halfway(
+        offer.creative_control as i32 as f32,
+        counter.creative_control as i32 as f32,
+    );
+    DealCounter {
+        advance: halfway(offer.advance as f32, counter.advance as f32).floor() as u32,
+        royalty_rate: (halfway(offer.royalty_rate, counter.royalty_rate) * 1000.0).floor() / 1000.0,
+        albums_required: halfway(
+            f32::from(offer.albums_required),
+            f32::from(counter.albums_required),
+        )
+        .ceil() as u8,
+        term_weeks: halfway(f32::from(offer.term_weeks), f32::from(counter.term_weeks)).ceil()
+            as u16,
+        creative_control: CreativeControl::ALL[control.floor().clamp(0.0, 2.0) as usize],
+    }
+}
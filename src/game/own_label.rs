@@ -0,0 +1,344 @@
+//! The band's own label. An established act can found one and from then on
+//! release its own records through it (`DistributionChannel::OwnLabel`):
+//! still the band's masters at indie margins, but with a real label's
+//! reach. It can also sign unsigned bands off the scene for an advance.
+//! Their records go out on the label and spread like an independent's, and
+//! the label takes its cut as they chart (`world/scene.rs`), plus a weekly
+//! catalogue income off its acts' fame — the same economics the other
+//! labels live by (`world/labels.rs`) — against its office and the upkeep
+//! of every act it carries.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::music::DistributionChannel;
+use super::world::SceneBand;
+use super::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnLabel {
+    pub name: String,
+    pub founded_week: u32,
+    pub acts: Vec<LabelAct>,
+}
+
+/// A scene band signed to the label.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelAct {
+    pub band: String,
+    pub advance: u32,
+    pub signed_week: u32,
+    /// Records out on the label, and how many were hits.
+    pub releases: u32,
+    pub hits: u32,
+    /// Everything the act has earned the label.
+    pub earned: u32,
+}
+
+impl Game {
+    /// Why the band can't found a label right now, if it can't.
+    pub fn label_founding_lock(&self) -> Option<String> {
+        if self.own_label.is_some() {
+            Some("You already run a label.".to_string())
+        } else if self.is_bankrupt() {
+            Some("No bank will back a bankrupt's label.".to_string())
+        } else if self.band.fame < OWN_LABEL_MIN_FAME {
+            Some(format!(
+                "Nobody takes a label seriously from a band under {} fame.",
+                OWN_LABEL_MIN_FAME
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Why a distribution channel can't be chosen right now, if it can't:
+    /// the fame gate, and for the own label, founding it first.
+    pub fn distribution_lock(&self, channel: DistributionChannel) -> Option<String> {
+        if channel == DistributionChannel::OwnLabel && self.own_label.is_none() {
+            Some("Found your own label first.".to_string())
+        } else if !channel.is_available(self.band.fame) {
+            Some(format!(
+                "{} needs {} fame — you're not there yet.",
+                channel.label(),
+                channel.fame_gate()
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// What signing a scene band costs up front.
+    pub fn signing_advance(band: &SceneBand) -> u32 {
+        u32::from(band.fame) * OWN_LABEL_ADVANCE_PER_FAME
+    }
+
+    /// Why a scene band won't sign to the label, if it won't.
+    pub fn signing_lock(&self, band: &SceneBand) -> Option<String> {
+        let label = self.own_label.as_ref()?;
+        if band.label.is_some() {
+            Some(format!("{} are already signed.", band.name))
+        } else if label.acts.len() >= OWN_LABEL_MAX_ACTS {
+            Some(format!("{} can't carry another act.", label.name))
+        } else if band.fame >= self.band.fame {
+            Some(format!(
+                "{} are bigger than you — they won't sign to your label.",
+                band.name
+            ))
+        } else if band.is_rival() {
+            Some(format!("{} won't sign with you.", band.name))
+        } else {
+            None
+        }
+    }
+
+    /// Unsigned scene bands the label could sign, most famous first.
+    pub fn label_prospects(&self) -> Vec<&SceneBand> {
+        let mut prospects: Vec<&SceneBand> = self
+            .world
+            .bands
+            .iter()
+            .filter(|band| band.label.is_none() && band.fame < self.band.fame)
+            .collect();
+        prospects.sort_by_key(|band| std::cmp::Reverse(band.fame));
+        prospects
+    }
+
+    /// The label screen's rows: its acts, then the bands it could sign,
+    /// each with whether it's already on the label.
+    pub fn label_rows(&self) -> Vec<(String, bool)> {
+        let Some(label) = &self.own_label else {
+            return Vec::new();
+        };
+        label
+            .acts
+            .iter()
+            .map(|act| (act.band.clone(), true))
+            .chain(
+                self.label_prospects()
+                    .into_iter()
+                    .map(|band| (band.name.clone(), false)),
+            )
+            .collect()
+    }
+
+    /// What the label costs to run a week: the office and every act.
+    pub fn own_label_overhead(&self) -> u32 {
+        self.own_label.as_ref().map_or(0, |label| {
+            OWN_LABEL_OVERHEAD_PER_WEEK + label.acts.len() as u32 * LABEL_UPKEEP_PER_ACT as u32
+        })
+    }
+
+    /// Found the band's label. Instant.
+    pub(super) fn action_found_label(&mut self) -> Result<(), String> {
+        if let Some(lock) = self.label_founding_lock() {
+            return Err(lock);
+        }
+        if !self.player.can_afford(OWN_LABEL_FOUNDING_COST as i32) {
+            return Err(format!(
+                "Founding a label takes ${}.",
+                OWN_LABEL_FOUNDING_COST
+            ));
+        }
+        let name = format!("{} Records", self.band.name);
+        self.post(
+            LedgerCategory::Label,
+            -(OWN_LABEL_FOUNDING_COST as i32),
+            format!("Founding {}", name),
+        );
+        self.world.player_label = Some(name.clone());
+        self.log(format!(
+            "🏷️ {} opens its doors. Your records can go out on it now, and the scene's unsigned acts are yours to sign.",
+            name
+        ));
+        self.own_label = Some(OwnLabel {
+            name,
+            founded_week: self.week,
+            acts: Vec::new(),
+        });
+        Ok(())
+    }
+
+    /// Sign a scene band to the label for its advance. Instant.
+    pub(super) fn action_sign_to_label(&mut self, band_name: &str) -> Result<(), String> {
+        let Some(label_name) = self.own_label.as_ref().map(|label| label.name.clone()) else {
+            return Err("You don't run a label.".to_string());
+        };
+        let band = self
+            .world
+            .scene_band(band_name)
+            .ok_or_else(|| format!("{} aren't around any more.", band_name))?;
+        if let Some(lock) = self.signing_lock(band) {
+            return Err(lock);
+        }
+        let advance = Self::signing_advance(band);
+        if !self.player.can_afford(advance as i32) {
+            return Err(format!("{} want ${} to sign.", band_name, advance));
+        }
+        self.post(
+            LedgerCategory::Label,
+            -(advance as i32),
+            format!("Advance: {}", band_name),
+        );
+        if let Some(band) = self.world.scene_band_mut(band_name) {
+            band.label = Some(label_name.clone());
+            band.momentum = (band.momentum + 1).min(3);
+        }
+        if let Some(label) = &mut self.own_label {
+            label.acts.push(LabelAct {
+                band: band_name.to_string(),
+                advance,
+                signed_week: self.week,
+                releases: 0,
+                hits: 0,
+                earned: 0,
+            });
+        }
+        self.log(format!(
+            "🖋️ {} sign to {} for a ${} advance.",
+            band_name, label_name, advance
+        ));
+        Ok(())
+    }
+
+    /// Let an act go. Instant; the advance is gone.
+    pub(super) fn action_drop_from_label(&mut self, band_name: &str) -> Result<(), String> {
+        let Some(label) = &mut self.own_label else {
+            return Err("You don't run a label.".to_string());
+        };
+        let Some(index) = label.acts.iter().position(|act| act.band == band_name) else {
+            return Err(format!("{} aren't on {}.", band_name, label.name));
+        };
+        label.acts.remove(index);
+        let label_name = label.name.clone();
+        if let Some(band) = self.world.scene_band_mut(band_name) {
+            band.label = None;
+        }
+        self.log(format!("✂️ {} let {} go.", label_name, band_name));
+        Ok(())
+    }
+
+    /// The label's weeks: it books its take on every record its acts put
+    /// out and its catalogue income off their fame, pays its office and
+    /// their upkeep, and loses any act that split up.
+    pub(super) fn own_label_weekly_update(&mut self, weeks_elapsed: u32) {
+        let Some(label) = &self.own_label else {
+            return;
+        };
+        let label_name = label.name.clone();
+        let market = self.world.get_market_modifier();
+
+        let departed: Vec<String> = label
+            .acts
+            .iter()
+            .filter(|act| {
+                self.world
+                    .scene_band(&act.band)
+                    .is_none_or(|band| band.label.as_deref() != Some(label_name.as_str()))
+            })
+            .map(|act| act.band.clone())
+            .collect();
+        for band in departed {
+            if let Some(label) = &mut self.own_label {
+                label.acts.retain(|act| act.band != band);
+            }
+            self.log(format!(
+                "💔 {} have split up — one act fewer on {}.",
+                band, label_name
+            ));
+        }
+
+        for release in std::mem::take(&mut self.world.player_label_releases) {
+            let take =
+                (release.score as f32 * LABEL_TAKE_PER_SCORE * f32::from(OWN_LABEL_MARKET_REACH)
+                    / 100.0
+                    * market) as u32;
+            let hit = release
+                .best_position
+                .is_some_and(|position| position <= LABEL_HIT_MAX_POSITION);
+            let Some(act) = self
+                .own_label
+                .as_mut()
+                .and_then(|label| label.acts.iter_mut().find(|act| act.band == release.band))
+            else {
+                continue;
+            };
+            act.releases += 1;
+            act.hits += u32::from(hit);
+            act.earned += take;
+            self.post(
+                LedgerCategory::Label,
+                take as i32,
+                format!("'{}' by {}", release.title, release.band),
+            );
+            if hit {
+                self.log(format!(
+                    "📀 {}'s '{}' on {} charts at #{} — ${} to the label.",
+                    release.band,
+                    release.title,
+                    label_name,
+                    release.best_position.unwrap_or_default(),
+                    take
+                ));
+            }
+        }
+
+        let catalogue: Vec<(String, u32)> = self
+            .own_label
+            .as_ref()
+            .map(|label| {
+                label
+                    .acts
+                    .iter()
+                    .filter_map(|act| {
+                        let fame = self.world.scene_band(&act.band)?.fame;
+                        let income = (LABEL_CATALOGUE_PER_FAME as f32 * f32::from(fame) * market)
+                            as u32
+                            * weeks_elapsed;
+                        Some((act.band.clone(), income))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut catalogue_total = 0;
+        for (band, income) in catalogue {
+            if let Some(act) = self
+                .own_label
+                .as_mut()
+                .and_then(|label| label.acts.iter_mut().find(|act| act.band == band))
+            {
+                act.earned += income;
+            }
+            catalogue_total += income;
+        }
+        self.post(
+            LedgerCategory::Label,
+            catalogue_total as i32,
+            "Label catalogue",
+        );
+        self.post(
+            LedgerCategory::Label,
+            -((self.own_label_overhead() * weeks_elapsed) as i32),
+            "Label office and upkeep",
+        );
+    }
+
+    /// The receiver closes the label: its acts go free and the name goes
+    /// with it.
+    pub(super) fn close_own_label(&mut self) {
+        let Some(label) = self.own_label.take() else {
+            return;
+        };
+        self.world.player_label = None;
+        self.world.player_label_releases.clear();
+        for act in &label.acts {
+            if let Some(band) = self.world.scene_band_mut(&act.band) {
+                band.label = None;
+            }
+        }
+        self.log(format!(
+            "🏷️ {} closes — its acts are free agents again.",
+            label.name
+        ));
+    }
+}
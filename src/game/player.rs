@@ -103,7 +103,7 @@ impl LifestyleTier {
     }
 
     /// Added to the health/stress recovery of rest-type actions
-    /// (`LazeAround`, `TakeBreak`).
+    /// (`LazeAround`, `TakeVacation`).
     pub fn rest_healing_bonus(&self) -> u8 {
         constants::LIFESTYLE_REST_HEALING_BONUS[self.index()]
     }
@@ -145,19 +145,6 @@ impl Player {
         self.money >= cost
     }
 
-    pub fn spend_money(&mut self, amount: i32) -> bool {
-        if self.can_afford(amount) {
-            self.money -= amount;
-            true
-        } else {
-            false
-        }
-    }
-
-    pub fn earn_money(&mut self, amount: u32) {
-        self.money += amount as i32;
-    }
-
     pub fn is_addicted(&self) -> bool {
         self.drug_addiction > 50 || self.alcohol_addiction > 50
     }
@@ -0,0 +1,204 @@
+//! Regional fame: how known the band is, region by region. Touring and
+//! festivals build it (`actions/live.rs`); a region the band stops visiting
+//! forgets it, on its own grace clock, unless a record charting in that
+//! country keeps the name about. The fame map gathers each country's
+//! regions with what the band has there — fame, the last visit, its chart
+//! placing and the records it has sold — so the UI can show why a
+//! territory isn't buying.
+
+use super::constants::*;
+use super::*;
+
+/// One region on the fame map.
+#[derive(Debug, Clone)]
+pub struct RegionStanding {
+    pub name: String,
+    pub fame: u8,
+    /// The week the band last played there; `None` if it never has.
+    pub last_visit: Option<u32>,
+}
+
+/// One country on the fame map: its regions and, where it is a sales
+/// territory, the band's chart placing and sales there.
+#[derive(Debug, Clone)]
+pub struct CountryStanding {
+    pub name: String,
+    /// `None` for a country the band can tour that isn't a sales territory
+    /// (no chart, no record shops of its own).
+    pub territory: Option<world::ChartRegion>,
+    /// The band's best placing on the territory's chart right now.
+    pub chart_position: Option<usize>,
+    pub copies_sold: u32,
+    pub regions: Vec<RegionStanding>,
+}
+
+impl Game {
+    /// Every country the band can tour — the sales territories in chart-tab
+    /// order, then the rest — each with its regions biggest first.
+    pub fn fame_map(&self) -> Vec<CountryStanding> {
+        let regions = self.get_sorted_regions();
+        let mut countries: Vec<(&str, String, Option<world::ChartRegion>)> =
+            world::ChartRegion::TERRITORIES
+                .iter()
+                .map(|&territory| {
+                    (
+                        Self::territory_country(territory),
+                        territory.label().to_string(),
+                        Some(territory),
+                    )
+                })
+                .collect();
+        let mut others: Vec<&str> = regions
+            .iter()
+            .map(|(country, ..)| country.as_str())
+            .filter(|country| {
+                !world::ChartRegion::TERRITORIES
+                    .iter()
+                    .any(|&territory| Self::territory_country(territory) == *country)
+            })
+            .collect();
+        others.dedup();
+        countries.extend(others.into_iter().map(|key| (key, country_name(key), None)));
+
+        countries
+            .into_iter()
+            .map(|(country, name, territory)| {
+                let mut standings: Vec<(u32, RegionStanding)> = regions
+                    .iter()
+                    .filter(|(country_key, ..)| *country_key == country)
+                    .map(|(country_key, region_key, name, population, ..)| {
+                        let key = format!("{}:{}", country_key, region_key);
+                        (
+                            *population,
+                            RegionStanding {
+                                name: name.clone(),
+                                fame: self.regional_fame.get(&key).copied().unwrap_or(0),
+                                last_visit: self.regional_last_visit.get(&key).copied(),
+                            },
+                        )
+                    })
+                    .collect();
+                standings.sort_by_key(|(population, _)| std::cmp::Reverse(*population));
+                CountryStanding {
+                    name,
+                    territory,
+                    chart_position: territory
+                        .and_then(|territory| self.world.regional_charts.get(&territory))
+                        .and_then(|entries| entries.iter().position(|entry| entry.is_player))
+                        .map(|index| index + 1),
+                    copies_sold: self.copies_by_country.get(country).copied().unwrap_or(0),
+                    regions: standings.into_iter().map(|(_, region)| region).collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Start a region's grace clock over: the band just played there.
+    pub(super) fn mark_region_visited(&mut self, key: &str) {
+        self.regional_last_visit.insert(key.to_string(), self.week);
+    }
+
+    /// Regional fame fades where the band hasn't played in
+    /// `REGIONAL_FAME_GRACE_WEEKS`: a point a week, down to
+    /// `REGIONAL_FAME_FLOOR`. A record on the country's chart holds every
+    /// region in it. A region with fame but no recorded visit (an old save,
+    /// or fame from a news event) starts its clock now.
+    pub(super) fn update_regional_fame(&mut self, weeks_elapsed: u32) {
+        let charting: Vec<&'static str> = world::ChartRegion::TERRITORIES
+            .iter()
+            .filter(|territory| {
+                self.world
+                    .regional_charts
+                    .get(territory)
+                    .is_some_and(|entries| entries.iter().any(|entry| entry.is_player))
+            })
+            .map(|&territory| Self::territory_country(territory))
+            .collect();
+
+        let mut keys: Vec<String> = self.regional_fame.keys().cloned().collect();
+        keys.sort();
+        let exiled = self.in_tax_exile();
+        let mut forgetting: Vec<(String, u8)> = Vec::new();
+        for key in keys {
+            let last_visit = *self
+                .regional_last_visit
+                .entry(key.clone())
+                .or_insert(self.week);
+            let country = key.split(':').next().unwrap_or_default();
+            // A tax exile's home crowd gets no grace and no chart to hold
+            // it: it forgets the band from the week it leaves.
+            let home_in_exile = exiled && country == HOME_COUNTRY;
+            if charting.contains(&country) && !home_in_exile {
+                continue;
+            }
+            let neglected = if home_in_exile {
+                weeks_elapsed
+            } else {
+                self.week
+                    .saturating_sub(last_visit)
+                    .saturating_sub(REGIONAL_FAME_GRACE_WEEKS)
+                    .min(weeks_elapsed)
+            };
+            let Some(fame) = self.regional_fame.get_mut(&key) else {
+                continue;
+            };
+            if neglected == 0 || *fame <= REGIONAL_FAME_FLOOR {
+                continue;
+            }
+            let before = *fame;
+            let loss = (neglected.min(u32::from(u8::MAX)) as u8)
+                .saturating_mul(REGIONAL_FAME_DECAY_PER_WEEK);
+            *fame = fame.saturating_sub(loss).max(REGIONAL_FAME_FLOOR);
+            // News only when a region drops a tier, not every week.
+            if *fame / REGIONAL_FAME_NEWS_STEP < before / REGIONAL_FAME_NEWS_STEP {
+                forgetting.push((key, *fame));
+            }
+        }
+
+        let regions = self.get_sorted_regions();
+        for (key, fame) in forgetting {
+            let name = regions
+                .iter()
+                .find(|(country, region, ..)| format!("{}:{}", country, region) == key)
+                .map(|(_, _, name, ..)| name.clone())
+                .unwrap_or(key);
+            self.log(format!(
+                "🗺️ {} is forgetting you — regional fame down to {}. Time to tour there again.",
+                name, fame
+            ));
+        }
+    }
+
+    /// Book a week's sales against the territories they sold in, in
+    /// proportion to the band's presence in each.
+    pub(super) fn credit_territory_sales(&mut self, units: u32, presences: [f32; 4]) {
+        let total: f32 = presences.iter().sum();
+        if units == 0 || total <= 0.0 {
+            return;
+        }
+        for (territory, presence) in world::ChartRegion::TERRITORIES.iter().zip(presences) {
+            let share = (units as f32 * presence / total).round() as u32;
+            if share > 0 {
+                *self
+                    .copies_by_country
+                    .entry(Self::territory_country(*territory).to_string())
+                    .or_insert(0) += share;
+            }
+        }
+    }
+}
+
+/// `markets.json` keys countries in snake case; "new_zealand" reads as
+/// "New Zealand".
+pub(super) fn country_name(key: &str) -> String {
+    key.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
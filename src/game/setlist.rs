@@ -0,0 +1,139 @@
+//! Setlists: what the band plays on a tour (`TourTerms::setlist`). The
+//! crowd knows the hits — how high a record charted and how many copies
+//! are out there — and every familiar song lifts the night; unreleased
+//! songs cost a little reception but sharpen with every show; a set too
+//! short to fill the night sinks every verdict. An empty setlist is the
+//! band's usual set and moves nothing. The tour itself applies the shift
+//! and the road-testing (`actions/live.rs`); this module is the math.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::music::Release;
+use super::*;
+
+/// One song on a setlist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SetlistSong {
+    /// A released single or album, by release id, played the way the crowd
+    /// knows it.
+    Release(u32),
+    /// An unreleased song, by song id, getting road-tested.
+    Unreleased(u32),
+}
+
+impl Game {
+    /// Everything the band could play, with a name for each: the releases
+    /// newest first, then the unreleased songs.
+    pub fn setlist_catalogue(&self) -> Vec<(SetlistSong, String)> {
+        let mut releases: Vec<&Release> = self
+            .band
+            .singles_released
+            .iter()
+            .chain(&self.band.albums_released)
+            .collect();
+        releases.sort_by_key(|release| std::cmp::Reverse(release.week_released));
+        releases
+            .into_iter()
+            .map(|release| (SetlistSong::Release(release.id), release.name.clone()))
+            .chain(
+                self.band
+                    .unreleased_songs
+                    .iter()
+                    .map(|song| (SetlistSong::Unreleased(song.id), song.name.clone())),
+            )
+            .collect()
+    }
+
+    fn setlist_release(&self, id: u32) -> Option<&Release> {
+        self.band
+            .singles_released
+            .iter()
+            .chain(&self.band.albums_released)
+            .find(|release| release.id == id)
+    }
+
+    /// A setlist the band can play: no longer than `SETLIST_MAX_SONGS`, no
+    /// song twice, and every song still in the catalogue.
+    pub(super) fn check_setlist(&self, setlist: &[SetlistSong]) -> Result<(), String> {
+        if setlist.len() > SETLIST_MAX_SONGS {
+            return Err(format!(
+                "A set can run to at most {} songs.",
+                SETLIST_MAX_SONGS
+            ));
+        }
+        for (i, song) in setlist.iter().enumerate() {
+            if setlist[..i].contains(song) {
+                return Err("The setlist plays a song twice.".to_string());
+            }
+            let known = match *song {
+                SetlistSong::Release(id) => self.setlist_release(id).is_some(),
+                SetlistSong::Unreleased(id) => {
+                    self.band.unreleased_songs.iter().any(|song| song.id == id)
+                }
+            };
+            if !known {
+                return Err("A song on the setlist is no longer in the catalogue.".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// How well the crowd knows a release, 0.0-1.0: its peak chart
+    /// placement and its sales.
+    fn release_familiarity(release: &Release) -> f32 {
+        let chart = release
+            .peak_chart_position
+            .map(|position| f32::from(101 - position.min(100)) / 100.0)
+            .unwrap_or(0.0);
+        let sales = (release.copies_sold as f32 / SETLIST_SALES_FAMILIAR as f32).min(1.0);
+        SETLIST_CHART_WEIGHT * chart + (1.0 - SETLIST_CHART_WEIGHT) * sales
+    }
+
+    /// The reception a setlist adds to (or takes off) every show: the
+    /// set's average familiarity, less the new songs and any shortfall
+    /// against a full night. The usual set is 0.
+    pub fn setlist_reception_shift(&self, setlist: &[SetlistSong]) -> f32 {
+        if setlist.is_empty() {
+            return 0.0;
+        }
+        let (familiarity, new_songs) =
+            setlist
+                .iter()
+                .fold((0.0, 0u32), |(familiarity, new_songs), song| match *song {
+                    SetlistSong::Release(id) => (
+                        familiarity
+                            + self
+                                .setlist_release(id)
+                                .map_or(0.0, Self::release_familiarity),
+                        new_songs,
+                    ),
+                    SetlistSong::Unreleased(_) => (familiarity, new_songs + 1),
+                });
+        let shortfall = SETLIST_MIN_SONGS.saturating_sub(setlist.len());
+        familiarity / setlist.len() as f32 * SETLIST_FAMILIARITY_MAX
+            - new_songs as f32 * SETLIST_NEW_SONG_COST
+            - shortfall as f32 * SETLIST_SHORT_SONG_PENALTY
+    }
+
+    /// Play the setlist's unreleased songs in for `shows` nights: each
+    /// gains a point of songwriting quality every
+    /// `SETLIST_ROAD_TEST_SHOWS_PER_POINT` shows, up to the tour's cap.
+    /// Returns how many songs improved and by how much each.
+    pub(super) fn road_test_setlist(&mut self, setlist: &[SetlistSong], shows: u32) -> (u32, u8) {
+        let gain = (shows / SETLIST_ROAD_TEST_SHOWS_PER_POINT)
+            .min(u32::from(SETLIST_ROAD_TEST_MAX_GAIN)) as u8;
+        if gain == 0 {
+            return (0, 0);
+        }
+        let mut improved = 0;
+        for song in &mut self.band.unreleased_songs {
+            if setlist.contains(&SetlistSong::Unreleased(song.id)) && song.songwriting_quality < 100
+            {
+                song.songwriting_quality = song.songwriting_quality.saturating_add(gain).min(100);
+                improved += 1;
+            }
+        }
+        (improved, gain)
+    }
+}
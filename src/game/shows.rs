@@ -44,6 +44,11 @@ impl ShowVerdict {
         }
     }
 
+    /// How much better than usual the merch table does tonight.
+    pub(super) fn merch_mult(self) -> f32 {
+        MERCH_VERDICT_MULT[self as usize]
+    }
+
     /// The momentum delta this verdict applies, before the 0.85-1.15 clamp
     /// (§B — Momentum). [tune]
     pub(super) fn momentum_delta(self) -> f32 {
@@ -73,17 +78,29 @@ pub(super) fn reception_attendance_factor(reception: u8) -> f32 {
         + t * (RECEPTION_ATTENDANCE_MAX_FACTOR - RECEPTION_ATTENDANCE_MIN_FACTOR)
 }
 
+/// Merch units one show sells: the fame-scaled share of the crowd that
+/// buys, lifted or sunk by the verdict, capped by what's left in stock.
+/// Deterministic — no rng — so stocking merch never shifts a seeded run.
+pub(super) fn merch_units(attendance: u32, fame: u8, verdict: ShowVerdict, stock: u32) -> u32 {
+    let buy_rate = MERCH_BUY_RATE_BASE + MERCH_BUY_RATE_PER_FAME * fame as f32;
+    let units = (attendance as f32 * buy_rate * verdict.merch_mult()).round() as u32;
+    units.min(stock)
+}
+
 /// Roll one show's reception (§B):
 /// `band_base + condition + era_fit + variance + creativity_upside`,
 /// clamped to 0-100. `band_base` is the dominant term by design — a tight,
 /// uninspired band (0 creativity) can still be exceptional; creativity only
-/// ever widens the upside tail, never multiplies the base.
+/// ever widens the upside tail, never multiplies the base. `variance_scale`
+/// narrows the variance term (a sound engineer, `gear.rs`) without
+/// changing what's drawn.
 pub(super) fn compute_reception(
     band: &Band,
     stress: u8,
     health: u8,
     era_genre_modifier: f32,
     creativity: u8,
+    variance_scale: f32,
     rng: &mut impl Rng,
 ) -> u8 {
     let band_base = constants::RECEPTION_BAND_BASE_SKILL_WEIGHT
@@ -101,7 +118,8 @@ pub(super) fn compute_reception(
 
     let era_fit = era_fit_scaled(era_genre_modifier);
 
-    let variance = rng.gen_range(-RECEPTION_VARIANCE_RANGE..=RECEPTION_VARIANCE_RANGE) as f32;
+    let variance =
+        rng.gen_range(-RECEPTION_VARIANCE_RANGE..=RECEPTION_VARIANCE_RANGE) as f32 * variance_scale;
 
     let upside_max = (creativity / RECEPTION_CREATIVITY_UPSIDE_DIVISOR) as i32;
     let creativity_upside = rng.gen_range(0..=upside_max) as f32;
@@ -127,6 +145,9 @@ pub struct ShowReport {
     pub attendance: u32,
     pub capacity: u32,
     pub take: u32,
+    /// Merch revenue from this show, on top of `take`.
+    #[serde(default)]
+    pub merch: u32,
 }
 
 /// A resolved tour (or a one-off gig, stored as a single-row report) —
@@ -137,12 +158,17 @@ pub struct TourReport {
     pub avg_reception: u8,
     pub total_gross: u32,
     pub fame_gained: u8,
+    /// Merch revenue across every row — kept apart from the box office in
+    /// `total_gross`.
+    #[serde(default)]
+    pub total_merch: u32,
 }
 
 impl TourReport {
     /// Build a report from resolved rows, computing the summary fields.
     pub(super) fn from_rows(rows: Vec<ShowReport>, fame_gained: u8) -> Self {
         let total_gross: u32 = rows.iter().map(|row| row.take).sum();
+        let total_merch: u32 = rows.iter().map(|row| row.merch).sum();
         let avg_reception = if rows.is_empty() {
             0
         } else {
@@ -154,6 +180,7 @@ impl TourReport {
             avg_reception,
             total_gross,
             fame_gained,
+            total_merch,
         }
     }
 
@@ -233,6 +260,17 @@ mod tests {
         assert!((reception_attendance_factor(100) - RECEPTION_ATTENDANCE_MAX_FACTOR).abs() < 1e-6);
     }
 
+    #[test]
+    fn merch_sales_scale_with_the_verdict_and_stop_at_the_stock() {
+        let solid = merch_units(1_000, 50, ShowVerdict::Solid, u32::MAX);
+        let great = merch_units(1_000, 50, ShowVerdict::Great, u32::MAX);
+        let rough = merch_units(1_000, 50, ShowVerdict::Rough, u32::MAX);
+        assert!(rough < solid && solid < great);
+        assert!(merch_units(1_000, 90, ShowVerdict::Solid, u32::MAX) > solid);
+        assert_eq!(merch_units(1_000, 50, ShowVerdict::Great, 7), 7);
+        assert_eq!(merch_units(1_000, 50, ShowVerdict::Great, 0), 0);
+    }
+
     #[test]
     fn a_perfect_band_with_zero_creativity_can_still_be_exceptional() {
         // 100% skill, full reputation, 0 creativity: band_base alone is
@@ -244,7 +282,7 @@ mod tests {
         // StepRng always returns the same word, driving gen_range to its
         // minimum on both the variance and creativity-upside rolls.
         let mut rng = StepRng::new(0, 0);
-        let reception = compute_reception(&band, 0, 100, 1.0, 0, &mut rng);
+        let reception = compute_reception(&band, 0, 100, 1.0, 0, 1.0, &mut rng);
         assert_eq!(
             reception, 90,
             "band_base 100 + worst-case variance -10 should still land at 90"
@@ -267,7 +305,7 @@ mod tests {
         let band = band_with(50, 50);
         for seed in 0..50u64 {
             let mut rng = StdRng::seed_from_u64(seed);
-            let _ = compute_reception(&band, 0, 100, 1.0, 0, &mut rng);
+            let _ = compute_reception(&band, 0, 100, 1.0, 0, 1.0, &mut rng);
         }
     }
 
@@ -277,10 +315,10 @@ mod tests {
         let band = band_with(50, 50);
         // Midpoint rng: near-zero variance and creativity upside contribution.
         let mut rng_ok = StepRng::new(0, 0);
-        let healthy = compute_reception(&band, 0, 100, 1.0, 0, &mut rng_ok);
+        let healthy = compute_reception(&band, 0, 100, 1.0, 0, 1.0, &mut rng_ok);
 
         let mut rng_bad = StepRng::new(0, 0);
-        let stressed_and_sick = compute_reception(&band, 80, 10, 1.0, 0, &mut rng_bad);
+        let stressed_and_sick = compute_reception(&band, 80, 10, 1.0, 0, 1.0, &mut rng_bad);
 
         assert_eq!(
             healthy.saturating_sub(stressed_and_sick),
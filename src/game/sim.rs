@@ -78,7 +78,33 @@ pub(super) fn seeded_game(seed: u64) -> Game {
         data_files,
         pending_deal_offers: Vec::new(),
         pending_support_offer: None,
+        pending_festival_offer: None,
+        pending_headline_offer: None,
+        manager: None,
+        pending_manager_offers: Vec::new(),
+        manager_cooldown: 0,
+        manager_scout_weeks: 1,
+        pending_incident: None,
+        pending_takeover: None,
+        pending_label_demand: None,
+        ledger: Vec::new(),
+        royalty_statements: Vec::new(),
+        open_royalty_statements: Vec::new(),
+        loan: None,
+        bankrupt_until: None,
+        accountant: false,
+        tax_exiles: Vec::new(),
+        tax_assessments: Vec::new(),
+        investments: Vec::new(),
+        own_label: None,
+        on_tour: false,
         regional_fame: std::collections::HashMap::new(),
+        regional_last_visit: std::collections::HashMap::new(),
+        copies_by_country: std::collections::HashMap::new(),
+        merch_stock: 0,
+        gear: crate::game::gear::starting_gear(),
+        crew: Vec::new(),
+        gear_insured: false,
         idle_streak: 0,
         decay_streak: 0,
         genre_trend_reported: 0,
@@ -89,6 +115,7 @@ pub(super) fn seeded_game(seed: u64) -> Game {
         next_release_id: 0,
         just_released_music: Vec::new(),
         last_tour_report: None,
+        live_tapes: None,
         turn_log: Vec::new(),
         rockstar_achieved: false,
         current_distribution_channel: music::DistributionChannel::default(),
@@ -213,29 +240,49 @@ fn self_care(game: &Game) -> Option<GameAction> {
         return Some(if player.can_afford(constants::DOCTOR_VISIT_COST) {
             GameAction::VisitDoctor
         } else {
-            GameAction::TakeBreak
+            getaway(game)
         });
     }
     if player.stress >= STRESS_CEILING {
-        return Some(GameAction::TakeBreak);
+        return Some(getaway(game));
     }
     None
 }
 
+/// The month-long holiday the old flat Take a Break used to be, if the bot
+/// can pay for it; otherwise the best trip it can, and a week on the couch
+/// when even the seaside is out of reach. Bots never cruise — ten quiet
+/// weeks is a superstar's move, not a policy.
+fn getaway(game: &Game) -> GameAction {
+    [
+        Vacation::MediterraneanHoliday,
+        Vacation::CountryRetreat,
+        Vacation::SeasideWeekend,
+    ]
+    .into_iter()
+    .find(|trip| game.player.can_afford(trip.cost() as i32))
+    .map_or(GameAction::LazeAround, GameAction::TakeVacation)
+}
+
 /// The biggest stage whose door policy admits the band right now
-/// (venue gate: `prestige <= fame + 20`).
-fn biggest_open_venue(game: &Game) -> usize {
+/// (venue gate: `prestige <= fame + 20`) and that hasn't banned them.
+fn biggest_open_venue(game: &Game) -> Option<usize> {
     (0..game.world.venues.len())
-        .filter(|&i| game.world.venues[i].prestige <= game.band.fame.saturating_add(20))
+        .filter(|&i| {
+            let venue = &game.world.venues[i];
+            game.venue_in_reach(venue) && !venue.is_banned(game.week)
+        })
         .max_by_key(|&i| game.world.venues[i].capacity)
-        .expect("at least one venue is always open")
 }
 
 fn gig_or_rest(game: &Game) -> GameAction {
-    if game.player.stress < GIG_STRESS_GUARD && game.player.health >= GIG_HEALTH_GUARD {
-        GameAction::Gig(biggest_open_venue(game))
-    } else {
-        GameAction::LazeAround
+    match biggest_open_venue(game) {
+        Some(venue)
+            if game.player.stress < GIG_STRESS_GUARD && game.player.health >= GIG_HEALTH_GUARD =>
+        {
+            GameAction::Gig(venue)
+        }
+        _ => GameAction::LazeAround,
     }
 }
 
@@ -308,6 +355,23 @@ fn indie_loop(game: &Game, pressing: Option<usize>, allow_support: bool) -> Game
     {
         return GameAction::AcceptSupportTour;
     }
+    // A promoter-backed headline tour costs nothing up front.
+    if allow_support
+        && game.pending_headline_offer.is_some()
+        && game.player.stress < TOUR_STRESS_GUARD
+        && game.player.health >= TOUR_HEALTH_GUARD
+    {
+        return GameAction::AcceptHeadlineTour;
+    }
+    // Festival slots likewise; booking is free until the day.
+    if allow_support
+        && game
+            .pending_festival_offer
+            .as_ref()
+            .is_some_and(|offer| !offer.booked)
+    {
+        return GameAction::AcceptFestival;
+    }
     // Work the room while there's a record on the shelves.
     if !game.just_released_music.is_empty() {
         return gig_or_rest(game);
@@ -364,7 +428,9 @@ fn label_loyalist(game: &Game) -> GameAction {
 fn best_affordable_channel(game: &Game, reserve: i32) -> DistributionChannel {
     let mut best = DistributionChannel::ALL[0];
     for &channel in DistributionChannel::ALL.iter() {
-        if channel.is_available(game.band.fame) && game.player.can_afford(reserve + channel.fee()) {
+        if game.distribution_lock(channel).is_none()
+            && game.player.can_afford(reserve + channel.fee())
+        {
             best = channel;
         }
     }
@@ -469,7 +535,7 @@ enum Ending {
 fn ending_of(game: &Game) -> Ending {
     if game.player.health == 0 {
         Ending::Died
-    } else if game.player.money < 0 && game.band.fame < 10 {
+    } else if game.game_over && game.is_bankrupt() {
         Ending::WentBroke
     } else if game.rockstar_achieved {
         Ending::Rockstar
@@ -869,8 +935,8 @@ fn no_bot_panics_or_stalls_inside_five_years() {
     for bot in Bot::ALL {
         for seed in [11u64, 27, 43] {
             let career = run_career(bot, seed, SMOKE_HORIZON);
-            // Week jumps are bounded: a break adds 4, a support tour at
-            // most 5. Anything larger means the calendar broke.
+            // Week jumps are bounded: a bot's longest getaway adds 4, a
+            // support tour at most 5. Anything larger means the calendar broke.
             assert!(
                 career.weeks <= SMOKE_HORIZON + 6,
                 "{} on seed {seed} overshot the horizon: week {}",
@@ -949,13 +1015,15 @@ fn a_pure_gig_grinder_stalls_at_the_base_live_cap() {
             }
             game.take_turn_log();
             // Adapted for v0.6 (L3): gigging now costs stress (§B), so
-            // `self_care` can force a real `TakeBreak` (jumps `BREAK_WEEKS`)
-            // once stress crosses `STRESS_CEILING` — event suppression only
-            // needs `last_event_week` re-parked after every turn, which it
-            // is below, regardless of how many weeks a turn advances.
+            // `self_care` can force a real vacation (jumps up to the longest
+            // trip's weeks) once stress crosses `STRESS_CEILING` — event
+            // suppression only needs `last_event_week` re-parked after every
+            // turn, which it is below, regardless of how many weeks a turn
+            // advances.
+            let longest_trip = VACATION_WEEKS[VACATION_WEEKS.len() - 1];
             assert!(
-                game.week <= week_before + BREAK_WEEKS,
-                "event suppression re-parks every turn; no action should ever jump further than a break (seed {seed})"
+                game.week <= week_before + longest_trip,
+                "event suppression re-parks every turn; no action should ever jump further than a vacation (seed {seed})"
             );
             game.events.last_event_week = u32::MAX;
             assert!(
@@ -1038,7 +1106,7 @@ fn reception_lands_the_designed_verdict_tiers_by_skill() {
         let mut rng = StdRng::seed_from_u64(seed);
         let mut counts = [0u32; 4];
         for _ in 0..samples {
-            let reception = shows::compute_reception(band, 30, 80, 1.0, 50, &mut rng);
+            let reception = shows::compute_reception(band, 30, 80, 1.0, 50, 1.0, &mut rng);
             let idx = match shows::ShowVerdict::from_reception(reception) {
                 shows::ShowVerdict::Rough => 0,
                 shows::ShowVerdict::Solid => 1,
@@ -1480,6 +1548,10 @@ fn sales_tail_income_by_quality_tier_sweep() {
             certified: 0,
             distribution_channel: None,
             label_market_reach: None,
+            live: None,
+            outside_deal: false,
+            master_owner: None,
+            rerecording_of: None,
         }
     }
 
@@ -0,0 +1,183 @@
+//! When the label the band is signed to is bought out (`world/labels.rs`),
+//! the contract moves to the buyer: its reach, a royalty pulled halfway
+//! toward the buyer's own rate, the same albums and clock still owed. Masters
+//! the old label held move with it. For a few weeks after, the band can ask
+//! the buyer to put its old terms back on the table — an offer it can take,
+//! counter or turn down like any other — or walk under the key-man clause,
+//! free of the contract and whatever it still owed, leaving the masters
+//! behind. Let the window pass and the new terms stand.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::*;
+use super::music::MasterOwner;
+use super::world::{PotentialDealOffer, find_label_with_tier};
+use super::*;
+
+/// A takeover of the band's label, waiting on the band's answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelTakeover {
+    pub old_label: String,
+    pub buyer: String,
+    /// The terms the contract had before it moved.
+    pub old_royalty_rate: f32,
+    pub old_market_reach: u8,
+    /// After this week the new terms stand and the choice is gone.
+    pub expires_week: u32,
+}
+
+impl Game {
+    /// The weekly takeover check, run before a folded label's deal is torn
+    /// up: masters and the contract follow a bought label to its buyer, and
+    /// an unanswered takeover lapses once its window closes.
+    pub(super) fn label_takeover_check(&mut self) {
+        let world = &self.world;
+        for release in self
+            .band
+            .albums_released
+            .iter_mut()
+            .chain(&mut self.band.singles_released)
+            .chain(&mut self.just_released_music)
+        {
+            if let Some(MasterOwner::Label { name, .. }) = &mut release.master_owner
+                && let Some(buyer) = world.label_successor(name)
+            {
+                *name = buyer.to_string();
+            }
+        }
+
+        if let Some(takeover) = &self.pending_takeover {
+            let still_there = self
+                .band
+                .current_deal()
+                .is_some_and(|deal| deal.label_name == takeover.buyer);
+            if !still_there {
+                self.pending_takeover = None;
+            } else if self.week >= takeover.expires_week {
+                let buyer = takeover.buyer.clone();
+                self.pending_takeover = None;
+                self.log(format!(
+                    "🏢 You never answered {}'s takeover — its terms stand now.",
+                    buyer
+                ));
+            }
+        }
+
+        let Some(deal) = self.band.current_deal() else {
+            return;
+        };
+        let Some(buyer) = self.world.label_successor(&deal.label_name) else {
+            return;
+        };
+        let Some((tier, label)) = find_label_with_tier(&self.data_files, buyer) else {
+            return;
+        };
+        let buyer = buyer.to_string();
+        let takeover = LabelTakeover {
+            old_label: deal.label_name.clone(),
+            buyer: buyer.clone(),
+            old_royalty_rate: deal.royalty_rate,
+            old_market_reach: deal.market_reach,
+            expires_week: self.week + TAKEOVER_DECISION_WEEKS,
+        };
+        let buyer_rate = f32::from(label.royalty_rate) / 100.0;
+        let royalty_rate = takeover.old_royalty_rate
+            + (buyer_rate - takeover.old_royalty_rate) * TAKEOVER_ROYALTY_SHIFT;
+        let market_reach = label.market_reach;
+        if let Some(deal) = self.band.record_deal.as_mut() {
+            deal.label_name = buyer.clone();
+            deal.label_tier = tier.to_string();
+            deal.market_reach = market_reach;
+            deal.royalty_rate = royalty_rate;
+        }
+        self.log(format!(
+            "🏢 {} has bought {} — your contract moves with it: reach {} → {}, royalty {:.0}% → {:.0}%.",
+            buyer,
+            takeover.old_label,
+            takeover.old_market_reach,
+            market_reach,
+            takeover.old_royalty_rate * 100.0,
+            royalty_rate * 100.0
+        ));
+        self.log(format!(
+            "📞 You have {} weeks to renegotiate or walk under the key-man clause — press V.",
+            TAKEOVER_DECISION_WEEKS
+        ));
+        self.pending_takeover = Some(takeover);
+    }
+
+    /// Ask the buyer to put the old contract's terms back on the table:
+    /// its royalty, the albums and weeks still owed, the balance carried
+    /// over. The offer waits with the others, to take, counter or turn
+    /// down. Instant.
+    pub(super) fn action_renegotiate_takeover(&mut self) -> Result<(), String> {
+        let takeover = self
+            .pending_takeover
+            .as_ref()
+            .ok_or("There's no takeover to answer.")?;
+        let deal = self
+            .band
+            .current_deal()
+            .ok_or("You're not signed to anyone any more.")?;
+        let (_, label) = find_label_with_tier(&self.data_files, &deal.label_name)
+            .ok_or_else(|| format!("{} isn't taking calls.", deal.label_name))?;
+        let term_weeks = if deal.term_weeks == 0 {
+            0
+        } else {
+            let ends = deal.signed_week + u32::from(deal.term_weeks);
+            (ends.saturating_sub(self.week) as u16).max(NEGOTIATION_MIN_TERM_WEEKS)
+        };
+        let offer = PotentialDealOffer {
+            label_name: deal.label_name.clone(),
+            label_tier: deal.label_tier.clone(),
+            advance: 0,
+            royalty_rate: takeover.old_royalty_rate,
+            albums_required: deal
+                .albums_required
+                .saturating_sub(deal.albums_delivered)
+                .max(1),
+            original_label_data: label.clone(),
+            expires_week: Some(self.week + DEAL_OFFER_LIFETIME_WEEKS),
+            term_weeks,
+            carry_forward_unrecouped: deal.unrecouped.max(0),
+            creative_control: deal.creative_control,
+            counters: 0,
+        };
+        let buyer = takeover.buyer.clone();
+        self.pending_deal_offers.push(offer);
+        self.pending_takeover = None;
+        self.log(format!(
+            "📝 {} will hear you out — its offer on your old terms is waiting in Deal Offers.",
+            buyer
+        ));
+        Ok(())
+    }
+
+    /// Walk under the key-man clause: the people who signed the band are
+    /// gone, so the band can go too — no breach, no cooldown, nothing more
+    /// owed. The masters stay with the buyer. Instant.
+    pub(super) fn action_key_man_exit(&mut self) -> Result<(), String> {
+        let takeover = self
+            .pending_takeover
+            .take()
+            .ok_or("There's no takeover to answer.")?;
+        let deal = self
+            .band
+            .record_deal
+            .take()
+            .ok_or("You're not signed to anyone any more.")?;
+        self.pending_deal_offers
+            .retain(|offer| offer.label_name != deal.label_name);
+        self.log(format!(
+            "🚪 The people who signed you left with {} — you invoke the key-man clause and walk. {} keeps the masters; you're a free agent.",
+            takeover.old_label, deal.label_name
+        ));
+        if deal.unrecouped > 0 {
+            self.log(format!(
+                "🗑️ The ${} you still owed stays on {}'s books, not yours.",
+                deal.unrecouped, deal.label_name
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+//! Income tax. At each new year the taxman assesses the year just gone: the
+//! year's profit off the books (`ledger.rs`) — everything earned, less the
+//! cost of making and selling the music, but not the rent, the doctor or
+//! the loans — taxed under the regime in force that year in the country the
+//! band lived in (`data/tax.json`). An accountant on retainer finds
+//! deductions and can set up a tax exile: a year living abroad, taxed at
+//! that country's rates if the band is away for most of a tax year, but
+//! with no shows at home and the home crowd forgetting it meanwhile.
+
+use serde::{Deserialize, Serialize};
+
+use crate::data_loader::TaxRegime;
+
+use super::constants::*;
+use super::regional::country_name;
+use super::*;
+
+/// A year the band spent living abroad.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxExile {
+    /// Country key, as in `tax.json`.
+    pub country: String,
+    pub started_week: u32,
+    pub until_week: u32,
+}
+
+impl TaxExile {
+    pub fn country_name(&self) -> String {
+        country_name(&self.country)
+    }
+}
+
+/// One year's tax bill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxAssessment {
+    pub year: u32,
+    /// Country key of the regime it was assessed under.
+    pub country: String,
+    pub profit: u32,
+    /// What the accountant knocked off the profit.
+    pub deductions: u32,
+    pub tax: u32,
+}
+
+impl TaxAssessment {
+    pub fn country_name(&self) -> String {
+        country_name(&self.country)
+    }
+}
+
+/// A country the band could go into exile in, with what it would cost.
+#[derive(Debug, Clone)]
+pub struct TaxHaven {
+    pub country: String,
+    pub name: String,
+    pub regime: TaxRegime,
+    /// The tax this year's profit so far would come to there.
+    pub estimate: u32,
+}
+
+impl Game {
+    /// The exile the band is living in right now, if any.
+    pub fn tax_exile(&self) -> Option<&TaxExile> {
+        self.tax_exiles
+            .last()
+            .filter(|exile| exile.until_week > self.week)
+    }
+
+    pub fn in_tax_exile(&self) -> bool {
+        self.tax_exile().is_some()
+    }
+
+    /// The country whose regime taxes a year: wherever the band lived for
+    /// most of it.
+    pub fn tax_residence(&self, year: u32) -> &str {
+        let first_week = (year.saturating_sub(STARTING_YEAR)) * WEEKS_PER_YEAR + 1;
+        let last_week = first_week + WEEKS_PER_YEAR;
+        self.tax_exiles
+            .iter()
+            .find(|exile| {
+                let overlap = exile
+                    .until_week
+                    .min(last_week)
+                    .saturating_sub(exile.started_week.max(first_week));
+                overlap >= TAX_RESIDENCY_WEEKS
+            })
+            .map_or(HOME_COUNTRY, |exile| exile.country.as_str())
+    }
+
+    /// What the band made in a year, as the taxman counts it.
+    pub fn taxable_profit(&self, year: u32) -> u32 {
+        let profit: i64 = self
+            .ledger
+            .iter()
+            .filter(|entry| entry.year == year && entry.category.taxable())
+            .map(|entry| i64::from(entry.amount))
+            .sum();
+        profit.clamp(0, i64::from(u32::MAX)) as u32
+    }
+
+    /// The bill a year's profit comes to, as things stand.
+    pub fn tax_assessment(&self, year: u32) -> TaxAssessment {
+        let country = self.tax_residence(year).to_string();
+        let profit = self.taxable_profit(year);
+        let deductions = if self.accountant {
+            (profit as f32 * ACCOUNTANT_DEDUCTION_SHARE) as u32
+        } else {
+            0
+        };
+        let tax = self
+            .data_files
+            .tax_data
+            .regime(&country, year)
+            .map_or(0, |regime| regime.tax_on(profit - deductions));
+        TaxAssessment {
+            year,
+            country,
+            profit,
+            deductions,
+            tax,
+        }
+    }
+
+    /// Everywhere the band could go into exile this year, lowest tax on
+    /// this year's profit first.
+    pub fn tax_havens(&self) -> Vec<TaxHaven> {
+        let year = self.timeline.get_current_year();
+        let profit = self.tax_assessment(year);
+        let mut havens: Vec<TaxHaven> = self
+            .data_files
+            .tax_data
+            .countries()
+            .into_iter()
+            .filter(|&country| country != HOME_COUNTRY)
+            .filter_map(|country| {
+                let regime = self.data_files.tax_data.regime(country, year)?.clone();
+                Some(TaxHaven {
+                    country: country.to_string(),
+                    name: country_name(country),
+                    estimate: regime.tax_on(profit.profit - profit.deductions),
+                    regime,
+                })
+            })
+            .collect();
+        havens.sort_by_key(|haven| (haven.estimate, haven.regime.relocation_cost));
+        havens
+    }
+
+    /// The taxman's visit for the year just ended. The bill comes out of
+    /// the account whatever's in it.
+    pub(super) fn assess_income_tax(&mut self, year: u32) {
+        let assessment = self.tax_assessment(year);
+        if assessment.profit == 0 {
+            return;
+        }
+        self.post(
+            LedgerCategory::Tax,
+            -(assessment.tax as i32),
+            format!("Income tax {}", year),
+        );
+        let deductions = if assessment.deductions > 0 {
+            format!(
+                " (your accountant found ${} in deductions)",
+                assessment.deductions
+            )
+        } else {
+            String::new()
+        };
+        self.log(format!(
+            "🧾 The taxman's bill for {}: ${} on ${} of profit{}, at {} rates.",
+            year,
+            assessment.tax,
+            assessment.profit,
+            deductions,
+            assessment.country_name()
+        ));
+        self.tax_assessments.push(assessment);
+    }
+
+    /// The accountant's retainer, and the end of an exile.
+    pub(super) fn tax_weekly_upkeep(&mut self, weeks_elapsed: u32) {
+        if self.accountant {
+            self.post(
+                LedgerCategory::Tax,
+                -((ACCOUNTANT_FEE_PER_WEEK * weeks_elapsed) as i32),
+                "Accountant",
+            );
+        }
+        let week_before = self.week.saturating_sub(weeks_elapsed);
+        if let Some(exile) = self
+            .tax_exiles
+            .last()
+            .filter(|exile| exile.until_week > week_before && exile.until_week <= self.week)
+        {
+            let name = exile.country_name();
+            self.log(format!(
+                "🏠 The year in {} is up — the band is home, and free to play it again.",
+                name
+            ));
+        }
+    }
+
+    /// Take on an accountant, or let them go. Instant.
+    pub(super) fn action_set_accountant(&mut self, hired: bool) -> Result<(), String> {
+        if hired == self.accountant {
+            return Err(if hired {
+                "You already have an accountant.".to_string()
+            } else {
+                "You don't have an accountant.".to_string()
+            });
+        }
+        if !hired && self.in_tax_exile() {
+            return Err("Not in the middle of an exile they're running.".to_string());
+        }
+        self.accountant = hired;
+        self.log(if hired {
+            format!(
+                "🧮 Took on an accountant at ${} a week to keep the taxman at bay.",
+                ACCOUNTANT_FEE_PER_WEEK
+            )
+        } else {
+            "🧮 Let the accountant go.".to_string()
+        });
+        Ok(())
+    }
+
+    /// Move abroad for a year. Instant.
+    pub(super) fn action_go_into_tax_exile(&mut self, country: &str) -> Result<(), String> {
+        if !self.accountant {
+            return Err("You'll need an accountant to set up a tax exile.".to_string());
+        }
+        if self.in_tax_exile() {
+            return Err("You're already living abroad.".to_string());
+        }
+        if country == HOME_COUNTRY {
+            return Err("That's home.".to_string());
+        }
+        let year = self.timeline.get_current_year();
+        let regime = self
+            .data_files
+            .tax_data
+            .regime(country, year)
+            .ok_or_else(|| format!("Nobody knows the tax rules in '{}'.", country))?;
+        let cost = regime.relocation_cost;
+        let name = country_name(country);
+        if !self.player.can_afford(cost as i32) {
+            return Err(format!("Moving to {} costs ${}.", name, cost));
+        }
+        self.post(
+            LedgerCategory::Living,
+            -(cost as i32),
+            format!("Moving to {}", name),
+        );
+        self.tax_exiles.push(TaxExile {
+            country: country.to_string(),
+            started_week: self.week,
+            until_week: self.week + TAX_EXILE_WEEKS,
+        });
+        self.log(format!(
+            "✈️ Tax exile: the band decamps to {} for a year. No shows at home until it's over — and the home crowd won't wait forever.",
+            name
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+//! A&R (`a_and_r.rs`): a label short on creative freedom putting demands on
+//! the band's records, complying and defying, the deal clock a defiance
+//! cuts, and artist development in the rehearsal room.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::a_and_r::LabelDemandKind;
+use crate::game::band::CreativeControl;
+use crate::game::genre::MusicGenre;
+
+use super::*;
+
+/// A band signed to a real label under the given clause, with songs to
+/// record, money to record them with and a calm head.
+fn signed_to(label: &str, control: CreativeControl) -> Game {
+    let mut game = test_game();
+    game.player.money = 100_000;
+    game.player.stress = 0;
+    game.player.creativity = 50;
+    let mut deal = test_deal(70, 0.12);
+    deal.label_name = label.to_string();
+    deal.creative_control = control;
+    game.band.record_deal = Some(deal);
+    for id in 0..constants::MIN_ALBUM_SONGS as u32 {
+        game.band.unreleased_songs.push(music::Song {
+            id,
+            name: format!("Song {id}"),
+            songwriting_quality: 50,
+        });
+    }
+    game
+}
+
+#[test]
+fn a_label_led_major_wants_a_genre_it_can_sell() {
+    // Columbia sells pop, rock and folk; a metal band gets told to change.
+    let mut game = signed_to("Columbia Records", CreativeControl::LabelLed);
+    game.band.genre = MusicGenre::Metal;
+    game.action_record_single(None, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    let demand = game.pending_label_demand.clone().expect("A&R speaks up");
+    assert_eq!(demand.kind, LabelDemandKind::ChangeGenre(MusicGenre::Pop));
+
+    game.action_answer_label_demand(true).unwrap();
+    assert!(game.pending_label_demand.is_none());
+    assert_eq!(game.just_released_music[0].genre, Some(MusicGenre::Pop));
+    assert!(game.action_answer_label_demand(true).is_err());
+}
+
+#[test]
+fn the_band_keeps_the_final_say_it_negotiated() {
+    let mut game = signed_to("Columbia Records", CreativeControl::BandLed);
+    game.band.genre = MusicGenre::Metal;
+    game.action_record_single(None, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    assert!(game.pending_label_demand.is_none());
+
+    // A label known for freedom leaves even a label-led deal alone.
+    let mut game = signed_to("Dischord Records", CreativeControl::LabelLed);
+    game.band.genre = MusicGenre::Jazz;
+    game.action_record_single(None, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    assert!(game.pending_label_demand.is_none());
+}
+
+#[test]
+fn defying_a_refused_concept_album_costs_the_contract_and_the_clock() {
+    let mut game = signed_to("Columbia Records", CreativeControl::LabelLed);
+    game.band.genre = MusicGenre::Rock;
+    game.player.creativity = 90;
+    if let Some(deal) = game.band.record_deal.as_mut() {
+        deal.signed_week = game.week;
+        deal.term_weeks = AR_DEFIANCE_TERM_CUT_WEEKS + 4;
+    }
+    game.action_record_album(None, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    assert_eq!(
+        game.pending_label_demand.as_ref().unwrap().kind,
+        LabelDemandKind::DropConcept
+    );
+
+    game.action_answer_label_demand(false).unwrap();
+    assert!(
+        game.just_released_music[0].outside_deal,
+        "a defied album doesn't count"
+    );
+    let deal = game.band.current_deal().unwrap();
+    assert_eq!(deal.defiances, 1);
+    assert_eq!(deal.term_weeks, 4);
+
+    // The shortened clock runs out with both albums still owed.
+    game.week += 4;
+    game.label_weekly_deal_check(&mut StdRng::seed_from_u64(2));
+    assert!(game.band.current_deal().is_none(), "dropped for breach");
+}
+
+#[test]
+fn a_concept_album_is_the_bands_idea_not_free_quality() {
+    let recorded = |creativity: u8| {
+        let mut game = signed_to("Columbia Records", CreativeControl::LabelLed);
+        game.band.genre = MusicGenre::Rock;
+        game.player.creativity = creativity;
+        game.action_record_album(None, &mut StdRng::seed_from_u64(1))
+            .unwrap();
+        game
+    };
+    let plain = recorded(CONCEPT_ALBUM_MIN_CREATIVITY - 1);
+    let mut concept = recorded(100);
+    assert_eq!(
+        concept.pending_label_demand.as_ref().unwrap().kind,
+        LabelDemandKind::DropConcept
+    );
+    let quality = concept.just_released_music[0].release_quality;
+    assert_eq!(
+        quality, plain.just_released_music[0].release_quality,
+        "the idea adds nothing to the record"
+    );
+
+    concept.action_answer_label_demand(true).unwrap();
+    assert_eq!(concept.just_released_music[0].release_quality, quality);
+    assert_eq!(
+        concept.player.creativity,
+        100 - AR_DROP_CONCEPT_CREATIVITY_COST
+    );
+}
+
+#[test]
+fn an_unanswered_demand_goes_the_labels_way() {
+    // Atlantic sells rock; a rock record under its producer's quality gets
+    // the producer.
+    let mut game = signed_to("Atlantic Records", CreativeControl::LabelLed);
+    game.band.genre = MusicGenre::Rock;
+    game.action_record_single(None, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    let quality = game.just_released_music[0].release_quality;
+    assert!(quality < AR_PRODUCER_QUALITY);
+    assert_eq!(
+        game.pending_label_demand.as_ref().unwrap().kind,
+        LabelDemandKind::HouseProducer
+    );
+    let owed = game.band.current_deal().unwrap().unrecouped;
+
+    // The fee joins the balance when the default lands; the week's
+    // royalties then start paying it down, so check it at that moment.
+    let mut settled = game.clone();
+    settled.resolve_label_demand_by_default();
+    assert_eq!(
+        settled.band.current_deal().unwrap().unrecouped,
+        owed + AR_PRODUCER_FEE
+    );
+
+    game.process_turn(GameAction::LazeAround).unwrap();
+    assert!(game.pending_label_demand.is_none());
+    assert!(game.just_released_music[0].release_quality > quality);
+    assert_eq!(game.band.current_deal().unwrap().defiances, 0);
+}
+
+#[test]
+fn artist_development_makes_rehearsal_count_for_more() {
+    let skill_after_practice = |label: Option<&str>| {
+        let mut game = match label {
+            Some(label) => signed_to(label, CreativeControl::Shared),
+            None => test_game(),
+        };
+        game.player.stress = 0;
+        game.band.skill = 20;
+        game.action_practice().unwrap();
+        game.band.skill
+    };
+    assert_eq!(skill_after_practice(None), 22);
+    // 4AD (95 artist development) adds two points; SST (65) adds none.
+    assert_eq!(skill_after_practice(Some("4AD Records")), 24);
+    assert_eq!(skill_after_practice(Some("SST Records")), 22);
+}
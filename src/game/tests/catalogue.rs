@@ -0,0 +1,216 @@
+//! The back catalogue (`catalogue.rs`): who owns a new master, what the
+//! tail pays each owner, and buying back, selling and re-recording.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::music::MasterOwner;
+
+use super::*;
+
+/// A record past its first run with a tail still selling.
+fn back_catalogue_single(id: u32, owner: MasterOwner) -> Release {
+    let mut release = test_release(id, ReleaseType::Single);
+    release.initial_sales_score = 600;
+    release.copies_pressed = 0; // uncapped: demand alone drives the tail
+    release.copies_sold = 3_000;
+    release.master_owner = Some(owner);
+    release
+}
+
+fn island() -> MasterOwner {
+    MasterOwner::Label {
+        name: "Island Records".to_string(),
+        royalty_rate: 0.12,
+    }
+}
+
+/// One tail week, well past the first run.
+fn tail_week(game: &mut Game) {
+    game.week = INITIAL_SALES_WINDOW_WEEKS + 6;
+    game.last_sales_pass_week = None;
+    game.process_music_releases_and_marketing();
+}
+
+#[test]
+fn a_new_master_belongs_to_whoever_the_band_records_for() {
+    let mut game = test_game();
+    game.player.money = 100_000;
+    for id in 0..2 {
+        game.band.unreleased_songs.push(music::Song {
+            id,
+            name: format!("Song {id}"),
+            songwriting_quality: 50,
+        });
+    }
+    let mut rng = StdRng::seed_from_u64(1);
+    game.action_record_single(None, &mut rng).unwrap();
+    assert_eq!(
+        game.just_released_music[0].master_owner,
+        Some(MasterOwner::Band)
+    );
+
+    game.band.record_deal = Some(test_deal(70, 0.14));
+    game.action_record_single(None, &mut rng).unwrap();
+    assert_eq!(
+        game.just_released_music[1].master_owner,
+        Some(MasterOwner::Label {
+            name: "Test Records".to_string(),
+            royalty_rate: 0.14
+        })
+    );
+}
+
+#[test]
+fn the_tail_pays_the_band_in_full_only_on_masters_it_owns() {
+    let mut game = test_game();
+    game.band.singles_released = vec![
+        back_catalogue_single(1, MasterOwner::Band),
+        back_catalogue_single(2, island()),
+    ];
+    let island_cash = game.world.label("Island Records").unwrap().cash;
+    tail_week(&mut game);
+
+    let [owned, licensed] = [0, 1].map(|i| game.band.singles_released[i].clone());
+    assert_eq!(owned.copies_sold, licensed.copies_sold, "same demand");
+    assert!(
+        owned.total_income_generated > licensed.total_income_generated * 4,
+        "{} vs {}",
+        owned.total_income_generated,
+        licensed.total_income_generated
+    );
+    assert!(
+        game.world.label("Island Records").unwrap().cash > island_cash,
+        "the label keeps what its royalty doesn't pay"
+    );
+}
+
+#[test]
+fn buying_a_master_back_clears_what_the_band_owes_that_label() {
+    let mut game = test_game();
+    let mut deal = test_deal(70, 0.12);
+    deal.label_name = "Island Records".to_string();
+    deal.unrecouped = 4_000;
+    game.band.record_deal = Some(deal);
+    game.band.singles_released = vec![back_catalogue_single(1, island())];
+    game.player.money = 20_000;
+
+    let release = &game.band.singles_released[0];
+    let (owed, premium) = game.buyback_price(release).unwrap();
+    assert_eq!(owed, 4_000);
+    assert_eq!(
+        premium,
+        (release.master_value() as f32 * MASTER_BUYBACK_PREMIUM) as u32
+    );
+    let island_cash = game.world.label("Island Records").unwrap().cash;
+
+    game.action_buy_back_master(1).unwrap();
+    assert_eq!(game.player.money, 20_000 - (owed + premium) as i32);
+    assert_eq!(game.band.current_deal().unwrap().unrecouped, 0);
+    assert_eq!(
+        game.world.label("Island Records").unwrap().cash,
+        island_cash + i64::from(owed + premium)
+    );
+    assert!(game.band.singles_released[0].band_owns_master());
+    assert!(
+        game.action_buy_back_master(1).is_err(),
+        "it's the band's already"
+    );
+}
+
+#[test]
+fn a_sold_master_pays_a_lump_sum_and_nothing_after() {
+    let mut game = test_game();
+    game.band.singles_released = vec![back_catalogue_single(1, MasterOwner::Band)];
+    let (buyer, price) = game
+        .master_sale_offer(&game.band.singles_released[0])
+        .expect("a label with money to spare");
+    let buyer_cash = game.world.label(&buyer).unwrap().cash;
+    let money = game.player.money;
+
+    game.action_sell_master(1).unwrap();
+    assert_eq!(game.player.money, money + price as i32);
+    assert_eq!(
+        game.world.label(&buyer).unwrap().cash,
+        buyer_cash - i64::from(price)
+    );
+    assert!(
+        game.master_sale_offer(&game.band.singles_released[0])
+            .is_none()
+    );
+
+    let income = game.band.singles_released[0].total_income_generated;
+    tail_week(&mut game);
+    let release = &game.band.singles_released[0];
+    assert!(release.copies_sold > 3_000, "it still sells");
+    assert_eq!(release.total_income_generated, income, "for the buyer");
+}
+
+#[test]
+fn a_re_recording_splits_the_originals_audience() {
+    let mut game = test_game();
+    game.player.money = 100_000;
+    game.band.singles_released = vec![back_catalogue_single(1, island())];
+    let mut deal = test_deal(70, 0.12);
+    deal.label_name = "Island Records".to_string();
+    game.band.record_deal = Some(deal);
+    let mut rng = StdRng::seed_from_u64(4);
+    assert!(
+        game.action_rerecord(1, None, &mut rng).is_err(),
+        "not while signed to the label that owns it"
+    );
+
+    game.band.record_deal = None;
+    assert!(
+        game.action_rerecord(7, None, &mut rng).is_err(),
+        "no such release"
+    );
+    game.action_rerecord(1, None, &mut rng).unwrap();
+    let remake = game.just_released_music[0].clone();
+    assert_eq!(remake.rerecording_of, Some(1));
+    assert_eq!(remake.master_owner, Some(MasterOwner::Band));
+    assert!(game.check_rerecord(&game.band.singles_released[0]).is_err());
+    assert!(
+        game.calculate_release_sales_score(&remake)
+            > game.calculate_release_sales_score(&test_release(9, ReleaseType::Single)),
+        "the fans who know the songs come along"
+    );
+
+    game.just_released_music.clear();
+    let sold = |game: &mut Game| {
+        game.band.singles_released[0].copies_sold = 3_000;
+        tail_week(game);
+        game.band.singles_released[0].copies_sold - 3_000
+    };
+    let alone = sold(&mut game);
+    let mut out = remake;
+    out.initial_sales_score = 400;
+    game.band.singles_released.push(out);
+    let split = sold(&mut game);
+    assert!(split * 3 < alone * 2, "{split} vs {alone}");
+}
+
+#[test]
+fn a_label_cuts_singles_only_from_albums_it_owns() {
+    let mut game = test_game();
+    game.band.record_deal = Some(test_deal(70, 0.12));
+    let mut album = test_release(1, ReleaseType::Album);
+    album.master_owner = Some(MasterOwner::Band);
+    game.band.albums_released = vec![album];
+    game.idle_streak = 10;
+    game.week = 100;
+    for seed in 0..50 {
+        game.label_single_cut_check(&mut StdRng::seed_from_u64(seed));
+    }
+    assert!(game.just_released_music.is_empty());
+
+    game.band.albums_released[0].master_owner = Some(MasterOwner::Label {
+        name: "Test Records".to_string(),
+        royalty_rate: 0.12,
+    });
+    for seed in 0..50 {
+        game.label_single_cut_check(&mut StdRng::seed_from_u64(seed));
+    }
+    let single = &game.just_released_music[0];
+    assert_eq!(single.master_label(), Some("Test Records"));
+}
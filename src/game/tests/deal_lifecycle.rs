@@ -25,6 +25,8 @@ fn signed_deal_with_real_label(game: &Game, signed_week: u32, term_weeks: u16) -
         unrecouped: 0,
         signed_week,
         term_weeks,
+        creative_control: band::CreativeControl::Shared,
+        defiances: 0,
     }
 }
 
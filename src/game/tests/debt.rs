@@ -0,0 +1,145 @@
+//! Debt (`debt.rs`): the bank loan and its weekly repayments, the label's
+//! cash against royalties, and bankruptcy — what the receiver takes, and
+//! why a second one is the end.
+
+use crate::game::music::MasterOwner;
+use crate::game::player::LifestyleTier;
+
+use super::*;
+
+#[test]
+fn the_bank_lends_against_fame_and_takes_its_repayment_weekly() {
+    let mut game = test_game();
+    game.band.fame = 40;
+    game.player.money = 1_000;
+    let limit = game.credit_limit();
+    assert_eq!(limit, 40 * LOAN_LIMIT_PER_FAME);
+    assert!(
+        game.action_take_loan(limit + 1).is_err(),
+        "not past the limit"
+    );
+
+    game.action_take_loan(2_000).unwrap();
+    assert_eq!(game.player.money, 3_000);
+    assert_eq!(game.credit_available(), limit - 2_000);
+    let loan = game.loan.clone().unwrap();
+    assert!(
+        loan.weekly_payment > 2_000 / LOAN_TERM_WEEKS,
+        "interest on top"
+    );
+
+    game.loan_weekly_repayment(4);
+    let after = game.loan.clone().unwrap();
+    assert_eq!(game.player.money, 3_000 - 4 * loan.weekly_payment as i32);
+    assert!(after.balance < 2_000);
+    assert!(
+        after.balance > 2_000 - 4 * loan.weekly_payment,
+        "interest accrued"
+    );
+
+    game.action_repay_loan(u32::MAX).unwrap();
+    assert!(game.loan.is_none(), "paid off early");
+    let posted: i32 = game
+        .ledger
+        .iter()
+        .filter(|entry| entry.category == LedgerCategory::Loans)
+        .map(|entry| entry.amount)
+        .sum();
+    assert_eq!(game.player.money, 1_000 + posted);
+}
+
+#[test]
+fn a_defaulted_loan_brings_in_the_receiver() {
+    let mut game = test_game();
+    game.band.fame = 50;
+    game.player.lifestyle = LifestyleTier::Townhouse;
+    game.gear.push(GearItem::new(GearKind::Van));
+    game.gear_insured = true;
+    let mut single = test_release(1, ReleaseType::Single);
+    single.copies_sold = 20_000;
+    single.master_owner = Some(MasterOwner::Band);
+    game.band.singles_released = vec![single];
+    let mut fresh = test_release(2, ReleaseType::Single);
+    fresh.copies_sold = 20_000;
+    fresh.master_owner = Some(MasterOwner::Band);
+    game.just_released_music = vec![fresh];
+    game.action_take_loan(5_000).unwrap();
+
+    game.player.money = -300;
+    game.loan_weekly_repayment(LOAN_DEFAULT_MISSED_PAYMENTS);
+    assert_eq!(
+        game.loan.as_ref().unwrap().missed_payments,
+        LOAN_DEFAULT_MISSED_PAYMENTS
+    );
+    game.check_insolvency();
+
+    assert!(!game.is_game_over(), "bankrupt, not finished");
+    assert!(game.is_bankrupt());
+    assert_eq!(game.player.lifestyle, LifestyleTier::Squat);
+    assert!(
+        game.gear
+            .iter()
+            .all(|item| item.kind == GearKind::Instruments),
+        "everything but the instruments"
+    );
+    assert!(!game.gear_insured);
+    assert!(!game.band.singles_released[0].band_owns_master());
+    assert!(
+        !game.just_released_music[0].band_owns_master(),
+        "a record just out is seized too"
+    );
+    assert!(game.loan.is_none());
+    assert!(game.player.money >= 0, "whatever's left is written off");
+    assert_eq!(game.credit_limit(), 0);
+    assert!(game.action_take_loan(100).is_err());
+
+    game.player.money = -50;
+    game.check_insolvency();
+    assert!(
+        !game.is_game_over(),
+        "in the red but famous isn't insolvent"
+    );
+    game.band.fame = BANKRUPTCY_FAME - 1;
+    game.check_insolvency();
+    assert!(game.is_game_over(), "broke again before the discharge");
+}
+
+#[test]
+fn a_bankruptcy_is_discharged_after_a_year() {
+    let mut game = test_game();
+    game.player.money = -100;
+    game.band.fame = 0;
+    game.check_insolvency();
+    assert_eq!(game.player.money, 0);
+    assert!(game.is_bankrupt());
+
+    game.week += BANKRUPTCY_DISCHARGE_WEEKS;
+    game.loan_weekly_repayment(1);
+    assert!(!game.is_bankrupt());
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("bankruptcy is discharged"))
+    );
+}
+
+#[test]
+fn a_label_fronts_cash_against_royalties_up_to_its_limit() {
+    let mut game = test_game();
+    let mut deal = test_deal(95, 0.12);
+    deal.label_name = "Atlantic Records".to_string();
+    deal.unrecouped = 1_500;
+    game.band.record_deal = Some(deal);
+    let limit = 95 * LABEL_CASH_ADVANCE_PER_REACH;
+    assert_eq!(game.label_cash_advance_available(), limit - 1_500);
+
+    let money = game.player.money;
+    game.action_draw_label_advance(2_000).unwrap();
+    assert_eq!(game.player.money, money + 2_000);
+    assert_eq!(game.band.current_deal().unwrap().unrecouped, 3_500);
+    assert_eq!(game.label_cash_advance_available(), limit - 3_500);
+    assert!(game.action_draw_label_advance(limit).is_err());
+
+    game.band.record_deal = None;
+    assert!(game.action_draw_label_advance(100).is_err(), "unsigned");
+}
@@ -10,7 +10,7 @@ fn same_seed_and_same_choices_replay_the_same_career() {
     fn scripted_run(seed: u64) -> (u32, i32, u8, String) {
         let mut game = crate::game::sim::seeded_game(seed);
         // A representative career slice: writing, gigging, idling, one
-        // club-run single, and a multi-week break (so the per-week RNG
+        // club-run single, and a multi-week vacation (so the per-week RNG
         // keying survives calendar jumps).
         let script = [
             GameAction::WriteSongs,
@@ -22,7 +22,7 @@ fn same_seed_and_same_choices_replay_the_same_career() {
             GameAction::Gig(0),
             GameAction::LazeAround,
             GameAction::Gig(0),
-            GameAction::TakeBreak,
+            GameAction::TakeVacation(Vacation::CountryRetreat),
             GameAction::WriteSongs,
             GameAction::WriteSongs,
             GameAction::Gig(0),
@@ -12,7 +12,8 @@ use rand::rngs::StdRng;
 // stress in a way that would trip the guard within 300 gigs at fame 0... in
 // fact each successful gig now costs stress, so the loop below resets
 // stress/health each iteration deliberately: this test is about the fame
-// cap, not the stress economy.
+// cap, not the stress economy. Likewise venue bans: 300 shows at fame 0
+// would wear out every promoter in town.
 #[test]
 fn gigging_alone_cannot_make_you_a_star() {
     let mut game = test_game();
@@ -22,6 +23,9 @@ fn gigging_alone_cannot_make_you_a_star() {
     for _ in 0..300 {
         game.player.stress = 0;
         game.player.health = 100;
+        for venue in &mut game.world.venues {
+            venue.banned_until = 0;
+        }
         let venue = best_open_venue(&game);
         game.action_play_gig(venue, &mut rng)
             .expect("gig should succeed");
@@ -205,3 +209,19 @@ fn a_release_on_the_shelves_keeps_the_band_visible() {
     );
     assert_eq!(game.idle_streak, 0);
 }
+
+#[test]
+fn a_world_cruise_costs_fame_through_idle_decay_alone() {
+    let mut game = test_game();
+    game.band.fame = 25; // 4-week grace at this fame
+    game.player.money = 20_000;
+
+    game.process_turn(GameAction::TakeVacation(Vacation::WorldCruise))
+        .expect("a rich player can cruise");
+
+    assert!(
+        game.band.fame < 25,
+        "ten quiet weeks away should outlast the grace window (fame {})",
+        game.band.fame
+    );
+}
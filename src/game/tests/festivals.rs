@@ -0,0 +1,217 @@
+//! Festivals: the yearly calendar and its bills, fame-gated slot offers,
+//! and playing a booked slot (`world/festivals.rs`).
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::world::{Festival, FestivalBilling, FestivalSlot};
+
+use super::*;
+
+fn test_festival(size: u8, week: u32) -> Festival {
+    Festival {
+        id: 1,
+        name: "Test Fest".to_string(),
+        region_key: "united_kingdom:london".to_string(),
+        region_name: "London".to_string(),
+        week,
+        size,
+        lineup: vec![
+            FestivalSlot {
+                billing: FestivalBilling::Headliner,
+                act: "Big Stars".to_string(),
+            },
+            FestivalSlot {
+                billing: FestivalBilling::Opener,
+                act: "Small Fry".to_string(),
+            },
+        ],
+        offered: false,
+    }
+}
+
+fn test_offer(billing: FestivalBilling, week: u32, booked: bool) -> FestivalOffer {
+    FestivalOffer {
+        festival_id: 1,
+        festival: "Test Fest".to_string(),
+        region_name: "London".to_string(),
+        billing,
+        pay: 2_000,
+        fame_gain: FESTIVAL_BILLING_FAME[billing as usize],
+        expires_week: week,
+        booked,
+    }
+}
+
+#[test]
+fn each_year_publishes_a_summer_calendar_billed_from_the_scene() {
+    let mut game = crate::game::sim::seeded_game(34);
+    game.process_turn(GameAction::LazeAround).unwrap();
+    assert_eq!(game.world.festival_year, constants::STARTING_YEAR);
+    assert!(game.world.festivals.len() >= FESTIVAL_BASE_COUNT);
+
+    let scene: Vec<&str> = game.world.bands.iter().map(|b| b.name.as_str()).collect();
+    for festival in &game.world.festivals {
+        let week_of_year = festival.week % constants::WEEKS_PER_YEAR;
+        assert!(
+            (FESTIVAL_SEASON_START_WEEK..=FESTIVAL_SEASON_END_WEEK).contains(&week_of_year),
+            "{} falls outside the season",
+            festival.name
+        );
+        let total_slots: usize = FESTIVAL_BILLING_SLOTS.iter().sum();
+        assert_eq!(
+            festival.lineup.len(),
+            total_slots,
+            "the scene fills the bill"
+        );
+        assert!(
+            festival
+                .lineup
+                .iter()
+                .all(|slot| scene.contains(&slot.act.as_str()))
+        );
+        assert!(festival.headliner().is_some());
+    }
+    let first_ids: Vec<u32> = game.world.festivals.iter().map(|f| f.id).collect();
+
+    game.week = constants::WEEKS_PER_YEAR + 1;
+    game.process_turn(GameAction::LazeAround).unwrap();
+    assert_eq!(game.world.festival_year, constants::STARTING_YEAR + 1);
+    assert!(
+        game.world
+            .festivals
+            .iter()
+            .all(|festival| !first_ids.contains(&festival.id)),
+        "last year's calendar is replaced"
+    );
+}
+
+#[test]
+fn offers_go_to_the_best_billing_the_bands_fame_earns() {
+    let mut game = test_game();
+    let mut rng = StdRng::seed_from_u64(3);
+
+    game.band.fame = 5;
+    game.world.festivals = vec![test_festival(60, game.week + 2)];
+    for _ in 0..50 {
+        game.update_festival_offer(&mut rng);
+    }
+    assert!(
+        game.pending_festival_offer.is_none(),
+        "too obscure for even the opening slot"
+    );
+
+    for (fame, billing) in [
+        (20, FestivalBilling::Opener),
+        (45, FestivalBilling::SubHeadliner),
+        (60, FestivalBilling::Headliner),
+    ] {
+        game.band.fame = fame;
+        game.world.festivals = vec![test_festival(60, game.week + 2)];
+        game.pending_festival_offer = None;
+        for _ in 0..50 {
+            game.update_festival_offer(&mut rng);
+        }
+        let offer = game
+            .pending_festival_offer
+            .as_ref()
+            .expect("a qualifying band gets asked");
+        assert_eq!(offer.billing, billing, "fame {fame}");
+        assert_eq!(offer.expires_week, game.week + 2);
+        assert!(game.world.festivals[0].offered);
+    }
+}
+
+#[test]
+fn an_unanswered_offer_lapses_on_the_day() {
+    let mut game = test_game();
+    game.world.festivals = vec![test_festival(60, 10)];
+    game.pending_festival_offer = Some(test_offer(FestivalBilling::Opener, 10, false));
+    game.week = 10;
+    let money = game.player.money;
+
+    game.update_festival_offer(&mut StdRng::seed_from_u64(0));
+    assert!(game.pending_festival_offer.is_none());
+    assert_eq!(game.player.money, money, "nothing played, nothing paid");
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("went ahead without you"))
+    );
+}
+
+#[test]
+fn a_booked_slot_plays_on_the_day_and_word_travels_abroad() {
+    let mut game = test_game();
+    game.band.fame = 45;
+    game.world.festivals = vec![test_festival(60, 10)];
+    game.pending_festival_offer = Some(test_offer(FestivalBilling::SubHeadliner, 10, false));
+    game.action_accept_festival().expect("booking is free");
+    assert!(game.action_accept_festival().is_err(), "already booked");
+
+    game.week = 10;
+    let money = game.player.money;
+    game.update_festival_offer(&mut StdRng::seed_from_u64(0));
+
+    assert!(game.pending_festival_offer.is_none());
+    assert!(game.player.money >= money + 2_000, "the fee is paid");
+    assert!(game.band.fame > 45);
+    let home = game.regional_fame["united_kingdom:london"];
+    let home_country = game.regional_fame["united_kingdom:scotland"];
+    let abroad = game.regional_fame["japan:tokyo"];
+    assert!(
+        home > 0 && home == home_country,
+        "the whole host country hears it"
+    );
+    assert!(abroad > 0 && abroad < home, "a share travels abroad");
+    assert!(
+        game.world.festivals[0]
+            .lineup
+            .iter()
+            .any(|slot| slot.act == game.band.name
+                && slot.billing == FestivalBilling::SubHeadliner),
+        "the band is on the bill"
+    );
+    let report = game.last_tour_report.as_ref().expect("a one-row report");
+    assert_eq!(report.rows.len(), 1);
+    assert_eq!(report.rows[0].capacity, 60 * FESTIVAL_CROWD_PER_SIZE);
+}
+
+#[test]
+fn pulling_out_of_a_booked_slot_clears_it() {
+    let mut game = test_game();
+    game.pending_festival_offer = Some(test_offer(FestivalBilling::Opener, 10, true));
+    game.action_decline_festival()
+        .expect("pulling out is allowed");
+    assert!(game.pending_festival_offer.is_none());
+    assert!(game.action_decline_festival().is_err());
+}
+
+#[test]
+fn a_booked_slot_rules_out_trips_that_run_past_it() {
+    let mut game = test_game();
+    game.band.fame = 70;
+    game.player.money = 1_000_000;
+    game.player.stress = 0;
+    game.player.health = 100;
+    let day = game.week + 3;
+    game.world.festivals = vec![test_festival(60, day)];
+    game.pending_festival_offer = Some(test_offer(FestivalBilling::Opener, day, true));
+
+    let err = game
+        .action_go_on_tour(0, TourRig::Van, 4, &mut StdRng::seed_from_u64(1))
+        .expect_err("a four-week tour runs past the festival");
+    assert!(err.contains("Test Fest"), "unexpected error: {err}");
+    assert!(game.action_take_vacation(Vacation::WorldCruise).is_err());
+    assert!(
+        game.check_festival_clash(3).is_ok(),
+        "home in time for the day"
+    );
+
+    // Pulling out frees the calendar.
+    game.action_decline_festival().unwrap();
+    assert!(
+        game.action_go_on_tour(0, TourRig::Van, 4, &mut StdRng::seed_from_u64(1))
+            .is_ok()
+    );
+}
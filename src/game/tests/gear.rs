@@ -0,0 +1,265 @@
+//! Gear and crew: wear and breakdowns, owned vehicles against rig hire,
+//! wages, insurance, and the shop (`gear.rs`, `actions/business.rs`).
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use super::*;
+
+fn condition_of(game: &Game, kind: GearKind) -> f32 {
+    game.gear
+        .iter()
+        .find(|item| item.kind == kind)
+        .map(|item| item.condition)
+        .expect("the band owns it")
+}
+
+#[test]
+fn a_gig_wears_the_playing_gear_but_not_the_vehicles() {
+    let mut game = test_game();
+    game.gear.push(GearItem::new(GearKind::Van));
+    let before = condition_of(&game, GearKind::Instruments);
+
+    let venue = best_open_venue(&game);
+    game.action_play_gig(venue, &mut StdRng::seed_from_u64(3))
+        .unwrap();
+
+    assert_eq!(
+        condition_of(&game, GearKind::Instruments),
+        before - GEAR_WEAR_PER_SHOW
+    );
+    assert_eq!(condition_of(&game, GearKind::Van), 100.0);
+
+    game.wear_gear(0, Some(TourRig::Van), 2);
+    assert_eq!(
+        condition_of(&game, GearKind::Van),
+        100.0 - 2.0 * GEAR_VEHICLE_WEAR_PER_WEEK
+    );
+}
+
+#[test]
+fn worn_gear_breaks_down_more_and_crew_cut_the_risk() {
+    let mut game = test_game();
+    assert_eq!(
+        game.gear_breakdown_chance(None),
+        0.0,
+        "gear in working order never breaks"
+    );
+
+    game.gear[0].condition = 40.0;
+    let worn = game.gear_breakdown_chance(None);
+    game.gear[0].condition = 10.0;
+    let wrecked = game.gear_breakdown_chance(None);
+    assert!(worn > 0.0 && wrecked > worn);
+
+    game.crew.push(CrewRole::TourManager);
+    game.crew.push(CrewRole::Roadie);
+    assert!(game.gear_breakdown_chance(None) < wrecked);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let money = game.player.money;
+    let broke_down = (0..200).find_map(|_| game.roll_gear_breakdown(None, &mut rng));
+    assert_eq!(broke_down, Some(GearKind::Instruments));
+    assert_eq!(game.gear[0].condition, GEAR_BREAKDOWN_CONDITION);
+    assert!(
+        game.player.money < money,
+        "the patch-up is paid on the spot"
+    );
+}
+
+#[test]
+fn an_owned_van_cuts_the_tour_quote() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    let hired = game.quote_tour(0, TourRig::Van, 3).unwrap().cost;
+    let bus = game.quote_tour(0, TourRig::Bus, 3).unwrap().cost;
+
+    game.gear.push(GearItem::new(GearKind::Van));
+    assert!(game.quote_tour(0, TourRig::Van, 3).unwrap().cost < hired);
+    assert_eq!(
+        game.quote_tour(0, TourRig::Bus, 3).unwrap().cost,
+        bus,
+        "a van does nothing for a bus tour"
+    );
+}
+
+#[test]
+fn crew_draw_a_weekly_wage_and_walk_when_payroll_bounces() {
+    let mut game = test_game();
+    game.band.fame = 40;
+    game.player.money = 5_000;
+    game.process_turn(GameAction::HireCrew(CrewRole::SoundEngineer))
+        .unwrap();
+    game.process_turn(GameAction::HireCrew(CrewRole::Roadie))
+        .unwrap();
+    assert!(
+        game.process_turn(GameAction::HireCrew(CrewRole::SoundEngineer))
+            .is_err(),
+        "only one sound engineer"
+    );
+
+    let money = game.player.money;
+    game.gear_weekly_upkeep(2);
+    assert_eq!(
+        game.player.money,
+        money - 2 * (CrewRole::SoundEngineer.wage() + CrewRole::Roadie.wage()) as i32
+    );
+
+    game.player.money = 10;
+    game.gear_weekly_upkeep(1);
+    assert!(game.player.money < 0, "wages are owed even into the red");
+    assert!(game.crew.is_empty());
+}
+
+#[test]
+fn crew_are_gated_on_fame() {
+    let mut game = test_game();
+    game.band.fame = 0;
+    assert!(
+        game.process_turn(GameAction::HireCrew(CrewRole::TourManager))
+            .is_err()
+    );
+    game.process_turn(GameAction::HireCrew(CrewRole::Roadie))
+        .unwrap();
+}
+
+#[test]
+fn insurance_covers_most_of_a_gear_incident() {
+    let mut game = test_game();
+    game.player.money = 5_000;
+
+    let money = game.player.money;
+    game.settle_gear_insurance(1_000);
+    assert_eq!(game.player.money, money, "uninsured pays it all");
+
+    game.gear_insured = true;
+    game.settle_gear_insurance(1_000);
+    assert_eq!(
+        game.player.money,
+        money + (1_000.0 * (1.0 - GEAR_INSURANCE_EXCESS_SHARE)).round() as i32
+    );
+
+    // Insured gear is still stolen; the claim is what it was worth.
+    let stolen: crate::data_loader::Incident = serde_json::from_str(
+        r#"{"id":"stolen","category":"road","weight":1,"message":"m",
+            "effects":{"money":[-100,-100],"gear_condition":[-100,-100]}}"#,
+    )
+    .expect("parses");
+    game.gear = vec![GearItem::new(GearKind::Backline)];
+    let claim = GearKind::Backline.price() as i32;
+    let money = game.player.money;
+    game.apply_incident(&stolen, &mut StdRng::seed_from_u64(1));
+    assert!(game.gear.is_empty(), "insurance doesn't stop a thief");
+    assert_eq!(
+        game.player.money,
+        money - 100 + (claim as f32 * (1.0 - GEAR_INSURANCE_EXCESS_SHARE)).round() as i32
+    );
+
+    // A bill that never touched the gear isn't the insurer's business.
+    let tow: crate::data_loader::Incident = serde_json::from_str(
+        r#"{"id":"tow","category":"gear","weight":1,"message":"m",
+            "effects":{"money":[-300,-300]}}"#,
+    )
+    .expect("parses");
+    game.gear = vec![GearItem::new(GearKind::Backline)];
+    let money = game.player.money;
+    game.apply_incident(&tow, &mut StdRng::seed_from_u64(1));
+    assert_eq!(game.player.money, money - 300);
+
+    game.gear_insured = false;
+    let money = game.player.money;
+    game.apply_incident(&stolen, &mut StdRng::seed_from_u64(1));
+    assert!(game.gear.is_empty(), "uninsured gear is gone for good");
+    assert_eq!(game.player.money, money - 100);
+}
+
+#[test]
+fn an_insured_blowout_and_the_service_after_it_never_pay_the_band() {
+    let blowout = |bill: i32| -> crate::data_loader::Incident {
+        serde_json::from_str(&format!(
+            r#"{{"id":"blowout","category":"gear","weight":1,"message":"m",
+                "effects":{{"money":[{bill},{bill}],"gear_condition":[-25,-25]}}}}"#
+        ))
+        .expect("parses")
+    };
+    // A big bill, one under the service price, and none at all.
+    for bill in [-1_000, -50, 0] {
+        let mut game = test_game();
+        game.player.money = 10_000;
+        game.gear_insured = true;
+        game.gear = vec![GearItem::new(GearKind::Backline)];
+        let money = game.player.money;
+
+        game.apply_incident(&blowout(bill), &mut StdRng::seed_from_u64(1));
+        game.action_service_gear().expect("the service goes ahead");
+        assert_eq!(condition_of(&game, GearKind::Backline), 100.0);
+        assert!(
+            game.player.money < money,
+            "a ${} blowout left the band ${} up",
+            -bill,
+            game.player.money - money
+        );
+    }
+}
+
+#[test]
+fn a_sound_engineer_narrows_the_reception_spread() {
+    let game = test_game();
+    let spread = |scale: f32| {
+        let receptions: Vec<u8> = (0..200)
+            .map(|seed| {
+                crate::game::shows::compute_reception(
+                    &game.band,
+                    game.player.stress,
+                    game.player.health,
+                    1.0,
+                    game.player.creativity,
+                    scale,
+                    &mut StdRng::seed_from_u64(seed),
+                )
+            })
+            .collect();
+        receptions.iter().max().unwrap() - receptions.iter().min().unwrap()
+    };
+
+    let mut with_crew = test_game();
+    with_crew.crew.push(CrewRole::SoundEngineer);
+    assert_eq!(game.reception_variance_mult(), 1.0);
+    assert!(spread(with_crew.reception_variance_mult()) < spread(1.0));
+}
+
+#[test]
+fn the_shop_buys_sells_and_services() {
+    let mut game = test_game();
+    game.player.money = 20_000;
+
+    game.process_turn(GameAction::BuyGear(GearKind::PaSystem))
+        .unwrap();
+    assert!(game.owns_gear(GearKind::PaSystem));
+    assert_eq!(
+        game.player.money,
+        20_000 - GearKind::PaSystem.price() as i32
+    );
+    assert!(
+        game.process_turn(GameAction::BuyGear(GearKind::Truck))
+            .is_err(),
+        "a truck waits on the fame to tour in one"
+    );
+
+    game.process_turn(GameAction::ServiceGear).unwrap();
+    assert_eq!(condition_of(&game, GearKind::Instruments), 100.0);
+    assert!(game.process_turn(GameAction::ServiceGear).is_err());
+
+    let money = game.player.money;
+    game.process_turn(GameAction::SellGear(GearKind::PaSystem))
+        .unwrap();
+    assert!(!game.owns_gear(GearKind::PaSystem));
+    assert_eq!(
+        game.player.money,
+        money + (GearKind::PaSystem.price() as f32 * GEAR_RESALE_SHARE) as i32
+    );
+    assert!(
+        game.process_turn(GameAction::SellGear(GearKind::Instruments))
+            .is_err()
+    );
+}
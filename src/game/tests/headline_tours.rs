@@ -0,0 +1,198 @@
+//! Headline tour offers: promoters calling once the band is famous, the
+//! deal paying out while the promoter carries the costs, and how the shows
+//! move the promoter's opinion (`world/promoters.rs`).
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::shows::ShowVerdict;
+
+use super::*;
+
+fn test_offer(game: &mut Game, deal: PromoterDeal) -> HeadlineTourOffer {
+    game.world
+        .ensure_tour_promoters(&game.data_files, &mut StdRng::seed_from_u64(0));
+    HeadlineTourOffer {
+        promoter: game.world.tour_promoters[0].name.clone(),
+        legs: vec![
+            TourLeg {
+                region_index: 0,
+                weeks: 1,
+            },
+            TourLeg {
+                region_index: 1,
+                weeks: 1,
+            },
+        ],
+        rig: TourRig::Bus,
+        deal,
+        expires_week: game.week + HEADLINE_OFFER_LIFETIME_WEEKS,
+    }
+}
+
+#[test]
+fn promoters_only_call_established_acts() {
+    let mut game = test_game();
+    let mut rng = StdRng::seed_from_u64(5);
+
+    game.band.fame = HEADLINE_OFFER_MIN_FAME - 1;
+    for _ in 0..200 {
+        game.update_headline_tour_offer(&mut rng);
+    }
+    assert!(game.pending_headline_offer.is_none());
+
+    game.band.fame = 80;
+    for _ in 0..200 {
+        game.update_headline_tour_offer(&mut rng);
+        if game.pending_headline_offer.is_some() {
+            break;
+        }
+    }
+    let offer = game
+        .pending_headline_offer
+        .as_ref()
+        .expect("a famous act gets a call");
+    assert_eq!(game.world.tour_promoters.len(), TOUR_PROMOTER_COUNT);
+    assert!(game.world.tour_promoter(&offer.promoter).is_some());
+    assert!((HEADLINE_OFFER_STOPS.0..=HEADLINE_OFFER_STOPS.1).contains(&offer.legs.len()));
+    assert!(
+        game.quote_route(&offer.legs, offer.rig, TourTerms::default())
+            .is_ok(),
+        "the route on offer is one the band can play"
+    );
+    assert_eq!(
+        offer.expires_week,
+        game.week + HEADLINE_OFFER_LIFETIME_WEEKS
+    );
+}
+
+#[test]
+fn a_promoter_who_has_soured_stops_calling() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    game.world
+        .ensure_tour_promoters(&game.data_files, &mut StdRng::seed_from_u64(0));
+    for promoter in &mut game.world.tour_promoters {
+        promoter.opinion = TOUR_PROMOTER_OFFER_MIN_OPINION - 1;
+    }
+    let mut rng = StdRng::seed_from_u64(5);
+    for _ in 0..200 {
+        game.update_headline_tour_offer(&mut rng);
+    }
+    assert!(game.pending_headline_offer.is_none());
+}
+
+#[test]
+fn a_guarantee_pays_in_full_and_the_promoter_pays_the_bill() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    game.player.money = 0;
+    let offer = test_offer(&mut game, PromoterDeal::Guarantee(5_000));
+    game.pending_headline_offer = Some(offer);
+    let week = game.week;
+
+    game.action_accept_headline_tour(&mut StdRng::seed_from_u64(1))
+        .expect("a backed tour needs no money up front");
+
+    assert!(game.pending_headline_offer.is_none());
+    assert_eq!(game.player.money, 5_000, "the fee, and no bill");
+    assert_eq!(game.week, week + 2);
+    assert_eq!(game.world.tour_promoters[0].tours_promoted, 1);
+    let report = game.last_tour_report.as_ref().expect("a tour report");
+    assert_eq!(report.rows.len() as u32, 2 * SHOWS_PER_TOUR_WEEK);
+}
+
+#[test]
+fn a_percentage_deal_pays_a_share_of_the_net() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    game.player.money = 0;
+    let offer = test_offer(&mut game, PromoterDeal::Percentage(0.7));
+    let cost = game
+        .quote_route(&offer.legs, offer.rig, TourTerms::default())
+        .unwrap()
+        .cost;
+    game.pending_headline_offer = Some(offer);
+
+    game.action_accept_headline_tour(&mut StdRng::seed_from_u64(1))
+        .unwrap();
+
+    let gross: u32 = game
+        .last_tour_report
+        .as_ref()
+        .unwrap()
+        .rows
+        .iter()
+        .map(|row| row.take)
+        .sum();
+    let expected = PromoterDeal::Percentage(0.7).band_take(gross, cost);
+    assert_eq!(game.player.money, expected as i32);
+}
+
+#[test]
+fn a_tour_that_loses_the_promoter_money_sours_them() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    let offer = test_offer(&mut game, PromoterDeal::Guarantee(1_000_000));
+    game.world.tour_promoters[0].opinion = TOUR_PROMOTER_OFFER_MIN_OPINION;
+    game.pending_headline_offer = Some(offer);
+
+    game.action_accept_headline_tour(&mut StdRng::seed_from_u64(1))
+        .unwrap();
+
+    assert!(!game.world.tour_promoters[0].makes_offers());
+    assert!(game.turn_log.iter().any(|line| line.contains("lost $")));
+}
+
+#[test]
+fn rough_shows_cost_the_promoters_goodwill_and_great_ones_earn_it() {
+    let mut game = test_game();
+    game.world
+        .ensure_tour_promoters(&game.data_files, &mut StdRng::seed_from_u64(0));
+    let promoter = &mut game.world.tour_promoters[0];
+
+    let change = promoter.record_tour(&[ShowVerdict::Rough; 5], false);
+    assert_eq!(change, 5 * i16::from(TOUR_PROMOTER_SHOW_DELTA[0]));
+    assert!(promoter.opinion < TOUR_PROMOTER_START);
+
+    let before = promoter.opinion;
+    assert!(promoter.record_tour(&[ShowVerdict::Great; 5], false) > 0);
+    let after_great = promoter.opinion;
+    assert!(after_great > before);
+
+    let change = promoter.record_tour(&[ShowVerdict::Solid; 5], true);
+    assert_eq!(change, -i16::from(TOUR_PROMOTER_LOSS_PENALTY));
+    assert_eq!(promoter.tours_promoted, 3);
+}
+
+#[test]
+fn an_unwell_band_cannot_take_the_tour_and_the_offer_stands() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    let offer = test_offer(&mut game, PromoterDeal::Guarantee(5_000));
+    game.pending_headline_offer = Some(offer);
+    game.player.health = TOUR_HEALTH_GUARD - 1;
+
+    assert!(
+        game.action_accept_headline_tour(&mut StdRng::seed_from_u64(1))
+            .is_err()
+    );
+    assert!(game.pending_headline_offer.is_some());
+}
+
+#[test]
+fn offers_lapse_and_can_be_declined() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    let offer = test_offer(&mut game, PromoterDeal::Guarantee(5_000));
+    game.pending_headline_offer = Some(offer.clone());
+    game.week = offer.expires_week;
+    game.update_headline_tour_offer(&mut StdRng::seed_from_u64(0));
+    assert!(game.pending_headline_offer.is_none());
+    assert!(game.turn_log.iter().any(|line| line.contains("lapsed")));
+
+    game.pending_headline_offer = Some(offer);
+    game.action_decline_headline_tour().unwrap();
+    assert!(game.pending_headline_offer.is_none());
+    assert!(game.action_decline_headline_tour().is_err());
+}
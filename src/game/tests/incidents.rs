@@ -5,7 +5,15 @@
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 
-use crate::data_loader::{Incident, IncidentConditions, IncidentEffects, IncidentsData};
+use std::collections::BTreeMap;
+
+use crate::data_loader::{
+    Incident, IncidentChoice, IncidentConditions, IncidentContext, IncidentEffects,
+    IncidentFollowUp, IncidentsData, MarketsData, RegionalFameEffect,
+};
+
+use crate::game::genre::MusicGenre;
+use crate::game::player::LifestyleTier;
 
 use super::*;
 
@@ -18,22 +26,77 @@ fn incident(id: &str, conditions: IncidentConditions, effects: IncidentEffects)
         conditions,
         effects,
         message: format!("🧪 test:{id}"),
+        choices: Vec::new(),
+        cooldown_weeks: 0,
+        once_per_career: false,
+        follow_up_only: false,
+        follow_up: None,
+    }
+}
+
+/// The shipped markets, for region checks.
+fn shipped_markets() -> MarketsData {
+    test_game().data_files.markets_data
+}
+
+/// A decision incident: pay $100 for +2 fame (fame 30+ only), or the free
+/// default that costs a little happiness.
+fn decision() -> Incident {
+    Incident {
+        choices: vec![
+            IncidentChoice {
+                label: "Pay up".to_string(),
+                conditions: IncidentConditions {
+                    min_fame: Some(30),
+                    ..Default::default()
+                },
+                cost: 100,
+                effects: IncidentEffects {
+                    fame: Some([2, 2]),
+                    ..Default::default()
+                },
+                message: "🧪 paid".to_string(),
+                default: false,
+                follow_up: None,
+            },
+            IncidentChoice {
+                label: "Walk away".to_string(),
+                conditions: IncidentConditions::default(),
+                cost: 0,
+                effects: IncidentEffects {
+                    happiness: Some([-3, -3]),
+                    ..Default::default()
+                },
+                message: "🧪 walked".to_string(),
+                default: true,
+                follow_up: None,
+            },
+        ],
+        ..incident(
+            "decision",
+            IncidentConditions::default(),
+            IncidentEffects::default(),
+        )
     }
 }
 
 #[test]
 fn validation_rejects_malformed_pools() {
     let parse = |json: &str| -> IncidentsData { serde_json::from_str(json).expect("parses") };
+    let markets = shipped_markets();
 
     // Empty pool.
     assert!(
-        parse(r#"{"incidents":[]}"#).validate().is_err(),
+        parse(r#"{"incidents":[]}"#).validate(&markets).is_err(),
         "an empty incident list must be rejected"
     );
 
     // Weight below 1.
     let w0 = r#"{"incidents":[{"id":"a","category":"c","weight":0,"effects":{},"message":"m"}]}"#;
-    assert!(parse(w0).validate().is_err(), "weight 0 must be rejected");
+    assert!(
+        parse(w0).validate(&markets).is_err(),
+        "weight 0 must be rejected"
+    );
 
     // Duplicate ids.
     let dup = r#"{"incidents":[
@@ -41,20 +104,23 @@ fn validation_rejects_malformed_pools() {
         {"id":"a","category":"c","weight":1,"effects":{},"message":"m"}
     ]}"#;
     assert!(
-        parse(dup).validate().is_err(),
+        parse(dup).validate(&markets).is_err(),
         "duplicate ids must be rejected"
     );
 
     // An effect range with lo > hi.
     let bad = r#"{"incidents":[{"id":"a","category":"c","weight":1,"effects":{"money":[10,-5]},"message":"m"}]}"#;
     assert!(
-        parse(bad).validate().is_err(),
+        parse(bad).validate(&markets).is_err(),
         "an inverted effect range must be rejected"
     );
 
     // A well-formed minimal pool passes.
     let ok = r#"{"incidents":[{"id":"a","category":"c","weight":1,"effects":{"stress":[-5,5]},"message":"m"}]}"#;
-    assert!(parse(ok).validate().is_ok(), "a valid pool must pass");
+    assert!(
+        parse(ok).validate(&markets).is_ok(),
+        "a valid pool must pass"
+    );
 }
 
 #[test]
@@ -62,7 +128,7 @@ fn the_shipped_incident_pool_loads_and_validates() {
     let game = test_game();
     let data = &game.data_files.incidents_data;
     assert!(
-        data.validate().is_ok(),
+        data.validate(&game.data_files.markets_data).is_ok(),
         "the shipped data/incidents.json must validate"
     );
     assert!(
@@ -122,7 +188,12 @@ fn eligible_incidents_filter_on_fame_and_signed() {
         ],
     };
     let ids = |fame: u8, signed: bool| -> Vec<String> {
-        data.eligible_incidents(fame, signed)
+        let ctx = IncidentContext {
+            fame,
+            signed,
+            ..Default::default()
+        };
+        data.eligible_incidents(&ctx, &BTreeMap::new(), 1)
             .iter()
             .map(|i| i.id.clone())
             .collect()
@@ -298,3 +369,632 @@ fn a_seeded_run_triggers_incidents_deterministically() {
         "a 40-week idle run should surface at least one incident"
     );
 }
+
+#[test]
+fn validation_demands_one_free_unconditional_default_choice() {
+    let parse = |json: &str| -> IncidentsData { serde_json::from_str(json).expect("parses") };
+    let markets = shipped_markets();
+    let pool = |choices: &str| {
+        format!(
+            r#"{{"incidents":[{{"id":"a","category":"c","weight":1,"message":"m","choices":[{choices}]}}]}}"#
+        )
+    };
+
+    let no_default = pool(r#"{"label":"x","message":"m"},{"label":"y","message":"m"}"#);
+    assert!(
+        parse(&no_default).validate(&markets).is_err(),
+        "a default is required"
+    );
+
+    let two_defaults = pool(
+        r#"{"label":"x","message":"m","default":true},{"label":"y","message":"m","default":true}"#,
+    );
+    assert!(
+        parse(&two_defaults).validate(&markets).is_err(),
+        "only one default"
+    );
+
+    let paid_default = pool(r#"{"label":"x","message":"m","cost":50,"default":true}"#);
+    assert!(
+        parse(&paid_default).validate(&markets).is_err(),
+        "the default is free"
+    );
+
+    let gated_default =
+        pool(r#"{"label":"x","message":"m","conditions":{"min_fame":10},"default":true}"#);
+    assert!(
+        parse(&gated_default).validate(&markets).is_err(),
+        "the default is unconditional"
+    );
+
+    let inverted = pool(
+        r#"{"label":"x","message":"m","effects":{"fame":[3,1]}},{"label":"y","message":"m","default":true}"#,
+    );
+    assert!(
+        parse(&inverted).validate(&markets).is_err(),
+        "choice ranges validate too"
+    );
+
+    let ok = pool(
+        r#"{"label":"x","message":"m","cost":50,"effects":{"fame":[1,3]}},{"label":"y","message":"m","default":true}"#,
+    );
+    assert!(parse(&ok).validate(&markets).is_ok());
+
+    // The shipped pool carries real decisions, all well-formed.
+    let game = test_game();
+    assert!(
+        game.data_files
+            .incidents_data
+            .incidents
+            .iter()
+            .any(|i| !i.choices.is_empty()),
+        "data/incidents.json should ship decision incidents"
+    );
+}
+
+#[test]
+fn a_decision_waits_for_an_answer_and_the_answer_lands() {
+    let mut game = test_game();
+    game.band.fame = 40;
+    game.player.money = 500;
+    game.apply_incident(&decision(), &mut StdRng::seed_from_u64(1));
+    assert!(game.pending_incident.is_some(), "the decision is parked");
+
+    game.process_turn(GameAction::ResolveIncident(0))
+        .expect("paying is open to a fame-40 act with $500");
+    assert!(game.pending_incident.is_none());
+    assert_eq!(game.player.money, 400, "the choice's cost is paid");
+    assert_eq!(game.band.fame, 42, "the choice's effects land");
+    assert!(game.take_turn_log().iter().any(|l| l.contains("🧪 paid")));
+    assert!(
+        game.process_turn(GameAction::ResolveIncident(0)).is_err(),
+        "nothing left to answer"
+    );
+}
+
+#[test]
+fn unavailable_choices_are_refused() {
+    let mut game = test_game();
+    game.band.fame = 10; // below the paid choice's fame gate
+    game.player.money = 500;
+    game.apply_incident(&decision(), &mut StdRng::seed_from_u64(1));
+    assert!(game.process_turn(GameAction::ResolveIncident(0)).is_err());
+
+    game.band.fame = 40;
+    game.player.money = 50; // can't cover the $100
+    assert!(game.process_turn(GameAction::ResolveIncident(0)).is_err());
+    assert!(game.pending_incident.is_some(), "a refusal changes nothing");
+    assert_eq!(game.player.money, 50);
+}
+
+#[test]
+fn playing_on_takes_the_default_choice() {
+    let mut game = test_game();
+    game.player.money = 10_000;
+    game.player.happiness = 50;
+    game.apply_incident(&decision(), &mut StdRng::seed_from_u64(1));
+    game.take_turn_log();
+
+    game.process_turn(GameAction::LazeAround)
+        .expect("a week-consuming action still goes ahead");
+    let log = game.take_turn_log();
+    let settled = log.iter().position(|l| l.contains("🧪 walked"));
+    let lazed = log.iter().position(|l| l.contains("took it easy"));
+    assert!(
+        settled.is_some(),
+        "the headless default settles the decision, got: {log:?}"
+    );
+    assert!(
+        settled < lazed,
+        "the default lands before the week's action"
+    );
+    // The same turn may raise a fresh decision from the pool — just never
+    // this one left open.
+    assert!(
+        game.pending_incident
+            .as_ref()
+            .is_none_or(|pending| pending.id != "decision")
+    );
+}
+
+#[test]
+fn a_rejected_action_leaves_the_decision_open() {
+    let mut game = test_game();
+    game.player.money = 10_000;
+    game.player.happiness = 50;
+    game.band.fame = 0;
+    game.apply_incident(&decision(), &mut StdRng::seed_from_u64(1));
+    game.take_turn_log();
+
+    assert!(
+        game.process_turn(GameAction::GoOnTour(0, TourRig::Van, 4))
+            .is_err(),
+        "a 4-week tour is out of reach at fame 0"
+    );
+    assert_eq!(
+        game.pending_incident.as_ref().map(|pending| &*pending.id),
+        Some("decision"),
+        "no turn was taken, so the question is still open"
+    );
+    assert_eq!(game.player.happiness, 50, "the default hasn't landed");
+    assert!(
+        !game
+            .take_turn_log()
+            .iter()
+            .any(|line| line.contains("🧪 walked"))
+    );
+}
+
+#[test]
+fn conditions_read_era_genre_lifestyle_stats_and_the_road() {
+    let base = IncidentContext {
+        fame: 40,
+        year: 1984,
+        era: "MTV Generation".to_string(),
+        genre: MusicGenre::Metal,
+        lifestyle: LifestyleTier::CityApartment,
+        stress: 70,
+        happiness: 30,
+        money: 500,
+        ..Default::default()
+    };
+    let holds = |conditions: IncidentConditions, ctx: &IncidentContext| conditions.matches(ctx);
+
+    let eighties = IncidentConditions {
+        min_year: Some(1980),
+        max_year: Some(1989),
+        ..Default::default()
+    };
+    assert!(holds(eighties.clone(), &base));
+    assert!(!holds(
+        eighties,
+        &IncidentContext {
+            year: 1990,
+            ..base.clone()
+        }
+    ));
+
+    let mtv = IncidentConditions {
+        eras: Some(vec!["mtv generation".to_string()]),
+        ..Default::default()
+    };
+    assert!(holds(mtv, &base), "era names match case-insensitively");
+
+    // A genre answers to its name and to its market aliases.
+    for genre in ["Metal", "hair_metal"] {
+        let wanted = IncidentConditions {
+            genres: Some(vec![genre.to_string()]),
+            ..Default::default()
+        };
+        assert!(holds(wanted, &base), "{genre} should match a metal band");
+    }
+    let punks = IncidentConditions {
+        genres: Some(vec!["punk".to_string()]),
+        ..Default::default()
+    };
+    assert!(!holds(punks, &base));
+
+    let high_life = IncidentConditions {
+        lifestyles: Some(vec![LifestyleTier::Townhouse, LifestyleTier::Mansion]),
+        ..Default::default()
+    };
+    assert!(!holds(high_life.clone(), &base));
+    assert!(holds(
+        high_life,
+        &IncidentContext {
+            lifestyle: LifestyleTier::Mansion,
+            ..base.clone()
+        }
+    ));
+
+    let frazzled_and_broke = IncidentConditions {
+        min_stress: Some(70),
+        max_happiness: Some(30),
+        max_money: Some(500),
+        ..Default::default()
+    };
+    assert!(
+        holds(frazzled_and_broke.clone(), &base),
+        "bounds are inclusive"
+    );
+    assert!(!holds(
+        frazzled_and_broke,
+        &IncidentContext {
+            money: 501,
+            ..base.clone()
+        }
+    ));
+
+    let road = IncidentConditions {
+        on_tour: Some(true),
+        ..Default::default()
+    };
+    assert!(!holds(road.clone(), &base));
+    assert!(holds(
+        road,
+        &IncidentContext {
+            on_tour: true,
+            ..base
+        }
+    ));
+}
+
+#[test]
+fn cooldowns_and_once_per_career_hold_incidents_back() {
+    let data = IncidentsData {
+        incidents: vec![
+            Incident {
+                cooldown_weeks: 10,
+                ..incident(
+                    "cooling",
+                    IncidentConditions::default(),
+                    IncidentEffects::default(),
+                )
+            },
+            Incident {
+                once_per_career: true,
+                ..incident(
+                    "once",
+                    IncidentConditions::default(),
+                    IncidentEffects::default(),
+                )
+            },
+            Incident {
+                follow_up_only: true,
+                ..incident(
+                    "chained",
+                    IncidentConditions::default(),
+                    IncidentEffects::default(),
+                )
+            },
+        ],
+    };
+    let ctx = IncidentContext::default();
+    let ids = |last_fired: &BTreeMap<String, u32>, week: u32| -> Vec<String> {
+        data.eligible_incidents(&ctx, last_fired, week)
+            .iter()
+            .map(|i| i.id.clone())
+            .collect()
+    };
+
+    let fresh = ids(&BTreeMap::new(), 5);
+    assert_eq!(fresh, vec!["cooling", "once"], "follow-ups never roll");
+
+    let fired: BTreeMap<String, u32> = [("cooling".to_string(), 5), ("once".to_string(), 5)]
+        .into_iter()
+        .collect();
+    assert!(ids(&fired, 14).is_empty());
+    assert_eq!(ids(&fired, 15), vec!["cooling"], "the cooldown lifts");
+    assert!(
+        !ids(&fired, 500).contains(&"once".to_string()),
+        "once is once"
+    );
+}
+
+#[test]
+fn follow_ups_fire_on_schedule_unless_the_story_moved_on() {
+    let mut game = test_game();
+    game.band.fame = 30;
+    game.data_files.incidents_data = IncidentsData {
+        incidents: vec![
+            Incident {
+                once_per_career: true,
+                follow_up: Some(IncidentFollowUp {
+                    incident: "sequel".to_string(),
+                    weeks: 3,
+                }),
+                ..incident(
+                    "opener",
+                    IncidentConditions::default(),
+                    IncidentEffects::default(),
+                )
+            },
+            Incident {
+                follow_up_only: true,
+                ..incident(
+                    "sequel",
+                    IncidentConditions {
+                        min_fame: Some(20),
+                        ..Default::default()
+                    },
+                    IncidentEffects::default(),
+                )
+            },
+        ],
+    };
+    game.week = 10;
+    let opener = game.data_files.incidents_data.incidents[0].clone();
+    game.apply_incident(&opener, &mut StdRng::seed_from_u64(1));
+    game.events.last_event_week = 10;
+    game.take_turn_log();
+
+    game.week = 12;
+    game.events.last_event_week = 11;
+    game.maybe_trigger_incident(&mut StdRng::seed_from_u64(2))
+        .unwrap();
+    assert!(
+        !game.take_turn_log().join("\n").contains("test:sequel"),
+        "not due yet"
+    );
+
+    game.week = 13;
+    game.events.last_event_week = 12;
+    game.maybe_trigger_incident(&mut StdRng::seed_from_u64(3))
+        .unwrap();
+    let log = game.take_turn_log().join("\n");
+    assert!(log.contains("test:sequel"), "due this week; got: {log}");
+    assert!(!log.contains("test:opener"), "in place of the random roll");
+    assert!(game.events.scheduled.is_empty());
+
+    // Scheduled again, but the band has faded below the sequel's bar by
+    // the time it comes due: the storyline just drops.
+    game.events.schedule("sequel", 13, 1);
+    game.band.fame = 5;
+    game.week = 14;
+    game.maybe_trigger_incident(&mut StdRng::seed_from_u64(4))
+        .unwrap();
+    assert!(game.events.scheduled.is_empty());
+    assert!(!game.take_turn_log().join("\n").contains("test:sequel"));
+}
+
+#[test]
+fn a_choice_can_book_a_follow_up() {
+    let mut game = test_game();
+    game.band.fame = 40;
+    game.player.money = 1_000;
+    let mut decision = decision();
+    decision.choices[0].follow_up = Some(IncidentFollowUp {
+        incident: "decision".to_string(),
+        weeks: 2,
+    });
+    game.week = 7;
+    game.apply_incident(&decision, &mut StdRng::seed_from_u64(1));
+    assert!(
+        game.events.scheduled.is_empty(),
+        "nothing until they choose"
+    );
+    game.resolve_incident_choice(0, &mut StdRng::seed_from_u64(1))
+        .expect("fame 40 with cash on hand can pay");
+    assert_eq!(game.events.scheduled.len(), 1);
+    assert_eq!(game.events.scheduled[0].week, 9);
+}
+
+#[test]
+fn validation_checks_bounds_and_follow_up_targets() {
+    let parse = |json: &str| -> IncidentsData { serde_json::from_str(json).expect("parses") };
+    let markets = shipped_markets();
+
+    let inverted = r#"{"incidents":[{"id":"a","category":"c","weight":1,"message":"m","conditions":{"min_year":1990,"max_year":1980}}]}"#;
+    assert!(
+        parse(inverted).validate(&markets).is_err(),
+        "min_year > max_year"
+    );
+
+    let unknown = r#"{"incidents":[{"id":"a","category":"c","weight":1,"message":"m","follow_up":{"incident":"b","weeks":2}}]}"#;
+    assert!(
+        parse(unknown).validate(&markets).is_err(),
+        "a follow-up must exist"
+    );
+
+    let same_week = r#"{"incidents":[
+        {"id":"a","category":"c","weight":1,"message":"m","follow_up":{"incident":"b","weeks":0}},
+        {"id":"b","category":"c","weight":1,"message":"m","follow_up_only":true}
+    ]}"#;
+    assert!(
+        parse(same_week).validate(&markets).is_err(),
+        "a follow-up is at least a week out"
+    );
+
+    let orphan = r#"{"incidents":[{"id":"a","category":"c","weight":1,"message":"m","follow_up_only":true}]}"#;
+    assert!(
+        parse(orphan).validate(&markets).is_err(),
+        "a follow-up-only incident needs something to schedule it"
+    );
+
+    let chained = r#"{"incidents":[
+        {"id":"a","category":"c","weight":1,"message":"m","once_per_career":true,
+         "conditions":{"genres":["punk"],"lifestyles":["Squat"],"on_tour":true},
+         "choices":[{"label":"x","message":"m","follow_up":{"incident":"b","weeks":2}},
+                    {"label":"y","message":"m","default":true}]},
+        {"id":"b","category":"c","weight":1,"message":"m","follow_up_only":true,"cooldown_weeks":8}
+    ]}"#;
+    assert!(parse(chained).validate(&markets).is_ok());
+}
+
+#[test]
+fn effects_reach_the_band_its_region_and_the_label() {
+    let mut game = test_game();
+    game.band.skill = 98;
+    game.band.reputation.media_presence = 10;
+    game.band.deal_cooldown = 3;
+    game.band.record_deal = Some(test_deal(50, 0.10));
+    game.band.record_deal.as_mut().unwrap().unrecouped = 1_000;
+    let inc = incident(
+        "wide",
+        IncidentConditions {
+            signed: Some(true),
+            ..Default::default()
+        },
+        IncidentEffects {
+            band_skill: Some([5, 5]),
+            media_presence: Some([-20, -20]),
+            critical_acclaim: Some([4, 4]),
+            regional_fame: Some(RegionalFameEffect {
+                region: "japan:tokyo".to_string(),
+                change: [7, 7],
+            }),
+            deal_cooldown: Some([-10, -10]),
+            unrecouped: Some([500, 500]),
+            ..Default::default()
+        },
+    );
+    let acclaim = game.band.reputation.critical_acclaim;
+    game.apply_incident(&inc, &mut StdRng::seed_from_u64(1));
+
+    assert_eq!(game.band.skill, 100, "band skill clamps at 100");
+    assert_eq!(game.band.reputation.media_presence, 0, "and floors at 0");
+    assert_eq!(game.band.reputation.critical_acclaim, acclaim + 4);
+    assert_eq!(game.regional_fame.get("japan:tokyo"), Some(&7));
+    assert_eq!(game.band.deal_cooldown, 0);
+    assert_eq!(game.band.record_deal.as_ref().unwrap().unrecouped, 1_500);
+
+    // Unsigned, the ledger effect has nothing to land on.
+    let mut game = test_game();
+    game.apply_incident(&inc, &mut StdRng::seed_from_u64(1));
+    assert!(game.band.record_deal.is_none());
+}
+
+#[test]
+fn a_member_effect_lands_on_one_bandmate_and_names_them() {
+    let mut game = test_game();
+    for member in &mut game.band.members {
+        member.loyalty = 50;
+    }
+    let arrest = Incident {
+        message: "🚔 {member} was arrested in Tokyo.".to_string(),
+        ..incident(
+            "arrest",
+            IncidentConditions::default(),
+            IncidentEffects {
+                member_loyalty: Some([-10, -10]),
+                ..Default::default()
+            },
+        )
+    };
+    game.apply_incident(&arrest, &mut StdRng::seed_from_u64(9));
+    let hit: Vec<String> = game
+        .band
+        .members
+        .iter()
+        .filter(|member| member.loyalty == 40)
+        .map(|member| member.name.clone())
+        .collect();
+    assert_eq!(hit.len(), 1, "exactly one bandmate takes the hit");
+    let log = game.take_turn_log().join("\n");
+    assert!(
+        log.contains(&format!("{} was arrested", hit[0])),
+        "got: {log}"
+    );
+}
+
+#[test]
+fn release_effects_hit_the_newest_release() {
+    let mut game = test_game();
+    let mut old = test_release(1, ReleaseType::Album);
+    old.week_released = 5;
+    old.copies_pressed = 10_000;
+    let mut newest = test_release(2, ReleaseType::Single);
+    newest.week_released = 20;
+    newest.copies_pressed = 5_000;
+    newest.copies_sold = 3_000;
+    newest.marketing_level_achieved = 30;
+    game.band.albums_released.push(old);
+    game.band.singles_released.push(newest);
+    assert!(game.incident_context().has_release);
+
+    let fire = incident(
+        "fire",
+        IncidentConditions {
+            has_release: Some(true),
+            ..Default::default()
+        },
+        IncidentEffects {
+            release_stock: Some([-4_000, -4_000]),
+            release_marketing: Some([10, 10]),
+            ..Default::default()
+        },
+    );
+    game.week = 21;
+    game.apply_incident(&fire, &mut StdRng::seed_from_u64(1));
+
+    let single = &game.band.singles_released[0];
+    assert_eq!(single.copies_pressed, 3_000, "stock can't go below sold");
+    assert_eq!(single.marketing_level_achieved, 40);
+    assert_eq!(
+        single.active_marketing.len(),
+        1,
+        "the buzz is booked as a campaign so it outlasts the week"
+    );
+    assert_eq!(
+        single.active_marketing[0].end_week,
+        21 + INCIDENT_PRESS_WEEKS
+    );
+    assert_eq!(game.band.albums_released[0].copies_pressed, 10_000);
+
+    let backlash = incident(
+        "backlash",
+        IncidentConditions {
+            has_release: Some(true),
+            ..Default::default()
+        },
+        IncidentEffects {
+            release_marketing: Some([-15, -15]),
+            ..Default::default()
+        },
+    );
+    game.apply_incident(&backlash, &mut StdRng::seed_from_u64(1));
+    let single = &game.band.singles_released[0];
+    assert_eq!(single.marketing_level_achieved, 25);
+    assert!(
+        single.active_marketing.is_empty(),
+        "the free press is spent"
+    );
+}
+
+#[test]
+fn validation_demands_a_target_for_every_effect() {
+    let parse = |json: &str| -> IncidentsData { serde_json::from_str(json).expect("parses") };
+    let markets = shipped_markets();
+    let one = |conditions: &str, effects: &str, message: &str| {
+        format!(
+            r#"{{"incidents":[{{"id":"a","category":"c","weight":1,"conditions":{conditions},"effects":{effects},"message":"{message}"}}]}}"#
+        )
+    };
+
+    let no_release = one("{}", r#"{"release_stock":[-100,-10]}"#, "m");
+    assert!(
+        parse(&no_release).validate(&markets).is_err(),
+        "needs has_release"
+    );
+    let unsigned = one("{}", r#"{"unrecouped":[100,200]}"#, "m");
+    assert!(parse(&unsigned).validate(&markets).is_err(), "needs signed");
+    let inverted = one("{}", r#"{"member_skill":[5,-5]}"#, "m");
+    assert!(parse(&inverted).validate(&markets).is_err());
+    let bad_key = one(
+        "{}",
+        r#"{"regional_fame":{"region":"tokyo","change":[1,2]}}"#,
+        "m",
+    );
+    assert!(
+        parse(&bad_key).validate(&markets).is_err(),
+        "region is country:region"
+    );
+    let nobody = one("{}", r#"{"happiness":[1,2]}"#, "{member} smiles");
+    assert!(
+        parse(&nobody).validate(&markets).is_err(),
+        "{{member}} needs a member"
+    );
+
+    let ok = one(
+        r#"{"signed":true,"has_release":true}"#,
+        r#"{"release_stock":[-100,-10],"unrecouped":[100,200],"member_loyalty":[-5,-1],"regional_fame":{"region":"japan:tokyo","change":[-3,-1]}}"#,
+        "{member} sulks",
+    );
+    let ok = parse(&ok);
+    assert!(ok.validate(&markets).is_ok());
+
+    // Regions are checked against the shipped markets.
+    let atlantis = one(
+        "{}",
+        r#"{"regional_fame":{"region":"atlantis:capital","change":[1,2]}}"#,
+        "m",
+    );
+    assert!(parse(&atlantis).validate(&markets).is_err());
+    let lost = r#"{"incidents":[{"id":"a","category":"c","weight":1,"message":"m",
+        "choices":[{"label":"x","message":"m","effects":{"regional_fame":{"region":"japan:atlantis","change":[1,2]}}},
+                   {"label":"y","message":"m","default":true}]}]}"#;
+    assert!(
+        parse(lost).validate(&markets).is_err(),
+        "a choice's region is checked too"
+    );
+}
@@ -0,0 +1,137 @@
+//! Investments (`investments.rs`): buying in and selling up at each
+//! kind's liquidity, the studio's saving on a session, markets that follow
+//! the economy, and the receiver's sale.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::investments::InvestmentKind;
+use crate::game::world::EconomicState;
+
+use super::*;
+
+#[test]
+fn a_studio_of_your_own_cuts_the_session_and_sells_at_a_loss() {
+    let mut game = test_game();
+    game.player.money = 200_000;
+    let hired = game.recording_cost(&ReleaseType::Album);
+
+    game.action_buy_investment(InvestmentKind::Studio).unwrap();
+    assert!(game.action_buy_investment(InvestmentKind::Studio).is_err());
+    assert_eq!(
+        game.player.money,
+        200_000 - InvestmentKind::Studio.price() as i32
+    );
+    let own = game.recording_cost(&ReleaseType::Album);
+    assert_eq!(
+        own,
+        (hired as f32 * (1.0 - OWN_STUDIO_RECORDING_SAVING)) as i32
+    );
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let money = game.player.money;
+    game.investments_weekly_update(10, &mut rng);
+    assert_eq!(
+        game.player.money,
+        money - 10 * STUDIO_UPKEEP_PER_WEEK as i32,
+        "no swing, no income — just the upkeep"
+    );
+    let studio = game.investment(InvestmentKind::Studio).unwrap().clone();
+    assert!(studio.value < studio.paid, "it ages");
+
+    let money = game.player.money;
+    game.action_sell_investment(InvestmentKind::Studio).unwrap();
+    assert_eq!(game.player.money, money + studio.sale_value() as i32);
+    assert!(
+        studio.sale_value() < studio.value,
+        "few buyers for a studio"
+    );
+    assert!(!game.owns_studio());
+    assert_eq!(game.recording_cost(&ReleaseType::Album), hired);
+    assert!(game.action_sell_investment(InvestmentKind::Studio).is_err());
+}
+
+#[test]
+fn the_markets_follow_the_economy() {
+    let year_of = |state: EconomicState| {
+        let mut game = test_game();
+        game.player.money = 100_000;
+        game.world.music_market.economic_state = state;
+        game.action_buy_investment(InvestmentKind::BlueChips)
+            .unwrap();
+        game.action_buy_investment(InvestmentKind::BlueChips)
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(11);
+        game.investments_weekly_update(WEEKS_PER_YEAR, &mut rng);
+        game.investment(InvestmentKind::BlueChips).unwrap().clone()
+    };
+    let boom = year_of(EconomicState::Booming);
+    let slump = year_of(EconomicState::Recession);
+    assert_eq!(
+        boom.paid,
+        2 * InvestmentKind::BlueChips.price(),
+        "two stakes"
+    );
+    assert!(boom.value > boom.paid);
+    assert!(slump.value < slump.paid);
+    assert!(
+        boom.sale_value() > boom.value * 9 / 10,
+        "shares sell for nearly what they're worth"
+    );
+}
+
+#[test]
+fn property_pays_rent_and_the_imprint_its_office() {
+    let mut game = test_game();
+    game.player.money = 100_000;
+    game.action_buy_investment(InvestmentKind::Property)
+        .unwrap();
+    game.action_buy_investment(InvestmentKind::Imprint).unwrap();
+    let mut rng = StdRng::seed_from_u64(5);
+    game.investments_weekly_update(WEEKS_PER_YEAR, &mut rng);
+
+    let posted = |note: &str| -> i32 {
+        game.ledger
+            .iter()
+            .filter(|entry| entry.note == note)
+            .map(|entry| entry.amount)
+            .sum()
+    };
+    assert!(posted("Rent from the tenants") > 0);
+    assert_eq!(
+        posted("Imprint office"),
+        -((IMPRINT_OVERHEAD_PER_WEEK * WEEKS_PER_YEAR) as i32)
+    );
+    let spent: i32 = game.ledger.iter().map(|entry| entry.amount).sum();
+    assert_eq!(
+        game.player.money,
+        100_000 + spent,
+        "the books still balance"
+    );
+}
+
+#[test]
+fn the_receiver_sells_every_investment() {
+    let mut game = test_game();
+    game.player.money = 100_000;
+    game.action_buy_investment(InvestmentKind::Property)
+        .unwrap();
+    game.action_buy_investment(InvestmentKind::RecordShares)
+        .unwrap();
+    let fetches: u32 = game
+        .investments
+        .iter()
+        .map(|investment| investment.sale_value())
+        .sum();
+    game.player.money = -(fetches as i32) - 1_000;
+    game.band.fame = 0;
+    game.check_insolvency();
+
+    assert!(game.is_bankrupt());
+    assert!(game.investments.is_empty());
+    assert_eq!(game.player.money, 0, "not enough to cover it all");
+    assert!(
+        game.action_buy_investment(InvestmentKind::BlueChips)
+            .is_err()
+    );
+}
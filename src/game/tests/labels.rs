@@ -0,0 +1,251 @@
+//! Labels as going concerns (`world/labels.rs`): opening in their founding
+//! year, the cash that decides advances and signings, and what folding does
+//! to the scene acts and the player on the roster.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::data_loader::RecordLabel;
+use crate::game::world::LabelState;
+
+use super::*;
+
+fn label_data(game: &Game, name: &str) -> RecordLabel {
+    let labels = game.data_files.get_record_labels_data();
+    labels
+        .major_labels
+        .iter()
+        .chain(&labels.independent_labels)
+        .chain(&labels.boutique_labels)
+        .find(|label| label.name == name)
+        .expect("label in the data file")
+        .clone()
+}
+
+fn set_cash(game: &mut Game, name: &str, cash: i64) {
+    let label = game
+        .world
+        .labels
+        .iter_mut()
+        .find(|label| label.name == name)
+        .expect("label trading");
+    label.cash = cash;
+}
+
+#[test]
+fn labels_open_in_the_year_they_were_founded() {
+    let mut game = test_game();
+    assert!(game.world.label("Atlantic Records").is_some());
+    assert!(game.world.label("Island Records").is_some());
+    assert!(
+        game.world.label("Sub Pop Records").is_none(),
+        "Sub Pop isn't founded until 1986"
+    );
+    assert!(
+        game.world
+            .bands
+            .iter()
+            .filter_map(|band| band.label.as_deref())
+            .all(|name| game.world.label(name).is_some()),
+        "the scene starts signed only to labels already trading"
+    );
+
+    while game.timeline.get_current_year() < 1986 {
+        game.timeline.advance_year();
+    }
+    let news = game.world.update_week(
+        &game.timeline,
+        &game.data_files,
+        &mut StdRng::seed_from_u64(3),
+    );
+    assert!(game.world.label("Sub Pop Records").is_some());
+    assert!(
+        news.iter()
+            .any(|line| line.contains("Sub Pop Records opens"))
+    );
+    assert!(game.world.label("Matador Records").is_none());
+}
+
+#[test]
+fn a_label_short_of_cash_offers_no_advance() {
+    let mut game = test_game();
+    let island = label_data(&game, "Island Records");
+    assert!(game.world.label_advance_budget(&island) > 0);
+
+    for band in &mut game.world.bands {
+        if band.label.as_deref() == Some("Island Records") {
+            band.label = None;
+        }
+    }
+
+    // Cash to keep the lights on, none to spare for an advance.
+    let overhead = LabelState::weekly_overhead(&island, 0);
+    set_cash(&mut game, "Island Records", overhead * 20);
+    assert_eq!(game.world.label_advance_budget(&island), 0);
+    assert_eq!(
+        game.world
+            .label("Island Records")
+            .unwrap()
+            .standing(&island, 0),
+        "Stretched"
+    );
+
+    game.band.fame = 45;
+    game.band.singles_released = (1..=3)
+        .map(|id| test_release(id, ReleaseType::Single))
+        .collect();
+    let mut rng = StdRng::seed_from_u64(5);
+    let mut offered = false;
+    for _ in 0..60 {
+        for offer in game
+            .world
+            .generate_deal_offers(&game.band, &game.data_files, &mut rng)
+        {
+            if offer.label_name == "Island Records" {
+                assert_eq!(offer.advance, 0, "a stretched label pays nothing up front");
+                offered = true;
+            }
+        }
+    }
+    assert!(offered, "a stretched label still signs");
+
+    // In the red it signs nobody at all.
+    set_cash(&mut game, "Island Records", -1);
+    assert_eq!(game.world.label_willingness(&island), 0);
+    for _ in 0..60 {
+        assert!(
+            game.world
+                .generate_deal_offers(&game.band, &game.data_files, &mut rng)
+                .iter()
+                .all(|offer| offer.label_name != "Island Records")
+        );
+    }
+}
+
+#[test]
+fn a_label_grows_choosier_as_its_roster_fills() {
+    let mut game = test_game();
+    let track = label_data(&game, "Track Records");
+    for band in &mut game.world.bands {
+        if band.label.as_deref() == Some("Track Records") {
+            band.label = None;
+        }
+    }
+    let empty = game.world.label_willingness(&track);
+    for band in game.world.bands.iter_mut().take(6) {
+        band.label = Some("Track Records".to_string());
+    }
+    let full = game.world.label_willingness(&track);
+    assert!(empty > full, "{empty} {full}");
+    for band in game.world.bands.iter_mut().take(12) {
+        band.label = Some("Track Records".to_string());
+    }
+    assert_eq!(game.world.label_willingness(&track), 0, "well past full");
+}
+
+#[test]
+fn the_band_takes_a_place_on_its_labels_roster() {
+    let mut game = test_game();
+    let track = label_data(&game, "Track Records");
+    let mut deal = test_deal(30, 0.12);
+    deal.label_name = track.name.clone();
+    game.band.record_deal = Some(deal);
+    set_cash(&mut game, &track.name, 1_000_000);
+    game.process_turn(GameAction::LazeAround).unwrap();
+
+    let scene = game.world.label_roster(&track.name).count();
+    assert_eq!(game.world.label_roster_size(&track.name), scene + 1);
+    let state = game.world.label(&track.name).unwrap();
+    assert!(
+        game.world.label_willingness(&track) < state.willingness_to_sign(&track, scene),
+        "the band fills a slot like any act"
+    );
+
+    game.band.record_deal = None;
+    game.process_turn(GameAction::LazeAround).unwrap();
+    assert_eq!(
+        game.world.label_roster_size(&track.name),
+        game.world.label_roster(&track.name).count(),
+        "a band off the label is off its roster"
+    );
+}
+
+#[test]
+fn a_label_in_the_red_too_long_folds_and_frees_its_acts() {
+    let mut game = test_game();
+    let act = game.world.bands[0].name.clone();
+    game.world.bands[0].label = Some("Charisma Records".to_string());
+    set_cash(&mut game, "Charisma Records", -1_000_000);
+    game.world
+        .labels
+        .iter_mut()
+        .find(|label| label.name == "Charisma Records")
+        .unwrap()
+        .weeks_in_red = LABEL_FOLD_WEEKS_IN_RED - 1;
+
+    let news = game.world.update_week(
+        &game.timeline,
+        &game.data_files,
+        &mut StdRng::seed_from_u64(9),
+    );
+    assert!(game.world.label("Charisma Records").unwrap().folded);
+    assert!(
+        news.iter()
+            .any(|line| line.contains("Charisma Records has gone under"))
+    );
+    assert_eq!(game.world.scene_band(&act).unwrap().label, None);
+    assert!(
+        game.world
+            .bands
+            .iter()
+            .all(|band| band.label.as_deref() != Some("Charisma Records"))
+    );
+
+    // Gone for good: it signs nobody and never reopens.
+    assert_eq!(
+        game.world
+            .label_willingness(&label_data(&game, "Charisma Records")),
+        0
+    );
+    game.world.update_week(
+        &game.timeline,
+        &game.data_files,
+        &mut StdRng::seed_from_u64(10),
+    );
+    assert!(game.world.label("Charisma Records").unwrap().folded);
+}
+
+#[test]
+fn signing_pays_out_of_the_labels_bank_and_folding_ends_the_deal() {
+    let mut game = test_game();
+    let island = label_data(&game, "Island Records");
+    let mut offer = test_deal_offer(&game, None);
+    offer.label_name = island.name.clone();
+    offer.original_label_data = island;
+    offer.advance = 5_000;
+    game.pending_deal_offers = vec![offer];
+    let before = game.world.label("Island Records").unwrap().cash;
+    game.action_accept_deal(0).unwrap();
+    assert_eq!(
+        game.world.label("Island Records").unwrap().cash,
+        before - 5_000
+    );
+
+    game.world
+        .labels
+        .iter_mut()
+        .find(|label| label.name == "Island Records")
+        .unwrap()
+        .folded = true;
+    game.label_weekly_deal_check(&mut StdRng::seed_from_u64(1));
+    assert!(game.band.current_deal().is_none());
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("your contract goes with it"))
+    );
+    assert!(
+        game.turn_log.iter().any(|line| line.contains("$5000")),
+        "the unrecouped advance dies with the label"
+    );
+}
@@ -0,0 +1,104 @@
+//! The books (`ledger.rs`): every dollar posted under a category, a year
+//! added up by category, and the labels' quarterly royalty statements.
+
+use crate::game::music::MasterOwner;
+
+use super::*;
+
+#[test]
+fn every_dollar_is_posted_and_the_books_balance() {
+    let mut game = test_game();
+    game.player.money = 10_000;
+    game.pending_manager_offers = vec![crate::game::world::PotentialManagerOffer {
+        name: "Sid Marlowe".to_string(),
+        cut: 0.10,
+        skill: 50,
+        honesty: 0,
+        expires_week: None,
+    }];
+    game.action_hire_manager(0).unwrap();
+
+    game.bank_income(1_000, LedgerCategory::Shows, "The Marquee");
+    game.action_visit_doctor().unwrap();
+    game.action_buy_gear(GearKind::Backline).unwrap();
+
+    let posted: i32 = game.ledger.iter().map(|entry| entry.amount).sum();
+    assert_eq!(
+        game.player.money,
+        10_000 + posted,
+        "the books match the bank"
+    );
+    assert!(
+        game.manager.as_ref().unwrap().skimmed > 0,
+        "the skim happened…"
+    );
+    let books = game.year_summary(STARTING_YEAR);
+    let row = |category| {
+        books
+            .by_category
+            .iter()
+            .find(|&&(c, _, _)| c == category)
+            .map(|&(_, income, out)| (income, out))
+    };
+    let (shows, _) = row(LedgerCategory::Shows).unwrap();
+    assert!(shows < 1_000, "…and the books only show what got through");
+    assert_eq!(row(LedgerCategory::Manager), Some((0, 100)));
+    assert_eq!(
+        row(LedgerCategory::Health),
+        Some((0, DOCTOR_VISIT_COST as u32))
+    );
+    assert!(row(LedgerCategory::Gear).is_some());
+    assert_eq!(row(LedgerCategory::Royalties), None);
+    assert_eq!(books.income(), shows);
+    assert_eq!(game.ledger_years(), vec![STARTING_YEAR]);
+}
+
+#[test]
+fn a_label_sends_a_royalty_statement_once_the_quarter_closes() {
+    let mut game = test_game();
+    let mut deal = test_deal(70, 0.12);
+    deal.label_name = "Island Records".to_string();
+    deal.unrecouped = 20;
+    game.band.record_deal = Some(deal);
+    let mut single = test_release(1, ReleaseType::Single);
+    single.initial_sales_score = 600;
+    single.copies_pressed = 0;
+    single.copies_sold = 3_000;
+    single.master_owner = Some(MasterOwner::Label {
+        name: "Island Records".to_string(),
+        royalty_rate: 0.12,
+    });
+    game.band.singles_released = vec![single];
+
+    game.week = INITIAL_SALES_WINDOW_WEEKS + 6;
+    game.last_sales_pass_week = None;
+    game.process_music_releases_and_marketing();
+    game.issue_royalty_statements();
+    assert!(
+        game.royalty_statements.is_empty(),
+        "the quarter's still open"
+    );
+
+    game.week = WEEKS_PER_QUARTER + 1;
+    game.issue_royalty_statements();
+    let statement = game.royalty_statements[0].clone();
+    assert_eq!(statement.label, "Island Records");
+    assert_eq!(statement.period(), "Q1 1970");
+    assert!(statement.copies > 0);
+    assert!((statement.rate() - 0.12).abs() < 0.01);
+    assert_eq!(statement.recouped, 20, "the advance is paid off first");
+    assert_eq!(statement.net(), statement.royalties - 20);
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("Island Records's statement for Q1 1970"))
+    );
+    let royalties: i32 = game
+        .ledger
+        .iter()
+        .filter(|entry| entry.category == LedgerCategory::Royalties)
+        .map(|entry| entry.amount)
+        .sum();
+    assert_eq!(royalties, statement.net() as i32);
+    assert!(game.open_royalty_statements.is_empty());
+}
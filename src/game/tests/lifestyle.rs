@@ -331,13 +331,17 @@ fn rest_healing_bonus_boosts_laze_around() {
 }
 
 #[test]
-fn rest_healing_bonus_boosts_take_break() {
+fn rest_healing_bonus_boosts_a_vacation() {
     let mut game = test_game();
     game.player.lifestyle = LifestyleTier::Townhouse; // rest-healing bonus +3
+    game.player.money = 1_000;
     game.player.health = 50;
 
-    game.action_take_break()
-        .expect("take break is always available");
+    game.action_take_vacation(Vacation::CountryRetreat)
+        .expect("an affordable trip always works");
 
-    assert_eq!(game.player.health, (50 + BREAK_HEALTH_GAIN + 3).min(100));
+    assert_eq!(
+        game.player.health,
+        (50 + Vacation::CountryRetreat.health_gain() + 3).min(100)
+    );
 }
@@ -0,0 +1,167 @@
+//! Live records: taping a tour, the quality of what's on the tapes, and
+//! releasing it as a live album or concert film (`actions/studio.rs`).
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::music::LiveFormat;
+use crate::game::shows::{ShowReport, TourReport};
+
+use super::*;
+
+fn tapes(receptions: &[u8]) -> TourReport {
+    let rows = receptions
+        .iter()
+        .enumerate()
+        .map(|(i, &reception)| ShowReport {
+            week: 0,
+            venue_name: format!("Venue {i}"),
+            verdict: String::new(),
+            reception,
+            attendance: 100,
+            capacity: 100,
+            take: 0,
+            merch: 0,
+        })
+        .collect();
+    TourReport::from_rows(rows, 0)
+}
+
+/// A band with one studio album out, its songs at `song_quality`.
+fn game_with_catalogue(song_quality: u8) -> Game {
+    let mut game = test_game();
+    let mut album = test_release(1, ReleaseType::Album);
+    album.songs_involved_quality_avg = song_quality;
+    game.band.albums_released.push(album);
+    game.next_release_id = 2;
+    game.player.money = 100_000;
+    game
+}
+
+#[test]
+fn taping_a_tour_adds_the_fee_and_leaves_the_tapes() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    game.player.money = 1_000_000;
+    let legs = [TourLeg {
+        region_index: 0,
+        weeks: 2,
+    }];
+    let taped = TourTerms {
+        recorded: true,
+        ..TourTerms::default()
+    };
+
+    let plain = game
+        .quote_route(&legs, TourRig::Van, TourTerms::default())
+        .unwrap();
+    let quote = game
+        .quote_route(&legs, TourRig::Van, taped.clone())
+        .unwrap();
+    assert_eq!(plain.recording_cost, 0);
+    assert_eq!(
+        quote.recording_cost,
+        LIVE_RECORDING_FEE_BASE + LIVE_RECORDING_FEE_PER_SHOW * quote.shows as i32
+    );
+    assert_eq!(quote.cost, plain.cost + quote.recording_cost);
+
+    game.action_tour_route(
+        &legs,
+        TourRig::Van,
+        TourTerms::default(),
+        &mut StdRng::seed_from_u64(1),
+    )
+    .unwrap();
+    assert!(game.live_tapes.is_none(), "an untaped tour leaves nothing");
+    game.action_tour_route(&legs, TourRig::Van, taped, &mut StdRng::seed_from_u64(2))
+        .unwrap();
+    let on_tape = game.live_tapes.as_ref().expect("the taped tour's report");
+    assert_eq!(on_tape.rows.len(), quote.shows as usize);
+}
+
+#[test]
+fn live_quality_comes_from_the_best_shows_and_the_catalogue() {
+    let mut game = game_with_catalogue(60);
+    let mut receptions = vec![90; LIVE_BEST_SHOWS];
+    receptions.extend([10, 10, 10]);
+    game.live_tapes = Some(tapes(&receptions));
+
+    let expected =
+        (90.0 * LIVE_PERFORMANCE_WEIGHT + 60.0 * (1.0 - LIVE_PERFORMANCE_WEIGHT)).round() as u8;
+    assert_eq!(
+        game.live_release_quality(LiveFormat::Album),
+        Ok(expected),
+        "the weak nights never make the record"
+    );
+    assert_eq!(
+        game.live_release_quality(LiveFormat::ConcertFilm),
+        Ok(expected + LIVE_FILM_QUALITY_BONUS)
+    );
+
+    let songs_before = game.band.unreleased_songs.len();
+    game.action_release_live(LiveFormat::Album, Some(0))
+        .unwrap();
+    let record = &game.just_released_music[0];
+    assert_eq!(record.release_quality, expected);
+    assert_eq!(record.release_type, ReleaseType::Album);
+    assert_eq!(record.live, Some(LiveFormat::Album));
+    assert_eq!(game.band.unreleased_songs.len(), songs_before);
+    assert!(game.live_tapes.is_none(), "the tapes are used up");
+}
+
+#[test]
+fn a_live_record_needs_tapes_a_catalogue_and_for_film_a_video_era() {
+    let mut game = test_game();
+    game.player.money = 100_000;
+    assert!(
+        game.action_release_live(LiveFormat::Album, Some(0))
+            .is_err()
+    );
+
+    game.live_tapes = Some(tapes(&[80, 80, 80]));
+    assert!(
+        game.action_release_live(LiveFormat::Album, Some(0))
+            .is_err(),
+        "no studio record, no set worth taping"
+    );
+
+    let mut game = game_with_catalogue(50);
+    game.live_tapes = Some(tapes(&[80; LIVE_MIN_SHOWS - 1]));
+    assert!(game.live_release_quality(LiveFormat::Album).is_err());
+
+    game.live_tapes = Some(tapes(&[80; LIVE_MIN_SHOWS]));
+    assert!(!game.timeline.is_video_era(), "the game opens in 1970");
+    assert!(
+        game.action_release_live(LiveFormat::ConcertFilm, Some(0))
+            .is_err()
+    );
+    assert!(
+        game.live_tapes.is_some(),
+        "a refused release keeps the tapes"
+    );
+}
+
+#[test]
+fn a_live_album_only_counts_toward_a_deal_when_the_label_agrees() {
+    for (reception, counts) in [(40, false), (100, true)] {
+        let mut game = game_with_catalogue(90);
+        game.band.sign_deal(test_deal(60, 0.12));
+        game.live_tapes = Some(tapes(&[reception; LIVE_MIN_SHOWS]));
+        game.action_release_live(LiveFormat::Album, None).unwrap();
+        assert_eq!(game.just_released_music[0].outside_deal, !counts);
+
+        game.week += INITIAL_SALES_WINDOW_WEEKS;
+        game.process_music_releases_and_marketing();
+        assert!(
+            game.band
+                .albums_released
+                .iter()
+                .any(|album| album.live.is_some()),
+            "the live album sells like any album"
+        );
+        assert_eq!(
+            game.band.current_deal().unwrap().albums_delivered,
+            u8::from(counts)
+        );
+    }
+}
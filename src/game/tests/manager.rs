@@ -0,0 +1,256 @@
+//! The manager (FUTURE §9.4): the pitch stream, the cut, what skill buys,
+//! the hidden skim and its discovery, and firing.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::music::MarketingCampaignType;
+use crate::game::world::PotentialManagerOffer;
+
+use super::*;
+
+/// Put a manager with the given terms on the slate and hire them.
+fn hire(game: &mut Game, skill: u8, honesty: u8, cut: f32) {
+    game.pending_manager_offers = vec![PotentialManagerOffer {
+        name: "Sid Marlowe".to_string(),
+        cut,
+        skill,
+        honesty,
+        expires_week: None,
+    }];
+    game.action_hire_manager(0)
+        .expect("the offer is on the slate");
+}
+
+#[test]
+fn a_manager_takes_a_cut_and_firing_costs_a_settlement() {
+    let mut game = test_game();
+    game.week = 10;
+    game.player.money = 0;
+    hire(&mut game, 50, 100, 0.20);
+    assert!(
+        game.action_hire_manager(0).is_err(),
+        "one manager at a time"
+    );
+
+    assert_eq!(
+        game.bank_income(1_000, LedgerCategory::Shows, "Gig"),
+        800,
+        "20% comes off the top"
+    );
+    assert_eq!(game.player.money, 800);
+    assert_eq!(game.manager.as_ref().unwrap().commission_paid, 200);
+
+    // $200 over 10 weeks is $20/week; the settlement is 8 weeks of that.
+    game.week = 20;
+    assert_eq!(game.manager_severance(), 160);
+    game.action_fire_manager()
+        .expect("there's a manager to fire");
+    assert!(game.manager.is_none());
+    assert_eq!(game.player.money, 640);
+    assert_eq!(game.manager_cooldown, MANAGER_COOLDOWN_WEEKS);
+    assert!(game.action_fire_manager().is_err(), "nobody left to fire");
+
+    // Without a manager, income is untouched again.
+    assert_eq!(game.bank_income(1_000, LedgerCategory::Shows, "Gig"), 1_000);
+}
+
+#[test]
+fn the_cooldown_keeps_managers_away_then_lifts() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    game.manager_cooldown = MANAGER_COOLDOWN_WEEKS;
+
+    for attempt in 0..40 {
+        game.manager_scout_weeks = MANAGER_SCOUT_INTERVAL_WEEKS;
+        game.manager_weekly_check(0, &mut StdRng::seed_from_u64(attempt));
+    }
+    assert!(
+        game.pending_manager_offers.is_empty(),
+        "nobody calls during the cooldown"
+    );
+
+    game.manager_weekly_check(MANAGER_COOLDOWN_WEEKS, &mut StdRng::seed_from_u64(0));
+    assert_eq!(game.manager_cooldown, 0, "the cooldown runs down with time");
+    let mut pitched = false;
+    for attempt in 0..40 {
+        game.manager_scout_weeks = MANAGER_SCOUT_INTERVAL_WEEKS;
+        game.manager_weekly_check(0, &mut StdRng::seed_from_u64(attempt));
+        if !game.pending_manager_offers.is_empty() {
+            pitched = true;
+            break;
+        }
+    }
+    assert!(
+        pitched,
+        "a famous act draws pitches once the cooldown lifts"
+    );
+}
+
+#[test]
+fn managers_pitch_a_buzzing_act_and_their_offers_lapse() {
+    let mut game = test_game();
+    game.week = 4;
+
+    // Nobody has heard of you: nobody calls.
+    for attempt in 0..40 {
+        game.manager_scout_weeks = MANAGER_SCOUT_INTERVAL_WEEKS;
+        game.manager_weekly_check(0, &mut StdRng::seed_from_u64(attempt));
+    }
+    assert!(game.pending_manager_offers.is_empty());
+
+    game.band.fame = 60;
+    for attempt in 0..40 {
+        game.manager_scout_weeks = MANAGER_SCOUT_INTERVAL_WEEKS;
+        game.manager_weekly_check(0, &mut StdRng::seed_from_u64(attempt));
+        if !game.pending_manager_offers.is_empty() {
+            break;
+        }
+    }
+    assert!(!game.pending_manager_offers.is_empty());
+    for offer in &game.pending_manager_offers {
+        assert!((MANAGER_CUT_MIN..=MANAGER_CUT_MAX).contains(&offer.cut));
+        assert!(offer.skill <= 100);
+        assert_eq!(offer.expires_week, Some(4 + MANAGER_OFFER_LIFETIME_WEEKS));
+    }
+
+    game.take_turn_log();
+    game.week = 4 + MANAGER_OFFER_LIFETIME_WEEKS;
+    game.manager_weekly_check(0, &mut StdRng::seed_from_u64(0));
+    assert!(
+        game.pending_manager_offers.is_empty(),
+        "ignored pitches lapse"
+    );
+    let log = game.take_turn_log().join("\n");
+    assert!(log.contains("another act to manage"), "got: {log}");
+}
+
+#[test]
+fn a_skilled_manager_opens_doors_and_cuts_costs() {
+    let mut game = test_game();
+    game.band.fame = 40;
+    let region_index = game
+        .get_sorted_regions()
+        .iter()
+        .position(|(_, _, _, _, _, fame_req)| *fame_req <= 40)
+        .expect("a region open at fame 40");
+    let tour_alone = game.quote_tour(region_index, TourRig::Van, 2).unwrap().cost;
+    let press_alone = game.marketing_cost(MarketingCampaignType::MusicVideo);
+    let venues_alone = game
+        .world
+        .venues
+        .iter()
+        .filter(|venue| game.venue_in_reach(venue))
+        .count();
+    assert_eq!(game.manager_royalty_bump(), 0.0);
+
+    hire(&mut game, 100, 100, 0.25);
+    let tour_managed = game.quote_tour(region_index, TourRig::Van, 2).unwrap().cost;
+    assert!(
+        tour_managed < tour_alone,
+        "tour costs fall: {tour_alone} → {tour_managed}"
+    );
+    assert_eq!(
+        game.marketing_cost(MarketingCampaignType::MusicVideo),
+        (press_alone as f32 * (1.0 - MANAGER_MARKETING_DISCOUNT_MAX)).round() as i32
+    );
+    let venues_managed = game
+        .world
+        .venues
+        .iter()
+        .filter(|venue| game.venue_in_reach(venue))
+        .count();
+    assert!(
+        venues_managed > venues_alone,
+        "a top manager books a tier early: {venues_alone} → {venues_managed}"
+    );
+
+    // Every fresh label offer arrives a few points richer.
+    assert!((game.manager_royalty_bump() - MANAGER_ROYALTY_BUMP_MAX).abs() < f32::EPSILON);
+    game.band.fame = 30;
+    game.band
+        .singles_released
+        .push(test_release(1, ReleaseType::Single));
+    game.week = 4;
+    let mut bumped = None;
+    for attempt in 0..80 {
+        game.check_and_generate_deal_offers(&mut StdRng::seed_from_u64(attempt));
+        if let Some(offer) = game.pending_deal_offers.first() {
+            bumped = Some(offer.clone());
+            break;
+        }
+    }
+    let offer = bumped.expect("an indie should bite eventually");
+    let list_rate = offer.original_label_data.royalty_rate as f32 / 100.0;
+    assert!((offer.royalty_rate - (list_rate + MANAGER_ROYALTY_BUMP_MAX)).abs() < 1e-6);
+}
+
+#[test]
+fn an_honest_manager_never_skims() {
+    let mut game = test_game();
+    hire(&mut game, 50, MANAGER_HONEST_THRESHOLD, 0.10);
+    game.bank_income(10_000, LedgerCategory::Shows, "Gig");
+    assert_eq!(game.manager.as_ref().unwrap().skimmed, 0);
+
+    for attempt in 0..200 {
+        game.manager_weekly_check(1, &mut StdRng::seed_from_u64(attempt));
+    }
+    assert!(game.manager.is_some(), "nothing to discover");
+}
+
+#[test]
+fn a_crooked_manager_skims_silently_until_caught() {
+    let mut game = test_game();
+    game.player.money = 0;
+    game.player.happiness = 80;
+    hire(&mut game, 50, 0, 0.10);
+
+    // 10% commission in the open, the full skim on top of it.
+    let net = game.bank_income(1_000, LedgerCategory::Shows, "Gig");
+    assert_eq!(net, 1_000 - 100 - (1_000.0 * MANAGER_SKIM_MAX) as u32);
+    let manager = game.manager.as_ref().unwrap();
+    assert_eq!(manager.commission_paid, 100, "the books show only the cut");
+    assert!(manager.skimmed > 0);
+    game.take_turn_log();
+
+    let mut weeks = 0;
+    while game.manager.is_some() {
+        game.manager_weekly_check(1, &mut StdRng::seed_from_u64(weeks));
+        weeks += 1;
+        assert!(weeks < 1_000, "a skim is discovered eventually");
+    }
+    assert_eq!(game.manager_cooldown, MANAGER_COOLDOWN_WEEKS);
+    assert_eq!(game.player.happiness, 80 - MANAGER_DISCOVERY_HAPPINESS_HIT);
+    assert_eq!(game.player.money, net as i32, "the skimmed money is gone");
+    let log = game.take_turn_log().join("\n");
+    assert!(log.contains("SCANDAL"), "got: {log}");
+}
+
+#[test]
+fn a_long_turn_still_steps_onto_the_scouting_beat() {
+    let mut game = test_game();
+    game.band.fame = 80;
+    let mut pitched = false;
+    for attempt in 0..40 {
+        // A six-week tour from week 3 of the cycle crosses the beat.
+        game.manager_scout_weeks = 3;
+        game.manager_weekly_check(6, &mut StdRng::seed_from_u64(attempt));
+        assert_eq!(game.manager_scout_weeks, 1, "the cycle keeps its phase");
+        if !game.pending_manager_offers.is_empty() {
+            pitched = true;
+            break;
+        }
+    }
+    assert!(pitched, "managers scout during a long turn");
+
+    // A week short of the beat, nobody scouts.
+    game.pending_manager_offers.clear();
+    for attempt in 0..40 {
+        game.manager_scout_weeks = 0;
+        game.manager_weekly_check(
+            MANAGER_SCOUT_INTERVAL_WEEKS - 1,
+            &mut StdRng::seed_from_u64(attempt),
+        );
+    }
+    assert!(game.pending_manager_offers.is_empty());
+}
@@ -11,23 +11,43 @@ use crate::game::world::PotentialDealOffer;
 use super::constants::{self, *};
 use super::*;
 
+mod a_and_r;
+mod catalogue;
 mod certifications;
 mod deal_lifecycle;
 mod deals;
+mod debt;
 mod determinism;
 mod fame;
+mod festivals;
+mod gear;
+mod headline_tours;
 mod history;
 mod incidents;
+mod investments;
 mod label_moves;
+mod labels;
+mod ledger;
 mod lifestyle;
+mod live_records;
+mod manager;
+mod negotiation;
+mod openers;
+mod own_label;
 mod recoupment;
+mod regional_fame;
 mod releases;
 mod repress;
+mod routes;
 mod save_compat;
+mod setlists;
 mod shows;
 mod smoke;
 mod studio;
 mod support;
+mod takeovers;
+mod tax;
+mod venues;
 
 fn test_game() -> Game {
     Game::new().expect("data files present")
@@ -53,6 +73,10 @@ fn test_release(id: u32, release_type: ReleaseType) -> Release {
         certified: 0,
         distribution_channel: None,
         label_market_reach: None,
+        live: None,
+        outside_deal: false,
+        master_owner: None,
+        rerecording_of: None,
     }
 }
 
@@ -76,7 +100,7 @@ fn give_regional_presence(game: &mut Game, level: u8) {
 /// The biggest venue whose door policy admits the band right now.
 fn best_open_venue(game: &Game) -> usize {
     (0..game.world.venues.len())
-        .filter(|&i| game.world.venues[i].prestige <= game.band.fame.saturating_add(20))
+        .filter(|&i| game.venue_in_reach(&game.world.venues[i]))
         .max_by_key(|&i| game.world.venues[i].capacity)
         .expect("at least one venue is always open")
 }
@@ -102,6 +126,8 @@ fn test_deal(market_reach: u8, royalty_rate: f32) -> band::RecordDeal {
         // window looks the label up by name.
         signed_week: 0,
         term_weeks: 0,
+        creative_control: band::CreativeControl::Shared,
+        defiances: 0,
     }
 }
 
@@ -118,5 +144,7 @@ fn test_deal_offer(game: &Game, expires_week: Option<u32>) -> PotentialDealOffer
         expires_week,
         term_weeks: 90,
         carry_forward_unrecouped: 0,
+        creative_control: band::CreativeControl::Shared,
+        counters: 0,
     }
 }
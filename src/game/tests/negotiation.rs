@@ -0,0 +1,201 @@
+//! Contract negotiation (`negotiation.rs`): what a counter costs a label,
+//! how it answers by the band's buzz, the bounds on a counter, and the
+//! creative-control clause carried into the signed deal.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::band::CreativeControl;
+use crate::game::negotiation::counter_greed;
+
+use super::*;
+
+fn offered(game: &mut Game) -> DealCounter {
+    game.pending_deal_offers = vec![test_deal_offer(game, None)];
+    DealCounter::from_offer(&game.pending_deal_offers[0])
+}
+
+/// Fame at the top and a catalogue behind it: as much buzz as a band
+/// without a chart record gets.
+fn hot_band() -> Game {
+    let mut game = test_game();
+    game.band.fame = 100;
+    game.band.albums_released = (1..=3)
+        .map(|id| test_release(id, ReleaseType::Album))
+        .collect();
+    game
+}
+
+fn counter(game: &mut Game, counter: DealCounter, seed: u64) -> Result<(), String> {
+    game.action_counter_deal(0, counter, &mut StdRng::seed_from_u64(seed))
+}
+
+#[test]
+fn a_counter_costs_the_label_by_what_it_gives_up() {
+    let game = test_game();
+    let mut offer = test_deal_offer(&game, None);
+    let mut ask = DealCounter::from_offer(&offer);
+    assert_eq!(counter_greed(&offer, &ask), 0.0);
+
+    ask.royalty_rate += 0.02;
+    let royalty_only = counter_greed(&offer, &ask);
+    assert!(royalty_only > 0.0);
+    ask.albums_required += 1;
+    assert!(
+        counter_greed(&offer, &ask) < royalty_only,
+        "an extra album buys some of it back"
+    );
+
+    let mut ask = DealCounter::from_offer(&offer);
+    ask.advance += 5_000;
+    ask.creative_control = CreativeControl::BandLed;
+    offer.original_label_data.financial_power = 20;
+    offer.original_label_data.creative_freedom = 20;
+    let stingy = counter_greed(&offer, &ask);
+    offer.original_label_data.financial_power = 90;
+    offer.original_label_data.creative_freedom = 90;
+    assert!(
+        counter_greed(&offer, &ask) < stingy,
+        "a rich, hands-off label minds the same ask less"
+    );
+}
+
+#[test]
+fn a_hot_band_gets_its_terms() {
+    let mut game = hot_band();
+    let mut ask = offered(&mut game);
+    ask.royalty_rate += 0.02;
+    ask.creative_control = CreativeControl::BandLed;
+    counter(&mut game, ask, 41).unwrap();
+
+    let offer = &game.pending_deal_offers[0];
+    assert_eq!(DealCounter::from_offer(offer), ask);
+    assert_eq!(offer.counters, 1);
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("agreed to your terms"))
+    );
+
+    game.action_accept_deal(0).unwrap();
+    let deal = game.band.current_deal().unwrap();
+    assert_eq!(deal.creative_control, CreativeControl::BandLed);
+    assert_eq!(deal.royalty_rate, ask.royalty_rate);
+}
+
+#[test]
+fn an_unknown_band_is_met_halfway_or_shown_the_door() {
+    let mut game = test_game();
+    let mut ask = offered(&mut game);
+    // Past anything an unknown band's appetite covers, inside twice it.
+    ask.royalty_rate += 0.025;
+    counter(&mut game, ask, 41).unwrap();
+    let offer = &game.pending_deal_offers[0];
+    assert!(offer.royalty_rate > 0.12 && offer.royalty_rate < ask.royalty_rate);
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("met you halfway"))
+    );
+
+    let mut greedy = DealCounter::from_offer(offer);
+    greedy.royalty_rate = 0.45;
+    counter(&mut game, greedy, 41).unwrap();
+    assert!(game.pending_deal_offers.is_empty(), "they walked");
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("walked away"))
+    );
+}
+
+#[test]
+fn a_label_hears_only_so_many_counters() {
+    let mut game = hot_band();
+    let ask = offered(&mut game);
+    for _ in 0..NEGOTIATION_MAX_COUNTERS {
+        counter(&mut game, ask, 7).unwrap();
+    }
+    assert!(counter(&mut game, ask, 7).is_err());
+    assert_eq!(game.pending_deal_offers.len(), 1);
+}
+
+#[test]
+fn a_counter_must_stay_within_bounds() {
+    let mut game = hot_band();
+    let ask = offered(&mut game);
+    let top = game.pending_deal_offers[0]
+        .original_label_data
+        .advance_range[1];
+    for bad in [
+        DealCounter {
+            advance: top * 2 + 1,
+            ..ask
+        },
+        DealCounter {
+            royalty_rate: NEGOTIATION_MAX_ROYALTY + 0.01,
+            ..ask
+        },
+        DealCounter {
+            albums_required: 0,
+            ..ask
+        },
+        DealCounter {
+            royalty_rate: -0.05,
+            ..ask
+        },
+        DealCounter {
+            term_weeks: NEGOTIATION_MIN_TERM_WEEKS - 1,
+            ..ask
+        },
+        DealCounter {
+            term_weeks: NEGOTIATION_MAX_TERM_WEEKS + 1,
+            ..ask
+        },
+    ] {
+        assert!(counter(&mut game, bad, 1).is_err(), "{bad:?}");
+    }
+    assert_eq!(game.pending_deal_offers[0].counters, 0);
+
+    // An offer saved without a term has none to bargain with.
+    game.pending_deal_offers[0].term_weeks = 0;
+    let termless = DealCounter {
+        term_weeks: 0,
+        ..ask
+    };
+    assert!(
+        counter(
+            &mut game,
+            DealCounter {
+                term_weeks: 1,
+                ..termless
+            },
+            1
+        )
+        .is_err()
+    );
+    assert_eq!(game.pending_deal_offers[0].counters, 0);
+    assert!(counter(&mut game, termless, 1).is_ok());
+}
+
+#[test]
+fn the_label_pushes_hardest_on_records_it_controls() {
+    let push = |control: CreativeControl| {
+        let mut game = test_game();
+        let mut deal = test_deal(70, 0.12);
+        deal.creative_control = control;
+        game.band.record_deal = Some(deal);
+        game.just_released_music
+            .push(test_release(1, ReleaseType::Single));
+        game.apply_label_promo();
+        game.just_released_music[0].marketing_level_achieved
+    };
+    let [label, shared, band] = CreativeControl::ALL.map(push);
+    assert!(label > shared && shared > band, "{label} {shared} {band}");
+    assert_eq!(
+        CreativeControl::offered_by(20),
+        CreativeControl::LabelLed,
+        "a controlling label opens with control"
+    );
+    assert_eq!(CreativeControl::offered_by(90), CreativeControl::BandLed);
+}
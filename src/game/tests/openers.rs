@@ -0,0 +1,239 @@
+//! Openers: a famous band taking a scene act out on its own tours — the
+//! draw and the fee in the quote, what the tour does for the opener, and
+//! how the rapport it builds plays out in support offers, poached deals
+//! and label rosters.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::shows::ShowVerdict;
+
+use super::*;
+
+const LEGS: [TourLeg; 1] = [TourLeg {
+    region_index: 0,
+    weeks: 2,
+}];
+
+fn with_opener(name: &str) -> TourTerms {
+    TourTerms {
+        opener: Some(name.to_string()),
+        ..TourTerms::default()
+    }
+}
+
+/// A famous band with a scene act of the given fame to take along.
+fn opener_game(opener_fame: u8) -> (Game, String) {
+    let mut game = test_game();
+    game.band.fame = 70;
+    game.player.money = 1_000_000;
+    let opener = &mut game.world.bands[0];
+    opener.fame = opener_fame;
+    opener.momentum = 0;
+    opener.rapport = 0;
+    let name = opener.name.clone();
+    (game, name)
+}
+
+#[test]
+fn an_opener_needs_a_famous_headliner_a_smaller_act_and_no_grudge() {
+    let (mut game, name) = opener_game(30);
+    assert!(
+        game.quote_route(&LEGS, TourRig::Van, with_opener(&name))
+            .is_ok()
+    );
+    let candidates = game.opener_candidates();
+    assert!(!candidates.is_empty() && candidates.len() <= OPENER_SHORTLIST);
+    assert!(
+        candidates
+            .windows(2)
+            .all(|pair| pair[0].heat() >= pair[1].heat())
+    );
+    for band in candidates
+        .iter()
+        .map(|band| band.name.clone())
+        .collect::<Vec<_>>()
+    {
+        assert!(
+            game.quote_route(&LEGS, TourRig::Van, with_opener(&band))
+                .is_ok()
+        );
+    }
+
+    game.world.bands[0].fame = 70;
+    assert!(
+        game.quote_route(&LEGS, TourRig::Van, with_opener(&name))
+            .is_err()
+    );
+
+    // The hottest act that could open — unless it holds a grudge.
+    game.world.bands[0].fame = 69;
+    game.world.bands[0].momentum = 3;
+    assert_eq!(game.opener_candidates()[0].name, name);
+    game.world.bands[0].rapport = SCENE_RIVAL_RAPPORT;
+    assert!(
+        game.quote_route(&LEGS, TourRig::Van, with_opener(&name))
+            .is_err()
+    );
+    assert!(
+        !game
+            .opener_candidates()
+            .iter()
+            .any(|band| band.name == name)
+    );
+
+    game.world.bands[0].rapport = 0;
+    game.band.fame = OPENER_MIN_FAME - 1;
+    assert!(
+        game.quote_route(&LEGS, TourRig::Van, with_opener(&name))
+            .is_err()
+    );
+    assert!(game.opener_candidates().is_empty());
+
+    game.band.fame = 70;
+    assert!(
+        game.quote_route(&LEGS, TourRig::Van, with_opener("Nobody Of Note"))
+            .is_err()
+    );
+}
+
+#[test]
+fn a_hot_opener_lifts_the_gross_and_charges_by_the_show() {
+    let (mut game, name) = opener_game(30);
+    let plain = game
+        .quote_route(&LEGS, TourRig::Van, TourTerms::default())
+        .unwrap();
+    let cool = game
+        .quote_route(&LEGS, TourRig::Van, with_opener(&name))
+        .unwrap();
+    let plain_mid = (plain.gross_low + plain.gross_high) as f32 / 2.0;
+    let expected_fee = plain_mid * game.world.bands[0].opener_fee_share();
+    assert!(
+        (cool.opener_cost as f32 - expected_fee).abs() <= expected_fee * 0.05 + 1.0,
+        "the fee is a share of the gross without them"
+    );
+    assert_eq!(cool.cost, plain.cost + cool.opener_cost);
+    assert!(cool.gross_high > plain.gross_high);
+
+    game.world.bands[0].momentum = 3;
+    let hot = game
+        .quote_route(&LEGS, TourRig::Van, with_opener(&name))
+        .unwrap();
+    assert_eq!(hot.opener_cost, cool.opener_cost, "the fee follows fame");
+    assert!(hot.gross_high > cool.gross_high, "momentum draws a crowd");
+}
+
+#[test]
+fn opening_a_tour_builds_the_openers_fame_momentum_and_rapport() {
+    let (mut game, name) = opener_game(30);
+    game.action_tour_route(
+        &LEGS,
+        TourRig::Van,
+        with_opener(&name),
+        &mut StdRng::seed_from_u64(3),
+    )
+    .unwrap();
+
+    let verdict =
+        ShowVerdict::from_reception(game.last_tour_report.as_ref().unwrap().avg_reception);
+    let opener = game.world.scene_band(&name).unwrap();
+    assert_eq!(
+        opener.fame,
+        30 + 2 * OPENER_FAME_PER_WEEK + OPENER_FAME_BONUS[verdict as usize]
+    );
+    assert_eq!(opener.rapport, OPENER_RAPPORT_DELTA[verdict as usize]);
+    assert_eq!(opener.momentum, i8::from(verdict != ShowVerdict::Rough));
+    assert!(game.turn_log.iter().any(|line| line.contains(&name)));
+}
+
+#[test]
+fn friends_ask_first_and_rivals_never_ask() {
+    let mut game = test_game();
+    game.band.fame = 20;
+    for band in &mut game.world.bands {
+        band.rapport = SCENE_RIVAL_RAPPORT;
+    }
+    let mut rng = StdRng::seed_from_u64(9);
+    for _ in 0..300 {
+        game.update_support_tour_offer(&mut rng);
+    }
+    assert!(
+        game.pending_support_offer.is_none(),
+        "the whole scene hates you"
+    );
+
+    let friend = game
+        .world
+        .bands
+        .iter()
+        .position(|band| band.fame >= 20 + SUPPORT_OFFER_FAME_GAP)
+        .expect("a bigger act on the scene");
+    game.world.bands[friend].rapport = SCENE_FRIEND_RAPPORT;
+    for _ in 0..300 {
+        game.update_support_tour_offer(&mut rng);
+        if game.pending_support_offer.is_some() {
+            break;
+        }
+    }
+    let offer = game.pending_support_offer.as_ref().expect("a friend calls");
+    assert_eq!(offer.host_band, game.world.bands[friend].name);
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("Returning the favour"))
+    );
+}
+
+#[test]
+fn friends_leave_a_rejected_deal_and_rivals_grab_it() {
+    let mut game = test_game();
+    for band in &mut game.world.bands {
+        band.label = Some("Somebody Else".to_string());
+    }
+    let (friend, rival) = (0, 1);
+    for (idx, fame, rapport) in [
+        (friend, 60, SCENE_FRIEND_RAPPORT),
+        (rival, 25, SCENE_RIVAL_RAPPORT),
+    ] {
+        let band = &mut game.world.bands[idx];
+        band.label = None;
+        band.fame = fame;
+        band.rapport = rapport;
+    }
+
+    let mut rng = StdRng::seed_from_u64(4);
+    let poacher = (0..50)
+        .find_map(|_| game.world.poach_rejected_deal("Turned Down", &mut rng))
+        .expect("someone takes the deal");
+    assert_eq!(poacher, game.world.bands[rival].name);
+    assert!(game.world.bands[friend].label.is_none());
+}
+
+#[test]
+fn signing_names_the_friends_and_rivals_already_on_the_roster() {
+    let mut game = test_game();
+    game.pending_deal_offers = vec![test_deal_offer(&game, None)];
+    let label = game.pending_deal_offers[0].label_name.clone();
+    for (idx, rapport) in [(0, SCENE_FRIEND_RAPPORT), (1, SCENE_RIVAL_RAPPORT)] {
+        let band = &mut game.world.bands[idx];
+        band.label = Some(label.clone());
+        band.rapport = rapport;
+    }
+    let (friend, rival) = (
+        game.world.bands[0].name.clone(),
+        game.world.bands[1].name.clone(),
+    );
+
+    game.action_accept_deal(0).unwrap();
+
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("Old friends") && line.contains(&friend))
+    );
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("frosty") && line.contains(&rival))
+    );
+}
@@ -0,0 +1,190 @@
+//! The band's own label (`own_label.rs`): founding it, the channel it
+//! opens, signing scene bands for an advance, and booking the label's take
+//! on the records they put out.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::music::DistributionChannel;
+use crate::game::world::PlayerLabelRelease;
+
+use super::*;
+
+/// A famous, flush band with its label founded.
+fn label_game() -> Game {
+    let mut game = test_game();
+    game.band.fame = 90;
+    game.player.money = 200_000;
+    game.action_found_label().unwrap();
+    game
+}
+
+/// The most famous unsigned scene band, friendly enough to sign.
+fn unsigned_prospect(game: &mut Game) -> String {
+    let band = game
+        .world
+        .bands
+        .iter_mut()
+        .filter(|band| band.label.is_none() && band.fame < 90)
+        .max_by_key(|band| band.fame)
+        .expect("an unsigned band on the scene");
+    band.rapport = 0;
+    band.name.clone()
+}
+
+#[test]
+fn founding_takes_fame_and_money_and_opens_the_channel() {
+    let mut game = test_game();
+    game.player.money = 200_000;
+    game.band.fame = OWN_LABEL_MIN_FAME - 1;
+    assert!(game.action_found_label().is_err());
+    assert!(
+        game.distribution_lock(DistributionChannel::OwnLabel)
+            .is_some()
+    );
+    assert!(
+        game.plan_distribution(DistributionChannel::OwnLabel)
+            .is_err()
+    );
+
+    game.band.fame = OWN_LABEL_MIN_FAME;
+    game.player.money = OWN_LABEL_FOUNDING_COST as i32 - 1;
+    assert!(game.action_found_label().is_err(), "can't afford it");
+    game.player.money = 200_000;
+    game.action_found_label().unwrap();
+    assert_eq!(game.player.money, 200_000 - OWN_LABEL_FOUNDING_COST as i32);
+    let name = format!("{} Records", game.band.name);
+    assert_eq!(game.own_label.as_ref().unwrap().name, name);
+    assert_eq!(game.world.player_label.as_deref(), Some(name.as_str()));
+    assert!(game.action_found_label().is_err(), "one label is enough");
+
+    assert_eq!(game.distribution_lock(DistributionChannel::OwnLabel), None);
+    assert_eq!(game.plan_distribution(DistributionChannel::OwnLabel), Ok(0));
+    assert!(
+        DistributionChannel::OwnLabel.reach_floor() > DistributionChannel::National.reach_floor(),
+        "a real label's reach"
+    );
+}
+
+#[test]
+fn signing_pays_the_advance_and_dropping_frees_the_act() {
+    let mut game = label_game();
+    let name = unsigned_prospect(&mut game);
+    let advance = Game::signing_advance(game.world.scene_band(&name).unwrap());
+    let label_name = game.own_label.as_ref().unwrap().name.clone();
+    let money = game.player.money;
+
+    game.action_sign_to_label(&name).unwrap();
+    assert_eq!(game.player.money, money - advance as i32);
+    assert_eq!(
+        game.world.scene_band(&name).unwrap().label.as_deref(),
+        Some(label_name.as_str())
+    );
+    assert_eq!(game.label_rows()[0], (name.clone(), true));
+    assert!(game.action_sign_to_label(&name).is_err(), "already signed");
+    assert!(
+        game.label_prospects().iter().all(|band| band.name != name),
+        "no longer a prospect"
+    );
+
+    let bigger = game.world.bands[0].name.clone();
+    game.world.bands[0].label = None;
+    game.world.bands[0].fame = 95;
+    assert!(
+        game.action_sign_to_label(&bigger).is_err(),
+        "too big to sign to you"
+    );
+
+    game.action_drop_from_label(&name).unwrap();
+    assert_eq!(game.world.scene_band(&name).unwrap().label, None);
+    assert!(game.own_label.as_ref().unwrap().acts.is_empty());
+    assert!(game.action_drop_from_label(&name).is_err());
+}
+
+#[test]
+fn the_scene_routes_an_acts_records_to_the_label() {
+    let mut game = label_game();
+    for _ in 0..OWN_LABEL_MAX_ACTS {
+        let name = unsigned_prospect(&mut game);
+        game.action_sign_to_label(&name).unwrap();
+    }
+    let label_name = game.own_label.as_ref().unwrap().name.clone();
+    let one_too_many = unsigned_prospect(&mut game);
+    assert!(
+        game.action_sign_to_label(&one_too_many).is_err(),
+        "the roster is full"
+    );
+
+    let mut rng = StdRng::seed_from_u64(5);
+    for _ in 0..200 {
+        if !game.world.player_label_releases.is_empty() {
+            break;
+        }
+        game.world
+            .update_week(&game.timeline, &game.data_files, &mut rng);
+    }
+    let release = game
+        .world
+        .player_label_releases
+        .first()
+        .cloned()
+        .expect("an act put a record out on the label");
+    assert_eq!(
+        game.world
+            .scene_band(&release.band)
+            .unwrap()
+            .label
+            .as_deref(),
+        Some(label_name.as_str())
+    );
+    assert!(
+        game.world.label(&label_name).is_none(),
+        "no data label behind the band's own"
+    );
+
+    game.own_label_weekly_update(1);
+    assert!(game.world.player_label_releases.is_empty());
+    assert!(game.ledger.iter().any(|entry| {
+        entry.category == LedgerCategory::Label
+            && entry.amount > 0
+            && entry.note.contains(&release.band)
+    }));
+}
+
+#[test]
+fn the_label_books_its_take_and_pays_its_way() {
+    let mut game = label_game();
+    let name = unsigned_prospect(&mut game);
+    game.action_sign_to_label(&name).unwrap();
+    let fame = game.world.scene_band(&name).unwrap().fame;
+    let market = game.world.get_market_modifier();
+    game.world.player_label_releases.push(PlayerLabelRelease {
+        band: name.clone(),
+        title: "Test Single".to_string(),
+        score: 100,
+        best_position: Some(1),
+    });
+
+    let money = game.player.money;
+    game.own_label_weekly_update(1);
+    let take =
+        (100.0 * LABEL_TAKE_PER_SCORE * f32::from(OWN_LABEL_MARKET_REACH) / 100.0 * market) as u32;
+    let catalogue = (LABEL_CATALOGUE_PER_FAME as f32 * f32::from(fame) * market) as u32;
+    assert_eq!(
+        game.player.money,
+        money + take as i32 + catalogue as i32 - game.own_label_overhead() as i32
+    );
+    let act = &game.own_label.as_ref().unwrap().acts[0];
+    assert_eq!((act.releases, act.hits), (1, 1));
+    assert_eq!(act.earned, take + catalogue);
+
+    // An act that splits up leaves the roster.
+    game.world.bands.retain(|band| band.name != name);
+    game.own_label_weekly_update(1);
+    assert!(game.own_label.as_ref().unwrap().acts.is_empty());
+
+    // The receiver closes the label.
+    game.close_own_label();
+    assert!(game.own_label.is_none());
+    assert_eq!(game.world.player_label, None);
+}
@@ -0,0 +1,155 @@
+//! Regional fame (`regional.rs`): neglected regions fade after their grace
+//! weeks, a charting record holds its country, touring restarts the clock,
+//! and sales are booked to the territories that bought them.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use super::*;
+
+const BERLIN: &str = "europe:germany";
+
+fn known_in_germany(fame: u8) -> Game {
+    let mut game = test_game();
+    game.week = 10;
+    game.regional_fame.insert(BERLIN.to_string(), fame);
+    game.mark_region_visited(BERLIN);
+    game
+}
+
+#[test]
+fn a_region_remembers_the_band_through_its_grace_weeks() {
+    let mut game = known_in_germany(40);
+    game.week += REGIONAL_FAME_GRACE_WEEKS;
+    game.update_regional_fame(REGIONAL_FAME_GRACE_WEEKS);
+    assert_eq!(game.regional_fame[BERLIN], 40);
+
+    game.week += 4;
+    game.update_regional_fame(4);
+    assert_eq!(
+        game.regional_fame[BERLIN],
+        40 - 4 * REGIONAL_FAME_DECAY_PER_WEEK
+    );
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("is forgetting you")),
+        "dropping below 40 makes the news"
+    );
+}
+
+#[test]
+fn a_neglected_region_fades_to_its_floor_and_no_further() {
+    let mut game = known_in_germany(30);
+    game.week += REGIONAL_FAME_GRACE_WEEKS + 200;
+    game.update_regional_fame(200);
+    assert_eq!(game.regional_fame[BERLIN], REGIONAL_FAME_FLOOR);
+
+    // A region that never knew the band more than the floor isn't touched.
+    game.regional_fame.insert("japan:tokyo".to_string(), 3);
+    game.week += 100;
+    game.update_regional_fame(100);
+    assert_eq!(game.regional_fame["japan:tokyo"], 3);
+}
+
+#[test]
+fn fame_with_no_recorded_visit_starts_its_clock_now() {
+    let mut game = test_game();
+    game.week = 300;
+    game.regional_fame.insert(BERLIN.to_string(), 50);
+    game.update_regional_fame(1);
+    assert_eq!(game.regional_fame[BERLIN], 50, "an old save isn't punished");
+    assert_eq!(game.regional_last_visit[BERLIN], 300);
+}
+
+#[test]
+fn a_record_on_the_countrys_chart_holds_its_regions() {
+    let mut game = known_in_germany(40);
+    game.world.submit_chart_entry(
+        world::ChartRegion::Europe,
+        "Big In Germany".to_string(),
+        game.band.name.clone(),
+        true,
+        u32::MAX / 2,
+    );
+    game.week += REGIONAL_FAME_GRACE_WEEKS + 10;
+    game.update_regional_fame(10);
+    assert_eq!(game.regional_fame[BERLIN], 40);
+}
+
+#[test]
+fn touring_a_region_restarts_its_clock() {
+    let mut game = known_in_germany(40);
+    game.band.fame = 90;
+    game.player.money = 1_000_000;
+    game.week += REGIONAL_FAME_GRACE_WEEKS;
+    let germany = game
+        .get_sorted_regions()
+        .iter()
+        .position(|(country, region, ..)| format!("{country}:{region}") == BERLIN)
+        .unwrap();
+    let legs = [TourLeg {
+        region_index: germany,
+        weeks: 1,
+    }];
+    let week = game.week;
+    game.action_tour_route(
+        &legs,
+        TourRig::Van,
+        TourTerms::default(),
+        &mut StdRng::seed_from_u64(40),
+    )
+    .unwrap();
+    assert_eq!(game.regional_last_visit[BERLIN], week);
+}
+
+#[test]
+fn sales_are_booked_to_the_territories_that_bought_them() {
+    let mut game = test_game();
+    // Known only at home: every copy sold in the UK.
+    game.credit_territory_sales(1_000, [0.5, 0.0, 0.0, 0.0]);
+    assert_eq!(game.copies_by_country["united_kingdom"], 1_000);
+    assert!(!game.copies_by_country.contains_key("japan"));
+
+    // Even presence at home and in America: an even split.
+    game.credit_territory_sales(1_000, [0.5, 0.0, 0.5, 0.0]);
+    assert_eq!(game.copies_by_country["united_kingdom"], 1_500);
+    assert_eq!(game.copies_by_country["united_states"], 500);
+}
+
+#[test]
+fn the_fame_map_lists_every_country_and_region() {
+    let mut game = known_in_germany(40);
+    game.copies_by_country.insert("europe".to_string(), 1_234);
+    let map = game.fame_map();
+    let territories: Vec<_> = map.iter().filter_map(|country| country.territory).collect();
+    assert_eq!(territories, world::ChartRegion::TERRITORIES);
+    let australia = map.last().unwrap();
+    assert_eq!(australia.name, "Australia");
+    assert_eq!(australia.territory, None);
+    assert_eq!(
+        map.iter()
+            .map(|country| country.regions.len())
+            .sum::<usize>(),
+        game.get_sorted_regions().len()
+    );
+
+    let europe = map
+        .iter()
+        .find(|country| country.territory == Some(world::ChartRegion::Europe))
+        .unwrap();
+    assert_eq!(europe.copies_sold, 1_234);
+    assert_eq!(europe.chart_position, None);
+    let germany = europe
+        .regions
+        .iter()
+        .find(|region| region.fame == 40)
+        .expect("Germany is on the map with its fame");
+    assert_eq!(germany.last_visit, Some(10));
+    assert!(
+        map.iter()
+            .flat_map(|country| &country.regions)
+            .filter(|region| region.last_visit.is_none())
+            .all(|region| region.fame == 0)
+    );
+}
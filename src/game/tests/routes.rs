@@ -0,0 +1,160 @@
+//! Routed (multi-region) tour tests: the route quote, its gates, and that
+//! booking a route charges what it quoted (`actions/live.rs`).
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use super::*;
+
+/// Index of `country:region` in the region picker's ordering.
+fn region_index(game: &Game, country: &str, region: &str) -> usize {
+    game.get_sorted_regions()
+        .iter()
+        .position(|(c, r, ..)| c == country && r == region)
+        .expect("region should exist in markets.json")
+}
+
+fn leg(region_index: usize, weeks: u8) -> TourLeg {
+    TourLeg {
+        region_index,
+        weeks,
+    }
+}
+
+#[test]
+fn a_single_stop_route_quotes_exactly_like_a_tour() {
+    let mut game = test_game();
+    game.band.fame = 90;
+
+    let tour = game.quote_tour(0, TourRig::Bus, 3).unwrap();
+    let route = game
+        .quote_route(&[leg(0, 3)], TourRig::Bus, TourTerms::default())
+        .unwrap();
+
+    assert!(route.travel.is_empty(), "one stop means no travel legs");
+    assert_eq!(route.cost, tour.cost);
+    assert_eq!(route.gross_low, tour.gross_low);
+    assert_eq!(route.gross_high, tour.gross_high);
+    assert_eq!(route.fame_gain, tour.fame_gain);
+    assert_eq!(route.shows, tour.shows);
+}
+
+#[test]
+fn route_travel_is_priced_domestic_or_international() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    let germany = region_index(&game, "europe", "germany");
+    let tokyo = region_index(&game, "japan", "tokyo");
+    let osaka = region_index(&game, "japan", "osaka");
+    let costs = &game.data_files.markets_data.market_modifiers.touring_costs["van_tour"];
+
+    let quote = game
+        .quote_route(
+            &[leg(germany, 2), leg(tokyo, 2), leg(osaka, 1)],
+            TourRig::Van,
+            TourTerms::default(),
+        )
+        .unwrap();
+
+    assert_eq!(quote.travel.len(), 2);
+    assert!(
+        quote.travel[0].international,
+        "Germany → Tokyo crosses borders"
+    );
+    assert_eq!(quote.travel[0].cost, costs.international_leg_cost as i32);
+    assert!(
+        !quote.travel[1].international,
+        "Tokyo → Osaka stays in Japan"
+    );
+    assert_eq!(quote.travel[1].cost, costs.domestic_leg_cost as i32);
+
+    let stops: i32 = quote.stops.iter().map(|s| s.cost).sum();
+    let travel: i32 = quote.travel.iter().map(|t| t.cost).sum();
+    assert_eq!(quote.cost, stops + travel, "the total is stops plus travel");
+    assert_eq!(quote.weeks, 5);
+    assert_eq!(quote.shows, 5 * SHOWS_PER_TOUR_WEEK);
+}
+
+#[test]
+fn route_gates_reject_bad_itineraries() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    let germany = region_index(&game, "europe", "germany");
+    let france = region_index(&game, "europe", "france");
+    let tokyo = region_index(&game, "japan", "tokyo");
+    let osaka = region_index(&game, "japan", "osaka");
+    let sydney = region_index(&game, "australia", "sydney");
+
+    assert!(
+        game.quote_route(&[], TourRig::Van, TourTerms::default())
+            .is_err(),
+        "empty route"
+    );
+    assert!(
+        game.quote_route(
+            &[leg(germany, 1), leg(germany, 1)],
+            TourRig::Van,
+            TourTerms::default()
+        )
+        .is_err(),
+        "a region can only be visited once per route"
+    );
+    assert!(
+        game.quote_route(
+            &[leg(germany, 4), leg(tokyo, 4), leg(osaka, 4)],
+            TourRig::Van,
+            TourTerms::default()
+        )
+        .is_err(),
+        "a route is capped at TOUR_ROUTE_MAX_WEEKS"
+    );
+    let five: Vec<TourLeg> = [germany, france, tokyo, osaka, sydney]
+        .into_iter()
+        .map(|i| leg(i, 1))
+        .collect();
+    assert!(five.len() > TOUR_ROUTE_MAX_STOPS);
+    assert!(
+        game.quote_route(&five, TourRig::Van, TourTerms::default())
+            .is_err(),
+        "a route is capped at TOUR_ROUTE_MAX_STOPS"
+    );
+
+    game.band.fame = TOUR_ROUTE_FAME_GATE - 1;
+    assert!(
+        game.quote_route(&[leg(0, 1), leg(1, 1)], TourRig::Van, TourTerms::default())
+            .is_err(),
+        "multi-stop routes are gated on fame"
+    );
+}
+
+#[test]
+fn booking_a_route_charges_the_quote_and_plays_every_stop() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    game.player.money = 1_000_000;
+    let germany = region_index(&game, "europe", "germany");
+    let tokyo = region_index(&game, "japan", "tokyo");
+    let legs = [leg(germany, 2), leg(tokyo, 3)];
+    let mut rng = StdRng::seed_from_u64(300);
+
+    let quote = game
+        .quote_route(&legs, TourRig::Truck, TourTerms::default())
+        .unwrap();
+    let money_before = game.player.money;
+    game.action_tour_route(&legs, TourRig::Truck, TourTerms::default(), &mut rng)
+        .expect("a well-off, famous band should be able to book this route");
+
+    let report = game
+        .last_tour_report
+        .clone()
+        .expect("a route produces a report");
+    let gross: i32 = report.rows.iter().map(|r| r.take as i32).sum();
+    assert_eq!(report.rows.len(), 5 * SHOWS_PER_TOUR_WEEK as usize);
+    assert_eq!(game.player.money, money_before - quote.cost + gross);
+    for key in ["europe:germany", "japan:tokyo"] {
+        assert!(
+            game.regional_fame.get(key).copied().unwrap_or(0) > 0,
+            "every stop on the route should gain regional fame ({key})"
+        );
+    }
+}
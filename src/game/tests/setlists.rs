@@ -0,0 +1,159 @@
+//! Setlists (`setlist.rs`): hits lifting the night, new songs costing a
+//! little and sharpening on the road, and a short set sinking every show.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::music::Song;
+
+use super::*;
+
+const LEGS: [TourLeg; 1] = [TourLeg {
+    region_index: 0,
+    weeks: 2,
+}];
+
+/// A band with a chart-topping, big-selling single, a flop album, and
+/// enough unreleased songs to fill a set.
+fn catalogue_game() -> Game {
+    let mut game = test_game();
+    game.band.fame = 60;
+    game.player.money = 1_000_000;
+    let mut hit = test_release(1, ReleaseType::Single);
+    hit.peak_chart_position = Some(1);
+    hit.copies_sold = SETLIST_SALES_FAMILIAR;
+    game.band.singles_released = vec![hit];
+    game.band.albums_released = vec![test_release(2, ReleaseType::Album)];
+    game.band.unreleased_songs = (10..20)
+        .map(|id| Song {
+            id,
+            name: format!("New Song {id}"),
+            songwriting_quality: 50,
+        })
+        .collect();
+    game
+}
+
+fn set_of(songs: &[SetlistSong]) -> TourTerms {
+    TourTerms {
+        setlist: songs.to_vec(),
+        ..TourTerms::default()
+    }
+}
+
+#[test]
+fn hits_lift_the_set_and_new_songs_and_short_sets_cost_it() {
+    let game = catalogue_game();
+    assert_eq!(game.setlist_reception_shift(&[]), 0.0, "the usual set");
+
+    let full_of_hits = vec![SetlistSong::Release(1); SETLIST_MIN_SONGS];
+    assert!(
+        (game.setlist_reception_shift(&full_of_hits) - SETLIST_FAMILIARITY_MAX).abs() < 0.01,
+        "a #1 that sold out its familiarity mark is as familiar as a song gets"
+    );
+
+    let new: Vec<SetlistSong> = (10..10 + SETLIST_MIN_SONGS as u32)
+        .map(SetlistSong::Unreleased)
+        .collect();
+    assert_eq!(
+        game.setlist_reception_shift(&new),
+        -(SETLIST_MIN_SONGS as f32) * SETLIST_NEW_SONG_COST
+    );
+
+    let mixed: Vec<SetlistSong> = [SetlistSong::Release(1), SetlistSong::Release(2)]
+        .into_iter()
+        .chain(new[..SETLIST_MIN_SONGS - 2].iter().copied())
+        .collect();
+    let shift = game.setlist_reception_shift(&mixed);
+    assert!(shift > game.setlist_reception_shift(&new));
+
+    // Two songs short: the hit and the flop averaged over six, four new
+    // songs, and two missing.
+    let short = &mixed[..SETLIST_MIN_SONGS - 2];
+    let expected = SETLIST_FAMILIARITY_MAX / short.len() as f32
+        - (short.len() - 2) as f32 * SETLIST_NEW_SONG_COST
+        - 2.0 * SETLIST_SHORT_SONG_PENALTY;
+    assert!((game.setlist_reception_shift(short) - expected).abs() < 0.01);
+}
+
+#[test]
+fn a_setlist_must_be_playable() {
+    let game = catalogue_game();
+    let quote = |songs: &[SetlistSong]| game.quote_route(&LEGS, TourRig::Van, set_of(songs));
+
+    assert!(quote(&[SetlistSong::Release(1), SetlistSong::Unreleased(10)]).is_ok());
+    assert!(quote(&[SetlistSong::Release(1), SetlistSong::Release(1)]).is_err());
+    assert!(quote(&[SetlistSong::Release(99)]).is_err());
+    assert!(quote(&[SetlistSong::Unreleased(1)]).is_err());
+
+    let too_long: Vec<SetlistSong> = (0..=SETLIST_MAX_SONGS as u32)
+        .map(SetlistSong::Unreleased)
+        .collect();
+    assert!(quote(&too_long).is_err());
+}
+
+/// Same seed, same rolls: a set heavy on new material plays lower than the
+/// usual set does, and the unreleased songs played come home sharper.
+#[test]
+fn a_tour_plays_the_set_and_road_tests_the_new_songs() {
+    let receptions = |terms: TourTerms| {
+        let mut game = catalogue_game();
+        game.action_tour_route(&LEGS, TourRig::Van, terms, &mut StdRng::seed_from_u64(39))
+            .unwrap();
+        let receptions: Vec<u8> = game
+            .last_tour_report
+            .as_ref()
+            .unwrap()
+            .rows
+            .iter()
+            .map(|row| row.reception)
+            .collect();
+        (game, receptions)
+    };
+
+    let (_, usual) = receptions(TourTerms::default());
+    let mut hits = vec![SetlistSong::Release(1)];
+    hits.extend((10..10 + SETLIST_MIN_SONGS as u32 - 1).map(SetlistSong::Unreleased));
+    let (game, with_set) = receptions(set_of(&hits));
+    // Every roll is the same; the first show differs by the set's shift
+    // alone (later ones also feel the reputation earlier shows built).
+    let shift = game.setlist_reception_shift(&hits);
+    assert!(shift < 0.0, "one hit can't carry seven new songs");
+    assert_eq!(
+        with_set[0],
+        (f32::from(usual[0]) + shift).round().clamp(0.0, 100.0) as u8
+    );
+    let total = |receptions: &[u8]| receptions.iter().map(|&r| u32::from(r)).sum::<u32>();
+    assert!(total(&with_set) < total(&usual));
+
+    let shows = with_set.len() as u32;
+    let gain = (shows / SETLIST_ROAD_TEST_SHOWS_PER_POINT)
+        .min(u32::from(SETLIST_ROAD_TEST_MAX_GAIN)) as u8;
+    for song in &game.band.unreleased_songs {
+        let played = hits.contains(&SetlistSong::Unreleased(song.id));
+        let expected = if played { 50 + gain } else { 50 };
+        assert_eq!(song.songwriting_quality, expected, "{}", song.name);
+    }
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("Road-tested"))
+    );
+}
+
+#[test]
+fn a_short_set_is_called_out_after_the_tour() {
+    let mut game = catalogue_game();
+    game.action_tour_route(
+        &LEGS,
+        TourRig::Van,
+        set_of(&[SetlistSong::Release(1)]),
+        &mut StdRng::seed_from_u64(1),
+    )
+    .unwrap();
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("left the crowds wanting"))
+    );
+}
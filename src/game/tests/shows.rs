@@ -391,3 +391,126 @@ fn quote_gross_range_matches_the_reception_attendance_spread() {
         "gross range should widen by the reception attendance spread: got {ratio}, expected ~{expected_ratio}"
     );
 }
+
+fn terms(tickets: TicketTier, merch_order: u32) -> TourTerms {
+    TourTerms {
+        tickets,
+        merch_order,
+        ..TourTerms::default()
+    }
+}
+
+/// Standard tickets are the untiered tour; cheap seats earn less at the
+/// door and premium seats more, once turnout is priced in.
+#[test]
+fn ticket_tiers_trade_price_against_turnout_in_the_quote() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    let legs = [TourLeg {
+        region_index: 0,
+        weeks: 2,
+    }];
+    let quote = |tier| {
+        game.quote_route(&legs, TourRig::Bus, terms(tier, 0))
+            .expect("quote should resolve")
+    };
+    let plain = game.quote_tour(0, TourRig::Bus, 2).unwrap();
+    let (cheap, standard, premium) = (
+        quote(TicketTier::Cheap),
+        quote(TicketTier::Standard),
+        quote(TicketTier::Premium),
+    );
+
+    assert_eq!(standard.gross_low, plain.gross_low);
+    assert_eq!(standard.gross_high, plain.gross_high);
+    assert!(cheap.gross_high < standard.gross_high);
+    assert!(premium.gross_high > standard.gross_high);
+    assert_eq!(cheap.cost, premium.cost, "the tier never changes the bill");
+}
+
+/// Same seed, same receptions: cheap seats put more people in the room
+/// than premium ones do.
+#[test]
+fn cheap_seats_pack_more_people_in_than_premium() {
+    let attendance = |tier| {
+        let mut game = test_game();
+        game.band.fame = 40;
+        game.player.money = 1_000_000;
+        let mut rng = StdRng::seed_from_u64(210);
+        let legs = [TourLeg {
+            region_index: 0,
+            weeks: 2,
+        }];
+        game.action_tour_route(&legs, TourRig::Van, terms(tier, 0), &mut rng)
+            .expect("the tour should book");
+        let report = game.last_tour_report.expect("a tour populates the report");
+        report.rows.iter().map(|row| row.attendance).sum::<u32>()
+    };
+
+    assert!(attendance(TicketTier::Cheap) > attendance(TicketTier::Premium));
+}
+
+/// The merch order is paid up front with the tour, sells per show into
+/// its own report column and its own revenue, and what's left stays in
+/// stock.
+#[test]
+fn merch_is_ordered_up_front_sold_per_show_and_kept_when_left_over() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    game.player.money = 1_000_000;
+    let mut rng = StdRng::seed_from_u64(211);
+    let legs = [TourLeg {
+        region_index: 0,
+        weeks: 1,
+    }];
+    let order = terms(TicketTier::Standard, 5_000);
+
+    let quote = game
+        .quote_route(&legs, TourRig::Van, order.clone())
+        .unwrap();
+    assert_eq!(quote.merch_cost, 5_000 * MERCH_UNIT_COST as i32);
+
+    let money_before = game.player.money;
+    game.action_tour_route(&legs, TourRig::Van, order, &mut rng)
+        .expect("the tour should book");
+
+    let report = game
+        .last_tour_report
+        .clone()
+        .expect("tour produces a report");
+    assert!(
+        report.total_merch > 0,
+        "a famous band should sell some merch"
+    );
+    assert_eq!(
+        report.total_merch,
+        report.rows.iter().map(|row| row.merch).sum::<u32>()
+    );
+    assert_eq!(
+        game.player.money,
+        money_before - quote.cost + report.total_gross as i32 + report.total_merch as i32
+    );
+    assert!(
+        game.merch_stock > 0 && game.merch_stock < 5_000,
+        "a one-week van tour shouldn't sell out 5,000 items, got {} left",
+        game.merch_stock
+    );
+}
+
+/// Leftover stock sells at a one-off gig too, and stops when it runs out.
+#[test]
+fn a_gig_sells_leftover_merch() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    game.merch_stock = 3;
+    let venue = best_open_venue(&game);
+    let mut rng = StdRng::seed_from_u64(212);
+
+    game.action_play_gig(venue, &mut rng)
+        .expect("the gig should play");
+
+    let report = game.last_tour_report.expect("a gig populates the report");
+    assert_eq!(game.merch_stock, 0, "a full room should clear three items");
+    assert!(report.rows[0].merch > 0);
+    assert_eq!(report.total_merch, report.rows[0].merch);
+}
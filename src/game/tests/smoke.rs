@@ -1,4 +1,4 @@
-//! Whole-turn smoke tests: no panics, break mechanics, and genre-trend press.
+//! Whole-turn smoke tests: no panics, vacation mechanics, and genre-trend press.
 
 use super::*;
 
@@ -13,7 +13,7 @@ fn a_full_season_of_turns_never_panics() {
             2 => GameAction::LazeAround,
             3 => GameAction::RecordSingle { pressing: Some(0) },
             4 => GameAction::Practice,
-            _ => GameAction::TakeBreak,
+            _ => GameAction::TakeVacation(Vacation::CountryRetreat),
         };
         // Rejected actions are fine; panics are not.
         let _ = game.process_turn(action);
@@ -24,25 +24,74 @@ fn a_full_season_of_turns_never_panics() {
 }
 
 #[test]
-fn a_break_is_a_real_break() {
+fn a_vacation_is_a_real_break() {
     let mut game = test_game();
     let week_before = game.week;
+    game.player.money = 5_000;
     game.player.health = 50;
     game.player.stress = 40;
     let happiness_before = game.player.happiness;
     let creativity_before = game.player.creativity;
 
-    game.action_take_break().expect("a break always works");
+    game.action_take_vacation(Vacation::MediterraneanHoliday)
+        .expect("an affordable trip always works");
 
     assert_eq!(
         game.week,
-        week_before + BREAK_WEEKS - 1,
+        week_before + Vacation::MediterraneanHoliday.weeks() - 1,
         "the turn itself adds the final week"
     );
-    assert_eq!(game.player.health, 80);
-    assert_eq!(game.player.stress, 0, "a break resets stress");
-    assert_eq!(game.player.happiness, happiness_before + 10);
-    assert_eq!(game.player.creativity, creativity_before + 10);
+    assert_eq!(game.player.money, 5_000 - 2_000, "paid up front");
+    assert_eq!(game.player.health, 100, "a month in the sun heals fully");
+    assert_eq!(game.player.stress, 0, "and resets stress");
+    assert_eq!(game.player.happiness, happiness_before + 25);
+    assert_eq!(game.player.creativity, creativity_before + 15);
+}
+
+#[test]
+fn a_weekend_away_is_cheap_and_partial() {
+    let mut game = test_game();
+    game.player.money = 100;
+    game.player.stress = 60;
+    let week_before = game.week;
+
+    game.action_take_vacation(Vacation::SeasideWeekend)
+        .expect("$50 is affordable");
+
+    assert_eq!(game.week, week_before, "a one-week trip is just this turn");
+    assert_eq!(game.player.money, 50);
+    assert_eq!(game.player.stress, 40, "a weekend only takes the edge off");
+}
+
+#[test]
+fn an_unaffordable_vacation_is_refused_and_changes_nothing() {
+    let mut game = test_game();
+    game.player.money = 1_000;
+    game.player.stress = 60;
+    let week_before = game.week;
+
+    let result = game.action_take_vacation(Vacation::WorldCruise);
+
+    assert!(result.is_err(), "a $12k cruise on $1k must be refused");
+    assert_eq!(game.player.money, 1_000);
+    assert_eq!(game.player.stress, 60);
+    assert_eq!(game.week, week_before);
+}
+
+#[test]
+fn a_world_cruise_resets_everything_and_dries_you_out() {
+    let mut game = test_game();
+    game.player.money = 20_000;
+    game.player.happiness = 20;
+    game.player.drug_addiction = 30;
+    game.player.alcohol_addiction = 10;
+
+    game.action_take_vacation(Vacation::WorldCruise)
+        .expect("a rich player can cruise");
+
+    assert_eq!(game.player.happiness, constants::MAX_HAPPINESS);
+    assert_eq!(game.player.drug_addiction, 10);
+    assert_eq!(game.player.alcohol_addiction, 0);
 }
 
 #[test]
@@ -182,7 +231,7 @@ fn death_ending_still_works() {
 }
 
 #[test]
-fn broke_and_unknown_ending_still_works() {
+fn broke_and_unknown_goes_bankrupt_then_ends_the_game() {
     let mut game = test_game();
     game.initialize_player("Test", "The Tests", genre::MusicGenre::Rock);
     // An unseeded game: an incident or a historical event on the single turn
@@ -198,15 +247,22 @@ fn broke_and_unknown_ending_still_works() {
         .collect();
     game.timeline.triggered_events.extend(every_event);
 
-    // Set up broke + unknown to trigger the broke-and-unknown ending
+    // Broke + unknown: the first time, the receiver moves in
     game.player.money = -100;
     game.band.fame = 5; // Below 10
     game.process_turn(GameAction::LazeAround)
         .expect("turn should succeed");
+    assert!(!game.is_game_over(), "bankruptcy isn't the end");
+    assert!(game.is_bankrupt());
+    assert_eq!(game.player.money, 0, "the debts are written off");
 
+    // Broke again before the discharge ends it
+    game.player.money = -100;
+    game.process_turn(GameAction::LazeAround)
+        .expect("turn should succeed");
     assert!(
         game.is_game_over(),
-        "game should end when broke and unknown"
+        "game should end when broke again while bankrupt"
     );
     let status = game.get_status_message();
     assert!(
@@ -0,0 +1,165 @@
+//! Label mergers (`world/labels.rs`) and takeovers of the band's own label
+//! (`takeover.rs`): who buys whom, what moves across, and the band's answer
+//! — renegotiate, walk under the key-man clause, or let the new terms stand.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::music::MasterOwner;
+use crate::game::world::LabelState;
+
+use super::*;
+
+fn label_state<'a>(game: &'a mut Game, name: &str) -> &'a mut LabelState {
+    game.world
+        .labels
+        .iter_mut()
+        .find(|label| label.name == name)
+        .expect("label trading")
+}
+
+/// The band signed to Charisma, with a single Charisma holds the master
+/// of — then Atlantic buys Charisma.
+fn signed_to_a_label_atlantic_buys() -> Game {
+    let mut game = test_game();
+    let mut deal = test_deal(30, 0.35);
+    deal.label_name = "Charisma Records".to_string();
+    deal.label_tier = "Boutique".to_string();
+    deal.albums_delivered = 1;
+    deal.unrecouped = 2_500;
+    deal.signed_week = 0;
+    deal.term_weeks = 78;
+    game.band.record_deal = Some(deal);
+    let mut single = test_release(1, ReleaseType::Single);
+    single.master_owner = Some(MasterOwner::Label {
+        name: "Charisma Records".to_string(),
+        royalty_rate: 0.35,
+    });
+    game.band.singles_released = vec![single];
+    game.week = 20;
+
+    let charisma = label_state(&mut game, "Charisma Records");
+    charisma.folded = true;
+    charisma.absorbed_by = Some("Atlantic Records".to_string());
+    game.label_weekly_deal_check(&mut StdRng::seed_from_u64(1));
+    game
+}
+
+#[test]
+fn a_bigger_label_buys_out_one_deep_in_the_red() {
+    let mut game = test_game();
+    for band in &mut game.world.bands {
+        if band.label.as_deref() == Some("Charisma Records") {
+            band.label = None;
+        }
+    }
+    let act = game.world.bands[0].name.clone();
+    game.world.bands[0].label = Some("Charisma Records".to_string());
+    let charisma = label_state(&mut game, "Charisma Records");
+    charisma.cash = -20_000;
+    charisma.releases = 7;
+    let power = |game: &Game, name: &str| {
+        crate::game::world::find_label_with_tier(&game.data_files, name)
+            .unwrap()
+            .1
+            .financial_power
+    };
+    let target_power = power(&game, "Charisma Records");
+    assert!(power(&game, "Atlantic Records") > target_power);
+    let atlantic = game.world.label("Atlantic Records").unwrap().clone();
+
+    let moved = game
+        .world
+        .absorb_label("Charisma Records", "Atlantic Records", target_power);
+
+    assert_eq!(moved, 1);
+    let charisma = game.world.label("Charisma Records").unwrap();
+    assert!(charisma.folded, "it trades on only as part of its buyer");
+    assert_eq!(charisma.absorbed_by.as_deref(), Some("Atlantic Records"));
+    assert_eq!(
+        game.world.label_successor("Charisma Records"),
+        Some("Atlantic Records")
+    );
+    assert_eq!(
+        game.world.scene_band(&act).unwrap().label.as_deref(),
+        Some("Atlantic Records"),
+        "the roster moves across"
+    );
+    let bought = game.world.label("Atlantic Records").unwrap();
+    assert_eq!(bought.releases, atlantic.releases + 7);
+    assert_eq!(
+        bought.cash,
+        atlantic.cash - 20_000 - i64::from(target_power) * LABEL_BUYOUT_PRICE_PER_POWER,
+        "the buyer pays the owners and takes on the debts"
+    );
+}
+
+#[test]
+fn a_takeover_moves_the_contract_and_masters_to_the_buyer() {
+    let game = signed_to_a_label_atlantic_buys();
+    let deal = game.band.current_deal().expect("the contract survives");
+    assert_eq!(deal.label_name, "Atlantic Records");
+    assert_eq!(deal.label_tier, "Major");
+    assert_eq!(deal.market_reach, 95);
+    // Halfway from Charisma's 35% toward Atlantic's 12%.
+    assert!((deal.royalty_rate - 0.235).abs() < 0.001);
+    assert_eq!(deal.unrecouped, 2_500);
+    assert_eq!(
+        game.band.singles_released[0].master_label(),
+        Some("Atlantic Records")
+    );
+    let takeover = game.pending_takeover.as_ref().expect("the band gets a say");
+    assert_eq!(takeover.old_label, "Charisma Records");
+    assert!(
+        game.turn_log
+            .iter()
+            .all(|line| !line.contains("your contract goes with it")),
+        "a bought label isn't a dead one"
+    );
+}
+
+#[test]
+fn renegotiating_puts_the_old_terms_back_on_the_table() {
+    let mut game = signed_to_a_label_atlantic_buys();
+    game.action_renegotiate_takeover().unwrap();
+    assert!(game.pending_takeover.is_none());
+    let offer = game.pending_deal_offers.last().unwrap();
+    assert_eq!(offer.label_name, "Atlantic Records");
+    assert_eq!(offer.royalty_rate, 0.35);
+    assert_eq!(offer.albums_required, 1, "the album still owed");
+    assert_eq!(offer.term_weeks, 58, "the weeks left on the clock");
+
+    let index = game.pending_deal_offers.len() - 1;
+    game.action_accept_deal(index).unwrap();
+    let deal = game.band.current_deal().unwrap();
+    assert_eq!(deal.royalty_rate, 0.35);
+    assert_eq!(deal.market_reach, 95, "the buyer's reach stays");
+    assert_eq!(deal.unrecouped, 2_500, "the balance carries over");
+}
+
+#[test]
+fn a_key_man_exit_frees_the_band_and_leaves_the_masters() {
+    let mut game = signed_to_a_label_atlantic_buys();
+    game.action_key_man_exit().unwrap();
+    assert!(game.band.current_deal().is_none());
+    assert_eq!(game.band.deal_cooldown, 0, "walking isn't a breach");
+    assert!(game.pending_takeover.is_none());
+    assert_eq!(
+        game.band.singles_released[0].master_label(),
+        Some("Atlantic Records")
+    );
+    assert!(game.action_key_man_exit().is_err(), "only once");
+}
+
+#[test]
+fn an_unanswered_takeover_lets_the_new_terms_stand() {
+    let mut game = signed_to_a_label_atlantic_buys();
+    game.week += TAKEOVER_DECISION_WEEKS;
+    game.label_weekly_deal_check(&mut StdRng::seed_from_u64(2));
+    assert!(game.pending_takeover.is_none());
+    assert_eq!(
+        game.band.current_deal().unwrap().label_name,
+        "Atlantic Records"
+    );
+    assert!(game.action_renegotiate_takeover().is_err());
+}
@@ -0,0 +1,132 @@
+//! Income tax (`tax.rs`): the era's bands on the year's profit, what the
+//! taxman won't let the band deduct, the accountant, and a year in exile —
+//! taxed abroad, but barred from home and forgotten there.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use super::*;
+
+const LONDON: &str = "united_kingdom:london";
+
+#[test]
+fn each_band_of_profit_is_taxed_at_its_own_rate() {
+    let game = test_game();
+    let tax = &game.data_files.tax_data;
+    let surtax = tax.regime(HOME_COUNTRY, 1970).unwrap();
+    assert_eq!(surtax.tax_on(1_500), 0, "under the allowance");
+    assert_eq!(
+        surtax.tax_on(11_500),
+        (8_000.0 * 0.30 + 2_000.0 * 0.45) as u32
+    );
+    let squeeze = tax.regime(HOME_COUNTRY, 1976).unwrap();
+    assert!((squeeze.top_rate() - 0.83).abs() < 0.001, "1974's regime");
+    assert_eq!(
+        tax.regime("ireland", 1985).unwrap().note,
+        tax.regime("ireland", 1970).unwrap().note,
+        "the one regime holds for good"
+    );
+    assert!(tax.regime("atlantis", 1970).is_none());
+}
+
+#[test]
+fn the_taxman_takes_his_share_of_the_year_at_new_year() {
+    let mut game = test_game();
+    game.player.money = 0;
+    game.bank_income(30_000, LedgerCategory::Shows, "The Rainbow");
+    game.post(LedgerCategory::Living, -5_000, "Rent");
+    game.post(LedgerCategory::Recording, -4_000, "Studio");
+    assert_eq!(
+        game.taxable_profit(STARTING_YEAR),
+        26_000,
+        "the rent isn't deductible"
+    );
+
+    let without = game.tax_assessment(STARTING_YEAR).tax;
+    game.accountant = true;
+    let with = game.tax_assessment(STARTING_YEAR);
+    assert_eq!(with.deductions, 5_200);
+    assert!(with.tax < without, "the accountant earns the retainer");
+
+    let money = game.player.money;
+    game.assess_income_tax(STARTING_YEAR);
+    assert_eq!(game.player.money, money - with.tax as i32);
+    assert_eq!(game.tax_assessments.len(), 1);
+    assert_eq!(game.tax_assessments[0].country, HOME_COUNTRY);
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("The taxman's bill for 1970"))
+    );
+    assert_eq!(
+        game.tax_assessment(STARTING_YEAR).profit,
+        26_000,
+        "the bill itself isn't a deduction"
+    );
+}
+
+#[test]
+fn a_year_in_exile_is_taxed_abroad_but_costs_the_home_crowd() {
+    let mut game = test_game();
+    game.player.money = 50_000;
+    game.band.fame = 90;
+    assert!(
+        game.action_go_into_tax_exile("ireland").is_err(),
+        "an accountant sets it up"
+    );
+    game.action_set_accountant(true).unwrap();
+    assert!(game.action_go_into_tax_exile(HOME_COUNTRY).is_err());
+
+    let havens = game.tax_havens();
+    assert!(havens.iter().all(|haven| haven.country != HOME_COUNTRY));
+    assert!(
+        havens
+            .windows(2)
+            .all(|pair| pair[0].estimate <= pair[1].estimate)
+    );
+    game.action_go_into_tax_exile("ireland").unwrap();
+    assert!(game.in_tax_exile());
+    assert!(game.action_set_accountant(false).is_err());
+    assert!(game.action_go_into_tax_exile("france").is_err());
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let venue = best_open_venue(&game);
+    assert!(game.action_play_gig(venue, &mut rng).is_err());
+    let london = game
+        .get_sorted_regions()
+        .iter()
+        .position(|(country, region, ..)| format!("{}:{}", country, region) == LONDON)
+        .unwrap();
+    let legs = [TourLeg {
+        region_index: london,
+        weeks: 2,
+    }];
+    assert!(
+        game.quote_route(&legs, TourRig::Van, TourTerms::default())
+            .is_err()
+    );
+
+    game.regional_fame.insert(LONDON.to_string(), 60);
+    game.mark_region_visited(LONDON);
+    game.week += 2;
+    game.update_regional_fame(2);
+    assert!(
+        game.regional_fame[LONDON] < 60,
+        "no grace weeks for the home crowd"
+    );
+
+    assert_eq!(game.tax_residence(STARTING_YEAR), "ireland");
+    game.week = game.tax_exile().unwrap().until_week;
+    assert!(!game.in_tax_exile());
+    game.tax_weekly_upkeep(1);
+    assert!(
+        game.turn_log
+            .iter()
+            .any(|line| line.contains("The year in Ireland is up"))
+    );
+    assert_eq!(
+        game.tax_residence(STARTING_YEAR + 2),
+        HOME_COUNTRY,
+        "back home"
+    );
+}
@@ -0,0 +1,284 @@
+//! The venue roster: promoter relationships, return bookings and bans,
+//! yearly openings and closures, and the rosters of toured regions
+//! (`world/venues.rs`).
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::game::shows::ShowVerdict;
+use crate::game::timeline::MusicTimeline;
+use crate::game::world::{Venue, VenueStanding};
+
+use super::*;
+
+fn fresh_venue(game: &Game) -> Venue {
+    game.world.venues[0].clone()
+}
+
+#[test]
+fn the_promoters_opinion_and_a_return_booking_move_the_fee() {
+    let game = test_game();
+    let mut venue = fresh_venue(&game);
+    assert!(
+        (venue.fee_mult(1) - 1.0).abs() < 1e-6,
+        "a new promoter is neutral"
+    );
+
+    venue.promoter = 100;
+    assert!((venue.fee_mult(1) - (1.0 + VENUE_PROMOTER_FEE_SWING)).abs() < 1e-6);
+    venue.promoter = 0;
+    assert!((venue.fee_mult(1) - (1.0 - VENUE_PROMOTER_FEE_SWING)).abs() < 1e-6);
+
+    venue.promoter = VENUE_PROMOTER_START;
+    venue.invited_until = 9;
+    assert!((venue.fee_mult(9) - VENUE_INVITE_FEE_BONUS).abs() < 1e-6);
+    assert!(
+        (venue.fee_mult(10) - 1.0).abs() < 1e-6,
+        "the invitation lapses"
+    );
+}
+
+#[test]
+fn a_great_night_earns_a_return_booking_that_the_next_show_spends() {
+    let game = test_game();
+    let mut venue = fresh_venue(&game);
+
+    assert_eq!(
+        venue.record_show(80, ShowVerdict::Great, 5),
+        VenueStanding::InvitedBack
+    );
+    assert!(venue.has_invite(5 + VENUE_INVITE_WEEKS));
+    assert!(!venue.has_invite(6 + VENUE_INVITE_WEEKS));
+    assert_eq!(venue.promoter, VENUE_PROMOTER_START + 5);
+
+    assert_eq!(
+        venue.record_show(50, ShowVerdict::Solid, 7),
+        VenueStanding::Unchanged
+    );
+    assert!(!venue.has_invite(8), "the return booking was used up");
+    assert_eq!(venue.shows_played, 2);
+    assert_eq!(venue.avg_reception(), Some(65));
+}
+
+#[test]
+fn enough_rough_nights_get_the_band_banned() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    let venue = best_open_venue(&game);
+    game.world.venues[venue].promoter = VENUE_BAN_PROMOTER + 1;
+
+    let standing = game.world.venues[venue].record_show(20, ShowVerdict::Rough, game.week);
+    assert_eq!(standing, VenueStanding::Banned);
+    assert_eq!(game.world.venues[venue].promoter, VENUE_PROMOTER_AFTER_BAN);
+
+    let mut rng = StdRng::seed_from_u64(330);
+    let err = game
+        .action_play_gig(venue, &mut rng)
+        .expect_err("a banned band can't play there");
+    assert!(err.contains("won't book you"), "unexpected error: {err}");
+
+    game.week += VENUE_BAN_WEEKS;
+    assert!(
+        game.action_play_gig(venue, &mut rng).is_ok(),
+        "the ban runs out"
+    );
+}
+
+#[test]
+fn a_friendly_promoter_pays_more_for_the_same_night() {
+    let earnings = |promoter| {
+        let mut game = test_game();
+        game.band.fame = 60;
+        let venue = best_open_venue(&game);
+        game.world.venues[venue].promoter = promoter;
+        let before = game.player.money;
+        game.action_play_gig(venue, &mut StdRng::seed_from_u64(331))
+            .expect("the gig should play");
+        game.player.money - before
+    };
+
+    assert!(earnings(100) > earnings(VENUE_PROMOTER_START));
+    assert!(earnings(0) < earnings(VENUE_PROMOTER_START));
+}
+
+#[test]
+fn venues_open_and_close_but_every_tier_stays_playable() {
+    let game = test_game();
+    let mut world = game.world.clone();
+    let timeline = MusicTimeline::new(&game.data_files);
+    let mut rng = StdRng::seed_from_u64(332);
+    let original: Vec<String> = world.venues.iter().map(|v| v.name.clone()).collect();
+    let mut news = 0;
+
+    for _ in 0..30 {
+        let tiers: Vec<usize> = world.venues.iter().map(Venue::tier).collect();
+        news += world
+            .turn_over_venues(&timeline, &game.data_files, &mut rng)
+            .len();
+        for tier in 0..VENUE_TIER_CAPACITY.len() {
+            assert!(
+                world.venues.iter().any(|venue| venue.tier() == tier),
+                "tier {tier} lost its last venue"
+            );
+        }
+        assert!(
+            tiers
+                .iter()
+                .enumerate()
+                .all(|(i, &tier)| world.venues[i].tier() == tier),
+            "a slot keeps its tier, so a venue index stays good"
+        );
+        let ladder = world.venue_ladder();
+        assert!(
+            ladder
+                .windows(2)
+                .all(|pair| world.venues[pair[0]].capacity <= world.venues[pair[1]].capacity),
+            "the ladder runs smallest to biggest"
+        );
+    }
+
+    assert!(news > 0, "thirty years should see some turnover");
+    let current: Vec<String> = world.venues.iter().map(|v| v.name.clone()).collect();
+    assert_ne!(current, original);
+}
+
+#[test]
+fn a_toured_region_keeps_a_roster_sized_by_venue_density() {
+    let mut game = test_game();
+    game.band.fame = 90;
+    game.player.money = 1_000_000;
+    let (country, region, ..) = game.get_sorted_regions()[0].clone();
+    let key = format!("{country}:{region}");
+    let density = game.data_files.markets_data.markets[&country].regions[&region].venue_density;
+    let mut rng = StdRng::seed_from_u64(333);
+
+    game.action_go_on_tour(0, TourRig::Van, 2, &mut rng)
+        .expect("the tour should book");
+
+    let roster = &game.world.regional_venues[&key];
+    assert_eq!(
+        roster.len(),
+        VENUE_REGION_ROSTER_MIN + usize::from(density / VENUE_REGION_DENSITY_STEP)
+    );
+    let report = game.last_tour_report.as_ref().expect("a tour report");
+    assert_eq!(
+        roster.iter().map(|venue| venue.shows_played).sum::<u32>(),
+        report.rows.len() as u32,
+        "every show is remembered by the room it was played in"
+    );
+    assert!(report.rows.iter().all(|row| {
+        roster
+            .iter()
+            .any(|venue| row.venue_name == format!("{} ({})", venue.name, venue.location))
+    }));
+
+    // A second visit plays the same rooms.
+    let before: Vec<String> = roster.iter().map(|venue| venue.name.clone()).collect();
+    game.player.stress = 0;
+    game.player.health = 100;
+    game.action_go_on_tour(0, TourRig::Van, 1, &mut rng)
+        .expect("the tour should book");
+    let after: Vec<String> = game.world.regional_venues[&key]
+        .iter()
+        .map(|venue| venue.name.clone())
+        .collect();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn a_region_that_banned_the_band_wont_book_it_and_friendly_rooms_pay_more() {
+    let toured = |promoter: u8| {
+        let mut game = crate::game::sim::seeded_game(334);
+        game.band.fame = 90;
+        game.player.money = 1_000_000;
+        game.action_go_on_tour(0, TourRig::Van, 1, &mut StdRng::seed_from_u64(334))
+            .expect("the tour should book");
+        let (country, region, ..) = game.get_sorted_regions()[0].clone();
+        let key = format!("{country}:{region}");
+        for venue in game.world.regional_venues.get_mut(&key).unwrap() {
+            venue.promoter = promoter;
+            venue.invited_until = 0;
+            venue.banned_until = 0;
+        }
+        game.player.stress = 0;
+        game.player.health = 100;
+        game.player.money = 1_000_000;
+        (game, key)
+    };
+
+    let gross = |promoter: u8| {
+        let (mut game, _) = toured(promoter);
+        game.action_go_on_tour(0, TourRig::Van, 1, &mut StdRng::seed_from_u64(335))
+            .expect("the tour should book");
+        game.last_tour_report
+            .as_ref()
+            .unwrap()
+            .rows
+            .iter()
+            .map(|row| row.take)
+            .sum::<u32>()
+    };
+    assert!(gross(100) > gross(VENUE_PROMOTER_START));
+    assert!(gross(0) < gross(VENUE_PROMOTER_START));
+
+    let (mut game, key) = toured(VENUE_PROMOTER_START);
+    let week = game.week;
+    for venue in game.world.regional_venues.get_mut(&key).unwrap() {
+        venue.banned_until = week + VENUE_BAN_WEEKS;
+    }
+    let money = game.player.money;
+    let err = game
+        .action_go_on_tour(0, TourRig::Van, 1, &mut StdRng::seed_from_u64(336))
+        .expect_err("every room has banned the band");
+    assert!(err.contains("banned you"), "unexpected error: {err}");
+    assert_eq!(
+        game.player.money, money,
+        "nothing is paid for a refused tour"
+    );
+
+    // Routing through a fresh region first doesn't build its rooms for a
+    // tour that is refused anyway.
+    let (country, region, ..) = game.get_sorted_regions()[1].clone();
+    let fresh = format!("{country}:{region}");
+    assert!(!game.world.regional_venues.contains_key(&fresh));
+    let legs = [
+        TourLeg {
+            region_index: 1,
+            weeks: 1,
+        },
+        TourLeg {
+            region_index: 0,
+            weeks: 1,
+        },
+    ];
+    let err = game
+        .action_tour_route(
+            &legs,
+            TourRig::Van,
+            TourTerms::default(),
+            &mut StdRng::seed_from_u64(337),
+        )
+        .expect_err("the route runs into a region that banned the band");
+    assert!(err.contains("banned you"), "unexpected error: {err}");
+    assert!(!game.world.regional_venues.contains_key(&fresh));
+}
+
+#[test]
+fn venues_saved_before_the_roster_load_with_a_neutral_promoter() {
+    let game = test_game();
+    let mut saved = serde_json::to_value(&game.world.venues[0]).expect("venues serialize");
+    let fields = saved.as_object_mut().expect("a venue is an object");
+    for key in [
+        "shows_played",
+        "reception_total",
+        "promoter",
+        "banned_until",
+        "invited_until",
+    ] {
+        fields.remove(key);
+    }
+    let loaded: Venue = serde_json::from_value(saved).expect("old venues must keep loading");
+    assert_eq!(loaded.promoter, VENUE_PROMOTER_START);
+    assert_eq!(loaded.shows_played, 0);
+    assert!(!loaded.is_banned(1));
+}
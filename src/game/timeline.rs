@@ -34,7 +34,7 @@ pub struct IndustryTrends {
     pub fan_loyalty_factor: u8,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MusicTimeline {
     pub eras: HashMap<u32, MusicEra>,
     pub current_year: u32,
@@ -127,6 +127,12 @@ impl MusicTimeline {
         self.get_current_era().industry_trends.album_vs_singles > 0.7
     }
 
+    /// Whether a concert film has an audience yet (music video's arrival).
+    pub fn is_video_era(&self) -> bool {
+        self.get_current_era().industry_trends.media_influence
+            >= crate::game::constants::LIVE_FILM_MEDIA_INFLUENCE
+    }
+
     pub fn get_trending_genres(&self) -> Vec<String> {
         self.get_current_era().dominant_genres.clone()
     }
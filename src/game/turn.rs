@@ -59,7 +59,11 @@ impl Game {
     fn is_publicly_active(&self, action: &GameAction) -> bool {
         let public_action = matches!(
             action,
-            GameAction::Gig(_) | GameAction::GoOnTour(..) | GameAction::AcceptSupportTour
+            GameAction::Gig(_)
+                | GameAction::GoOnTour(..)
+                | GameAction::GoOnTourRoute(..)
+                | GameAction::AcceptSupportTour
+                | GameAction::AcceptHeadlineTour
         ) || !self.just_released_music.is_empty();
         public_action
             || self.world.player_is_charting()
@@ -137,17 +141,24 @@ impl Game {
             return;
         }
 
-        let candidates: Vec<(String, u8)> = self
+        // A rival never asks; an old friend — an opener who has since
+        // outgrown the band — asks first.
+        let mut candidates: Vec<(String, u8, bool)> = self
             .world
             .bands
             .iter()
-            .filter(|b| b.fame >= self.band.fame.saturating_add(SUPPORT_OFFER_FAME_GAP))
-            .map(|b| (b.name.clone(), b.fame))
+            .filter(|b| {
+                b.fame >= self.band.fame.saturating_add(SUPPORT_OFFER_FAME_GAP) && !b.is_rival()
+            })
+            .map(|b| (b.name.clone(), b.fame, b.is_friend()))
             .collect();
+        if candidates.iter().any(|&(_, _, friend)| friend) {
+            candidates.retain(|&(_, _, friend)| friend);
+        }
         if candidates.is_empty() {
             return;
         }
-        let (host_band, host_fame) = candidates[rng.gen_range(0..candidates.len())].clone();
+        let (host_band, host_fame, friend) = candidates[rng.gen_range(0..candidates.len())].clone();
 
         let weeks = rng.gen_range(2..=4u32);
         let base_pay = weeks * (50 + u32::from(host_fame) * 5);
@@ -163,10 +174,180 @@ impl Game {
             fame_gain,
             expires_week: self.week + SUPPORT_OFFER_LIFETIME_WEEKS,
         });
+        if friend {
+            self.log(format!(
+                "🎟️ Returning the favour, {} want '{}' opening their {}-week tour — ${} and real exposure. Press T to respond.",
+                host_band, self.band.name, weeks, pay
+            ));
+        } else {
+            self.log(format!(
+                "🎟️ {} want '{}' opening their {}-week tour — ${} and real exposure. Press T to respond.",
+                host_band, self.band.name, weeks, pay
+            ));
+        }
+    }
+
+    /// On the day, play a booked festival slot or let an unanswered offer
+    /// lapse. Otherwise offer a slot at the next festival within reach —
+    /// the best billing the band's fame earns. Each festival asks at most
+    /// once a year.
+    pub(super) fn update_festival_offer(&mut self, rng: &mut impl Rng) {
+        if let Some(offer) = &self.pending_festival_offer {
+            if self.week < offer.expires_week {
+                return;
+            }
+            let offer = offer.clone();
+            self.pending_festival_offer = None;
+            if offer.booked {
+                self.play_festival(&offer, rng);
+            } else {
+                self.log(format!(
+                    "🎪 {} went ahead without you — the slot was filled.",
+                    offer.festival
+                ));
+            }
+            return;
+        }
+
+        let week = self.week;
+        let fame = self.band.fame;
+        let Some(index) = self.world.festivals.iter().position(|festival| {
+            !festival.offered
+                && festival.week > week
+                && festival.week - week <= FESTIVAL_OFFER_LEAD_WEEKS
+                && festival.best_billing_for(fame).is_some()
+        }) else {
+            return;
+        };
+        if !rng.gen_bool(FESTIVAL_OFFER_CHANCE) {
+            return;
+        }
+
+        let festival = &mut self.world.festivals[index];
+        festival.offered = true;
+        let Some(billing) = festival.best_billing_for(fame) else {
+            return;
+        };
+        let pay = (FESTIVAL_BILLING_PAY[billing as usize] as f32 * f32::from(festival.size) / 50.0
+            * self.timeline.get_gig_pay_modifier())
+        .round() as u32;
+        let offer = FestivalOffer {
+            festival_id: festival.id,
+            festival: festival.name.clone(),
+            region_name: festival.region_name.clone(),
+            billing,
+            pay,
+            fame_gain: FESTIVAL_BILLING_FAME[billing as usize],
+            expires_week: festival.week,
+            booked: false,
+        };
         self.log(format!(
-            "🎟️ {} want '{}' opening their {}-week tour — ${} and real exposure. Press T to respond.",
-            host_band, self.band.name, weeks, pay
+            "🎪 {} want '{}' as their {} in week {} — ${}. Press F to respond.",
+            offer.festival,
+            self.band.name,
+            billing.label(),
+            offer.expires_week,
+            pay
         ));
+        self.pending_festival_offer = Some(offer);
+    }
+
+    /// Expire a stale headline tour offer, or — once the band can fill rooms
+    /// on its name — roll for a promoter who still rates them to offer one:
+    /// a few regions the band can draw in, on the rig they expect to clear
+    /// the most on. The deal is sized from the route's own quote and the
+    /// promoter's opinion; a route they can't expect to profit on isn't
+    /// offered.
+    pub(super) fn update_headline_tour_offer(&mut self, rng: &mut impl Rng) {
+        if let Some(offer) = &self.pending_headline_offer {
+            if self.week >= offer.expires_week {
+                let promoter = offer.promoter.clone();
+                self.pending_headline_offer = None;
+                self.log(format!(
+                    "🎤 {}'s tour offer lapsed — they've booked another act.",
+                    promoter
+                ));
+            }
+            return;
+        }
+
+        if self.band.fame < HEADLINE_OFFER_MIN_FAME || !rng.gen_bool(HEADLINE_OFFER_CHANCE) {
+            return;
+        }
+        self.world.ensure_tour_promoters(&self.data_files, rng);
+        let keen: Vec<usize> = (0..self.world.tour_promoters.len())
+            .filter(|&i| self.world.tour_promoters[i].makes_offers())
+            .collect();
+        if keen.is_empty() {
+            return;
+        }
+        let promoter = &self.world.tour_promoters[keen[rng.gen_range(0..keen.len())]];
+        let (promoter, deal_mult) = (promoter.name.clone(), promoter.deal_mult());
+
+        let fame = self.band.fame;
+        let mut regions: Vec<usize> = self
+            .get_sorted_regions()
+            .iter()
+            .enumerate()
+            .filter(|(_, region)| fame >= region.5)
+            .map(|(i, _)| i)
+            .collect();
+        let stops = rng
+            .gen_range(HEADLINE_OFFER_STOPS.0..=HEADLINE_OFFER_STOPS.1)
+            .min(regions.len());
+        let mut legs: Vec<TourLeg> = (0..stops)
+            .map(|_| TourLeg {
+                region_index: regions.swap_remove(rng.gen_range(0..regions.len())),
+                weeks: rng.gen_range(HEADLINE_OFFER_STOP_WEEKS.0..=HEADLINE_OFFER_STOP_WEEKS.1),
+            })
+            .collect();
+        // Regions sort by country, so this routes each country in one go.
+        legs.sort_by_key(|leg| leg.region_index);
+        // The promoter sends whichever rig they expect to clear the most on.
+        let Some((rig, quote, projected_net)) = TourRig::ALL
+            .into_iter()
+            .filter_map(|rig| {
+                let quote = self.quote_route(&legs, rig, TourTerms::default()).ok()?;
+                let net = (quote.gross_low + quote.gross_high) as f32 / 2.0 - quote.cost as f32;
+                Some((rig, quote, net))
+            })
+            .max_by(|a, b| a.2.total_cmp(&b.2))
+        else {
+            return;
+        };
+        if projected_net <= 0.0 {
+            return;
+        }
+
+        let deal = if rng.gen_bool(HEADLINE_GUARANTEE_CHANCE) {
+            let fee = projected_net * HEADLINE_GUARANTEE_SHARE * deal_mult;
+            PromoterDeal::Guarantee(((fee / 100.0).round() * 100.0) as u32)
+        } else {
+            let share = rng.gen_range(HEADLINE_PERCENTAGE_RANGE.0..=HEADLINE_PERCENTAGE_RANGE.1)
+                * deal_mult;
+            PromoterDeal::Percentage(((share * 100.0).round() / 100.0).min(0.9))
+        };
+        let stops = quote
+            .stops
+            .iter()
+            .map(|stop| stop.region_name.as_str())
+            .collect::<Vec<_>>()
+            .join(" → ");
+        self.log(format!(
+            "🎤 {} want to put '{}' on a {}-week headline tour of {} — {}. Press O to respond.",
+            promoter,
+            self.band.name,
+            quote.weeks,
+            stops,
+            deal.label()
+        ));
+        self.pending_headline_offer = Some(HeadlineTourOffer {
+            promoter,
+            legs,
+            rig,
+            deal,
+            expires_week: self.week + HEADLINE_OFFER_LIFETIME_WEEKS,
+        });
     }
 
     /// When the era clearly loves — or has clearly abandoned — the band's
@@ -200,18 +381,28 @@ impl Game {
         }
     }
 
+    /// Show the world which label the band records for, so that label
+    /// counts it on its roster.
+    pub(super) fn sync_band_label(&mut self) {
+        self.world.band_label = self.band.current_deal().map(|deal| deal.label_name.clone());
+    }
+
     fn advance_week_events(&mut self, rng: &mut impl Rng) -> Result<(), String> {
         // Sync the timeline with the current week. Tours can jump several weeks
         // at once, so catch up year by year instead of testing a single boundary.
         let expected_year =
             constants::STARTING_YEAR + (self.week.saturating_sub(1)) / constants::WEEKS_PER_YEAR;
+        let new_year = self.timeline.get_current_year() < expected_year;
         while self.timeline.get_current_year() < expected_year {
+            let year_ended = self.timeline.get_current_year();
             self.timeline.advance_year();
             let year = self.timeline.get_current_year();
             let era_name = self.timeline.get_current_era().era_name.clone();
             self.log(format!("🗓️ It's now {} — the era of {}.", year, era_name));
+            self.assess_income_tax(year_ended);
         }
         self.update_genre_trend_news();
+        self.issue_royalty_statements();
 
         self.maybe_trigger_incident(rng)?;
 
@@ -229,12 +420,33 @@ impl Game {
             self.log(format!("📰 MUSIC NEWS: {}", historical_event));
         }
 
+        self.sync_band_label();
         let scene_news = self
             .world
             .update_week(&self.timeline, &self.data_files, &mut wk_rng);
         for item in scene_news {
             self.log(item);
         }
+        if new_year {
+            let venue_news =
+                self.world
+                    .turn_over_venues(&self.timeline, &self.data_files, &mut wk_rng);
+            for item in venue_news {
+                self.log(item);
+            }
+        }
+        let year = self.timeline.get_current_year();
+        if self.world.festival_year < year {
+            let festival_news = self.world.publish_festival_calendar(
+                year,
+                &self.timeline,
+                &self.data_files,
+                &mut wk_rng,
+            );
+            for item in festival_news {
+                self.log(item);
+            }
+        }
 
         self.label_single_cut_check(rng);
         // M9 (design §E-4/§E-5): the deal's clock — cooldown decrement,
@@ -242,6 +454,8 @@ impl Game {
         // week alongside the single-cut check, on the same action stream.
         self.label_weekly_deal_check(rng);
         self.update_support_tour_offer(rng);
+        self.update_festival_offer(rng);
+        self.update_headline_tour_offer(rng);
         Ok(())
     }
 
@@ -275,8 +489,11 @@ impl Game {
             let mut new_offers = self
                 .world
                 .generate_deal_offers(&self.band, &self.data_files, rng);
+            // A manager talks every label up a few royalty points (§9.4).
+            let royalty_bump = self.manager_royalty_bump();
             for offer in &mut new_offers {
                 offer.expires_week = Some(self.week + DEAL_OFFER_LIFETIME_WEEKS);
+                offer.royalty_rate = (offer.royalty_rate + royalty_bump).min(1.0);
             }
             if !new_offers.is_empty() {
                 let n = new_offers.len();
@@ -299,10 +516,39 @@ impl Game {
             action,
             GameAction::AcceptDeal(_)
                 | GameAction::RejectDeal(_)
+                | GameAction::CounterDeal(..)
                 | GameAction::DeclineSupportTour
+                | GameAction::AcceptFestival
+                | GameAction::DeclineFestival
+                | GameAction::DeclineHeadlineTour
                 | GameAction::StartMarketingCampaign(_, _)
                 | GameAction::ChangeLifestyle(_) // M2: instant, no week consumed
                 | GameAction::RePress { .. } // M6: instant re-press, no week consumed (§E-1)
+                | GameAction::BuyBackMaster(_)
+                | GameAction::SellMaster(_)
+                | GameAction::RenegotiateTakeover
+                | GameAction::KeyManExit
+                | GameAction::HireManager(_)
+                | GameAction::RejectManager(_)
+                | GameAction::FireManager
+                | GameAction::BuyGear(_)
+                | GameAction::SellGear(_)
+                | GameAction::ServiceGear
+                | GameAction::HireCrew(_)
+                | GameAction::FireCrew(_)
+                | GameAction::SetGearInsurance(_)
+                | GameAction::TakeLoan(_)
+                | GameAction::RepayLoan(_)
+                | GameAction::DrawLabelAdvance(_)
+                | GameAction::SetAccountant(_)
+                | GameAction::GoIntoTaxExile(_)
+                | GameAction::BuyInvestment(_)
+                | GameAction::SellInvestment(_)
+                | GameAction::FoundLabel
+                | GameAction::SignToLabel(_)
+                | GameAction::DropFromLabel(_)
+                | GameAction::AnswerLabelDemand(_)
+                | GameAction::ResolveIncident(_)
                 | GameAction::Quit
         );
 
@@ -310,19 +556,58 @@ impl Game {
         // in. Multi-week actions (tours, breaks) re-key next turn anyway.
         let mut rng = self.action_rng();
 
+        // Time never moves past an open question: a decision the player
+        // left unanswered takes its default before the next week's action
+        // runs, and so does a demand A&R put on the last record. A rejected
+        // action takes no turn, so the question is put back as it was.
+        let unsettled = (is_turn_consuming_action
+            && (self.pending_incident.is_some() || self.pending_label_demand.is_some()))
+        .then(|| self.clone());
+        if unsettled.is_some() {
+            if self.pending_incident.is_some() {
+                self.resolve_pending_incident_by_default(&mut rng);
+            }
+            if self.pending_label_demand.is_some() {
+                self.resolve_label_demand_by_default();
+            }
+        }
+
         let week_before = self.week;
-        self.execute_action(action.clone(), &mut rng)?; // Execute action first
+        // Execute action first
+        if let Err(err) = self.execute_action(action.clone(), &mut rng) {
+            if let Some(before) = unsettled {
+                *self = before;
+            }
+            return Err(err);
+        }
 
         if is_turn_consuming_action {
+            self.on_tour = matches!(
+                action,
+                GameAction::GoOnTour(..)
+                    | GameAction::GoOnTourRoute(..)
+                    | GameAction::AcceptSupportTour
+                    | GameAction::AcceptHeadlineTour
+            );
             self.week += 1; // Advance week only for turn-consuming actions
             self.advance_week_events(&mut rng)?; // Process standard weekly events
             self.update_public_visibility(&action, self.week - week_before);
+            self.update_regional_fame(self.week - week_before);
             self.update_lifestyle(&action);
         }
 
         // These happen after every action resolution, regardless of turn consumption
         self.process_music_releases_and_marketing();
         self.check_and_generate_deal_offers(&mut rng);
+        if is_turn_consuming_action {
+            self.manager_weekly_check(self.week - week_before, &mut rng);
+            self.gear_weekly_upkeep(self.week - week_before);
+            self.loan_weekly_repayment(self.week - week_before);
+            self.tax_weekly_upkeep(self.week - week_before);
+            self.investments_weekly_update(self.week - week_before, &mut rng);
+            self.own_label_weekly_update(self.week - week_before);
+        }
+        self.sync_band_label();
         self.check_game_over();
 
         Ok(!self.game_over)
@@ -332,9 +617,7 @@ impl Game {
         if self.player.health == 0 {
             self.game_over = true;
         }
-        if self.player.money < 0 && self.band.fame < 10 {
-            self.game_over = true;
-        }
+        self.check_insolvency();
 
         // Rockstar milestone: one-time event when thresholds are reached
         if !self.rockstar_achieved
@@ -356,8 +639,8 @@ impl Game {
     pub fn get_status_message(&self) -> String {
         if self.player.health == 0 {
             "You died from poor health!".to_string()
-        } else if self.player.money < 0 && self.band.fame < 10 {
-            "You went broke and nobody knows who you are!".to_string()
+        } else if self.game_over && self.is_bankrupt() {
+            "You went broke again before your bankruptcy was discharged!".to_string()
         } else if self.game_over {
             "You walked away from the rock life on your own terms.".to_string()
         } else {
@@ -3,13 +3,14 @@
 //! renewal window that opens before a healthy term's expiry (design §E-4).
 
 use crate::data_loader::{GameDataFiles, RecordLabel, RecordLabelsData};
-use crate::game::band::Band;
+use crate::game::band::{Band, CreativeControl};
 use crate::game::constants::{
     DEAL_EXTENSION_ADVANCE_FRACTION, DEAL_EXTENSION_ALBUMS, DEAL_EXTENSION_TERM_WEEKS,
     DEAL_NEW_CONTRACT_ROYALTY_BUMP_MAX, DEAL_NEW_CONTRACT_ROYALTY_BUMP_MIN,
     DEAL_RENEWAL_DECENT_SALES_MIN_COMMERCIAL_SUCCESS, DEAL_RENEWAL_DEEP_RED_UNRECOUPED,
     DEAL_RENEWAL_WEAK_SALES_MAX_COMMERCIAL_SUCCESS, DEAL_RENEWAL_WINDOW_WEEKS,
     DEAL_TERM_BOUTIQUE_WEEKS, DEAL_TERM_INDEPENDENT_WEEKS, DEAL_TERM_MAJOR_WEEKS,
+    LABEL_SIGNING_MIN_WILLINGNESS,
 };
 use rand::Rng;
 use serde::{Deserialize, Serialize};
@@ -49,6 +50,12 @@ pub struct PotentialDealOffer {
     /// `0` for every ordinary signing and every NEW CONTRACT renewal.
     #[serde(default)]
     pub carry_forward_unrecouped: i32,
+    #[serde(default)]
+    pub creative_control: CreativeControl,
+    /// Counters the player has already put to the label on this offer
+    /// (`negotiation.rs`).
+    #[serde(default)]
+    pub counters: u8,
 }
 
 impl GameWorld {
@@ -56,19 +63,21 @@ impl GameWorld {
         if !rng.gen_bool(0.6) {
             return None;
         }
+        // A friend won't take a deal the band turned down; a rival jumps
+        // the queue for it.
         let idx = self
             .bands
             .iter()
             .enumerate()
-            .filter(|(_, b)| b.label.is_none() && b.fame >= 20)
-            .max_by_key(|(_, b)| b.fame)
+            .filter(|(_, b)| b.label.is_none() && b.fame >= 20 && !b.is_friend())
+            .max_by_key(|(_, b)| (b.is_rival(), b.fame))
             .map(|(i, _)| i)?;
         let band = &mut self.bands[idx];
         band.label = Some(label_name.to_string());
         band.momentum = (band.momentum + 1).min(3);
         Some(band.name.clone())
     }
-    pub(super) fn band_buzz(&self, band: &Band) -> u8 {
+    pub(crate) fn band_buzz(&self, band: &Band) -> u8 {
         let fame_heat = u32::from(band.fame) * 3 / 10;
         let catalog_heat = (band.singles_released.len() as u32 * BUZZ_PER_SINGLE
             + band.albums_released.len() as u32 * BUZZ_PER_ALBUM)
@@ -143,6 +152,12 @@ impl GameWorld {
                     {
                         continue; // Already signed with this label
                     }
+                    // A label that hasn't opened yet, has folded, or has
+                    // no room or money for another act isn't looking.
+                    let willingness = self.label_willingness(label);
+                    if willingness < LABEL_SIGNING_MIN_WILLINGNESS {
+                        continue;
+                    }
 
                     // Random chance to make an offer
                     let offer_chance = match *tier_name {
@@ -174,7 +189,9 @@ impl GameWorld {
                         _ => 0.10,
                     };
 
-                    if rng.gen_bool(offer_chance) {
+                    // The keener the label, the likelier it calls.
+                    let keenness = 0.5 + f64::from(willingness) / 200.0;
+                    if rng.gen_bool(offer_chance * keenness) {
                         let advance_percentage = match band.fame {
                             0..=20 => rng.gen_range(0.0..0.4), // Lower end for low fame
                             21..=50 => rng.gen_range(0.3..0.7),
@@ -185,8 +202,10 @@ impl GameWorld {
                         let calculated_advance = label.advance_range[0]
                             + (advance_range_span as f32 * advance_percentage) as u32;
 
+                        // A label short of cash offers what it can spare.
                         let advance = calculated_advance
-                            .clamp(label.advance_range[0], label.advance_range[1]);
+                            .clamp(label.advance_range[0], label.advance_range[1])
+                            .min(self.label_advance_budget(label));
 
                         let royalty_rate = label.royalty_rate as f32 / 100.0;
 
@@ -210,6 +229,8 @@ impl GameWorld {
                             expires_week: None,
                             term_weeks,
                             carry_forward_unrecouped: 0,
+                            creative_control: CreativeControl::offered_by(label.creative_freedom),
+                            counters: 0,
                         });
                     }
                 }
@@ -237,11 +258,13 @@ impl GameWorld {
         }
         let labels_data = game_data.get_record_labels_data();
         let label_data = find_label_by_name(labels_data, &deal.label_tier, &deal.label_name)?;
+        let budget = self.label_advance_budget(label_data);
 
         match renewal_decision(deal.unrecouped, band.reputation.commercial_success) {
             RenewalDecision::Silence => None,
             RenewalDecision::Extension => {
-                let advance = (deal.advance as f32 * DEAL_EXTENSION_ADVANCE_FRACTION) as u32;
+                let advance =
+                    ((deal.advance as f32 * DEAL_EXTENSION_ADVANCE_FRACTION) as u32).min(budget);
                 Some(PotentialDealOffer {
                     label_name: deal.label_name.clone(),
                     label_tier: deal.label_tier.clone(),
@@ -254,6 +277,8 @@ impl GameWorld {
                     // The label protects its investment: the balance it's
                     // still owed carries into the new deal's ledger.
                     carry_forward_unrecouped: deal.unrecouped,
+                    creative_control: deal.creative_control,
+                    counters: 0,
                 })
             }
             RenewalDecision::NewContract => {
@@ -270,7 +295,8 @@ impl GameWorld {
                 let advance_range_span = label_data.advance_range[1] - label_data.advance_range[0];
                 let advance = (label_data.advance_range[0]
                     + (advance_range_span as f32 * advance_percentage) as u32)
-                    .clamp(label_data.advance_range[0], label_data.advance_range[1]);
+                    .clamp(label_data.advance_range[0], label_data.advance_range[1])
+                    .min(budget);
                 let term_weeks = term_weeks_for_tier(&deal.label_tier, rng);
                 let albums_required = match deal.label_tier.as_str() {
                     "Major" => rng.gen_range(2..=4),
@@ -290,6 +316,8 @@ impl GameWorld {
                     // Recouped — a fresh ledger starts clean off the new
                     // advance alone.
                     carry_forward_unrecouped: 0,
+                    creative_control: deal.creative_control,
+                    counters: 0,
                 })
             }
         }
@@ -0,0 +1,228 @@
+//! The summer festival calendar: published each new year, sized by the
+//! era's touring market, with bills filled from the scene. The band is
+//! offered a slot when it's famous enough (`turn.rs`) and plays it from
+//! `actions/live.rs`.
+
+use crate::data_loader::GameDataFiles;
+use crate::game::constants::*;
+use crate::game::timeline::MusicTimeline;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::GameWorld;
+
+const SUFFIXES: [&str; 6] = [
+    "Festival",
+    "Rock Festival",
+    "Open Air",
+    "Pop Festival",
+    "Weekender",
+    "Jam",
+];
+
+/// Where on the bill a slot sits, from first on to top of the poster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FestivalBilling {
+    Opener,
+    Support,
+    SubHeadliner,
+    Headliner,
+}
+
+impl FestivalBilling {
+    pub const ALL: [FestivalBilling; 4] = [
+        FestivalBilling::Opener,
+        FestivalBilling::Support,
+        FestivalBilling::SubHeadliner,
+        FestivalBilling::Headliner,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FestivalBilling::Opener => "opener",
+            FestivalBilling::Support => "support act",
+            FestivalBilling::SubHeadliner => "sub-headliner",
+            FestivalBilling::Headliner => "headliner",
+        }
+    }
+
+    /// Fame needed for this billing at a festival of the given size.
+    pub fn fame_gate(self, size: u8) -> u8 {
+        size.saturating_sub(FESTIVAL_BILLING_FAME_BELOW[self as usize])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FestivalSlot {
+    pub billing: FestivalBilling,
+    pub act: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Festival {
+    /// Unique across the game: the year times ten plus its place in the
+    /// calendar.
+    pub id: u32,
+    pub name: String,
+    /// `country:region`, like `Game::regional_fame`.
+    pub region_key: String,
+    pub region_name: String,
+    /// The absolute week it's held.
+    pub week: u32,
+    /// 0-100: sets the crowd, the fee, and the fame gates.
+    pub size: u8,
+    /// Headliner first.
+    pub lineup: Vec<FestivalSlot>,
+    /// Whether the band has been offered a slot this year.
+    #[serde(default)]
+    pub offered: bool,
+}
+
+impl Festival {
+    pub fn crowd(&self) -> u32 {
+        u32::from(self.size) * FESTIVAL_CROWD_PER_SIZE
+    }
+
+    pub fn country_key(&self) -> &str {
+        self.region_key.split(':').next().unwrap_or_default()
+    }
+
+    /// The best billing a band this famous can get here, if any.
+    pub fn best_billing_for(&self, fame: u8) -> Option<FestivalBilling> {
+        FestivalBilling::ALL
+            .into_iter()
+            .rev()
+            .find(|billing| fame >= billing.fame_gate(self.size))
+    }
+
+    pub fn headliner(&self) -> Option<&str> {
+        self.lineup
+            .iter()
+            .find(|slot| slot.billing == FestivalBilling::Headliner)
+            .map(|slot| slot.act.as_str())
+    }
+}
+
+impl GameWorld {
+    /// Publish the year's festival calendar, replacing last year's. Runs on
+    /// the world stream after the venue turnover, the first week of each
+    /// year (and the first week of a new game).
+    pub(in crate::game) fn publish_festival_calendar(
+        &mut self,
+        year: u32,
+        timeline: &MusicTimeline,
+        data_files: &GameDataFiles,
+        rng: &mut impl Rng,
+    ) -> Vec<String> {
+        let touring_market = timeline.get_current_era().market_conditions.touring_market;
+        let count = FESTIVAL_BASE_COUNT + usize::from(touring_market / FESTIVAL_TOURING_STEP);
+
+        let markets = &data_files.markets_data.markets;
+        let mut regions: Vec<(String, &crate::data_loader::RegionMarket)> = markets
+            .iter()
+            .flat_map(|(country, market)| {
+                market
+                    .regions
+                    .iter()
+                    .map(move |(region, data)| (format!("{}:{}", country, region), data))
+            })
+            .collect();
+        regions.sort_by(|a, b| a.0.cmp(&b.0));
+        if regions.is_empty() {
+            self.festivals.clear();
+            self.festival_year = year;
+            return Vec::new();
+        }
+
+        let year_start = (year - STARTING_YEAR) * WEEKS_PER_YEAR;
+        let mut festivals = Vec::with_capacity(count);
+        for i in 0..count {
+            let (region_key, region) = &regions[rng.gen_range(0..regions.len())];
+            let town = if region.major_cities.is_empty() {
+                region.name.as_str()
+            } else {
+                region.major_cities[rng.gen_range(0..region.major_cities.len())].as_str()
+            };
+            let suffix = SUFFIXES[rng.gen_range(0..SUFFIXES.len())];
+            let week_of_year = rng.gen_range(FESTIVAL_SEASON_START_WEEK..=FESTIVAL_SEASON_END_WEEK);
+            let size = (rng.gen_range(FESTIVAL_SIZE_ROLL.0..=FESTIVAL_SIZE_ROLL.1)
+                + touring_market / FESTIVAL_SIZE_TOURING_DIVISOR)
+                .min(100);
+            let lineup = self.fill_lineup(size, rng);
+            festivals.push(Festival {
+                id: year * 10 + i as u32,
+                name: format!("{} {}", town, suffix),
+                region_key: region_key.clone(),
+                region_name: region.name.clone(),
+                week: year_start + week_of_year,
+                size,
+                lineup,
+                offered: false,
+            });
+        }
+        festivals.sort_by_key(|festival| festival.week);
+
+        let news = festivals
+            .iter()
+            .map(|festival| match festival.headliner() {
+                Some(headliner) => format!(
+                    "🎪 {} announced for week {} in {} — {} to headline.",
+                    festival.name, festival.week, festival.region_name, headliner
+                ),
+                None => format!(
+                    "🎪 {} announced for week {} in {}.",
+                    festival.name, festival.week, festival.region_name
+                ),
+            })
+            .collect();
+        self.festivals = festivals;
+        self.festival_year = year;
+        news
+    }
+
+    /// Fill a bill from the scene, headliner down. Each slot goes to an act
+    /// whose fame fits that billing; failing that, to any act famous enough
+    /// for it; failing that, to the most famous act still free. A band
+    /// plays once per festival.
+    fn fill_lineup(&self, size: u8, rng: &mut impl Rng) -> Vec<FestivalSlot> {
+        let mut used = vec![false; self.bands.len()];
+        let mut lineup = Vec::new();
+        for (i, billing) in FestivalBilling::ALL.into_iter().enumerate().rev() {
+            let gate = billing.fame_gate(size);
+            let ceiling = FestivalBilling::ALL
+                .get(i + 1)
+                .map(|above| above.fame_gate(size))
+                .unwrap_or(u8::MAX);
+            for _ in 0..FESTIVAL_BILLING_SLOTS[i] {
+                let free = || (0..self.bands.len()).filter(|&b| !used[b]);
+                let fitting: Vec<usize> = free()
+                    .filter(|&b| (gate..ceiling).contains(&self.bands[b].fame))
+                    .collect();
+                let pick = if !fitting.is_empty() {
+                    Some(fitting[rng.gen_range(0..fitting.len())])
+                } else {
+                    let qualified: Vec<usize> =
+                        free().filter(|&b| self.bands[b].fame >= gate).collect();
+                    if qualified.is_empty() {
+                        free().max_by_key(|&b| self.bands[b].fame)
+                    } else {
+                        Some(qualified[rng.gen_range(0..qualified.len())])
+                    }
+                };
+                let Some(b) = pick else {
+                    break;
+                };
+                used[b] = true;
+                lineup.push(FestivalSlot {
+                    billing,
+                    act: self.bands[b].name.clone(),
+                });
+            }
+        }
+        lineup
+    }
+
+    pub fn festival(&self, id: u32) -> Option<&Festival> {
+        self.festivals.iter().find(|festival| festival.id == id)
+    }
+}
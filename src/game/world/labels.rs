@@ -0,0 +1,411 @@
+//! Record labels as going concerns. Each label in `record_labels.json`
+//! opens its doors in the year it was founded and from then on keeps a
+//! bank balance: its roster's catalogue and new records bring money in,
+//! overhead and the acts it carries take it out. A label with hits and
+//! room on its roster is keen to sign; one running short stops paying
+//! advances, and one that stays in the red long enough folds, leaving its
+//! acts unsigned — unless a bigger label buys it first, taking its roster,
+//! its catalogue and its debts. A healthy small label can be bought out too.
+//! The roster itself is whoever on the scene records for the label
+//! (`SceneBand::label`), plus the player when signed there.
+
+use crate::data_loader::{GameDataFiles, RecordLabel};
+use crate::game::constants::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::GameWorld;
+use super::scene::SceneBand;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelState {
+    pub name: String,
+    /// Money in the bank; below zero the label is running on debt.
+    pub cash: i64,
+    /// Records put out since the label opened, and how many of them
+    /// charted high enough to count as hits.
+    pub releases: u32,
+    pub hits: u32,
+    /// Weeks in a row the label has ended in the red.
+    pub weeks_in_red: u32,
+    /// Gone for good: a folded label never reopens.
+    pub folded: bool,
+    /// The label that bought this one out. A bought label is also
+    /// `folded`: it trades on only as part of its buyer.
+    #[serde(default)]
+    pub absorbed_by: Option<String>,
+}
+
+impl LabelState {
+    fn open(label: &RecordLabel) -> Self {
+        Self {
+            name: label.name.clone(),
+            cash: i64::from(label.financial_power) * LABEL_START_CASH_PER_POWER,
+            releases: 0,
+            hits: 0,
+            weeks_in_red: 0,
+            folded: false,
+            absorbed_by: None,
+        }
+    }
+
+    /// Share of the label's records that became hits. A label with little
+    /// history reads near even odds rather than all or nothing.
+    pub fn hit_rate(&self) -> f32 {
+        (self.hits as f32 + 1.0) / (self.releases as f32 + 2.0)
+    }
+
+    /// What the label spends a week: its offices by size, plus every act
+    /// it carries.
+    pub fn weekly_overhead(label: &RecordLabel, roster: usize) -> i64 {
+        i64::from(label.financial_power) * LABEL_OVERHEAD_PER_POWER
+            + roster as i64 * LABEL_UPKEEP_PER_ACT
+    }
+
+    /// Weeks of overhead the bank balance covers.
+    fn runway_weeks(&self, label: &RecordLabel, roster: usize) -> i64 {
+        self.cash / Self::weekly_overhead(label, roster).max(1)
+    }
+
+    /// The most the label will pay up front: whatever it holds beyond a
+    /// reserve for its own overhead. Nothing once the label is bleeding.
+    pub fn advance_budget(&self, label: &RecordLabel, roster: usize) -> u32 {
+        if self.folded {
+            return 0;
+        }
+        let reserve = Self::weekly_overhead(label, roster) * LABEL_ADVANCE_RESERVE_WEEKS;
+        (self.cash - reserve).clamp(0, i64::from(u32::MAX)) as u32
+    }
+
+    /// How keen the label is to sign another act, 0-100: room on the
+    /// roster, a record of hits, and cash to carry a new act all count. A
+    /// label in the red signs nobody.
+    pub fn willingness_to_sign(&self, label: &RecordLabel, roster: usize) -> u8 {
+        if self.folded || self.cash <= 0 {
+            return 0;
+        }
+        let capacity = (usize::from(label.financial_power) / LABEL_POWER_PER_ROSTER_SLOT).max(2);
+        // A full roster makes a label choosy; well past full, it's closed.
+        let room = (1.0 - roster as f32 / capacity as f32 * LABEL_FULL_ROSTER_RELUCTANCE).max(0.0);
+        let form = 0.5 + self.hit_rate();
+        let runway =
+            (self.runway_weeks(label, roster) as f32 / LABEL_COMFORTABLE_RUNWAY_WEEKS).min(1.0);
+        (room * form * runway * 100.0).clamp(0.0, 100.0) as u8
+    }
+
+    /// A word on the label's finances for the offer screen.
+    pub fn standing(&self, label: &RecordLabel, roster: usize) -> &'static str {
+        if self.absorbed_by.is_some() {
+            "Absorbed"
+        } else if self.folded {
+            "Folded"
+        } else if self.cash < 0 {
+            "In the red"
+        } else if self.advance_budget(label, roster) == 0 {
+            "Stretched"
+        } else if self.runway_weeks(label, roster) as f32 >= LABEL_COMFORTABLE_RUNWAY_WEEKS * 2.0 {
+            "Flush"
+        } else {
+            "Steady"
+        }
+    }
+}
+
+/// Every label in the data file, majors first.
+pub(in crate::game) fn all_labels(
+    data_files: &GameDataFiles,
+) -> impl Iterator<Item = &RecordLabel> {
+    let labels = data_files.get_record_labels_data();
+    labels
+        .major_labels
+        .iter()
+        .chain(&labels.independent_labels)
+        .chain(&labels.boutique_labels)
+}
+
+pub(in crate::game) fn find_label<'a>(
+    data_files: &'a GameDataFiles,
+    name: &str,
+) -> Option<&'a RecordLabel> {
+    all_labels(data_files).find(|label| label.name == name)
+}
+
+/// A label in the data file along with the tier it's listed under.
+pub(in crate::game) fn find_label_with_tier<'a>(
+    data_files: &'a GameDataFiles,
+    name: &str,
+) -> Option<(&'static str, &'a RecordLabel)> {
+    let labels = data_files.get_record_labels_data();
+    [
+        ("Major", &labels.major_labels),
+        ("Independent", &labels.independent_labels),
+        ("Boutique", &labels.boutique_labels),
+    ]
+    .into_iter()
+    .find_map(|(tier, list)| {
+        list.iter()
+            .find(|label| label.name == name)
+            .map(|label| (tier, label))
+    })
+}
+
+impl GameWorld {
+    /// The label's state, if it has opened its doors (folded or not).
+    pub fn label(&self, name: &str) -> Option<&LabelState> {
+        self.labels.iter().find(|label| label.name == name)
+    }
+
+    fn label_mut(&mut self, name: &str) -> Option<&mut LabelState> {
+        self.labels.iter_mut().find(|label| label.name == name)
+    }
+
+    /// Who owns a bought-out label now, following the chain if its buyer
+    /// was bought in turn. `None` for a label nobody has bought.
+    pub fn label_successor(&self, name: &str) -> Option<&str> {
+        let mut owner = self.label(name)?.absorbed_by.as_deref()?;
+        while let Some(next) = self
+            .label(owner)
+            .and_then(|label| label.absorbed_by.as_deref())
+        {
+            owner = next;
+        }
+        Some(owner)
+    }
+
+    /// The scene acts recording for a label.
+    pub fn label_roster<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a SceneBand> {
+        self.bands
+            .iter()
+            .filter(move |band| band.label.as_deref() == Some(name))
+    }
+
+    /// How many acts a label carries: its scene roster, plus the player's
+    /// band when signed there.
+    pub fn label_roster_size(&self, name: &str) -> usize {
+        self.label_roster(name).count() + usize::from(self.band_label.as_deref() == Some(name))
+    }
+
+    /// How keen a label is to sign, counting its whole roster; 0 for a
+    /// label that hasn't opened yet or has folded.
+    pub fn label_willingness(&self, label: &RecordLabel) -> u8 {
+        self.label(&label.name).map_or(0, |state| {
+            state.willingness_to_sign(label, self.label_roster_size(&label.name))
+        })
+    }
+
+    /// The most a label can pay as an advance right now.
+    pub fn label_advance_budget(&self, label: &RecordLabel) -> u32 {
+        self.label(&label.name).map_or(0, |state| {
+            state.advance_budget(label, self.label_roster_size(&label.name))
+        })
+    }
+
+    /// Open every label founded by `year` that isn't trading yet. Returns
+    /// the names that opened.
+    pub(super) fn open_labels(&mut self, data_files: &GameDataFiles, year: u32) -> Vec<String> {
+        let mut opened = Vec::new();
+        for label in all_labels(data_files) {
+            if label.founded <= year && self.label(&label.name).is_none() {
+                self.labels.push(LabelState::open(label));
+                opened.push(label.name.clone());
+            }
+        }
+        opened
+    }
+
+    /// A record out on the label: the money it made the label, and whether
+    /// it was a hit. A label that isn't trading ignores it.
+    pub(in crate::game) fn credit_label_release(&mut self, name: &str, take: i64, hit: bool) {
+        if let Some(label) = self.label_mut(name).filter(|label| !label.folded) {
+            label.cash += take;
+            label.releases += 1;
+            if hit {
+                label.hits += 1;
+            }
+        }
+    }
+
+    /// Money the label pays out up front, like an advance.
+    pub(in crate::game) fn debit_label(&mut self, name: &str, amount: i64) {
+        if let Some(label) = self.label_mut(name) {
+            label.cash -= amount;
+        }
+    }
+
+    /// Money paid to the label outside a new record: a catalogue's takings,
+    /// or the price of a master it sells.
+    pub(in crate::game) fn credit_label(&mut self, name: &str, amount: i64) {
+        if let Some(label) = self.label_mut(name).filter(|label| !label.folded) {
+            label.cash += amount;
+        }
+    }
+
+    /// The label best placed to buy a master at `price`: whichever has the
+    /// most to spare, so long as it can pay out of its advance budget.
+    pub fn catalogue_buyer(&self, data_files: &GameDataFiles, price: u32) -> Option<String> {
+        all_labels(data_files)
+            .map(|label| (self.label_advance_budget(label), label))
+            .filter(|&(budget, _)| budget >= price)
+            .max_by_key(|&(budget, _)| budget)
+            .map(|(_, label)| label.name.clone())
+    }
+
+    /// The labels' week: new ones open as their year comes, the rest earn
+    /// off their back catalogue and their roster's fame and pay their
+    /// overhead, and any that has been in the red too long folds and lets
+    /// its acts go.
+    pub(super) fn update_labels(
+        &mut self,
+        data_files: &GameDataFiles,
+        year: u32,
+        news: &mut Vec<String>,
+    ) {
+        let first_opening = self.labels.is_empty();
+        for name in self.open_labels(data_files, year) {
+            if !first_opening {
+                news.push(format!("🏢 {} opens its doors and starts signing.", name));
+            }
+        }
+
+        let market = self.get_market_modifier();
+        let mut folded = Vec::new();
+        for label in all_labels(data_files) {
+            // The player's records pay the label as they sell, so only the
+            // scene acts' fame feeds the catalogue; the band still costs its
+            // share of the overhead.
+            let fame = self
+                .label_roster(&label.name)
+                .map(|band| i64::from(band.fame))
+                .sum::<i64>();
+            let roster = self.label_roster_size(&label.name);
+            let Some(state) = self.label_mut(&label.name).filter(|state| !state.folded) else {
+                continue;
+            };
+            let catalogue = ((fame * LABEL_CATALOGUE_PER_FAME
+                + i64::from(label.market_reach) * LABEL_BACK_CATALOGUE_PER_REACH)
+                as f32
+                * market) as i64;
+            state.cash += catalogue - LabelState::weekly_overhead(label, roster);
+            // The owners take their profits out of anything past a war chest.
+            let ceiling = i64::from(label.financial_power) * LABEL_CASH_CEILING_PER_POWER;
+            if state.cash > ceiling {
+                state.cash -= ((state.cash - ceiling) as f32 * LABEL_PROFIT_PAYOUT_SHARE) as i64;
+            }
+            if state.cash < 0 {
+                state.weeks_in_red += 1;
+                if state.weeks_in_red >= LABEL_FOLD_WEEKS_IN_RED {
+                    state.folded = true;
+                    folded.push(label.name.clone());
+                }
+            } else {
+                state.weeks_in_red = 0;
+            }
+        }
+
+        for name in folded {
+            let mut freed = 0;
+            for band in self
+                .bands
+                .iter_mut()
+                .filter(|band| band.label.as_deref() == Some(name.as_str()))
+            {
+                band.label = None;
+                band.momentum = (band.momentum - 1).max(-3);
+                freed += 1;
+            }
+            news.push(if freed == 0 {
+                format!("💀 {} has gone under.", name)
+            } else {
+                format!(
+                    "💀 {} has gone under — {} act{} on its roster {} unsigned.",
+                    name,
+                    freed,
+                    if freed == 1 { "" } else { "s" },
+                    if freed == 1 { "is" } else { "are" }
+                )
+            });
+        }
+    }
+
+    /// The week's mergers: at most one label is bought out by a bigger one
+    /// with the money to pay its owners and take on its debts. A label deep
+    /// in the red is a likely target; a healthy small one only rarely sells.
+    /// The buyer takes its cash, its track record and every act on its
+    /// roster.
+    pub(super) fn update_label_mergers(
+        &mut self,
+        data_files: &GameDataFiles,
+        rng: &mut impl Rng,
+        news: &mut Vec<String>,
+    ) {
+        for target in all_labels(data_files) {
+            let Some(state) = self.label(&target.name).filter(|state| !state.folded) else {
+                continue;
+            };
+            let distressed = state.weeks_in_red >= LABEL_DISTRESSED_WEEKS_IN_RED;
+            let chance = if distressed {
+                LABEL_DISTRESSED_BUYOUT_CHANCE
+            } else {
+                LABEL_BUYOUT_CHANCE
+            };
+            if !rng.gen_bool(chance) {
+                continue;
+            }
+            let cost = i64::from(target.financial_power) * LABEL_BUYOUT_PRICE_PER_POWER
+                + (-state.cash).max(0);
+            let Some(buyer) = all_labels(data_files)
+                .filter(|buyer| buyer.financial_power > target.financial_power)
+                .map(|buyer| (self.label_advance_budget(buyer), buyer))
+                .filter(|&(budget, _)| i64::from(budget) >= cost)
+                .max_by_key(|&(budget, _)| budget)
+                .map(|(_, buyer)| buyer.name.clone())
+            else {
+                continue;
+            };
+            let moved = self.absorb_label(&target.name, &buyer, target.financial_power);
+            news.push(format!(
+                "🏢 {} buys {}{} — {} act{} on its roster move{} across.",
+                buyer,
+                if distressed { "the struggling " } else { "" },
+                target.name,
+                moved,
+                if moved == 1 { "" } else { "s" },
+                if moved == 1 { "s" } else { "" }
+            ));
+            return;
+        }
+    }
+
+    /// Fold `target` into `buyer`: the buyer pays the owners, takes on the
+    /// target's balance and history, and signs its whole roster. Returns
+    /// how many acts moved.
+    pub(in crate::game) fn absorb_label(
+        &mut self,
+        target: &str,
+        buyer: &str,
+        target_power: u8,
+    ) -> usize {
+        let Some(state) = self.label_mut(target) else {
+            return 0;
+        };
+        let (cash, releases, hits) = (state.cash, state.releases, state.hits);
+        state.cash = 0;
+        state.folded = true;
+        state.absorbed_by = Some(buyer.to_string());
+        if let Some(buyer_state) = self.label_mut(buyer) {
+            buyer_state.cash += cash - i64::from(target_power) * LABEL_BUYOUT_PRICE_PER_POWER;
+            buyer_state.releases += releases;
+            buyer_state.hits += hits;
+        }
+        let mut moved = 0;
+        for band in self
+            .bands
+            .iter_mut()
+            .filter(|band| band.label.as_deref() == Some(target))
+        {
+            band.label = Some(buyer.to_string());
+            moved += 1;
+        }
+        moved
+    }
+}
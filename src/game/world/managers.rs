@@ -0,0 +1,98 @@
+//! Manager scouting (FUTURE §9.4): the deal-offer stream applied to a
+//! person. Managers read the same buzz labels do; what they're worth —
+//! and whether they can be trusted — is rolled here and only half shown.
+
+use crate::data_loader::GameDataFiles;
+use crate::game::band::Band;
+use crate::game::constants::{
+    MANAGER_CUT_MAX, MANAGER_CUT_MIN, MANAGER_MAX_OFFERS, MANAGER_OFFER_CHANCE,
+    MANAGER_OFFER_MIN_BUZZ,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::GameWorld;
+
+/// The band's manager, once hired. `cut` and `skill` are what the pitch
+/// promised and what the player sees; `honesty` and the running skim are
+/// hidden on purpose — the only way to learn them is to get caught out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manager {
+    pub name: String,
+    /// Share of ALL income reaching the player, 0.10–0.25.
+    pub cut: f32,
+    /// 0-100: how much they actually do for you.
+    pub skill: u8,
+    pub(in crate::game) honesty: u8,
+    pub hired_week: u32,
+    /// Commission taken in the open, for the severance math and the books.
+    #[serde(default)]
+    pub commission_paid: u32,
+    /// What's gone missing on top of the commission. Never shown.
+    #[serde(default)]
+    pub(in crate::game) skimmed: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PotentialManagerOffer {
+    pub name: String,
+    pub cut: f32,
+    pub skill: u8,
+    pub(in crate::game) honesty: u8,
+    /// Stamped by the game when the offer lands, like a label's.
+    #[serde(default)]
+    pub expires_week: Option<u32>,
+}
+
+impl PotentialManagerOffer {
+    pub(in crate::game) fn into_manager(self, hired_week: u32) -> Manager {
+        Manager {
+            name: self.name,
+            cut: self.cut,
+            skill: self.skill,
+            honesty: self.honesty,
+            hired_week,
+            commission_paid: 0,
+            skimmed: 0,
+        }
+    }
+}
+
+impl GameWorld {
+    /// One scouting cycle's worth of manager pitches. Buzz sets the floor
+    /// and ceiling of the skill on offer — a hot act draws the operators,
+    /// a local one the hustlers — and the better the manager, the bigger
+    /// the cut they ask. Honesty is independent of both.
+    pub fn generate_manager_offers(
+        &self,
+        band: &Band,
+        game_data: &GameDataFiles,
+        rng: &mut impl Rng,
+    ) -> Vec<PotentialManagerOffer> {
+        let buzz = self.band_buzz(band);
+        if buzz < MANAGER_OFFER_MIN_BUZZ || !rng.gen_bool(MANAGER_OFFER_CHANCE) {
+            return Vec::new();
+        }
+        let count = rng.gen_range(1..=MANAGER_MAX_OFFERS);
+        let skill_floor = (10 + u32::from(buzz)).min(80) as u8;
+        let skill_ceiling = (30 + u32::from(buzz) * 2).min(95) as u8;
+        (0..count)
+            .map(|_| {
+                let name = game_data.random_manager_name(rng);
+                let skill = rng.gen_range(skill_floor..=skill_ceiling);
+                let asking = MANAGER_CUT_MIN
+                    + (MANAGER_CUT_MAX - MANAGER_CUT_MIN) * f32::from(skill) / 100.0;
+                let cut =
+                    (asking + rng.gen_range(-0.02..=0.02)).clamp(MANAGER_CUT_MIN, MANAGER_CUT_MAX);
+                let honesty = rng.gen_range(0..=100);
+                PotentialManagerOffer {
+                    name,
+                    cut,
+                    skill,
+                    honesty,
+                    expires_week: None,
+                }
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,12 @@
-//! The living world: market, scene, charts, venues, and deal scouting.
+//! The living world: market, scene, charts, venues, and deal and manager
+//! scouting.
 
 mod charts;
 mod deals;
+mod festivals;
+mod labels;
+mod managers;
+mod promoters;
 mod regions;
 mod scene;
 mod venues;
@@ -11,12 +16,21 @@ mod venues;
 #[allow(unused_imports)]
 pub use charts::{CHART_DEPTH, ChartEntry};
 pub use deals::PotentialDealOffer;
+pub use festivals::{Festival, FestivalBilling, FestivalSlot};
+pub use labels::LabelState;
+pub(in crate::game) use labels::find_label_with_tier;
+pub use managers::{Manager, PotentialManagerOffer};
+pub use promoters::TourPromoter;
 pub use regions::ChartRegion;
 #[allow(unused_imports)]
-pub use scene::{SCENE_MAX_BANDS, SCENE_MIN_BANDS, SCENE_START_BANDS, SceneBand};
+pub use scene::{
+    PlayerLabelRelease, SCENE_MAX_BANDS, SCENE_MIN_BANDS, SCENE_START_BANDS, SceneBand,
+};
 pub use venues::Venue;
+pub(in crate::game) use venues::VenueStanding;
 
 use crate::data_loader::GameDataFiles;
+use crate::game::constants::STARTING_YEAR;
 use crate::game::genre::MusicGenre;
 use crate::game::timeline::MusicTimeline;
 use rand::Rng;
@@ -31,6 +45,36 @@ pub struct GameWorld {
     #[serde(alias = "competing_bands")]
     pub bands: Vec<SceneBand>,
     pub venues: Vec<Venue>,
+    /// Venue rosters of the regions the band has toured, keyed
+    /// `country:region` like `Game::regional_fame`; built on the first
+    /// tour there.
+    #[serde(default)]
+    pub regional_venues: BTreeMap<String, Vec<Venue>>,
+    /// This year's festival calendar, in date order, and the year it was
+    /// published for (0 until the first one is).
+    #[serde(default)]
+    pub festivals: Vec<Festival>,
+    #[serde(default)]
+    pub festival_year: u32,
+    /// The promoters who book headline tours, filled the first time one
+    /// could make an offer.
+    #[serde(default)]
+    pub tour_promoters: Vec<TourPromoter>,
+    /// Every label that has opened its doors, folded ones included;
+    /// opened as each one's founding year arrives (`labels.rs`).
+    #[serde(default)]
+    pub labels: Vec<LabelState>,
+    /// The player's own label, once founded (`own_label.rs`): scene bands
+    /// signed to it carry this name, and the records they put out wait
+    /// here until the game books what they earned.
+    #[serde(default)]
+    pub player_label: Option<String>,
+    #[serde(default)]
+    pub player_label_releases: Vec<PlayerLabelRelease>,
+    /// The label the player's band is signed to, mirrored from its deal
+    /// each turn so that label counts the band on its roster.
+    #[serde(default)]
+    pub band_label: Option<String>,
     pub current_trends: MusicTrend,
     #[serde(default)]
     pub dynamic_genre_modifiers: std::collections::HashMap<MusicGenre, f32>,
@@ -62,6 +106,18 @@ pub enum EconomicState {
     Booming,
 }
 
+impl EconomicState {
+    /// Key into `markets.json`'s `economic_cycle_effects` map.
+    pub fn markets_key(self) -> &'static str {
+        match self {
+            EconomicState::Recession => "recession",
+            EconomicState::Stagnant => "stagnant",
+            EconomicState::Growing => "growing",
+            EconomicState::Booming => "booming",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MusicTrend {
     Rock,
@@ -74,7 +130,7 @@ pub enum MusicTrend {
 
 impl GameWorld {
     pub fn new(data_files: &GameDataFiles, rng: &mut impl Rng) -> Self {
-        Self {
+        let mut world = Self {
             music_market: MusicMarket {
                 demand: 50,
                 saturation: 55,
@@ -82,11 +138,21 @@ impl GameWorld {
             },
             bands: Self::generate_scene(data_files, rng),
             venues: Self::generate_venues(data_files, rng),
+            regional_venues: BTreeMap::new(),
+            festivals: Vec::new(),
+            festival_year: 0,
+            tour_promoters: Vec::new(),
+            labels: Vec::new(),
+            player_label: None,
+            player_label_releases: Vec::new(),
+            band_label: None,
             current_trends: MusicTrend::Rock,
             dynamic_genre_modifiers: std::collections::HashMap::new(),
             charts: Vec::new(),
             regional_charts: BTreeMap::new(),
-        }
+        };
+        world.open_labels(data_files, STARTING_YEAR);
+        world
     }
 
     /// Advance the world by one week. Returns newsworthy events.
@@ -102,7 +168,9 @@ impl GameWorld {
         self.decay_charts(&mut news);
         self.update_scene_bands(rng, timeline, data_files, &mut news);
         self.update_scene_population(rng, timeline, data_files, &mut news);
+        self.update_labels(data_files, timeline.get_current_year(), &mut news);
         self.update_trends_with_timeline(timeline, rng);
+        self.update_label_mergers(data_files, rng, &mut news);
 
         // Decay dynamic genre modifiers
         let mut new_modifiers = std::collections::HashMap::new();
@@ -386,6 +454,10 @@ mod tests {
             certified: 0,
             distribution_channel: None,
             label_market_reach: None,
+            live: None,
+            outside_deal: false,
+            master_owner: None,
+            rerecording_of: None,
         }
     }
 
@@ -0,0 +1,94 @@
+//! Tour promoters: the outfits that book a famous band's headline tours
+//! and carry the money risk. The circuit is filled the first time one is
+//! needed (`turn.rs`); each promoter remembers how the shows they backed
+//! went, and stops calling a band that keeps losing them money.
+
+use crate::data_loader::GameDataFiles;
+use crate::game::constants::*;
+use crate::game::shows::ShowVerdict;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::GameWorld;
+
+const SUFFIXES: [&str; 5] = [
+    "Presents",
+    "Concerts",
+    "Promotions",
+    "Live",
+    "Entertainment",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TourPromoter {
+    pub name: String,
+    /// The promoter's opinion of the band, 0-100: moves the deal, and below
+    /// `TOUR_PROMOTER_OFFER_MIN_OPINION` ends the offers.
+    pub opinion: u8,
+    /// Headline tours the band has played for them.
+    #[serde(default)]
+    pub tours_promoted: u32,
+}
+
+impl TourPromoter {
+    pub fn makes_offers(&self) -> bool {
+        self.opinion >= TOUR_PROMOTER_OFFER_MIN_OPINION
+    }
+
+    /// The multiplier the promoter's opinion puts on a deal.
+    pub fn deal_mult(&self) -> f32 {
+        1.0 + (self.opinion as f32 - 50.0) / 50.0 * TOUR_PROMOTER_DEAL_SWING
+    }
+
+    /// Remember a tour they backed: every show's verdict moves the opinion,
+    /// and losing money on it costs a little more. Returns the change.
+    pub(in crate::game) fn record_tour(
+        &mut self,
+        verdicts: &[ShowVerdict],
+        lost_money: bool,
+    ) -> i16 {
+        self.tours_promoted += 1;
+        let mut delta: i16 = verdicts
+            .iter()
+            .map(|&verdict| i16::from(TOUR_PROMOTER_SHOW_DELTA[verdict as usize]))
+            .sum();
+        if lost_money {
+            delta -= i16::from(TOUR_PROMOTER_LOSS_PENALTY);
+        }
+        let before = self.opinion;
+        self.opinion = (i16::from(self.opinion) + delta).clamp(0, 100) as u8;
+        i16::from(self.opinion) - i16::from(before)
+    }
+}
+
+impl GameWorld {
+    /// Fill the promoter circuit if it's short — a new game, or a save from
+    /// before promoters existed. Names come from the manager pool; a clash
+    /// is rerolled, a few times at most.
+    pub(in crate::game) fn ensure_tour_promoters(
+        &mut self,
+        data_files: &GameDataFiles,
+        rng: &mut impl Rng,
+    ) {
+        for _ in 0..TOUR_PROMOTER_COUNT * 4 {
+            if self.tour_promoters.len() >= TOUR_PROMOTER_COUNT {
+                break;
+            }
+            let person = data_files.random_manager_name(rng);
+            let surname = person.split_whitespace().last().unwrap_or("Apex");
+            let name = format!("{} {}", surname, SUFFIXES[rng.gen_range(0..SUFFIXES.len())]);
+            if self.tour_promoters.iter().any(|p| p.name == name) {
+                continue;
+            }
+            self.tour_promoters.push(TourPromoter {
+                name,
+                opinion: TOUR_PROMOTER_START,
+                tours_promoted: 0,
+            });
+        }
+    }
+
+    pub fn tour_promoter(&self, name: &str) -> Option<&TourPromoter> {
+        self.tour_promoters.iter().find(|p| p.name == name)
+    }
+}
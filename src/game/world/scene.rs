@@ -1,12 +1,15 @@
 //! Scene bands: population, weekly lives, worldgen of the roster.
 
-use crate::data_loader::GameDataFiles;
+use crate::data_loader::{GameDataFiles, RecordLabel};
+use crate::game::constants::*;
 use crate::game::genre::MusicGenre;
 use crate::game::timeline::MusicTimeline;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use super::GameWorld;
+use super::labels;
 use super::regions::{self, ChartRegion};
 
 /// The scene never empties out or grows without bound.
@@ -28,9 +31,52 @@ pub struct SceneBand {
     /// Career trajectory: hits build it, flops and neglect erode it.
     #[serde(default)]
     pub momentum: i8,
+    /// How the band feels about the player's, from the tours it opened:
+    /// good nights build it, rough ones sour it.
+    #[serde(default)]
+    pub rapport: i8,
+}
+
+/// A record a scene band put out on the player's label, and where it
+/// charted best, waiting for the game to book the label's take.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerLabelRelease {
+    pub band: String,
+    pub title: String,
+    pub score: u32,
+    pub best_position: Option<usize>,
+}
+
+impl SceneBand {
+    pub fn is_friend(&self) -> bool {
+        self.rapport >= SCENE_FRIEND_RAPPORT
+    }
+
+    pub fn is_rival(&self) -> bool {
+        self.rapport <= SCENE_RIVAL_RAPPORT
+    }
+
+    /// The draw the band brings as an opener: fame, pushed up or down by
+    /// momentum, 0-100.
+    pub fn heat(&self) -> u8 {
+        (i16::from(self.fame) + i16::from(self.momentum) * OPENER_MOMENTUM_HEAT).clamp(0, 100) as u8
+    }
+
+    /// The share of a tour's projected gross the band asks to open it.
+    pub fn opener_fee_share(&self) -> f32 {
+        f32::from(self.fame) / 100.0 * OPENER_FEE_SHARE
+    }
 }
 
 impl GameWorld {
+    pub fn scene_band(&self, name: &str) -> Option<&SceneBand> {
+        self.bands.iter().find(|band| band.name == name)
+    }
+
+    pub(in crate::game) fn scene_band_mut(&mut self, name: &str) -> Option<&mut SceneBand> {
+        self.bands.iter_mut().find(|band| band.name == name)
+    }
+
     pub(super) fn update_scene_bands(
         &mut self,
         rng: &mut impl Rng,
@@ -44,6 +90,12 @@ impl GameWorld {
         // post-loop submission pass can work out where each release
         // charts without re-borrowing `self.bands` (design §C).
         let mut releases: Vec<(usize, String, u32, Option<String>, u8)> = Vec::new();
+        // Only labels trading and keen enough sign anyone this week, the
+        // keenest most often.
+        let keenness: HashMap<String, u32> = labels::all_labels(data_files)
+            .map(|label| (label.name.clone(), u32::from(self.label_willingness(label))))
+            .filter(|&(_, willingness)| willingness >= u32::from(LABEL_SIGNING_MIN_WILLINGNESS))
+            .collect();
 
         for (idx, band) in self.bands.iter_mut().enumerate() {
             let on_trend = trending.iter().any(|t| t.contains(band.genre.name()));
@@ -83,8 +135,14 @@ impl GameWorld {
             }
 
             // Signings: a rising unsigned act catches a label's ear.
-            if band.label.is_none() && band.fame >= 25 && rng.gen_bool(0.02) {
-                let label = Self::random_label_for_fame(data_files, band.fame, rng);
+            if band.label.is_none()
+                && band.fame >= 25
+                && rng.gen_bool(0.02)
+                && let Some(label) =
+                    Self::random_label_for_fame(data_files, band.fame, rng, |label| {
+                        keenness.get(&label.name).copied().unwrap_or(0)
+                    })
+            {
                 if band.fame >= 45 {
                     news.push(format!("🖋️ {} sign with {}.", band.name, label));
                 }
@@ -97,14 +155,20 @@ impl GameWorld {
         // scene band always competes on Local (design §C); unsigned acts
         // spill into the UK once famous enough, signed acts spread by
         // label tier — the `regions` module's presence API decides where.
+        // The player's label spreads its acts like an independent.
         for (idx, title, score, label, fame) in releases {
             let band_name = self.bands[idx].name.clone();
+            let on_player_label = label.is_some() && label == self.player_label;
 
             let mut regions_to_chart = vec![ChartRegion::Local];
-            match label
-                .as_deref()
-                .and_then(|name| regions::label_tier_for(name, data_files))
-            {
+            let tier = if on_player_label {
+                Some("Independent")
+            } else {
+                label
+                    .as_deref()
+                    .and_then(|name| regions::label_tier_for(name, data_files))
+            };
+            match tier {
                 Some(tier) => regions_to_chart.extend(regions::signed_spread(tier, rng)),
                 None => regions_to_chart.extend(regions::unsigned_spillover(fame).iter().copied()),
             }
@@ -122,6 +186,28 @@ impl GameWorld {
                 }
             }
 
+            // The label's cut of the record, bigger the further it reaches.
+            // The player's label books its own (`own_label.rs`).
+            if on_player_label {
+                self.player_label_releases.push(PlayerLabelRelease {
+                    band: band_name.clone(),
+                    title: title.clone(),
+                    score,
+                    best_position: positions.iter().map(|&(_, pos)| pos).min(),
+                });
+            } else if let Some(label) = label
+                .as_deref()
+                .and_then(|name| labels::find_label(data_files, name))
+            {
+                let take = (score as f32 * LABEL_TAKE_PER_SCORE * f32::from(label.market_reach)
+                    / 100.0
+                    * self.get_market_modifier()) as i64;
+                let hit = positions
+                    .iter()
+                    .any(|&(_, pos)| pos <= LABEL_HIT_MAX_POSITION);
+                self.credit_label_release(&label.name, take, hit);
+            }
+
             // Fame/momentum growth still tracks Local — the home board
             // every act enters, so the scene keeps living the way it
             // always did regardless of how far a release spreads abroad.
@@ -224,22 +310,44 @@ impl GameWorld {
                 genre,
                 label: None,
                 momentum: 1,
+                rapport: 0,
             });
         }
     }
-    fn random_label_for_fame(data_files: &GameDataFiles, fame: u8, rng: &mut impl Rng) -> String {
+    /// A label of the tier the band's fame draws, weighted by how keen
+    /// each is (`keenness`; 0 rules a label out). `None` when no label of
+    /// that tier will have the band.
+    fn random_label_for_fame(
+        data_files: &GameDataFiles,
+        fame: u8,
+        rng: &mut impl Rng,
+        keenness: impl Fn(&RecordLabel) -> u32,
+    ) -> Option<String> {
         let labels = &data_files.get_record_labels_data();
-        let pool = if fame >= 60 {
+        let tier = if fame >= 60 {
             &labels.major_labels
         } else if fame >= 35 {
             &labels.independent_labels
         } else {
             &labels.boutique_labels
         };
-        if pool.is_empty() {
-            return "an unknown label".to_string();
+        let weighted: Vec<(&RecordLabel, u32)> = tier
+            .iter()
+            .map(|label| (label, keenness(label)))
+            .filter(|&(_, weight)| weight > 0)
+            .collect();
+        let total: u32 = weighted.iter().map(|&(_, weight)| weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.gen_range(0..total);
+        for (label, weight) in weighted {
+            if roll < weight {
+                return Some(label.name.clone());
+            }
+            roll -= weight;
         }
-        pool[rng.gen_range(0..pool.len())].name.clone()
+        None
     }
 
     fn unique_band_name(&self, data_files: &GameDataFiles, rng: &mut impl Rng) -> String {
@@ -283,7 +391,9 @@ impl GameWorld {
             // Stars are usually signed already; mid-tier acts sometimes are.
             let signed = (fame >= 45 && rng.gen_bool(0.7)) || (fame >= 25 && rng.gen_bool(0.3));
             let label = if signed {
-                Some(Self::random_label_for_fame(data_files, fame, rng))
+                Self::random_label_for_fame(data_files, fame, rng, |label| {
+                    u32::from(label.founded <= STARTING_YEAR)
+                })
             } else {
                 None
             };
@@ -296,6 +406,7 @@ impl GameWorld {
                 genre: MusicGenre::random(rng),
                 label,
                 momentum: rng.gen_range(-1..=1),
+                rapport: 0,
             });
         }
 
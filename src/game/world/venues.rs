@@ -1,11 +1,28 @@
-//! Gig venues generated with the world.
+//! Gig venues: the home roster, which opens and closes rooms as the years
+//! turn, and the rosters of the regions the band tours. Every venue
+//! remembers how the band's shows there went.
 
 use crate::data_loader::GameDataFiles;
+use crate::game::constants::*;
+use crate::game::shows::ShowVerdict;
+use crate::game::timeline::MusicTimeline;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use super::GameWorld;
 
+const DISTRICTS: [&str; 5] = [
+    "Downtown",
+    "City Center",
+    "Industrial District",
+    "Uptown",
+    "Sports Complex",
+];
+
+fn default_promoter() -> u8 {
+    VENUE_PROMOTER_START
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Venue {
     pub name: String,
@@ -13,37 +30,238 @@ pub struct Venue {
     pub prestige: u8, // 0-100
     pub base_payment: u32,
     pub location: String,
+    /// Shows the band has played here, and the sum of their receptions.
+    #[serde(default)]
+    pub shows_played: u32,
+    #[serde(default)]
+    pub reception_total: u32,
+    /// The promoter's opinion of the band, 0-100: moves the fee.
+    #[serde(default = "default_promoter")]
+    pub promoter: u8,
+    /// The week the band may play here again after a ban (0: never banned).
+    #[serde(default)]
+    pub banned_until: u32,
+    /// The last week a return booking stands (0: none offered).
+    #[serde(default)]
+    pub invited_until: u32,
+}
+
+/// What a show did to the band's standing with a venue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::game) enum VenueStanding {
+    Unchanged,
+    InvitedBack,
+    Banned,
+}
+
+impl Venue {
+    fn new(name: String, capacity: u32, prestige: u8, base_payment: u32, location: String) -> Self {
+        Self {
+            name,
+            capacity,
+            prestige,
+            base_payment,
+            location,
+            shows_played: 0,
+            reception_total: 0,
+            promoter: VENUE_PROMOTER_START,
+            banned_until: 0,
+            invited_until: 0,
+        }
+    }
+
+    /// Which of the five home tiers this room is closest to, by capacity.
+    pub fn tier(&self) -> usize {
+        (0..VENUE_TIER_CAPACITY.len())
+            .min_by_key(|&i| VENUE_TIER_CAPACITY[i].abs_diff(self.capacity))
+            .unwrap_or(0)
+    }
+
+    /// Average reception of the band's shows here, if they've played it.
+    pub fn avg_reception(&self) -> Option<u8> {
+        (self.shows_played > 0).then(|| (self.reception_total / self.shows_played) as u8)
+    }
+
+    pub fn is_banned(&self, week: u32) -> bool {
+        week < self.banned_until
+    }
+
+    pub fn has_invite(&self, week: u32) -> bool {
+        week <= self.invited_until
+    }
+
+    /// The fee multiplier this week: the promoter's opinion, plus the
+    /// return-booking bonus while an invitation stands.
+    pub fn fee_mult(&self, week: u32) -> f32 {
+        let opinion = (self.promoter as f32 - 50.0) / 50.0 * VENUE_PROMOTER_FEE_SWING;
+        let invite = if self.has_invite(week) {
+            VENUE_INVITE_FEE_BONUS
+        } else {
+            1.0
+        };
+        (1.0 + opinion) * invite
+    }
+
+    /// Remember a show here: history, the promoter's opinion, and whether
+    /// that earns an invitation back or a ban. A standing invitation is
+    /// spent by the show that used it.
+    pub(in crate::game) fn record_show(
+        &mut self,
+        reception: u8,
+        verdict: ShowVerdict,
+        week: u32,
+    ) -> VenueStanding {
+        self.shows_played += 1;
+        self.reception_total += u32::from(reception);
+        self.invited_until = 0;
+        let delta = VENUE_PROMOTER_DELTA[verdict as usize];
+        self.promoter = self.promoter.saturating_add_signed(delta).min(100);
+
+        if self.promoter < VENUE_BAN_PROMOTER {
+            self.banned_until = week + VENUE_BAN_WEEKS;
+            self.promoter = VENUE_PROMOTER_AFTER_BAN;
+            VenueStanding::Banned
+        } else if matches!(verdict, ShowVerdict::Great | ShowVerdict::Transcendent) {
+            self.invited_until = week + VENUE_INVITE_WEEKS;
+            VenueStanding::InvitedBack
+        } else {
+            VenueStanding::Unchanged
+        }
+    }
+}
+
+/// A new home room cut from a tier's template, with some spread.
+fn open_venue(tier: usize, data_files: &GameDataFiles, rng: &mut impl Rng) -> Venue {
+    let scale: f32 = rng.gen_range(0.7..=1.3);
+    let prestige_shift: i8 = rng.gen_range(-5..=5);
+    let name = data_files.venue_names[rng.gen_range(0..data_files.venue_names.len())].clone();
+    let district = DISTRICTS[rng.gen_range(0..DISTRICTS.len())];
+    let city = &data_files.city_names[rng.gen_range(0..data_files.city_names.len())];
+    Venue::new(
+        name,
+        (VENUE_TIER_CAPACITY[tier] as f32 * scale).round() as u32,
+        VENUE_TIER_PRESTIGE[tier]
+            .saturating_add_signed(prestige_shift)
+            .min(100),
+        (VENUE_TIER_PAYMENT[tier] as f32 * scale).round() as u32,
+        format!("{}, {}", district, city),
+    )
 }
 
 impl GameWorld {
     pub(super) fn generate_venues(data_files: &GameDataFiles, rng: &mut impl Rng) -> Vec<Venue> {
-        let districts = [
-            "Downtown",
-            "City Center",
-            "Industrial District",
-            "Uptown",
-            "Sports Complex",
-        ];
-        let capacities = [50, 200, 500, 2000, 20000];
-        let prestiges = [10, 25, 40, 70, 95];
-        let payments = [100, 300, 800, 3000, 15000];
-
         let mut venues = Vec::new();
-        for i in 0..5 {
-            venues.push(Venue {
-                name: data_files.venue_names[rng.gen_range(0..data_files.venue_names.len())]
-                    .clone(),
-                capacity: capacities[i],
-                prestige: prestiges[i],
-                base_payment: payments[i],
-                location: format!(
-                    "{}, {}",
-                    districts[i],
-                    data_files.city_names[rng.gen_range(0..data_files.city_names.len())].clone()
-                ),
-            });
+        for i in 0..VENUE_TIER_CAPACITY.len() {
+            let name =
+                data_files.venue_names[rng.gen_range(0..data_files.venue_names.len())].clone();
+            let city = &data_files.city_names[rng.gen_range(0..data_files.city_names.len())];
+            venues.push(Venue::new(
+                name,
+                VENUE_TIER_CAPACITY[i],
+                VENUE_TIER_PRESTIGE[i],
+                VENUE_TIER_PAYMENT[i],
+                format!("{}, {}", DISTRICTS[i], city),
+            ));
         }
 
         venues
     }
-}
+
+    /// A new year on the home circuit: some rooms close, each replaced in
+    /// its slot by a new room of the same tier — so every rung of the
+    /// ladder stays playable — and more open at the end of the roster, up
+    /// to a size that grows with the era's touring market. Slots never
+    /// move, so a venue index (`GameAction::Gig`) stays good across years;
+    /// `venue_ladder` gives the smallest-to-biggest order. Runs on the
+    /// world stream, after the weekly scene update.
+    pub(in crate::game) fn turn_over_venues(
+        &mut self,
+        timeline: &MusicTimeline,
+        data_files: &GameDataFiles,
+        rng: &mut impl Rng,
+    ) -> Vec<String> {
+        let mut news = Vec::new();
+
+        for i in 0..self.venues.len() {
+            if !rng.gen_bool(VENUE_YEARLY_CLOSE_CHANCE) {
+                continue;
+            }
+            let venue = open_venue(self.venues[i].tier(), data_files, rng);
+            let closed = std::mem::replace(&mut self.venues[i], venue);
+            news.push(format!(
+                "🏚️ {} ({}) has closed its doors.",
+                closed.name, closed.location
+            ));
+            let venue = &self.venues[i];
+            news.push(format!(
+                "🎪 {} opens in {} — room for {}.",
+                venue.name, venue.location, venue.capacity
+            ));
+        }
+
+        let touring_market = timeline.get_current_era().market_conditions.touring_market;
+        let target = VENUE_ROSTER_BASE + usize::from(touring_market / VENUE_ROSTER_TOURING_STEP);
+        let mut opened = 0;
+        while self.venues.len() < target && opened < VENUE_MAX_OPENINGS_PER_YEAR {
+            let tier = rng.gen_range(0..VENUE_TIER_CAPACITY.len());
+            let venue = open_venue(tier, data_files, rng);
+            news.push(format!(
+                "🎪 {} opens in {} — room for {}.",
+                venue.name, venue.location, venue.capacity
+            ));
+            self.venues.push(venue);
+            opened += 1;
+        }
+
+        news
+    }
+
+    /// Home venue indices from the smallest room to the biggest — the
+    /// order the venue picker lists them in.
+    pub fn venue_ladder(&self) -> Vec<usize> {
+        let mut ladder: Vec<usize> = (0..self.venues.len()).collect();
+        ladder.sort_by_key(|&i| self.venues[i].capacity);
+        ladder
+    }
+
+    /// The roster of a touring region (`country:region`), built the first
+    /// time the band plays there: a few rooms plus one per step of the
+    /// region's `venue_density`, sized around its typical room.
+    pub(in crate::game) fn region_venues(
+        &mut self,
+        region_key: &str,
+        region_name: &str,
+        venue_density: u8,
+        typical_capacity: u32,
+        data_files: &GameDataFiles,
+        rng: &mut impl Rng,
+    ) -> &mut Vec<Venue> {
+        self.regional_venues
+            .entry(region_key.to_string())
+            .or_insert_with(|| {
+                let count = VENUE_REGION_ROSTER_MIN
+                    + usize::from(venue_density / VENUE_REGION_DENSITY_STEP);
+                let mut roster: Vec<Venue> = (0..count)
+                    .map(|_| {
+                        let name = data_files.venue_names
+                            [rng.gen_range(0..data_files.venue_names.len())]
+                        .clone();
+                        let city =
+                            &data_files.city_names[rng.gen_range(0..data_files.city_names.len())];
+                        let scale = rng.gen_range(
+                            1.0 - VENUE_REGION_CAPACITY_SPREAD..=1.0 + VENUE_REGION_CAPACITY_SPREAD,
+                        );
+                        Venue::new(
+                            name,
+                            ((typical_capacity as f32 * scale).round() as u32).max(1),
+                            0,
+                            0,
+                            format!("{}, {}", city, region_name),
+                        )
+                    })
+                    .collect();
+                roster.sort_by_key(|venue| venue.capacity);
+                roster
+            })
+    }
+}
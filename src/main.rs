@@ -25,6 +25,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "album_titles.txt",
                 "band_names.txt",
                 "band_member_names.txt",
+                "manager_names.txt",
                 "venue_names.txt",
                 "city_names.txt",
                 "timeline.json",
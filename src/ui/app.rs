@@ -2,10 +2,11 @@ use ratatui::DefaultTerminal;
 use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 use crate::data::constants;
-use crate::game::music::{DistributionChannel, ReleaseType};
+use crate::game::music::{DistributionChannel, LiveFormat, ReleaseType};
 use crate::game::{
-    BREAK_WEEKS, GIG_HEALTH_GUARD, GIG_STRESS_GUARD, Game, GameAction, PRESSING_TIERS,
-    STUDIO_STRESS_BLOCK, TOUR_HEALTH_GUARD, TOUR_STRESS_GUARD, TourRig,
+    CrewRole, DealCounter, GIG_HEALTH_GUARD, GIG_STRESS_GUARD, Game, GameAction, GearKind,
+    OWN_LABEL_FOUNDING_COST, OWN_LABEL_MIN_FAME, PRESSING_TIERS, STUDIO_STRESS_BLOCK,
+    TOUR_HEALTH_GUARD, TOUR_STRESS_GUARD, TourLeg, TourRig, TourTerms, Vacation,
 };
 
 use super::render;
@@ -49,7 +50,20 @@ pub enum Screen {
         selected: usize,
         detail: bool,
     },
+    /// A counter to the deal offer at `selected`, being edited field by
+    /// field before it goes to the label.
+    DealCounter {
+        selected: usize,
+        counter: DealCounter,
+        field: usize,
+    },
     SupportOffer,
+    /// The band's label has been bought: renegotiate or walk.
+    Takeover,
+    /// A&R wants the record just made changed: comply or defy.
+    LabelDemand,
+    FestivalOffer,
+    HeadlineOffer,
     Charts {
         region: crate::game::world::ChartRegion,
         scroll: usize,
@@ -75,11 +89,21 @@ pub enum Screen {
     },
     /// The rig + length picker reached after choosing a region: shows an
     /// itemized quote live as the player changes selection (design §A, M1).
+    /// With stops already on `App::tour_route`, this is the next stop and
+    /// the quote covers the whole route.
     TourBookingPicker {
         region_index: usize,
         rig: TourRig,
         weeks: u8,
     },
+    /// The setlist for the tour being booked, reached from the booking
+    /// picker (S) and returning to it with the same stop, rig and length.
+    SetlistPicker {
+        region_index: usize,
+        rig: TourRig,
+        weeks: u8,
+        selected: usize,
+    },
     PressingPicker {
         release_type: ReleaseType,
         selected: usize,
@@ -88,13 +112,70 @@ pub enum Screen {
         /// reach this screen (`open_pressing_picker` dispatches straight
         /// through).
         channel: DistributionChannel,
+        /// `Some` when pressing a live record from the tapes rather than a
+        /// studio one.
+        live: Option<LiveFormat>,
+        /// `Some(id)` when re-recording release `id` rather than cutting
+        /// new songs.
+        rerecord: Option<u32>,
+    },
+    /// The taped tour's live record: album or concert film, with its
+    /// quality and mixing cost up front.
+    LiveRelease {
+        format: LiveFormat,
     },
     TourReport {
         scroll: usize,
     },
+    /// Fame by territory: every country and region with the band's fame,
+    /// last visit, chart placing and sales there.
+    FameMap {
+        scroll: usize,
+    },
     LifestylePicker {
         selected: usize,
     },
+    /// Where to go on vacation, cheap weekend to world cruise (FUTURE §9.3).
+    VacationPicker {
+        selected: usize,
+    },
+    /// A decision incident waiting on an answer. Opens itself after any
+    /// action that raised one; there's no backing out, only choosing.
+    IncidentChoice {
+        selected: usize,
+    },
+    /// The current manager, or the managers pitching (FUTURE §9.4).
+    Manager {
+        selected: usize,
+    },
+    /// The gear inventory and road crew: buy, sell, service, hire, fire,
+    /// and the insurance switch.
+    Gear {
+        selected: usize,
+    },
+    /// The back catalogue: who owns each master and what it's worth, with
+    /// buying back, selling and re-recording.
+    Catalogue {
+        selected: usize,
+    },
+    /// The books for one year: money in and out by category, and the
+    /// royalty statements the labels sent.
+    Finance {
+        year: u32,
+    },
+    /// Where to go into tax exile, opened from the finances screen.
+    TaxExile {
+        selected: usize,
+    },
+    /// What the band has invested in, and what it could: buy in, sell up.
+    Investments {
+        selected: usize,
+    },
+    /// The band's own label: found it, then sign scene bands to it or let
+    /// its acts go. The rows are its acts, then the bands it could sign.
+    OwnLabel {
+        selected: usize,
+    },
     /// Which sold-out/low-stock release to re-press (design §E-1 indie
     /// half, M6).
     RePressPicker {
@@ -113,8 +194,12 @@ pub enum MenuKind {
     Action(GameAction),
     Deals,
     SupportTour,
+    Festival,
+    HeadlineTour,
     Charts,
     TourReport,
+    /// Open the fame-by-territory map.
+    FameMap,
     Marketing,
     Save,
     Load,
@@ -124,8 +209,23 @@ pub enum MenuKind {
     RecordSingle,
     RecordAlbum,
     Lifestyle,
+    /// Open the vacation picker (FUTURE §9.3).
+    Vacation,
     /// Open the re-press picker (design §E-1 indie half, M6).
     RePress,
+    /// Open the manager screen (FUTURE §9.4).
+    Manager,
+    /// Open the gear & crew screen.
+    Gear,
+    /// Open the live-record screen for the taped tour.
+    LiveRecord,
+    /// Open the back catalogue.
+    Catalogue,
+    Finance,
+    /// Open the investments screen.
+    Investments,
+    /// Open the band's own label.
+    OwnLabel,
 }
 
 pub struct MenuEntry {
@@ -136,6 +236,28 @@ pub struct MenuEntry {
     pub kind: MenuKind,
 }
 
+/// One row of the gear screen, in display order.
+pub enum GearRow {
+    Gear(GearKind),
+    Crew(CrewRole),
+    Insurance,
+}
+
+impl GearRow {
+    pub const COUNT: usize = GearKind::ALL.len() + CrewRole::ALL.len() + 1;
+
+    pub fn at(index: usize) -> GearRow {
+        let crew_start = GearKind::ALL.len();
+        if index < crew_start {
+            GearRow::Gear(GearKind::ALL[index])
+        } else if index < crew_start + CrewRole::ALL.len() {
+            GearRow::Crew(CrewRole::ALL[index - crew_start])
+        } else {
+            GearRow::Insurance
+        }
+    }
+}
+
 /// A release that can be marketed, in the order shown to the player.
 pub struct MarketingTarget {
     pub id: u32,
@@ -152,6 +274,12 @@ pub struct App {
     pub name_input: String,
     pub band_input: String,
     pub genre_selected: usize,
+    /// Stops already added to the tour being planned, the rig they're
+    /// booked with, and the ticket tier and merch order; cleared whenever
+    /// the planner opens or closes.
+    pub(crate) tour_route: Vec<TourLeg>,
+    pub(crate) tour_rig: TourRig,
+    pub(crate) tour_terms: TourTerms,
     pub(crate) should_exit: bool,
 }
 
@@ -167,6 +295,9 @@ impl App {
             name_input: String::new(),
             band_input: String::new(),
             genre_selected: 0,
+            tour_route: Vec::new(),
+            tour_rig: TourRig::Van,
+            tour_terms: TourTerms::default(),
             should_exit: false,
         }
     }
@@ -203,8 +334,8 @@ impl App {
         // on it too (always available while signed — the label distributes).
         let channel_available = signed
             || game
-                .current_distribution_channel
-                .is_available(game.band.fame);
+                .distribution_lock(game.current_distribution_channel)
+                .is_none();
         let (single_min, album_min) = if signed {
             (single_cost, album_cost)
         } else {
@@ -218,6 +349,7 @@ impl App {
         };
         let songs = game.band.unreleased_songs.len();
         let offers = game.pending_deal_offers.len();
+        let manager_offers = game.pending_manager_offers.len();
         let releases = game.just_released_music.len() + game.band.total_releases();
         // M6 (§E-1 indie half): releases eligible for a player-initiated
         // re-press right now — empty for a signed act (its label restocks
@@ -341,12 +473,39 @@ impl App {
                 enabled: game.pending_support_offer.is_some(),
                 kind: MenuKind::SupportTour,
             },
+            MenuEntry {
+                hotkey: 'f',
+                label: "Festival Slot…",
+                detail: match &game.pending_festival_offer {
+                    Some(offer) if offer.booked => {
+                        format!("{}: week {}", offer.festival, offer.expires_week)
+                    }
+                    Some(offer) => format!("{} want you!", offer.festival),
+                    None => "no offers".into(),
+                },
+                enabled: game.pending_festival_offer.is_some(),
+                kind: MenuKind::Festival,
+            },
+            MenuEntry {
+                hotkey: 'o',
+                label: "Tour Offer…",
+                detail: match &game.pending_headline_offer {
+                    Some(offer) => format!("{} call!", offer.promoter),
+                    None => "no offers".into(),
+                },
+                enabled: game.pending_headline_offer.is_some(),
+                kind: MenuKind::HeadlineTour,
+            },
             MenuEntry {
                 hotkey: '8',
-                label: "Take a Break",
-                detail: format!("{} weeks off — full recovery", BREAK_WEEKS),
-                enabled: true,
-                kind: MenuKind::Action(GameAction::TakeBreak),
+                label: "Take a Vacation…",
+                detail: if game.player.can_afford(Vacation::ALL[0].cost() as i32) {
+                    "weekend to world cruise".into()
+                } else {
+                    format!("need ${} to get away", Vacation::ALL[0].cost())
+                },
+                enabled: game.player.can_afford(Vacation::ALL[0].cost() as i32),
+                kind: MenuKind::Vacation,
             },
             MenuEntry {
                 hotkey: '9',
@@ -375,14 +534,43 @@ impl App {
             MenuEntry {
                 hotkey: 'v',
                 label: "Deal Offers…",
-                detail: if offers == 0 {
+                detail: if game.pending_takeover.is_some() {
+                    "takeover — your call!".into()
+                } else if offers == 0 {
                     "none pending".into()
                 } else {
                     format!("{} waiting!", offers)
                 },
-                enabled: offers > 0,
+                enabled: offers > 0 || game.pending_takeover.is_some(),
                 kind: MenuKind::Deals,
             },
+            MenuEntry {
+                hotkey: 'g',
+                label: "Manager…",
+                detail: match &game.manager {
+                    Some(manager) => format!("{} · {:.0}%", manager.name, manager.cut * 100.0),
+                    None if manager_offers > 0 => format!("{} pitching!", manager_offers),
+                    None if game.manager_cooldown > 0 => "nobody's calling".into(),
+                    None => "none pitching".into(),
+                },
+                enabled: game.manager.is_some() || manager_offers > 0,
+                kind: MenuKind::Manager,
+            },
+            MenuEntry {
+                hotkey: 'e',
+                label: "Gear & Crew…",
+                detail: if game.crew.is_empty() {
+                    format!("kit worth ${}", game.gear_value())
+                } else {
+                    format!(
+                        "{} crew · ${}/wk",
+                        game.crew.len(),
+                        game.crew_wages() + game.gear_insurance_premium()
+                    )
+                },
+                enabled: true,
+                kind: MenuKind::Gear,
+            },
             MenuEntry {
                 hotkey: 'c',
                 label: "Charts…",
@@ -413,6 +601,18 @@ impl App {
                 enabled: true,
                 kind: MenuKind::TourReport,
             },
+            MenuEntry {
+                hotkey: 'w',
+                label: "World Map…",
+                detail: {
+                    let map = game.fame_map();
+                    let regions = map.iter().flat_map(|country| &country.regions);
+                    let known = regions.clone().filter(|region| region.fame > 0).count();
+                    format!("{} of {} regions know you", known, regions.count())
+                },
+                enabled: true,
+                kind: MenuKind::FameMap,
+            },
             MenuEntry {
                 hotkey: 'h',
                 label: "Lifestyle…",
@@ -441,6 +641,77 @@ impl App {
                 enabled: !signed && repress_count > 0,
                 kind: MenuKind::RePress,
             },
+            MenuEntry {
+                hotkey: 'b',
+                label: "Back Catalogue…",
+                detail: if game.band.total_releases() == 0 {
+                    "nothing out yet".into()
+                } else {
+                    format!(
+                        "{} of {} masters yours",
+                        game.catalogue()
+                            .filter(|release| release.band_owns_master())
+                            .count(),
+                        game.band.total_releases()
+                    )
+                },
+                enabled: game.band.total_releases() > 0,
+                kind: MenuKind::Catalogue,
+            },
+            MenuEntry {
+                hotkey: 'n',
+                label: "Finances…",
+                detail: {
+                    let books = game.year_summary(game.timeline.get_current_year());
+                    format!(
+                        "{} in · {} out this year",
+                        crate::data::format_money(books.income() as i32),
+                        crate::data::format_money(books.expenses() as i32)
+                    )
+                },
+                enabled: true,
+                kind: MenuKind::Finance,
+            },
+            MenuEntry {
+                hotkey: 'i',
+                label: "Investments…",
+                detail: if game.investments.is_empty() {
+                    "nothing invested".into()
+                } else {
+                    format!(
+                        "worth {}",
+                        crate::data::format_money(game.investments_value() as i32)
+                    )
+                },
+                enabled: true,
+                kind: MenuKind::Investments,
+            },
+            MenuEntry {
+                hotkey: 'y',
+                label: "Your Label…",
+                detail: match &game.own_label {
+                    Some(label) => format!("{} · {} acts", label.name, label.acts.len()),
+                    None if game.band.fame < OWN_LABEL_MIN_FAME => {
+                        format!("needs fame {}", OWN_LABEL_MIN_FAME)
+                    }
+                    None => format!(
+                        "found one for {}",
+                        crate::data::format_money(OWN_LABEL_FOUNDING_COST as i32)
+                    ),
+                },
+                enabled: true,
+                kind: MenuKind::OwnLabel,
+            },
+            MenuEntry {
+                hotkey: 'a',
+                label: "Live Record…",
+                detail: match &game.live_tapes {
+                    Some(tapes) => format!("{} shows on tape", tapes.rows.len()),
+                    None => "tape a tour first".into(),
+                },
+                enabled: game.live_tapes.is_some(),
+                kind: MenuKind::LiveRecord,
+            },
             MenuEntry {
                 hotkey: 's',
                 label: "Save Game",
@@ -545,6 +816,10 @@ impl App {
         self.drain_game_log();
         if self.game.is_game_over() {
             self.screen = Screen::GameOver;
+        } else if self.game.pending_incident.is_some() {
+            self.screen = Screen::IncidentChoice { selected: 0 };
+        } else if self.game.pending_label_demand.is_some() {
+            self.screen = Screen::LabelDemand;
         }
     }
 
@@ -560,7 +835,12 @@ impl App {
             Screen::Setup { .. } => self.handle_setup_key(key),
             Screen::Main => self.handle_main_key(key),
             Screen::Deals { .. } => self.handle_deals_key(key),
+            Screen::DealCounter { .. } => self.handle_deal_counter_key(key),
             Screen::SupportOffer => self.handle_support_offer_key(key),
+            Screen::Takeover => self.handle_takeover_key(key),
+            Screen::LabelDemand => self.handle_label_demand_key(key),
+            Screen::FestivalOffer => self.handle_festival_offer_key(key),
+            Screen::HeadlineOffer => self.handle_headline_offer_key(key),
             Screen::Charts { .. } => self.handle_charts_key(key),
             Screen::MarketingRelease { .. } => self.handle_marketing_release_key(key),
             Screen::MarketingCampaign { .. } => self.handle_marketing_campaign_key(key),
@@ -569,9 +849,21 @@ impl App {
             Screen::VenuePicker { .. } => self.handle_venue_picker_key(key),
             Screen::RegionPicker { .. } => self.handle_region_picker_key(key),
             Screen::TourBookingPicker { .. } => self.handle_tour_booking_picker_key(key),
+            Screen::SetlistPicker { .. } => self.handle_setlist_picker_key(key),
             Screen::PressingPicker { .. } => self.handle_pressing_picker_key(key),
+            Screen::LiveRelease { .. } => self.handle_live_release_key(key),
             Screen::TourReport { .. } => self.handle_tour_report_key(key),
+            Screen::FameMap { .. } => self.handle_fame_map_key(key),
             Screen::LifestylePicker { .. } => self.handle_lifestyle_picker_key(key),
+            Screen::VacationPicker { .. } => self.handle_vacation_picker_key(key),
+            Screen::Manager { .. } => self.handle_manager_key(key),
+            Screen::Gear { .. } => self.handle_gear_key(key),
+            Screen::IncidentChoice { .. } => self.handle_incident_choice_key(key),
+            Screen::Catalogue { .. } => self.handle_catalogue_key(key),
+            Screen::Finance { .. } => self.handle_finance_key(key),
+            Screen::TaxExile { .. } => self.handle_tax_exile_key(key),
+            Screen::Investments { .. } => self.handle_investments_key(key),
+            Screen::OwnLabel { .. } => self.handle_own_label_key(key),
             Screen::RePressPicker { .. } => self.handle_repress_picker_key(key),
             Screen::RePressTierPicker { .. } => self.handle_repress_tier_picker_key(key),
         }
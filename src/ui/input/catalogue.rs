@@ -0,0 +1,66 @@
+//! Back catalogue input: buy a master back or sell one (both instant, so
+//! the screen stays open), or re-record a release the band doesn't own —
+//! through the pressing picker while unsigned, straight to the label when
+//! signed. Opened from the main menu (`MenuKind::Catalogue` in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::GameAction;
+use crate::ui::app::{App, LogKind, Screen};
+
+impl App {
+    pub(crate) fn handle_catalogue_key(&mut self, key: KeyEvent) {
+        let Screen::Catalogue { selected } = self.screen else {
+            return;
+        };
+        let count = self.game.band.total_releases();
+        if count == 0 {
+            self.screen = Screen::Main;
+            return;
+        }
+        let Some(release) = self.game.catalogue().nth(selected) else {
+            self.screen = Screen::Catalogue { selected: 0 };
+            return;
+        };
+        let release_id = release.id;
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::Catalogue {
+                    selected: super::cycle_index(selected, count, false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::Catalogue {
+                    selected: super::cycle_index(selected, count, true),
+                };
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                self.dispatch(GameAction::BuyBackMaster(release_id));
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.dispatch(GameAction::SellMaster(release_id));
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                if let Err(msg) = self.game.check_rerecord(release) {
+                    self.push_log(LogKind::Error, format!("❌ {msg}"));
+                } else if self.game.band.current_deal().is_some() {
+                    self.screen = Screen::Main;
+                    self.dispatch(GameAction::ReRecord {
+                        release_id,
+                        pressing: None,
+                    });
+                } else {
+                    self.screen = Screen::PressingPicker {
+                        release_type: release.release_type,
+                        selected: 0,
+                        channel: self.game.current_distribution_channel,
+                        live: None,
+                        rerecord: Some(release_id),
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
-use crate::game::GameAction;
+use crate::game::band::CreativeControl;
+use crate::game::{DealCounter, GameAction};
 use crate::ui::app::{App, Screen};
 
+/// Advance, royalty, albums, term, creative control.
+pub(crate) const DEAL_COUNTER_FIELDS: usize = 5;
+
 impl App {
     pub(crate) fn handle_deals_key(&mut self, key: KeyEvent) {
         let Screen::Deals { selected, detail } = self.screen else {
@@ -43,6 +47,13 @@ impl App {
                     detail: true,
                 };
             }
+            KeyCode::Char('n') | KeyCode::Char('N') if detail => {
+                self.screen = Screen::DealCounter {
+                    selected,
+                    counter: DealCounter::from_offer(&self.game.pending_deal_offers[selected]),
+                    field: 0,
+                };
+            }
             KeyCode::Char('a') | KeyCode::Char('A') => {
                 self.screen = Screen::Main;
                 self.dispatch(GameAction::AcceptDeal(selected));
@@ -63,6 +74,89 @@ impl App {
         }
     }
 
+    /// The counter screen: ↑↓ pick a term, ←→ change it, Enter sends the
+    /// counter, Esc goes back to the offer. The label's answer lands back
+    /// on the offer (or takes it off the table).
+    pub(crate) fn handle_deal_counter_key(&mut self, key: KeyEvent) {
+        let Screen::DealCounter {
+            selected,
+            mut counter,
+            field,
+        } = self.screen
+        else {
+            return;
+        };
+        let Some(offer) = self.game.pending_deal_offers.get(selected) else {
+            self.screen = Screen::Main;
+            return;
+        };
+        let advance_step = (offer.original_label_data.advance_range[1] / 20).max(100);
+        let back_to_offer = Screen::Deals {
+            selected,
+            detail: true,
+        };
+        match key.code {
+            KeyCode::Esc => self.screen = back_to_offer,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::DealCounter {
+                    selected,
+                    counter,
+                    field: super::cycle_index(field, DEAL_COUNTER_FIELDS, false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::DealCounter {
+                    selected,
+                    counter,
+                    field: super::cycle_index(field, DEAL_COUNTER_FIELDS, true),
+                };
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+                let up = matches!(key.code, KeyCode::Right | KeyCode::Char('l'));
+                match field {
+                    0 if up => counter.advance += advance_step,
+                    0 => counter.advance = counter.advance.saturating_sub(advance_step),
+                    1 if up => counter.royalty_rate = (counter.royalty_rate + 0.01).min(1.0),
+                    1 => counter.royalty_rate = (counter.royalty_rate - 0.01).max(0.0),
+                    2 if up => counter.albums_required = counter.albums_required.saturating_add(1),
+                    2 => counter.albums_required = counter.albums_required.saturating_sub(1).max(1),
+                    3 if up => counter.term_weeks = counter.term_weeks.saturating_add(13),
+                    3 => counter.term_weeks = counter.term_weeks.saturating_sub(13).max(13),
+                    _ => {
+                        let index = super::cycle_index(
+                            counter.creative_control as usize,
+                            CreativeControl::ALL.len(),
+                            up,
+                        );
+                        counter.creative_control = CreativeControl::ALL[index];
+                    }
+                }
+                self.screen = Screen::DealCounter {
+                    selected,
+                    counter,
+                    field,
+                };
+            }
+            KeyCode::Enter => {
+                let offers_before = self.game.pending_deal_offers.len();
+                self.dispatch(GameAction::CounterDeal(selected, counter));
+                let remaining = self.game.pending_deal_offers.len();
+                self.screen = if remaining == 0 {
+                    Screen::Main
+                } else if remaining < offers_before {
+                    // They walked: back to whatever is still on the table.
+                    Screen::Deals {
+                        selected: selected.min(remaining - 1),
+                        detail: false,
+                    }
+                } else {
+                    back_to_offer
+                };
+            }
+            _ => {}
+        }
+    }
+
     pub(crate) fn handle_support_offer_key(&mut self, key: KeyEvent) {
         if self.game.pending_support_offer.is_none() {
             self.screen = Screen::Main;
@@ -81,4 +175,87 @@ impl App {
             _ => {}
         }
     }
+
+    /// A takeover of the band's label: R asks the buyer for the old terms
+    /// (and opens the offer it puts on the table), K walks under the
+    /// key-man clause.
+    pub(crate) fn handle_takeover_key(&mut self, key: KeyEvent) {
+        if self.game.pending_takeover.is_none() {
+            self.screen = Screen::Main;
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.dispatch(GameAction::RenegotiateTakeover);
+                self.screen = match self.game.pending_deal_offers.len() {
+                    0 => Screen::Main,
+                    count => Screen::Deals {
+                        selected: count - 1,
+                        detail: true,
+                    },
+                };
+            }
+            KeyCode::Char('k') | KeyCode::Char('K') => {
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::KeyManExit);
+            }
+            _ => {}
+        }
+    }
+
+    /// A&R's demand on the record just made: C complies, D defies. Esc
+    /// doesn't close it — the label wants its answer before the week moves.
+    pub(crate) fn handle_label_demand_key(&mut self, key: KeyEvent) {
+        if self.game.pending_label_demand.is_none() {
+            self.screen = Screen::Main;
+            return;
+        }
+        let comply = match key.code {
+            KeyCode::Char('c') | KeyCode::Char('C') => true,
+            KeyCode::Char('d') | KeyCode::Char('D') => false,
+            _ => return,
+        };
+        self.screen = Screen::Main;
+        self.dispatch(GameAction::AnswerLabelDemand(comply));
+    }
+
+    pub(crate) fn handle_festival_offer_key(&mut self, key: KeyEvent) {
+        let Some(offer) = &self.game.pending_festival_offer else {
+            self.screen = Screen::Main;
+            return;
+        };
+        let booked = offer.booked;
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Enter if !booked => {
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::AcceptFestival);
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::DeclineFestival);
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn handle_headline_offer_key(&mut self, key: KeyEvent) {
+        if self.game.pending_headline_offer.is_none() {
+            self.screen = Screen::Main;
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Enter => {
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::AcceptHeadlineTour);
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::DeclineHeadlineTour);
+            }
+            _ => {}
+        }
+    }
 }
@@ -34,6 +34,10 @@ impl App {
                             self.push_log(LogKind::Ui, format!("📂 Game loaded from {}.", path));
                             if self.game.is_game_over() {
                                 self.screen = Screen::GameOver;
+                            } else if self.game.pending_incident.is_some() {
+                                self.screen = Screen::IncidentChoice { selected: 0 };
+                            } else if self.game.pending_label_demand.is_some() {
+                                self.screen = Screen::LabelDemand;
                             }
                         }
                         Err(e) => self.push_log(LogKind::Error, format!("❌ Load failed: {}", e)),
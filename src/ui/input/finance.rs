@@ -0,0 +1,85 @@
+//! Finances input: ←→ step through the years the books cover; B borrows
+//! from the bank, P pays the loan down and A draws cash from the label, a
+//! step at a time; C takes on or lets go the accountant and X opens the
+//! tax-exile picker. Esc closes. Opened from the main menu
+//! (`MenuKind::Finance` in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::{GameAction, MONEY_STEP};
+use crate::ui::app::{App, Screen};
+
+impl App {
+    pub(crate) fn handle_finance_key(&mut self, key: KeyEvent) {
+        let Screen::Finance { year } = self.screen else {
+            return;
+        };
+        let years = self.game.ledger_years();
+        let step = |forward: bool| {
+            let position = years.iter().position(|&y| y == year);
+            match position {
+                Some(index) => years[super::cycle_index(index, years.len(), forward)],
+                None => years.last().copied().unwrap_or(year),
+            }
+        };
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.screen = Screen::Finance { year: step(false) };
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.screen = Screen::Finance { year: step(true) };
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                let amount = MONEY_STEP.min(self.game.credit_available());
+                self.dispatch(GameAction::TakeLoan(amount));
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                let owed = self.game.loan.as_ref().map_or(0, |loan| loan.balance);
+                self.dispatch(GameAction::RepayLoan(MONEY_STEP.min(owed)));
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                let amount = MONEY_STEP.min(self.game.label_cash_advance_available());
+                self.dispatch(GameAction::DrawLabelAdvance(amount));
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.dispatch(GameAction::SetAccountant(!self.game.accountant));
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                self.screen = Screen::TaxExile { selected: 0 };
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn handle_tax_exile_key(&mut self, key: KeyEvent) {
+        let Screen::TaxExile { selected } = self.screen else {
+            return;
+        };
+        let havens = self.game.tax_havens();
+        let back = Screen::Finance {
+            year: self.game.timeline.get_current_year(),
+        };
+        match key.code {
+            KeyCode::Esc => self.screen = back,
+            KeyCode::Up | KeyCode::Char('k') if !havens.is_empty() => {
+                self.screen = Screen::TaxExile {
+                    selected: super::cycle_index(selected, havens.len(), false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') if !havens.is_empty() => {
+                self.screen = Screen::TaxExile {
+                    selected: super::cycle_index(selected, havens.len(), true),
+                };
+            }
+            KeyCode::Enter => {
+                let Some(haven) = havens.get(selected) else {
+                    return;
+                };
+                self.screen = back;
+                self.dispatch(GameAction::GoIntoTaxExile(haven.country.clone()));
+            }
+            _ => {}
+        }
+    }
+}
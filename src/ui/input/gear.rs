@@ -0,0 +1,45 @@
+//! Gear & crew screen input: one list of the gear shop, the crew roles and
+//! the insurance switch. Every key is an instant action, so the screen
+//! stays open to keep shopping. Opened from the main menu (`MenuKind::Gear`
+//! in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::GameAction;
+use crate::ui::app::{App, GearRow, Screen};
+
+impl App {
+    pub(crate) fn handle_gear_key(&mut self, key: KeyEvent) {
+        let Screen::Gear { selected } = self.screen else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::Gear {
+                    selected: super::cycle_index(selected, GearRow::COUNT, false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::Gear {
+                    selected: super::cycle_index(selected, GearRow::COUNT, true),
+                };
+            }
+            KeyCode::Enter | KeyCode::Char('b') | KeyCode::Char('B') => {
+                let action = match GearRow::at(selected) {
+                    GearRow::Gear(kind) => GameAction::BuyGear(kind),
+                    GearRow::Crew(role) => GameAction::HireCrew(role),
+                    GearRow::Insurance => GameAction::SetGearInsurance(!self.game.gear_insured),
+                };
+                self.dispatch(action);
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => match GearRow::at(selected) {
+                GearRow::Gear(kind) => self.dispatch(GameAction::SellGear(kind)),
+                GearRow::Crew(role) => self.dispatch(GameAction::FireCrew(role)),
+                GearRow::Insurance => self.dispatch(GameAction::SetGearInsurance(false)),
+            },
+            KeyCode::Char('v') | KeyCode::Char('V') => self.dispatch(GameAction::ServiceGear),
+            _ => {}
+        }
+    }
+}
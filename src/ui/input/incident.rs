@@ -0,0 +1,45 @@
+//! Decision-incident input: pick one of the pending incident's choices.
+//! The modal opens itself from `App::dispatch` (and on load) whenever the
+//! game is holding a decision; Esc doesn't close it — the week waits.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::GameAction;
+use crate::ui::app::{App, LogKind, Screen};
+
+impl App {
+    pub(crate) fn handle_incident_choice_key(&mut self, key: KeyEvent) {
+        let Screen::IncidentChoice { selected } = self.screen else {
+            return;
+        };
+        let Some(incident) = &self.game.pending_incident else {
+            self.screen = Screen::Main;
+            return;
+        };
+        let count = incident.choices.len();
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::IncidentChoice {
+                    selected: super::cycle_index(selected, count, false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::IncidentChoice {
+                    selected: super::cycle_index(selected, count, true),
+                };
+            }
+            KeyCode::Enter => {
+                if !self
+                    .game
+                    .incident_choice_available(&incident.choices[selected])
+                {
+                    self.push_log(LogKind::Ui, "That option isn't open to you.");
+                    return;
+                }
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::ResolveIncident(selected));
+            }
+            _ => {}
+        }
+    }
+}
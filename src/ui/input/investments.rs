@@ -0,0 +1,38 @@
+//! Investments screen input: ↑↓ pick an investment, B buys in (one more
+//! stake, for a market) and S sells the whole holding. Both are instant,
+//! so the screen stays open. Opened from the main menu
+//! (`MenuKind::Investments` in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::{GameAction, InvestmentKind};
+use crate::ui::app::{App, Screen};
+
+impl App {
+    pub(crate) fn handle_investments_key(&mut self, key: KeyEvent) {
+        let Screen::Investments { selected } = self.screen else {
+            return;
+        };
+        let kind = InvestmentKind::ALL[selected];
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::Investments {
+                    selected: super::cycle_index(selected, InvestmentKind::ALL.len(), false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::Investments {
+                    selected: super::cycle_index(selected, InvestmentKind::ALL.len(), true),
+                };
+            }
+            KeyCode::Enter | KeyCode::Char('b') | KeyCode::Char('B') => {
+                self.dispatch(GameAction::BuyInvestment(kind));
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.dispatch(GameAction::SellInvestment(kind));
+            }
+            _ => {}
+        }
+    }
+}
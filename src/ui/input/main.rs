@@ -1,6 +1,6 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
-use crate::game::GameAction;
+use crate::game::{GameAction, TourRig, TourTerms};
 use crate::ui::app::{App, MenuKind, Screen};
 
 impl App {
@@ -41,7 +41,9 @@ impl App {
                 self.open_pressing_picker(crate::game::music::ReleaseType::Album)
             }
             MenuKind::Deals => {
-                if self.game.pending_deal_offers.is_empty() {
+                if self.game.pending_takeover.is_some() {
+                    self.screen = Screen::Takeover;
+                } else if self.game.pending_deal_offers.is_empty() {
                     self.push_log(
                         crate::ui::app::LogKind::Ui,
                         "No deal offers on the table right now.",
@@ -53,6 +55,46 @@ impl App {
                     };
                 }
             }
+            MenuKind::Manager => {
+                if self.game.manager.is_none() && self.game.pending_manager_offers.is_empty() {
+                    self.push_log(
+                        crate::ui::app::LogKind::Ui,
+                        "Nobody's pitching to manage you right now.",
+                    );
+                } else {
+                    self.screen = Screen::Manager { selected: 0 };
+                }
+            }
+            MenuKind::Gear => self.screen = Screen::Gear { selected: 0 },
+            MenuKind::Investments => self.screen = Screen::Investments { selected: 0 },
+            MenuKind::OwnLabel => self.screen = Screen::OwnLabel { selected: 0 },
+            MenuKind::Finance => {
+                self.screen = Screen::Finance {
+                    year: self.game.timeline.get_current_year(),
+                }
+            }
+            MenuKind::Catalogue => {
+                if self.game.band.total_releases() == 0 {
+                    self.push_log(
+                        crate::ui::app::LogKind::Ui,
+                        "Nothing in the shops yet — the catalogue starts with your first record.",
+                    );
+                } else {
+                    self.screen = Screen::Catalogue { selected: 0 };
+                }
+            }
+            MenuKind::LiveRecord => {
+                if self.game.live_tapes.is_none() {
+                    self.push_log(
+                        crate::ui::app::LogKind::Ui,
+                        "No tapes yet — book a tour with taping on (R).",
+                    );
+                } else {
+                    self.screen = Screen::LiveRelease {
+                        format: crate::game::music::LiveFormat::Album,
+                    };
+                }
+            }
             MenuKind::SupportTour => {
                 if self.game.pending_support_offer.is_some() {
                     self.screen = Screen::SupportOffer;
@@ -63,6 +105,26 @@ impl App {
                     );
                 }
             }
+            MenuKind::HeadlineTour => {
+                if self.game.pending_headline_offer.is_some() {
+                    self.screen = Screen::HeadlineOffer;
+                } else {
+                    self.push_log(
+                        crate::ui::app::LogKind::Ui,
+                        "No promoters calling — they book acts who can fill the rooms.",
+                    );
+                }
+            }
+            MenuKind::Festival => {
+                if self.game.pending_festival_offer.is_some() {
+                    self.screen = Screen::FestivalOffer;
+                } else {
+                    self.push_log(
+                        crate::ui::app::LogKind::Ui,
+                        "No festival slots on offer — the bills go to bands people know.",
+                    );
+                }
+            }
             MenuKind::Charts => {
                 self.screen = Screen::Charts {
                     region: crate::game::world::ChartRegion::Local,
@@ -70,6 +132,7 @@ impl App {
                 }
             }
             MenuKind::TourReport => self.screen = Screen::TourReport { scroll: 0 },
+            MenuKind::FameMap => self.screen = Screen::FameMap { scroll: 0 },
             MenuKind::Marketing => {
                 let signed = self.game.band.current_deal().is_some();
                 let targets = self.marketing_targets();
@@ -116,6 +179,19 @@ impl App {
                         "You need more fame before promoters will book a tour!",
                     );
                 } else {
+                    self.tour_route.clear();
+                    self.tour_rig = TourRig::Van;
+                    // The setlist carries over from the last tour, less
+                    // anything that has left the catalogue since.
+                    let catalogue = self.game.setlist_catalogue();
+                    let setlist = std::mem::take(&mut self.tour_terms.setlist)
+                        .into_iter()
+                        .filter(|song| catalogue.iter().any(|(known, _)| known == song))
+                        .collect();
+                    self.tour_terms = TourTerms {
+                        setlist,
+                        ..TourTerms::default()
+                    };
                     self.screen = Screen::RegionPicker { selected: 0 };
                 }
             }
@@ -138,6 +214,7 @@ impl App {
                     .unwrap_or(0);
                 self.screen = Screen::LifestylePicker { selected };
             }
+            MenuKind::Vacation => self.screen = Screen::VacationPicker { selected: 0 },
             MenuKind::RePress => {
                 if self.game.band.current_deal().is_some() {
                     self.push_log(
@@ -220,6 +297,34 @@ impl App {
             _ => {}
         }
     }
+
+    /// The fame map scrolls a row at a time over every country header and
+    /// region row.
+    pub(crate) fn handle_fame_map_key(&mut self, key: KeyEvent) {
+        let Screen::FameMap { scroll } = self.screen else {
+            return;
+        };
+        let rows: usize = self
+            .game
+            .fame_map()
+            .iter()
+            .map(|country| country.regions.len() + 1)
+            .sum();
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::FameMap {
+                    scroll: scroll.saturating_sub(1),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::FameMap {
+                    scroll: (scroll + 1).min(rows.saturating_sub(1)),
+                };
+            }
+            _ => {}
+        }
+    }
 }
 
 #[cfg(test)]
@@ -265,6 +370,7 @@ mod tests {
                     attendance: 400,
                     capacity: 500,
                     take: 1200,
+                    merch: 0,
                 },
                 ShowReport {
                     week: 1,
@@ -274,11 +380,13 @@ mod tests {
                     attendance: 300,
                     capacity: 600,
                     take: 900,
+                    merch: 0,
                 },
             ],
             avg_reception: 67,
             total_gross: 2100,
             fame_gained: 2,
+            total_merch: 0,
         });
         app.screen = Screen::TourReport { scroll: 0 };
 
@@ -0,0 +1,63 @@
+//! Manager screen input (FUTURE §9.4): hire or send off a pitching manager,
+//! or fire the one you have. Opened from the main menu (`MenuKind::Manager`
+//! in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::GameAction;
+use crate::ui::app::{App, Screen};
+
+impl App {
+    pub(crate) fn handle_manager_key(&mut self, key: KeyEvent) {
+        let Screen::Manager { selected } = self.screen else {
+            return;
+        };
+
+        if self.game.manager.is_some() {
+            match key.code {
+                KeyCode::Esc => self.screen = Screen::Main,
+                KeyCode::Char('f') | KeyCode::Char('F') => {
+                    self.screen = Screen::Main;
+                    self.dispatch(GameAction::FireManager);
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        let count = self.game.pending_manager_offers.len();
+        if count == 0 {
+            self.screen = Screen::Main;
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::Manager {
+                    selected: super::cycle_index(selected, count, false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::Manager {
+                    selected: super::cycle_index(selected, count, true),
+                };
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Enter => {
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::HireManager(selected));
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.dispatch(GameAction::RejectManager(selected));
+                let remaining = self.game.pending_manager_offers.len();
+                self.screen = if remaining == 0 {
+                    Screen::Main
+                } else {
+                    Screen::Manager {
+                        selected: selected.min(remaining - 1),
+                    }
+                };
+            }
+            _ => {}
+        }
+    }
+}
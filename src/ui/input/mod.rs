@@ -1,12 +1,20 @@
 //! Keyboard input event handlers for each screen family, implementing `App` input helpers.
 
+mod catalogue;
 mod deals;
 mod file;
+mod finance;
+mod gear;
+mod incident;
+mod investments;
 mod lifestyle;
 mod main;
+mod manager;
 mod marketing;
+mod own_label;
 mod pickers;
 mod setup;
+mod vacation;
 
 /// Cycle a 0-based selection index one step over `count` items, wrapping at
 /// both ends: `forward` (Down/Right) advances, otherwise (Up/Left) it steps
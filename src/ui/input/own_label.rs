@@ -0,0 +1,58 @@
+//! Own-label screen input: F founds the label; once it's running, ↑↓ pick
+//! a row — its acts first, then the bands it could sign — and Enter signs
+//! the highlighted prospect or lets the highlighted act go. All instant,
+//! so the screen stays open. Opened from the main menu (`MenuKind::OwnLabel`
+//! in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::GameAction;
+use crate::ui::app::{App, Screen};
+
+impl App {
+    pub(crate) fn handle_own_label_key(&mut self, key: KeyEvent) {
+        let Screen::OwnLabel { selected } = self.screen else {
+            return;
+        };
+        if key.code == KeyCode::Esc {
+            self.screen = Screen::Main;
+            return;
+        }
+        if matches!(key.code, KeyCode::Char('f') | KeyCode::Char('F')) {
+            self.dispatch(GameAction::FoundLabel);
+            return;
+        }
+        let rows = self.game.label_rows();
+        if rows.is_empty() {
+            return;
+        }
+        let selected = selected.min(rows.len() - 1);
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::OwnLabel {
+                    selected: super::cycle_index(selected, rows.len(), false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::OwnLabel {
+                    selected: super::cycle_index(selected, rows.len(), true),
+                };
+            }
+            KeyCode::Enter => {
+                let (band, signed) = rows[selected].clone();
+                self.dispatch(if signed {
+                    GameAction::DropFromLabel(band)
+                } else {
+                    GameAction::SignToLabel(band)
+                });
+                if matches!(self.screen, Screen::OwnLabel { .. }) {
+                    let count = self.game.label_rows().len();
+                    self.screen = Screen::OwnLabel {
+                        selected: selected.min(count.saturating_sub(1)),
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
 use crate::data::format_money;
-use crate::game::music::{DistributionChannel, ReleaseType};
-use crate::game::{GameAction, PRESSING_TIERS, TourQuote, TourRig};
+use crate::game::music::{DistributionChannel, LiveFormat, ReleaseType};
+use crate::game::{
+    GameAction, PRESSING_TIERS, SETLIST_MAX_SONGS, TicketTier, TourLeg, TourRig, TourTerms,
+};
 use crate::ui::app::{App, LogKind, Screen};
 
 impl App {
@@ -20,15 +22,66 @@ impl App {
                 release_type,
                 selected: 0,
                 channel: self.game.current_distribution_channel,
+                live: None,
+                rerecord: None,
             };
         }
     }
 
+    /// Choose album or (in a video era) concert film for the taped tour.
+    /// Enter hands over to the pressing picker like a studio record —
+    /// straight to the label when signed.
+    pub(crate) fn handle_live_release_key(&mut self, key: KeyEvent) {
+        let Screen::LiveRelease { format } = self.screen else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Char('f')
+            | KeyCode::Char('F')
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Char('h')
+            | KeyCode::Char('l')
+                if self.game.timeline.is_video_era() =>
+            {
+                self.screen = Screen::LiveRelease {
+                    format: match format {
+                        LiveFormat::Album => LiveFormat::ConcertFilm,
+                        LiveFormat::ConcertFilm => LiveFormat::Album,
+                    },
+                };
+            }
+            KeyCode::Enter => {
+                if let Err(msg) = self.game.live_release_quality(format) {
+                    self.push_log(LogKind::Error, format!("❌ {msg}"));
+                } else if self.game.band.current_deal().is_some() {
+                    self.screen = Screen::Main;
+                    self.dispatch(GameAction::ReleaseLive {
+                        format,
+                        pressing: None,
+                    });
+                } else {
+                    self.screen = Screen::PressingPicker {
+                        release_type: ReleaseType::Album,
+                        selected: 0,
+                        channel: self.game.current_distribution_channel,
+                        live: Some(format),
+                        rerecord: None,
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub(crate) fn handle_pressing_picker_key(&mut self, key: KeyEvent) {
         let Screen::PressingPicker {
             release_type,
             selected,
             channel,
+            live,
+            rerecord,
         } = self.screen
         else {
             return;
@@ -41,6 +94,8 @@ impl App {
                     release_type,
                     selected: super::cycle_index(selected, count, false),
                     channel,
+                    live,
+                    rerecord,
                 };
             }
             KeyCode::Down | KeyCode::Char('j') => {
@@ -48,6 +103,8 @@ impl App {
                     release_type,
                     selected: super::cycle_index(selected, count, true),
                     channel,
+                    live,
+                    rerecord,
                 };
             }
             // M6 (§E-3): cycle the distribution channel independently of the
@@ -60,6 +117,8 @@ impl App {
                     release_type,
                     selected,
                     channel: DistributionChannel::ALL[idx],
+                    live,
+                    rerecord,
                 };
             }
             KeyCode::Right | KeyCode::Char('l') => {
@@ -69,18 +128,13 @@ impl App {
                     release_type,
                     selected,
                     channel: DistributionChannel::ALL[idx],
+                    live,
+                    rerecord,
                 };
             }
             KeyCode::Enter => {
-                if !channel.is_available(self.game.band.fame) {
-                    self.push_log(
-                        LogKind::Error,
-                        format!(
-                            "❌ {} needs {} fame — you're not there yet.",
-                            channel.label(),
-                            channel.fame_gate()
-                        ),
-                    );
+                if let Some(lock) = self.game.distribution_lock(channel) {
+                    self.push_log(LogKind::Error, format!("❌ {}", lock));
                     return;
                 }
                 // Persist the choice (M6): read by `action_record_single`/
@@ -89,11 +143,19 @@ impl App {
                 // default next time this picker opens.
                 self.game.current_distribution_channel = channel;
                 self.screen = Screen::Main;
-                let action = match release_type {
-                    ReleaseType::Single => GameAction::RecordSingle {
+                let action = match (live, rerecord, release_type) {
+                    (Some(format), _, _) => GameAction::ReleaseLive {
+                        format,
+                        pressing: Some(selected),
+                    },
+                    (None, Some(release_id), _) => GameAction::ReRecord {
+                        release_id,
                         pressing: Some(selected),
                     },
-                    ReleaseType::Album => GameAction::RecordAlbum {
+                    (None, None, ReleaseType::Single) => GameAction::RecordSingle {
+                        pressing: Some(selected),
+                    },
+                    (None, None, ReleaseType::Album) => GameAction::RecordAlbum {
                         pressing: Some(selected),
                     },
                 };
@@ -191,8 +253,9 @@ impl App {
                 };
             }
             KeyCode::Enter => {
-                let venue = &self.game.world.venues[selected];
-                if venue.prestige > self.game.band.fame.saturating_add(20) {
+                let index = self.game.world.venue_ladder()[selected];
+                let venue = &self.game.world.venues[index];
+                if !self.game.venue_in_reach(venue) {
                     self.push_log(
                         LogKind::Error,
                         format!(
@@ -200,15 +263,25 @@ impl App {
                             venue.name
                         ),
                     );
+                } else if venue.is_banned(self.game.week) {
+                    self.push_log(
+                        LogKind::Error,
+                        format!(
+                            "❌ '{}' won't book you again until week {}.",
+                            venue.name, venue.banned_until
+                        ),
+                    );
                 } else {
                     self.screen = Screen::Main;
-                    self.dispatch(GameAction::Gig(selected));
+                    self.dispatch(GameAction::Gig(index));
                 }
             }
             _ => {}
         }
     }
 
+    /// Region picker, also the "next stop" picker while routing a tour:
+    /// Backspace drops the last stop added, Esc abandons the whole plan.
     pub(crate) fn handle_region_picker_key(&mut self, key: KeyEvent) {
         let Screen::RegionPicker { selected } = self.screen else {
             return;
@@ -216,7 +289,13 @@ impl App {
         let sorted_regions = self.game.get_sorted_regions();
         let count = sorted_regions.len();
         match key.code {
-            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Esc => {
+                self.tour_route.clear();
+                self.screen = Screen::Main;
+            }
+            KeyCode::Backspace => {
+                self.tour_route.pop();
+            }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.screen = Screen::RegionPicker {
                     selected: super::cycle_index(selected, count, false),
@@ -237,12 +316,21 @@ impl App {
                             fame_req, region_name
                         ),
                     );
+                } else if self
+                    .tour_route
+                    .iter()
+                    .any(|leg| leg.region_index == selected)
+                {
+                    self.push_log(
+                        LogKind::Error,
+                        format!("❌ '{}' is already on the route.", region_name),
+                    );
                 } else {
                     // The rig/length choice — and its quote — come next
                     // (design §A, M1): fame gates the region, never the cost.
                     self.screen = Screen::TourBookingPicker {
                         region_index: selected,
-                        rig: TourRig::Van,
+                        rig: self.tour_rig,
                         weeks: 1,
                     };
                 }
@@ -255,8 +343,11 @@ impl App {
     /// rigs (↑↓) and weeks (←→), and shows the live quote via
     /// `draw_tour_booking_picker_modal`. Booking only dispatches once the
     /// quote resolves and the player can afford it — the gate check mirrors
-    /// `Game::quote_tour`/`action_go_on_tour` so the error the player sees
-    /// here always matches what booking would say.
+    /// `Game::quote_route`/`action_tour_route` so the error the player sees
+    /// here always matches what booking would say. A adds this stop to the
+    /// route and goes back for the next one; T, M and O cycle the ticket
+    /// tier, the merch order and the opener, which hold for the whole tour,
+    /// and S opens the setlist.
     pub(crate) fn handle_tour_booking_picker_key(&mut self, key: KeyEvent) {
         let Screen::TourBookingPicker {
             region_index,
@@ -266,6 +357,11 @@ impl App {
         else {
             return;
         };
+        let mut legs = self.tour_route.clone();
+        legs.push(TourLeg {
+            region_index,
+            weeks,
+        });
 
         match key.code {
             KeyCode::Esc => {
@@ -305,27 +401,145 @@ impl App {
                     weeks: new_weeks,
                 };
             }
-            KeyCode::Enter => {
-                let quote: Result<TourQuote, String> =
-                    self.game.quote_tour(region_index, rig, weeks);
-                match quote {
-                    Ok(quote) => {
-                        if self.game.player.can_afford(quote.cost) {
-                            self.screen = Screen::Main;
-                            self.dispatch(GameAction::GoOnTour(region_index, rig, weeks));
-                        } else {
-                            self.push_log(
-                                LogKind::Error,
-                                format!(
-                                    "❌ You need {} to finance this tour!",
-                                    format_money(quote.cost)
-                                ),
-                            );
-                        }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                let idx = super::cycle_index(
+                    self.tour_terms.tickets.ordinal(),
+                    TicketTier::ALL.len(),
+                    key.code == KeyCode::Char('t'),
+                );
+                self.tour_terms.tickets = TicketTier::ALL[idx];
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                let orders = TourTerms::MERCH_ORDERS;
+                let current = orders
+                    .iter()
+                    .position(|&units| units == self.tour_terms.merch_order)
+                    .unwrap_or(0);
+                let idx = super::cycle_index(current, orders.len(), key.code == KeyCode::Char('m'));
+                self.tour_terms.merch_order = orders[idx];
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.tour_terms.recorded = !self.tour_terms.recorded;
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.screen = Screen::SetlistPicker {
+                    region_index,
+                    rig,
+                    weeks,
+                    selected: 0,
+                };
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                // No opener, then the shortlist hottest first.
+                let mut choices: Vec<Option<String>> = vec![None];
+                choices.extend(
+                    self.game
+                        .opener_candidates()
+                        .iter()
+                        .map(|band| Some(band.name.clone())),
+                );
+                let current = choices
+                    .iter()
+                    .position(|choice| *choice == self.tour_terms.opener)
+                    .unwrap_or(0);
+                let idx =
+                    super::cycle_index(current, choices.len(), key.code == KeyCode::Char('o'));
+                self.tour_terms.opener = choices.swap_remove(idx);
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                match self.game.quote_route(&legs, rig, self.tour_terms.clone()) {
+                    Ok(_) => {
+                        self.tour_route = legs;
+                        self.tour_rig = rig;
+                        self.screen = Screen::RegionPicker {
+                            selected: region_index,
+                        };
                     }
                     Err(msg) => self.push_log(LogKind::Error, format!("❌ {msg}")),
                 }
             }
+            KeyCode::Enter => match self.game.quote_route(&legs, rig, self.tour_terms.clone()) {
+                Ok(quote) => {
+                    if self.game.player.can_afford(quote.cost) {
+                        self.tour_route.clear();
+                        self.screen = Screen::Main;
+                        let action = if legs.len() == 1 && self.tour_terms == TourTerms::default() {
+                            GameAction::GoOnTour(region_index, rig, weeks)
+                        } else {
+                            GameAction::GoOnTourRoute(legs, rig, self.tour_terms.clone())
+                        };
+                        self.dispatch(action);
+                    } else {
+                        self.push_log(
+                            LogKind::Error,
+                            format!(
+                                "❌ You need {} to finance this tour!",
+                                format_money(quote.cost)
+                            ),
+                        );
+                    }
+                }
+                Err(msg) => self.push_log(LogKind::Error, format!("❌ {msg}")),
+            },
+            _ => {}
+        }
+    }
+
+    /// The tour's setlist: Space adds the song under the cursor to the end
+    /// of the set or takes it off, C clears the set back to the usual one,
+    /// and Esc or Enter goes back to the booking picker.
+    pub(crate) fn handle_setlist_picker_key(&mut self, key: KeyEvent) {
+        let Screen::SetlistPicker {
+            region_index,
+            rig,
+            weeks,
+            selected,
+        } = self.screen
+        else {
+            return;
+        };
+        let catalogue = self.game.setlist_catalogue();
+        let back = Screen::TourBookingPicker {
+            region_index,
+            rig,
+            weeks,
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => self.screen = back,
+            KeyCode::Up | KeyCode::Char('k') if !catalogue.is_empty() => {
+                self.screen = Screen::SetlistPicker {
+                    region_index,
+                    rig,
+                    weeks,
+                    selected: super::cycle_index(selected, catalogue.len(), false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') if !catalogue.is_empty() => {
+                self.screen = Screen::SetlistPicker {
+                    region_index,
+                    rig,
+                    weeks,
+                    selected: super::cycle_index(selected, catalogue.len(), true),
+                };
+            }
+            KeyCode::Char(' ') => {
+                let Some(&(song, _)) = catalogue.get(selected) else {
+                    return;
+                };
+                let setlist = &mut self.tour_terms.setlist;
+                if let Some(position) = setlist.iter().position(|&played| played == song) {
+                    setlist.remove(position);
+                } else if setlist.len() < SETLIST_MAX_SONGS {
+                    setlist.push(song);
+                } else {
+                    self.push_log(
+                        LogKind::Error,
+                        format!("❌ A set can run to at most {SETLIST_MAX_SONGS} songs."),
+                    );
+                }
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => self.tour_terms.setlist.clear(),
             _ => {}
         }
     }
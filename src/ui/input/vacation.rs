@@ -0,0 +1,39 @@
+//! Vacation picker input (FUTURE §9.3). Opening the picker itself is wired
+//! from the main menu (`MenuKind::Vacation` in `main.rs`).
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::game::{GameAction, Vacation};
+use crate::ui::app::{App, LogKind, Screen};
+
+impl App {
+    pub(crate) fn handle_vacation_picker_key(&mut self, key: KeyEvent) {
+        let Screen::VacationPicker { selected } = self.screen else {
+            return;
+        };
+        let count = Vacation::ALL.len();
+        match key.code {
+            KeyCode::Esc => self.screen = Screen::Main,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::VacationPicker {
+                    selected: super::cycle_index(selected, count, false),
+                };
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::VacationPicker {
+                    selected: super::cycle_index(selected, count, true),
+                };
+            }
+            KeyCode::Enter => {
+                let trip = Vacation::ALL[selected];
+                if !self.game.player.can_afford(trip.cost() as i32) {
+                    self.push_log(LogKind::Ui, "You can't afford that trip.");
+                    return;
+                }
+                self.screen = Screen::Main;
+                self.dispatch(GameAction::TakeVacation(trip));
+            }
+            _ => {}
+        }
+    }
+}
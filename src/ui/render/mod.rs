@@ -25,9 +25,15 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
             layout::draw_main(frame, app);
             match &app.screen {
                 Screen::Deals { .. } => modals::draw_deals_modal(frame, app),
+                Screen::DealCounter { .. } => modals::draw_deal_counter_modal(frame, app),
                 Screen::SupportOffer => modals::draw_support_modal(frame, app),
+                Screen::Takeover => modals::draw_takeover_modal(frame, app),
+                Screen::LabelDemand => modals::draw_label_demand_modal(frame, app),
+                Screen::FestivalOffer => modals::draw_festival_modal(frame, app),
+                Screen::HeadlineOffer => modals::draw_headline_modal(frame, app),
                 Screen::Charts { .. } => modals::draw_charts_modal(frame, app),
                 Screen::TourReport { .. } => modals::draw_tour_report_modal(frame, app),
+                Screen::FameMap { .. } => modals::draw_fame_map_modal(frame, app),
                 Screen::MarketingRelease { .. } | Screen::MarketingCampaign { .. } => {
                     modals::draw_marketing_modal(frame, app)
                 }
@@ -37,8 +43,19 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
                 Screen::TourBookingPicker { .. } => {
                     modals::draw_tour_booking_picker_modal(frame, app)
                 }
+                Screen::SetlistPicker { .. } => modals::draw_setlist_picker_modal(frame, app),
                 Screen::PressingPicker { .. } => modals::draw_pressing_picker_modal(frame, app),
+                Screen::LiveRelease { .. } => modals::draw_live_release_modal(frame, app),
                 Screen::LifestylePicker { .. } => modals::draw_lifestyle_picker_modal(frame, app),
+                Screen::VacationPicker { .. } => modals::draw_vacation_picker_modal(frame, app),
+                Screen::Manager { .. } => modals::draw_manager_modal(frame, app),
+                Screen::Gear { .. } => modals::draw_gear_modal(frame, app),
+                Screen::Catalogue { .. } => modals::draw_catalogue_modal(frame, app),
+                Screen::Finance { .. } => modals::draw_finance_modal(frame, app),
+                Screen::TaxExile { .. } => modals::draw_tax_exile_modal(frame, app),
+                Screen::Investments { .. } => modals::draw_investments_modal(frame, app),
+                Screen::OwnLabel { .. } => modals::draw_own_label_modal(frame, app),
+                Screen::IncidentChoice { .. } => modals::draw_incident_choice_modal(frame, app),
                 Screen::RePressPicker { .. } => modals::draw_repress_picker_modal(frame, app),
                 Screen::RePressTierPicker { .. } => {
                     modals::draw_repress_tier_picker_modal(frame, app)
@@ -98,6 +115,7 @@ mod tests {
     use crate::game::{Game, ShowReport, TourReport};
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
     fn app_on_main() -> App {
         let mut app = App::new(Game::new().expect("data files present"));
@@ -135,12 +153,14 @@ mod tests {
                 attendance: 300 + i * 10,
                 capacity: 500,
                 take: 1000 + i * 50,
+                merch: 40 * i,
             })
             .collect();
         app.game.last_tour_report = Some(TourReport {
             avg_reception: 65,
             total_gross: rows.iter().map(|r| r.take).sum(),
             fame_gained: 5,
+            total_merch: rows.iter().map(|r| r.merch).sum(),
             rows,
         });
         app.screen = Screen::TourReport { scroll: 19 };
@@ -174,6 +194,677 @@ mod tests {
         // A high-fame band: every rig/length should quote cleanly.
         app.game.band.fame = 90;
         terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        // Premium seats, a merch order and taping add to the quote.
+        app.tour_terms = crate::game::TourTerms {
+            tickets: crate::game::TicketTier::Premium,
+            merch_order: 2_500,
+            recorded: true,
+            ..crate::game::TourTerms::default()
+        };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        // So does an opener, and the single-stop quote lists all three.
+        app.tour_terms.opener = app
+            .game
+            .opener_candidates()
+            .first()
+            .map(|band| band.name.clone());
+        assert!(app.tour_terms.opener.is_some());
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The setlist picker, empty and with songs on the set, and the booking
+    /// picker summing the set up.
+    #[test]
+    fn setlist_picker_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.band.unreleased_songs.clear();
+        app.screen = Screen::SetlistPicker {
+            region_index: 0,
+            rig: crate::game::TourRig::Van,
+            weeks: 1,
+            selected: 0,
+        };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        for id in 0..3 {
+            app.game
+                .band
+                .unreleased_songs
+                .push(crate::game::music::Song {
+                    id,
+                    name: format!("Road Song {id}"),
+                    songwriting_quality: 50,
+                });
+        }
+        app.tour_terms.setlist = vec![crate::game::SetlistSong::Unreleased(1)];
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.screen = Screen::TourBookingPicker {
+            region_index: 0,
+            rig: crate::game::TourRig::Van,
+            weeks: 1,
+        };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// Both tour pickers mid-route: the region picker marks the stops
+    /// already planned and the booking picker quotes the whole route.
+    #[test]
+    fn tour_route_pickers_render_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.band.fame = 90;
+        app.tour_route = vec![crate::game::TourLeg {
+            region_index: 0,
+            weeks: 2,
+        }];
+
+        app.screen = Screen::RegionPicker { selected: 1 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.screen = Screen::TourBookingPicker {
+            region_index: 5,
+            rig: crate::game::TourRig::Bus,
+            weeks: 2,
+        };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        // An over-long route degrades to the quote's error message.
+        app.screen = Screen::TourBookingPicker {
+            region_index: 5,
+            rig: crate::game::TourRig::Bus,
+            weeks: 4,
+        };
+        app.tour_route[0].weeks = 4;
+        app.tour_route.push(crate::game::TourLeg {
+            region_index: 1,
+            weeks: 4,
+        });
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The venue picker with a played room, a return booking, and a ban.
+    #[test]
+    fn venue_picker_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        let week = app.game.week;
+        app.game.world.venues[0].shows_played = 3;
+        app.game.world.venues[0].reception_total = 180;
+        app.game.world.venues[1].invited_until = week + 4;
+        app.game.world.venues[2].banned_until = week + 10;
+
+        app.screen = Screen::VenuePicker { selected: 2 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The festival offer, open and booked, with this year's bill.
+    #[test]
+    fn festival_offer_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game
+            .process_turn(crate::game::GameAction::LazeAround)
+            .unwrap();
+        let festival = app.game.world.festivals[0].clone();
+        app.game.pending_festival_offer = Some(crate::game::FestivalOffer {
+            festival_id: festival.id,
+            festival: festival.name,
+            region_name: festival.region_name,
+            billing: crate::game::world::FestivalBilling::Support,
+            pay: 1_500,
+            fame_gain: 5,
+            expires_week: festival.week,
+            booked: false,
+        });
+
+        app.screen = Screen::FestivalOffer;
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.game.pending_festival_offer.as_mut().unwrap().booked = true;
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The headline tour offer, on a route the band can play and one it
+    /// has fallen short of.
+    #[test]
+    fn headline_offer_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.band.fame = 80;
+        app.game.pending_headline_offer = Some(crate::game::HeadlineTourOffer {
+            promoter: "Marlowe Presents".to_string(),
+            legs: vec![
+                crate::game::TourLeg {
+                    region_index: 0,
+                    weeks: 2,
+                },
+                crate::game::TourLeg {
+                    region_index: 1,
+                    weeks: 1,
+                },
+            ],
+            rig: crate::game::TourRig::Full,
+            deal: crate::game::PromoterDeal::Percentage(0.7),
+            expires_week: app.game.week + 4,
+        });
+
+        app.screen = Screen::HeadlineOffer;
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.game.band.fame = 10;
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The live-record screen should render with tapes but no catalogue
+    /// to play from, then with both, and hand an unsigned band on to the
+    /// pressing picker.
+    #[test]
+    fn live_record_screen_renders_without_panicking() {
+        use crate::game::music::{LiveFormat, Release, ReleaseType};
+
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        let show = ShowReport {
+            week: 1,
+            venue_name: "The Roundhouse".to_string(),
+            verdict: "Great".to_string(),
+            reception: 85,
+            attendance: 400,
+            capacity: 500,
+            take: 1_000,
+            merch: 0,
+        };
+        app.game.live_tapes = Some(TourReport {
+            rows: vec![show; 4],
+            avg_reception: 85,
+            ..TourReport::default()
+        });
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('a')));
+        assert!(matches!(
+            app.screen,
+            Screen::LiveRelease {
+                format: LiveFormat::Album
+            }
+        ));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.game.band.albums_released.push(Release {
+            id: 1,
+            name: "Debut".to_string(),
+            release_type: ReleaseType::Album,
+            release_quality: 60,
+            week_released: 1,
+            songs_involved_quality_avg: 60,
+            active_marketing: Vec::new(),
+            marketing_level_achieved: 0,
+            initial_sales_score: 0,
+            total_income_generated: 0,
+            genre: None,
+            copies_pressed: 1_000,
+            copies_sold: 0,
+            peak_chart_position: None,
+            singles_cut: 0,
+            certified: 0,
+            distribution_channel: None,
+            label_market_reach: None,
+            live: None,
+            outside_deal: false,
+            master_owner: None,
+            rerecording_of: None,
+        });
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_live_release_key(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(
+            app.screen,
+            Screen::PressingPicker { live: Some(_), .. }
+        ));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The back catalogue should render an owned and a label-held master,
+    /// and hand a re-recording to the pressing picker while unsigned.
+    #[test]
+    fn catalogue_screen_renders_without_panicking() {
+        use crate::game::music::{MasterOwner, ReleaseType};
+
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.player.money = 50_000;
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('b')));
+        assert!(matches!(app.screen, Screen::Main), "nothing out yet");
+        for (id, owner) in [
+            (1, MasterOwner::Band),
+            (
+                2,
+                MasterOwner::Label {
+                    name: "Island Records".to_string(),
+                    royalty_rate: 0.12,
+                },
+            ),
+        ] {
+            app.game
+                .band
+                .singles_released
+                .push(crate::game::music::Release {
+                    id,
+                    name: format!("Record {id}"),
+                    release_type: ReleaseType::Single,
+                    release_quality: 60,
+                    week_released: 1,
+                    songs_involved_quality_avg: 60,
+                    active_marketing: Vec::new(),
+                    marketing_level_achieved: 0,
+                    initial_sales_score: 300,
+                    total_income_generated: 0,
+                    genre: None,
+                    copies_pressed: 1_000,
+                    copies_sold: 800,
+                    peak_chart_position: None,
+                    singles_cut: 0,
+                    certified: 0,
+                    distribution_channel: None,
+                    label_market_reach: None,
+                    live: None,
+                    outside_deal: false,
+                    master_owner: Some(owner),
+                    rerecording_of: None,
+                });
+        }
+
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('b')));
+        assert!(matches!(app.screen, Screen::Catalogue { selected: 0 }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_catalogue_key(KeyEvent::from(KeyCode::Down));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        app.handle_catalogue_key(KeyEvent::from(KeyCode::Char('r')));
+        assert!(matches!(
+            app.screen,
+            Screen::PressingPicker {
+                rerecord: Some(2),
+                ..
+            }
+        ));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The finances screen should render on empty books, then with a week
+    /// of spending on them, step between the years it covers, borrow, and
+    /// open the tax-exile picker and move abroad from it.
+    #[test]
+    fn finance_screen_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('n')));
+        assert!(matches!(app.screen, Screen::Finance { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_finance_key(KeyEvent::from(KeyCode::Esc));
+        app.dispatch(crate::game::GameAction::VisitDoctor);
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('n')));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        app.handle_finance_key(KeyEvent::from(KeyCode::Left));
+        app.handle_finance_key(KeyEvent::from(KeyCode::Right));
+        assert!(matches!(app.screen, Screen::Finance { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.game.band.fame = 40;
+        app.handle_finance_key(KeyEvent::from(KeyCode::Char('b')));
+        assert!(app.game.loan.is_some());
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_finance_key(KeyEvent::from(KeyCode::Char('x')));
+        assert!(matches!(app.screen, Screen::TaxExile { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        app.handle_tax_exile_key(KeyEvent::from(KeyCode::Esc));
+        app.handle_finance_key(KeyEvent::from(KeyCode::Char('c')));
+        assert!(app.game.accountant);
+        app.game.player.money = 50_000;
+        app.handle_finance_key(KeyEvent::from(KeyCode::Char('x')));
+        app.handle_tax_exile_key(KeyEvent::from(KeyCode::Down));
+        app.handle_tax_exile_key(KeyEvent::from(KeyCode::Enter));
+        assert!(app.game.in_tax_exile());
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// A takeover of the band's label opens from the deal-offers entry,
+    /// renders, and renegotiating lands on the buyer's offer.
+    #[test]
+    fn takeover_screen_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.band.record_deal = Some(crate::game::band::RecordDeal {
+            label_name: "Charisma Records".to_string(),
+            label_tier: "Boutique".to_string(),
+            advance: 0,
+            royalty_rate: 0.35,
+            albums_required: 2,
+            albums_delivered: 0,
+            market_reach: 30,
+            unrecouped: 1_000,
+            signed_week: 0,
+            term_weeks: 78,
+            creative_control: crate::game::band::CreativeControl::Shared,
+            defiances: 0,
+        });
+        let charisma = app
+            .game
+            .world
+            .labels
+            .iter_mut()
+            .find(|label| label.name == "Charisma Records")
+            .expect("Charisma trading");
+        charisma.folded = true;
+        charisma.absorbed_by = Some("Atlantic Records".to_string());
+        app.dispatch(crate::game::GameAction::LazeAround);
+        assert!(app.game.pending_takeover.is_some());
+
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('v')));
+        assert!(matches!(app.screen, Screen::Takeover));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_takeover_key(KeyEvent::from(KeyCode::Char('r')));
+        assert!(matches!(app.screen, Screen::Deals { detail: true, .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// A&R's demand on a record cut under a label-led deal opens its modal,
+    /// renders, and defying it closes the modal and cuts the term.
+    #[test]
+    fn label_demand_screen_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.band.genre = crate::game::genre::MusicGenre::Metal;
+        app.game.player.money = 100_000;
+        app.game.player.stress = 0;
+        app.game.band.record_deal = Some(crate::game::band::RecordDeal {
+            label_name: "Columbia Records".to_string(),
+            label_tier: "Major".to_string(),
+            advance: 0,
+            royalty_rate: 0.12,
+            albums_required: 2,
+            albums_delivered: 0,
+            market_reach: 70,
+            unrecouped: 0,
+            signed_week: 0,
+            term_weeks: 104,
+            creative_control: crate::game::band::CreativeControl::LabelLed,
+            defiances: 0,
+        });
+        app.game
+            .band
+            .unreleased_songs
+            .push(crate::game::music::Song {
+                id: 0,
+                name: "Heavy".to_string(),
+                songwriting_quality: 50,
+            });
+        app.dispatch(crate::game::GameAction::RecordSingle { pressing: None });
+        assert!(app.game.pending_label_demand.is_some());
+        assert!(matches!(app.screen, Screen::LabelDemand));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_label_demand_key(KeyEvent::from(KeyCode::Char('x')));
+        assert!(matches!(app.screen, Screen::LabelDemand), "wants an answer");
+        app.handle_label_demand_key(KeyEvent::from(KeyCode::Char('d')));
+        assert!(matches!(app.screen, Screen::Main));
+        let deal = app.game.band.current_deal().unwrap();
+        assert_eq!((deal.defiances, deal.term_weeks), (1, 91));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The gear & crew screen should render without panicking on a fresh
+    /// band, then with bought gear, a hired crew and insurance — all of
+    /// which it buys through its own keys.
+    #[test]
+    fn gear_screen_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.player.money = 50_000;
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('e')));
+        assert!(matches!(app.screen, Screen::Gear { selected: 0 }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        let press = |app: &mut App, code| app.handle_gear_key(KeyEvent::from(code));
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char('b'));
+        assert!(app.game.owns_gear(crate::game::GearKind::Backline));
+        for _ in 0..7 {
+            press(&mut app, KeyCode::Down);
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.game.crew_count(crate::game::CrewRole::Roadie), 1);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Enter);
+        assert!(app.game.gear_insured);
+        assert!(matches!(app.screen, Screen::Gear { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The investments screen should open from the main menu and render
+    /// with nothing invested, then after buying a stake and selling it.
+    #[test]
+    fn investments_screen_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.player.money = 50_000;
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('i')));
+        assert!(matches!(app.screen, Screen::Investments { selected: 0 }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        let press = |app: &mut App, code| app.handle_investments_key(KeyEvent::from(code));
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Char('b'));
+        press(&mut app, KeyCode::Char('b'));
+        assert_eq!(app.game.investments.len(), 1);
+        assert_eq!(app.game.investments[0].paid, 20_000);
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        press(&mut app, KeyCode::Char('s'));
+        assert!(app.game.investments.is_empty());
+        assert!(matches!(app.screen, Screen::Investments { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The label screen should render before the label is founded, then
+    /// with an act signed and after letting it go.
+    #[test]
+    fn own_label_screen_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.handle_main_key(KeyEvent::from(KeyCode::Char('y')));
+        assert!(matches!(app.screen, Screen::OwnLabel { selected: 0 }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        let press = |app: &mut App, code| app.handle_own_label_key(KeyEvent::from(code));
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Char('f'));
+        assert!(app.game.own_label.is_none(), "not famous enough yet");
+        app.game.band.fame = 90;
+        app.game.player.money = 200_000;
+        for band in &mut app.game.world.bands {
+            band.rapport = 0;
+        }
+        press(&mut app, KeyCode::Char('f'));
+        assert!(app.game.own_label.is_some());
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.game.own_label.as_ref().unwrap().acts.len(), 1);
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        press(&mut app, KeyCode::Enter);
+        assert!(app.game.own_label.as_ref().unwrap().acts.is_empty());
+        assert!(matches!(app.screen, Screen::OwnLabel { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The vacation picker (FUTURE §9.3) should render without panicking
+    /// broke (every trip locked) and flush (every trip bookable).
+    #[test]
+    fn vacation_picker_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+
+        app.game.player.money = 0;
+        app.screen = Screen::VacationPicker { selected: 0 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.game.player.money = 50_000;
+        app.screen = Screen::VacationPicker { selected: 3 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// An offer's detail and the counter drafted from it should render,
+    /// with a term changed, and the counter should go to the label.
+    #[test]
+    fn deal_counter_renders_and_sends_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        let label = app
+            .game
+            .data_files
+            .get_record_labels_data()
+            .independent_labels[0]
+            .clone();
+        app.game.pending_deal_offers = vec![crate::game::world::PotentialDealOffer {
+            label_name: label.name.clone(),
+            label_tier: "Independent".to_string(),
+            advance: 1_000,
+            royalty_rate: 0.12,
+            albums_required: 1,
+            original_label_data: label,
+            expires_week: None,
+            term_weeks: 90,
+            carry_forward_unrecouped: 0,
+            creative_control: crate::game::band::CreativeControl::Shared,
+            counters: 0,
+        }];
+        app.screen = Screen::Deals {
+            selected: 0,
+            detail: true,
+        };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.handle_deals_key(KeyEvent::from(KeyCode::Char('n')));
+        let press = |app: &mut App, code| app.handle_deal_counter_key(KeyEvent::from(code));
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Right);
+        assert!(matches!(
+            app.screen,
+            Screen::DealCounter { counter, field: 1, .. } if counter.royalty_rate > 0.12
+        ));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        press(&mut app, KeyCode::Enter);
+        assert!(
+            app.game.pending_deal_offers.is_empty()
+                || app.game.pending_deal_offers[0].counters == 1
+        );
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The world map should render for an unknown band and for one with
+    /// fame, visits and sales abroad, scrolled past the last row.
+    #[test]
+    fn fame_map_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+
+        app.screen = Screen::FameMap { scroll: 0 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.game.week = 40;
+        app.game
+            .regional_fame
+            .insert("europe:germany".to_string(), 55);
+        app.game
+            .regional_last_visit
+            .insert("europe:germany".to_string(), 12);
+        app.game
+            .copies_by_country
+            .insert("europe".to_string(), 12_000);
+        app.screen = Screen::FameMap { scroll: 999 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The manager screen (FUTURE §9.4) should render without panicking
+    /// both as a slate of pitches and as the hired manager's card.
+    #[test]
+    fn manager_screen_renders_without_panicking() {
+        use rand::SeedableRng;
+
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        app.game.band.fame = 60;
+        for seed in 0..40 {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            app.game.pending_manager_offers = app.game.world.generate_manager_offers(
+                &app.game.band,
+                &app.game.data_files,
+                &mut rng,
+            );
+            if !app.game.pending_manager_offers.is_empty() {
+                break;
+            }
+        }
+        assert!(!app.game.pending_manager_offers.is_empty());
+        app.screen = Screen::Manager { selected: 0 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.dispatch(crate::game::GameAction::HireManager(0));
+        assert!(app.game.manager.is_some());
+        app.screen = Screen::Manager { selected: 0 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+    }
+
+    /// The decision-incident modal should render without panicking with
+    /// a mix of open and locked choices, and open itself on dispatch.
+    #[test]
+    fn incident_choice_modal_renders_without_panicking() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).expect("test backend");
+        let mut app = app_on_main();
+        let decision = app
+            .game
+            .data_files
+            .incidents_data
+            .incidents
+            .iter()
+            .find(|i| i.choices.len() > 2)
+            .cloned()
+            .expect("a shipped decision with a gated choice");
+        app.game.pending_incident = Some(decision);
+        app.dispatch(crate::game::GameAction::ChangeLifestyle(
+            app.game.player.lifestyle,
+        ));
+        assert!(matches!(app.screen, Screen::IncidentChoice { .. }));
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        app.screen = Screen::IncidentChoice { selected: 2 };
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
     }
 
     /// The charts modal (region tabs, empty board, a populated board
@@ -235,6 +926,8 @@ mod tests {
             release_type: ReleaseType::Single,
             selected: 0,
             channel: DistributionChannel::National,
+            live: None,
+            rerecord: None,
         };
         terminal.draw(|frame| draw(frame, &mut app)).unwrap();
 
@@ -244,6 +937,8 @@ mod tests {
             release_type: ReleaseType::Album,
             selected: 2,
             channel: DistributionChannel::Regional,
+            live: None,
+            rerecord: None,
         };
         terminal.draw(|frame| draw(frame, &mut app)).unwrap();
 
@@ -259,6 +954,8 @@ mod tests {
             unrecouped: 0,
             signed_week: 0,
             term_weeks: 0,
+            creative_control: crate::game::band::CreativeControl::Shared,
+            defiances: 0,
         });
         terminal.draw(|frame| draw(frame, &mut app)).unwrap();
         app.game.band.record_deal = None;
@@ -295,6 +992,10 @@ mod tests {
                     certified: 0,
                     distribution_channel: None,
                     label_market_reach: None,
+                    live: None,
+                    outside_deal: false,
+                    master_owner: None,
+                    rerecording_of: None,
                 }
             });
         release.copies_pressed = 1_000;
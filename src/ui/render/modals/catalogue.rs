@@ -0,0 +1,113 @@
+//! Back catalogue screen: every release with who owns its master, copies
+//! sold and what it's worth, and for the one selected what buying it back,
+//! selling it or re-recording it would take.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::data::format_money;
+use crate::game::music::{MasterOwner, Release};
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_catalogue_modal(frame: &mut Frame, app: &App) {
+    let Screen::Catalogue { selected } = app.screen else {
+        return;
+    };
+    let game = &app.game;
+    let area = centered_rect(84, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(" 📼 Back Catalogue ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" B buy back · S sell · R re-record · Esc close ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [list_area, detail_area] =
+        Layout::vertical([Constraint::Min(4), Constraint::Length(3)]).areas(inner);
+
+    let items: Vec<ListItem> = game
+        .catalogue()
+        .map(|release| {
+            let (owner, color) = owner_label(release);
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<34.34}", release.name), Style::new().bold()),
+                Span::styled(format!("{:<26.26}", owner), Style::new().fg(color)),
+                Span::raw(format!("{:>8} sold", release.copies_sold)),
+                Span::raw(format!(
+                    "  worth {:>7}",
+                    format_money(release.master_value() as i32)
+                )),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let Some(release) = game.catalogue().nth(selected) else {
+        return;
+    };
+    let mut detail = Vec::new();
+    if let Some((owed, premium)) = game.buyback_price(release) {
+        let owed_note = if owed > 0 {
+            format!(
+                " ({} still owed + {} premium)",
+                format_money(owed as i32),
+                format_money(premium as i32)
+            )
+        } else {
+            String::new()
+        };
+        detail.push(Line::from(format!(
+            "Buy back: {}{}",
+            format_money((owed + premium) as i32),
+            owed_note
+        )));
+        detail.push(match game.check_rerecord(release) {
+            Ok(()) => Line::styled(
+                "Re-record: the songs are yours to cut again.",
+                Style::new().fg(Color::Green),
+            ),
+            Err(reason) => Line::styled(
+                format!("Re-record: {}", reason),
+                Style::new().fg(Color::DarkGray),
+            ),
+        });
+    } else {
+        detail.push(match game.master_sale_offer(release) {
+            Some((buyer, price)) => Line::from(format!(
+                "Sell: {} would pay {} — and keep every sale after.",
+                buyer,
+                format_money(price as i32)
+            )),
+            None => Line::styled(
+                "Sell: no label has the money for it right now.",
+                Style::new().fg(Color::DarkGray),
+            ),
+        });
+    }
+    frame.render_widget(
+        Paragraph::new(detail).wrap(Wrap { trim: false }),
+        detail_area,
+    );
+}
+
+/// Who holds the master, as the list shows it.
+fn owner_label(release: &Release) -> (String, Color) {
+    match &release.master_owner {
+        Some(MasterOwner::Label { name, royalty_rate }) if *royalty_rate > 0.0 => (
+            format!("{} · {:.0}%", name, royalty_rate * 100.0),
+            Color::Yellow,
+        ),
+        Some(MasterOwner::Label { name, .. }) => (format!("sold to {}", name), Color::DarkGray),
+        _ => ("yours".to_string(), Color::Green),
+    }
+}
@@ -1,4 +1,5 @@
-//! Deal offer detail and support-slot offer overlays.
+//! Deal offer detail, support-slot, festival-slot, and headline tour offer
+//! overlays.
 
 use ratatui::{
     Frame,
@@ -8,6 +9,7 @@ use ratatui::{
 };
 
 use crate::data::format_money;
+use crate::game::{AR_DEFIANCE_TERM_CUT_WEEKS, NEGOTIATION_MAX_COUNTERS};
 use crate::ui::app::{App, Screen};
 
 use super::super::centered_rect;
@@ -39,6 +41,11 @@ pub(crate) fn draw_deals_modal(frame: &mut Frame, app: &App) {
                 offer.royalty_rate * 100.0
             )),
             Line::from(format!("  Albums required  {}", offer.albums_required)),
+            Line::from(format!("  Term             {} weeks", offer.term_weeks)),
+            Line::from(format!(
+                "  Creative control {}",
+                offer.creative_control.label()
+            )),
         ];
         if let Some(deadline) = offer.expires_week {
             let weeks_left = deadline.saturating_sub(app.game.week);
@@ -61,10 +68,27 @@ pub(crate) fn draw_deals_modal(frame: &mut Frame, app: &App) {
                 "  Creative freedom   {}/100",
                 data.creative_freedom
             )),
+        ]);
+        if let Some(state) = app.game.world.label(&data.name) {
+            let roster = app.game.world.label_roster_size(&data.name);
+            lines.extend([
+                Line::from(format!(
+                    "  Roster             {} act{} · {:.0}% hit rate",
+                    roster,
+                    if roster == 1 { "" } else { "s" },
+                    state.hit_rate() * 100.0
+                )),
+                Line::from(format!(
+                    "  Finances           {}",
+                    state.standing(data, roster)
+                )),
+            ]);
+        }
+        lines.extend([
             Line::from(format!("  Reputation: {}", data.reputation)),
             Line::from(""),
             Line::styled(
-                "  [A]ccept · [R]eject · [Esc] back",
+                "  [A]ccept · [N]egotiate · [R]eject · [Esc] back",
                 Style::new().fg(Color::DarkGray),
             ),
         ]);
@@ -100,6 +124,102 @@ pub(crate) fn draw_deals_modal(frame: &mut Frame, app: &App) {
     }
 }
 
+/// The counter being drafted: each term as offered and as asked, the
+/// selected one highlighted, and how many counters the label will still
+/// hear.
+pub(crate) fn draw_deal_counter_modal(frame: &mut Frame, app: &App) {
+    let Screen::DealCounter {
+        selected,
+        counter,
+        field,
+    } = &app.screen
+    else {
+        return;
+    };
+    let Some(offer) = app.game.pending_deal_offers.get(*selected) else {
+        return;
+    };
+    let area = centered_rect(64, 60, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::bordered()
+        .title(format!(" 🤝 Counter {} ", offer.label_name))
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" ↑↓ term · ←→ change · Enter send · Esc back ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = [
+        (
+            "Advance",
+            format_money(offer.advance as i32),
+            format_money(counter.advance as i32),
+        ),
+        (
+            "Royalty rate",
+            format!("{:.1}%", offer.royalty_rate * 100.0),
+            format!("{:.1}%", counter.royalty_rate * 100.0),
+        ),
+        (
+            "Albums required",
+            offer.albums_required.to_string(),
+            counter.albums_required.to_string(),
+        ),
+        (
+            "Term",
+            format!("{} wks", offer.term_weeks),
+            format!("{} wks", counter.term_weeks),
+        ),
+        (
+            "Creative control",
+            offer.creative_control.label().to_string(),
+            counter.creative_control.label().to_string(),
+        ),
+    ];
+    let mut lines = vec![
+        Line::from(""),
+        Line::styled(
+            format!("  {:<18}{:<14}{}", "", "Offered", "You ask"),
+            Style::new().fg(Color::Cyan).bold(),
+        ),
+    ];
+    for (i, (name, offered, asked)) in rows.into_iter().enumerate() {
+        let changed = offered != asked;
+        let line = Line::from(vec![
+            Span::raw(format!("  {:<18}{:<14}", name, offered)),
+            Span::styled(
+                asked,
+                if changed {
+                    Style::new().fg(Color::Yellow).bold()
+                } else {
+                    Style::new()
+                },
+            ),
+        ]);
+        lines.push(if i == *field {
+            line.style(Style::new().add_modifier(Modifier::REVERSED))
+        } else {
+            line
+        });
+    }
+    let counters_left = NEGOTIATION_MAX_COUNTERS.saturating_sub(offer.counters);
+    lines.extend([
+        Line::from(""),
+        Line::styled(
+            if counters_left == 0 {
+                "  They've called this their final offer.".to_string()
+            } else {
+                format!(
+                    "  They'll hear {} more counter{}. Ask too much and they may walk.",
+                    counters_left,
+                    if counters_left == 1 { "" } else { "s" }
+                )
+            },
+            Style::new().fg(Color::DarkGray),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
 pub(crate) fn draw_support_modal(frame: &mut Frame, app: &App) {
     let Some(offer) = &app.game.pending_support_offer else {
         return;
@@ -144,3 +264,277 @@ pub(crate) fn draw_support_modal(frame: &mut Frame, app: &App) {
     ];
     frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
 }
+
+pub(crate) fn draw_takeover_modal(frame: &mut Frame, app: &App) {
+    let Some(takeover) = &app.game.pending_takeover else {
+        return;
+    };
+    let Some(deal) = app.game.band.current_deal() else {
+        return;
+    };
+
+    let area = centered_rect(62, 50, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::bordered()
+        .title(" 🏢 Label Takeover ")
+        .title_style(Style::new().fg(Color::Yellow).bold());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let weeks_left = takeover.expires_week.saturating_sub(app.game.week);
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                takeover.buyer.clone(),
+                Style::new().fg(Color::Magenta).bold(),
+            ),
+            Span::raw(format!(" has bought {}.", takeover.old_label)),
+        ])
+        .centered(),
+        Line::from(""),
+        Line::from(format!(
+            "  Reach      {} → {}",
+            takeover.old_market_reach, deal.market_reach
+        )),
+        Line::from(format!(
+            "  Royalty    {:.0}% → {:.0}%",
+            takeover.old_royalty_rate * 100.0,
+            deal.royalty_rate * 100.0
+        )),
+        Line::from(format!(
+            "  Still owed {} of {} albums · {} unrecouped",
+            deal.albums_required.saturating_sub(deal.albums_delivered),
+            deal.albums_required,
+            format_money(deal.unrecouped.max(0))
+        )),
+        Line::from(format!(
+            "  The new terms stand in {} week{}",
+            weeks_left,
+            if weeks_left == 1 { "" } else { "s" }
+        )),
+        Line::from(""),
+        Line::styled(
+            "  [R]enegotiate · [K]ey-man exit — walk, leave the masters · [Esc] think it over",
+            Style::new().fg(Color::DarkGray),
+        ),
+    ];
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+pub(crate) fn draw_label_demand_modal(frame: &mut Frame, app: &App) {
+    let Some(demand) = &app.game.pending_label_demand else {
+        return;
+    };
+    let Some(deal) = app.game.band.current_deal() else {
+        return;
+    };
+
+    let area = centered_rect(62, 50, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::bordered()
+        .title(" 📠 A&R ")
+        .title_style(Style::new().fg(Color::Yellow).bold());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let defy = if demand.release_type == crate::game::music::ReleaseType::Album {
+        "It goes out your way, but the label won't count the album toward your contract"
+    } else {
+        "It goes out your way"
+    };
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                demand.label_name.clone(),
+                Style::new().fg(Color::Magenta).bold(),
+            ),
+            Span::raw(format!(" has heard '{}'.", demand.release_name)),
+        ])
+        .centered(),
+        Line::from(""),
+        Line::from(format!("  '{}'", demand.kind.memo())),
+        Line::from(""),
+        Line::from(format!("  Comply  {}", demand.kind.if_complied())),
+        Line::from(if deal.term_weeks > 0 {
+            format!(
+                "  Defy    {}, and the term is cut by {} weeks.",
+                defy, AR_DEFIANCE_TERM_CUT_WEEKS
+            )
+        } else {
+            format!("  Defy    {}.", defy)
+        }),
+        Line::from(format!(
+            "          Defied so far under this deal: {}",
+            deal.defiances
+        )),
+        Line::from(""),
+        Line::styled(
+            "  [C]omply · [D]efy · playing on complies",
+            Style::new().fg(Color::DarkGray),
+        ),
+    ];
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+pub(crate) fn draw_festival_modal(frame: &mut Frame, app: &App) {
+    let Some(offer) = &app.game.pending_festival_offer else {
+        return;
+    };
+
+    let area = centered_rect(62, 70, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::bordered()
+        .title(" 🎪 Festival Slot ")
+        .title_style(Style::new().fg(Color::Yellow).bold());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let weeks_left = offer.expires_week.saturating_sub(app.game.week);
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                offer.festival.clone(),
+                Style::new().fg(Color::Magenta).bold(),
+            ),
+            Span::raw(format!(
+                " in {} want you as their {}.",
+                offer.region_name,
+                offer.billing.label()
+            )),
+        ])
+        .centered(),
+        Line::from(""),
+        Line::from(format!(
+            "  The day    week {} ({} week{} away)",
+            offer.expires_week,
+            weeks_left,
+            if weeks_left == 1 { "" } else { "s" }
+        )),
+        Line::from(format!("  Fee        {}", format_money(offer.pay as i32))),
+        Line::from(format!(
+            "  Exposure   fame +{}, and word travels across the region",
+            offer.fame_gain
+        )),
+        Line::from(""),
+    ];
+    if let Some(festival) = app.game.world.festival(offer.festival_id) {
+        lines.push(Line::styled(
+            format!("  The bill — a crowd of {}", festival.crowd()),
+            Style::new().bold(),
+        ));
+        for slot in &festival.lineup {
+            lines.push(Line::from(format!(
+                "  {:<14} {}",
+                slot.billing.label(),
+                slot.act
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::styled(
+        if offer.booked {
+            "  Booked — you play on the day. [R] pull out · [Esc] close"
+        } else {
+            "  [A]ccept · [R]eject · [Esc] think it over"
+        },
+        Style::new().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+pub(crate) fn draw_headline_modal(frame: &mut Frame, app: &App) {
+    let Some(offer) = &app.game.pending_headline_offer else {
+        return;
+    };
+
+    let area = centered_rect(66, 70, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::bordered()
+        .title(" 🎤 Headline Tour Offer ")
+        .title_style(Style::new().fg(Color::Yellow).bold());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let weeks_left = offer.expires_week.saturating_sub(app.game.week);
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                offer.promoter.clone(),
+                Style::new().fg(Color::Magenta).bold(),
+            ),
+            Span::raw(" want to book your headline tour."),
+        ])
+        .centered(),
+        Line::from(""),
+    ];
+    match app
+        .game
+        .quote_route(&offer.legs, offer.rig, crate::game::TourTerms::default())
+    {
+        Ok(quote) => {
+            lines.push(Line::styled(
+                format!(
+                    "  {} · {} weeks · {} shows",
+                    offer.rig.label(),
+                    quote.weeks,
+                    quote.shows
+                ),
+                Style::new().bold(),
+            ));
+            for stop in &quote.stops {
+                lines.push(Line::from(format!(
+                    "  {:<22} {} week{}",
+                    stop.region_name,
+                    stop.weeks,
+                    if stop.weeks == 1 { "" } else { "s" }
+                )));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "  Projected  {} – {} gross",
+                format_money(quote.gross_low as i32),
+                format_money(quote.gross_high as i32)
+            )));
+            lines.push(Line::from(format!(
+                "  Costs      {} — the promoter's to carry",
+                format_money(quote.cost)
+            )));
+            lines.push(Line::from(format!("  Fame       +{}", quote.fame_gain)));
+        }
+        Err(reason) => {
+            lines.push(Line::styled(
+                format!("  You can't play this route right now: {}", reason),
+                Style::new().fg(Color::Red),
+            ));
+        }
+    }
+    lines.push(Line::from(format!("  Your deal  {}", offer.deal.label())));
+    if let Some(promoter) = app.game.world.tour_promoter(&offer.promoter)
+        && promoter.tours_promoted > 0
+    {
+        lines.push(Line::from(format!(
+            "  History    {} tour{} together",
+            promoter.tours_promoted,
+            if promoter.tours_promoted == 1 {
+                ""
+            } else {
+                "s"
+            }
+        )));
+    }
+    lines.push(Line::from(format!(
+        "  Offer expires in {} week{}",
+        weeks_left,
+        if weeks_left == 1 { "" } else { "s" }
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "  [A]ccept · [R]eject · [Esc] think it over",
+        Style::new().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
@@ -0,0 +1,95 @@
+//! Fame-by-territory overlay: each country the band can tour, with its chart
+//! placing and records sold, and under it every region's fame and when the
+//! band last played there — the picture behind a territory that isn't
+//! buying.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+/// A fame figure's color: unknown, a name, a draw, a headliner.
+fn fame_color(fame: u8) -> Color {
+    match fame {
+        0 => Color::DarkGray,
+        1..=19 => Color::Red,
+        20..=49 => Color::Yellow,
+        _ => Color::Green,
+    }
+}
+
+pub(crate) fn draw_fame_map_modal(frame: &mut Frame, app: &App) {
+    let Screen::FameMap { scroll } = app.screen else {
+        return;
+    };
+    let area = centered_rect(80, 80, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(" 🗺️ World Map ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" ↑↓ scroll · Esc close ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [header_area, list_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(3)]).areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(Line::styled(
+            format!(
+                "  {:<30}{:<8}{:<16}{:<10}{}",
+                "Country / region", "Fame", "Last played", "Chart", "Sold"
+            ),
+            Style::new().fg(Color::Cyan).bold(),
+        )),
+        header_area,
+    );
+
+    let week = app.game.week;
+    let mut items: Vec<ListItem> = Vec::new();
+    for country in app.game.fame_map() {
+        // A country with no sales territory has no chart and no shops.
+        let (chart, sold) = match country.territory {
+            Some(_) => (
+                country
+                    .chart_position
+                    .map_or("—".to_string(), |position| format!("#{}", position)),
+                country.copies_sold.to_string(),
+            ),
+            None => ("n/a".to_string(), "n/a".to_string()),
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("  {:<54}", country.name), Style::new().bold()),
+            Span::raw(format!("{:<10}", chart)),
+            Span::styled(sold, Style::new().fg(Color::Cyan)),
+        ])));
+        for region in &country.regions {
+            let last_played = match region.last_visit {
+                Some(visit) if visit >= week => "this week".to_string(),
+                Some(visit) => format!("{} wks ago", week - visit),
+                None => "never".to_string(),
+            };
+            items.push(ListItem::new(Line::from(vec![
+                Span::raw(format!("    {:<28}", region.name)),
+                Span::styled(
+                    format!("{:<8}", region.fame),
+                    Style::new().fg(fame_color(region.fame)),
+                ),
+                Span::styled(last_played, Style::new().fg(Color::DarkGray)),
+            ])));
+        }
+    }
+
+    let selected = scroll.min(items.len().saturating_sub(1));
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
@@ -0,0 +1,280 @@
+//! Finances screen: one year of the books — money in and out by category
+//! with the year's totals — the band's debts and what it could still
+//! borrow, where it's taxed and what the year's bill comes to so far, and
+//! the royalty statements the labels sent that year. The tax-exile picker
+//! lists the countries the band could move to, cheapest bill first.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::data::format_money;
+use crate::game::{ACCOUNTANT_FEE_PER_WEEK, MONEY_STEP};
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_finance_modal(frame: &mut Frame, app: &App) {
+    let Screen::Finance { year } = app.screen else {
+        return;
+    };
+    let game = &app.game;
+    let area = centered_rect(80, 80, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(format!(" 💰 Finances — {} ", year))
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(format!(
+            " ←→ year · B borrow / P repay / A label cash ${} · C accountant · X exile · Esc close ",
+            MONEY_STEP
+        ));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let books = game.year_summary(year);
+    let statements: Vec<_> = game
+        .royalty_statements
+        .iter()
+        .filter(|statement| statement.year() == year)
+        .collect();
+    let [books_area, debt_area, statements_area] = Layout::vertical([
+        Constraint::Length(books.by_category.len() as u16 + 4),
+        Constraint::Length(7),
+        Constraint::Min(3),
+    ])
+    .areas(inner);
+
+    let mut lines = vec![Line::styled(
+        format!("{:<16}{:>12}{:>12}{:>12}", "", "In", "Out", "Net"),
+        Style::new().fg(Color::DarkGray),
+    )];
+    if books.by_category.is_empty() {
+        lines.push(Line::styled(
+            "Nothing on the books this year.",
+            Style::new().fg(Color::DarkGray),
+        ));
+    }
+    for &(category, income, out) in &books.by_category {
+        lines.push(money_row(category.label(), income, out));
+    }
+    lines.push(Line::from(""));
+    lines.push(
+        money_row("Total", books.income(), books.expenses())
+            .style(Style::new().add_modifier(Modifier::BOLD)),
+    );
+    frame.render_widget(Paragraph::new(lines), books_area);
+
+    let mut lines = vec![Line::styled("Debt & tax", Style::new().fg(Color::Yellow))];
+    lines.push(match &game.loan {
+        Some(loan) => {
+            let bounced = if loan.missed_payments > 0 {
+                format!(" · {} repayment(s) bounced", loan.missed_payments)
+            } else {
+                String::new()
+            };
+            Line::from(format!(
+                "Bank loan: {} owed at {:.1}% — {} a week{}",
+                format_money(loan.balance as i32),
+                loan.yearly_rate * 100.0,
+                format_money(loan.weekly_payment as i32),
+                bounced
+            ))
+        }
+        None => Line::from("No bank loan."),
+    });
+    lines.push(match game.bankrupt_until {
+        Some(until) => Line::styled(
+            format!(
+                "Bankrupt — discharged in {} weeks. No bank will lend to you.",
+                until.saturating_sub(game.week)
+            ),
+            Style::new().fg(Color::Red),
+        ),
+        None => Line::from(format!(
+            "The bank will lend you {} more at {:.1}%.",
+            format_money(game.credit_available() as i32),
+            game.loan_rate() * 100.0
+        )),
+    });
+    if let Some(deal) = game.band.current_deal() {
+        lines.push(Line::from(format!(
+            "{} will front you {} against royalties.",
+            deal.label_name,
+            format_money(game.label_cash_advance_available() as i32)
+        )));
+    }
+    let bill = game.tax_assessment(year);
+    let regime_note = game
+        .data_files
+        .tax_data
+        .regime(&bill.country, year)
+        .map_or(String::new(), |regime| format!(" ({})", regime.note));
+    lines.push(Line::from(format!(
+        "Taxed at {} rates{}: {} on {} of profit{}.",
+        bill.country_name(),
+        regime_note,
+        format_money(bill.tax as i32),
+        format_money(bill.profit as i32),
+        if year == game.timeline.get_current_year() {
+            " so far"
+        } else {
+            ""
+        }
+    )));
+    lines.push(match game.tax_exile() {
+        Some(exile) => Line::styled(
+            format!(
+                "In tax exile in {} until week {} — no shows at home.",
+                exile.country_name(),
+                exile.until_week
+            ),
+            Style::new().fg(Color::Cyan),
+        ),
+        None if game.accountant => Line::from(format!(
+            "Accountant on retainer at {} a week{}.",
+            format_money(ACCOUNTANT_FEE_PER_WEEK as i32),
+            if bill.deductions > 0 {
+                format!(
+                    " — {} in deductions found",
+                    format_money(bill.deductions as i32)
+                )
+            } else {
+                String::new()
+            }
+        )),
+        None => Line::styled("No accountant.", Style::new().fg(Color::DarkGray)),
+    });
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), debt_area);
+
+    let mut lines = vec![Line::styled(
+        "Royalty statements",
+        Style::new().fg(Color::Yellow),
+    )];
+    if statements.is_empty() {
+        lines.push(Line::styled(
+            "No label has accounted to you this year.",
+            Style::new().fg(Color::DarkGray),
+        ));
+    }
+    for statement in statements {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} {:<22.22}", statement.period(), statement.label),
+                Style::new().bold(),
+            ),
+            Span::raw(format!(
+                " {:>7} copies · gross {} · {:.0}% = {} · recouped {} · net {}",
+                statement.copies,
+                format_money(statement.gross as i32),
+                statement.rate() * 100.0,
+                format_money(statement.royalties as i32),
+                format_money(statement.recouped as i32),
+                format_money(statement.net() as i32)
+            )),
+        ]));
+    }
+    frame.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: false }),
+        statements_area,
+    );
+}
+
+pub(crate) fn draw_tax_exile_modal(frame: &mut Frame, app: &App) {
+    let Screen::TaxExile { selected } = app.screen else {
+        return;
+    };
+    let game = &app.game;
+    let area = centered_rect(86, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(" ✈️ Tax Exile — a year abroad ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" Enter move · Esc back ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [header_area, list_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(inner);
+
+    let home = game.tax_assessment(game.timeline.get_current_year());
+    let mut header = vec![Line::from(format!(
+        "At home this year's bill comes to {} so far. Abroad for most of the year, \
+         you're taxed there instead — but no shows at home, and the crowd forgets.",
+        format_money(home.tax as i32)
+    ))];
+    if !game.accountant {
+        header.push(Line::styled(
+            "You'll need an accountant first (C on the finances screen).",
+            Style::new().fg(Color::Red),
+        ));
+    } else if let Some(exile) = game.tax_exile() {
+        header.push(Line::styled(
+            format!("Already living in {}.", exile.country_name()),
+            Style::new().fg(Color::Red),
+        ));
+    }
+    frame.render_widget(
+        Paragraph::new(header).wrap(Wrap { trim: true }),
+        header_area,
+    );
+
+    let items: Vec<ListItem> = game
+        .tax_havens()
+        .into_iter()
+        .map(|haven| {
+            let affordable = game.player.can_afford(haven.regime.relocation_cost as i32);
+            let style = if affordable {
+                Style::new().fg(Color::White)
+            } else {
+                Style::new().fg(Color::DarkGray)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<16}", haven.name), style.bold()),
+                Span::styled(
+                    format!("  Top rate {:>3.0}%", haven.regime.top_rate() * 100.0),
+                    style,
+                ),
+                Span::styled(
+                    format!("  Bill ~{:<9}", format_money(haven.estimate as i32)),
+                    style,
+                ),
+                Span::styled(
+                    format!(
+                        "  Move {:<9}",
+                        format_money(haven.regime.relocation_cost as i32)
+                    ),
+                    style,
+                ),
+                Span::styled(
+                    format!("  {}", haven.regime.note),
+                    Style::new().fg(Color::Yellow),
+                ),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
+
+/// One line of the year's books: money in, money out, and the difference.
+fn money_row(label: &str, income: u32, out: u32) -> Line<'static> {
+    let net = income as i32 - out as i32;
+    Line::from(vec![
+        Span::raw(format!("{:<16}", label)),
+        Span::styled(
+            format!("{:>12}", format_money(income as i32)),
+            Style::new().fg(Color::Green),
+        ),
+        Span::styled(
+            format!("{:>12}", format_money(-(out as i32))),
+            Style::new().fg(Color::Red),
+        ),
+        Span::raw(format!("{:>12}", format_money(net))),
+    ])
+}
@@ -0,0 +1,114 @@
+//! Gear & crew screen: the shop with what the band owns and its condition,
+//! the crew roles with who's on the payroll, and the insurance switch.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::data::format_money;
+use crate::ui::app::{App, GearRow, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_gear_modal(frame: &mut Frame, app: &App) {
+    let Screen::Gear { selected } = app.screen else {
+        return;
+    };
+    let game = &app.game;
+    let area = centered_rect(80, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(" 🎸 Gear & Crew ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" B buy/hire · S sell/fire · V service all · Esc close ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [list_area, summary_area] =
+        Layout::vertical([Constraint::Min(4), Constraint::Length(2)]).areas(inner);
+
+    let items: Vec<ListItem> = (0..GearRow::COUNT)
+        .map(|index| match GearRow::at(index) {
+            GearRow::Gear(kind) => {
+                let owned = game.gear.iter().find(|item| item.kind == kind);
+                let status = match owned {
+                    Some(item) => Span::styled(
+                        format!("owned · {:>3.0}%", item.condition),
+                        Style::new().fg(condition_color(item.condition)),
+                    ),
+                    None => Span::styled("—", Style::new().fg(Color::DarkGray)),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<22}", kind.label()), Style::new().bold()),
+                    Span::raw(format!("{:>9}  ", format_money(kind.price() as i32))),
+                    status,
+                ]))
+            }
+            GearRow::Crew(role) => {
+                let hired = game.crew_count(role);
+                let gate = if game.band.fame < role.fame_gate() {
+                    Span::styled(
+                        format!("🔒 needs fame {}", role.fame_gate()),
+                        Style::new().fg(Color::DarkGray),
+                    )
+                } else {
+                    Span::styled(
+                        format!("{}/{} hired", hired, role.max_hired()),
+                        Style::new().fg(if hired > 0 {
+                            Color::Green
+                        } else {
+                            Color::White
+                        }),
+                    )
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<22}", role.label()), Style::new().bold()),
+                    Span::raw(format!("{:>6}/wk  ", format_money(role.wage() as i32))),
+                    gate,
+                ]))
+            }
+            GearRow::Insurance => ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<22}", "Gear insurance"), Style::new().bold()),
+                if game.gear_insured {
+                    Span::styled(
+                        format!(
+                            "insured · {}/wk",
+                            format_money(game.gear_insurance_premium() as i32)
+                        ),
+                        Style::new().fg(Color::Green),
+                    )
+                } else {
+                    Span::styled("uninsured", Style::new().fg(Color::DarkGray))
+                },
+            ])),
+        })
+        .collect();
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let summary = Line::styled(
+        format!(
+            "Kit worth {} · service {} · crew {}/wk",
+            format_money(game.gear_value() as i32),
+            format_money(game.gear_service_cost() as i32),
+            format_money(game.crew_wages() as i32)
+        ),
+        Style::new().fg(Color::Cyan),
+    );
+    frame.render_widget(Paragraph::new(vec![Line::from(""), summary]), summary_area);
+}
+
+fn condition_color(condition: f32) -> Color {
+    if condition >= 75.0 {
+        Color::Green
+    } else if condition >= 50.0 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
@@ -0,0 +1,74 @@
+//! Decision-incident modal: the incident's message and its choices, each
+//! with its cost. Choices the band can't take right now (conditions unmet
+//! or too expensive) are greyed out, and the default — what happens if you
+//! just play on — is marked.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::data::format_money;
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_incident_choice_modal(frame: &mut Frame, app: &App) {
+    let Screen::IncidentChoice { selected } = app.screen else {
+        return;
+    };
+    let Some(incident) = &app.game.pending_incident else {
+        return;
+    };
+    let area = centered_rect(72, 50, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::bordered()
+        .title(" ❓ Your Call ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" ↑↓ choose · Enter decide ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [message_area, list_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(inner);
+    frame.render_widget(
+        Paragraph::new(Line::from(format!(" {}", incident.message))).wrap(Wrap { trim: false }),
+        message_area,
+    );
+
+    let items: Vec<ListItem> = incident
+        .choices
+        .iter()
+        .map(|choice| {
+            let available = app.game.incident_choice_available(choice);
+            let style = if available {
+                Style::new().fg(Color::White)
+            } else {
+                Style::new().fg(Color::DarkGray)
+            };
+            let mut spans = vec![Span::styled(format!("{:<36}", choice.label), style.bold())];
+            if choice.cost > 0 {
+                spans.push(Span::styled(
+                    format!("{:>8}", format_money(choice.cost as i32)),
+                    style,
+                ));
+            }
+            if choice.default {
+                spans.push(Span::styled(
+                    "  (if you play on)",
+                    Style::new().fg(Color::DarkGray),
+                ));
+            }
+            if !available {
+                spans.push(Span::styled("  🔒", Style::new().fg(Color::Red)));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
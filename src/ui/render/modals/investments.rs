@@ -0,0 +1,96 @@
+//! Investments screen: each investment with its price, risk and liquidity
+//! and what the band holds of it, what the highlighted one does, and the
+//! portfolio's worth and running costs.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::data::format_money;
+use crate::game::InvestmentKind;
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_investments_modal(frame: &mut Frame, app: &App) {
+    let Screen::Investments { selected } = app.screen else {
+        return;
+    };
+    let game = &app.game;
+    let area = centered_rect(84, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(" 💼 Investments ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" B buy in · S sell up · Esc close ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [list_area, detail_area] =
+        Layout::vertical([Constraint::Min(6), Constraint::Length(4)]).areas(inner);
+
+    let items: Vec<ListItem> = InvestmentKind::ALL
+        .iter()
+        .map(|&kind| {
+            let price = if kind.is_market() {
+                format!("{}/stake", format_money(kind.price() as i32))
+            } else {
+                format_money(kind.price() as i32)
+            };
+            let holding = match game.investment(kind) {
+                Some(investment) => {
+                    let gain = investment.value as i32 - investment.paid as i32;
+                    Span::styled(
+                        format!(
+                            "worth {} ({}{})",
+                            format_money(investment.value as i32),
+                            if gain >= 0 { "+" } else { "" },
+                            format_money(gain)
+                        ),
+                        Style::new().fg(if gain >= 0 { Color::Green } else { Color::Red }),
+                    )
+                }
+                None => Span::styled("—", Style::new().fg(Color::DarkGray)),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<22}", kind.label()), Style::new().bold()),
+                Span::raw(format!("{:>15}  ", price)),
+                Span::raw(format!("risk {:<9} ", kind.risk_label())),
+                Span::raw(format!("sells for {:>3.0}%  ", kind.liquidity() * 100.0)),
+                holding,
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let kind = InvestmentKind::ALL[selected];
+    let paid: u32 = game
+        .investments
+        .iter()
+        .map(|investment| investment.paid)
+        .sum();
+    let detail = vec![
+        Line::from(kind.blurb()),
+        Line::from(""),
+        Line::styled(
+            format!(
+                "Portfolio worth {} on {} put in · running costs {}/wk · economy {}",
+                format_money(game.investments_value() as i32),
+                format_money(paid as i32),
+                format_money(game.investments_upkeep() as i32),
+                game.world.music_market.economic_state
+            ),
+            Style::new().fg(Color::Cyan),
+        ),
+    ];
+    frame.render_widget(
+        Paragraph::new(detail).wrap(Wrap { trim: true }),
+        detail_area,
+    );
+}
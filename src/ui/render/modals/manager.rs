@@ -0,0 +1,92 @@
+//! Manager screen (FUTURE §9.4): the current manager's terms and what
+//! they've taken so far, or the slate of managers pitching. Honesty is
+//! never shown — that's the gamble.
+
+use ratatui::{
+    Frame,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::data::format_money;
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_manager_modal(frame: &mut Frame, app: &App) {
+    let Screen::Manager { selected } = app.screen else {
+        return;
+    };
+    let area = centered_rect(70, 50, frame.area());
+    frame.render_widget(Clear, area);
+
+    if let Some(manager) = &app.game.manager {
+        let block = Block::bordered()
+            .title(format!(" 💼 {} ", manager.name))
+            .title_style(Style::new().fg(Color::Yellow).bold());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let weeks = app.game.week.saturating_sub(manager.hired_week);
+        let lines = vec![
+            Line::from(""),
+            Line::from(format!("  Commission       {:.0}%", manager.cut * 100.0)),
+            Line::from(format!("  Skill            {}/100", manager.skill)),
+            Line::from(format!(
+                "  With you         {} week{}",
+                weeks,
+                if weeks == 1 { "" } else { "s" }
+            )),
+            Line::from(format!(
+                "  Paid so far      {}",
+                format_money(manager.commission_paid as i32)
+            )),
+            Line::from(format!(
+                "  Settlement owed  {}",
+                format_money(app.game.manager_severance() as i32)
+            )),
+            Line::from(""),
+            Line::styled("  [F]ire · [Esc] back", Style::new().fg(Color::DarkGray)),
+        ];
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .game
+        .pending_manager_offers
+        .iter()
+        .map(|offer| {
+            let mut spans = vec![
+                Span::styled(format!("{:<20}", offer.name), Style::new().bold()),
+                Span::styled(
+                    format!("skill {:<4}", offer.skill),
+                    Style::new().fg(Color::Cyan),
+                ),
+                Span::raw(format!("{:.0}% of everything", offer.cut * 100.0)),
+            ];
+            if let Some(deadline) = offer.expires_week {
+                let weeks_left = deadline.saturating_sub(app.game.week);
+                spans.push(Span::styled(
+                    format!(
+                        "  · {} wk{} left",
+                        weeks_left,
+                        if weeks_left == 1 { "" } else { "s" }
+                    ),
+                    Style::new().fg(Color::DarkGray),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .title(" 💼 Managers Pitching ")
+                .title_bottom(" A hire · R send off · Esc close "),
+        )
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
@@ -85,9 +85,10 @@ pub(crate) fn draw_marketing_modal(frame: &mut Frame, app: &App) {
                 .iter()
                 .map(|c| {
                     let spec = c.spec();
+                    let cost = app.game.marketing_cost(*c);
                     ListItem::new(Line::from(vec![
                         Span::styled(format!("{:<18}", spec.name), Style::new().bold()),
-                        Span::styled(format!("${:<6}", spec.cost), Style::new().fg(Color::Green)),
+                        Span::styled(format!("${:<6}", cost), Style::new().fg(Color::Green)),
                         Span::raw(format!(
                             "{} weeks · +{} buzz",
                             spec.duration_weeks, spec.effectiveness_bonus
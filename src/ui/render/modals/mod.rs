@@ -1,20 +1,42 @@
 //! Overlay modals and pickers drawn on top of the main layout.
 
+mod catalogue;
 mod charts;
 mod deals;
+mod fame_map;
 mod file;
+mod finance;
+mod gear;
+mod incident;
+mod investments;
 mod lifestyle;
+mod manager;
 mod marketing;
+mod own_label;
 mod pickers;
 mod tour;
+mod vacation;
 
+pub(super) use catalogue::draw_catalogue_modal;
 pub(super) use charts::draw_charts_modal;
-pub(super) use deals::{draw_deals_modal, draw_support_modal};
+pub(super) use deals::{
+    draw_deal_counter_modal, draw_deals_modal, draw_festival_modal, draw_headline_modal,
+    draw_label_demand_modal, draw_support_modal, draw_takeover_modal,
+};
+pub(super) use fame_map::draw_fame_map_modal;
 pub(super) use file::draw_file_modal;
+pub(super) use finance::{draw_finance_modal, draw_tax_exile_modal};
+pub(super) use gear::draw_gear_modal;
+pub(super) use incident::draw_incident_choice_modal;
+pub(super) use investments::draw_investments_modal;
 pub(super) use lifestyle::draw_lifestyle_picker_modal;
+pub(super) use manager::draw_manager_modal;
 pub(super) use marketing::draw_marketing_modal;
+pub(super) use own_label::draw_own_label_modal;
 pub(super) use pickers::{
-    draw_pressing_picker_modal, draw_region_picker_modal, draw_repress_picker_modal,
-    draw_repress_tier_picker_modal, draw_tour_booking_picker_modal, draw_venue_picker_modal,
+    draw_live_release_modal, draw_pressing_picker_modal, draw_region_picker_modal,
+    draw_repress_picker_modal, draw_repress_tier_picker_modal, draw_setlist_picker_modal,
+    draw_tour_booking_picker_modal, draw_venue_picker_modal,
 };
 pub(super) use tour::draw_tour_report_modal;
+pub(super) use vacation::draw_vacation_picker_modal;
@@ -0,0 +1,142 @@
+//! Own-label screen: before it's founded, what founding takes; after, the
+//! label's acts with what each has earned it, the scene bands it could
+//! sign with their advance, and its roster and running costs.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::data::format_money;
+use crate::game::{Game, OWN_LABEL_FOUNDING_COST, OWN_LABEL_MAX_ACTS};
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_own_label_modal(frame: &mut Frame, app: &App) {
+    let Screen::OwnLabel { selected } = app.screen else {
+        return;
+    };
+    let game = &app.game;
+    let area = centered_rect(84, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let Some(label) = &game.own_label else {
+        let block = Block::bordered()
+            .title(" 🏷️ Your Label ")
+            .title_style(Style::new().fg(Color::Yellow).bold())
+            .title_bottom(" F found it · Esc close ");
+        let status = match game.label_founding_lock() {
+            Some(lock) => Line::styled(lock, Style::new().fg(Color::Red)),
+            None => Line::styled(
+                format!(
+                    "Founding {} Records costs {}.",
+                    game.band.name,
+                    format_money(OWN_LABEL_FOUNDING_COST as i32)
+                ),
+                Style::new().fg(Color::Green),
+            ),
+        };
+        let text = vec![
+            Line::from(
+                "A label of your own puts your records out with a real label's reach while \
+                 you keep the masters, and lets you sign the scene's unsigned bands: the label \
+                 takes its cut of every record they put out.",
+            ),
+            Line::from(""),
+            status,
+        ];
+        frame.render_widget(
+            Paragraph::new(text).wrap(Wrap { trim: true }).block(block),
+            area,
+        );
+        return;
+    };
+
+    let block = Block::bordered()
+        .title(format!(" 🏷️ {} ", label.name))
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(" Enter sign / let go · Esc close ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [list_area, detail_area] =
+        Layout::vertical([Constraint::Min(6), Constraint::Length(3)]).areas(inner);
+
+    let rows = game.label_rows();
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|(name, signed)| row_item(game, name, *signed))
+        .collect();
+    if items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No acts yet, and nobody on the scene you could sign."),
+            list_area,
+        );
+    } else {
+        let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(selected.min(rows.len() - 1)));
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+
+    let earned: u32 = label.acts.iter().map(|act| act.earned).sum();
+    let detail = vec![Line::styled(
+        format!(
+            "{}/{} acts · earned {} since week {} · running costs {}/wk",
+            label.acts.len(),
+            OWN_LABEL_MAX_ACTS,
+            format_money(earned as i32),
+            label.founded_week,
+            format_money(game.own_label_overhead() as i32)
+        ),
+        Style::new().fg(Color::Cyan),
+    )];
+    frame.render_widget(
+        Paragraph::new(detail).wrap(Wrap { trim: true }),
+        detail_area,
+    );
+}
+
+/// One row: an act with its record on the label, or a prospect with what
+/// signing it takes.
+fn row_item<'a>(game: &Game, name: &str, signed: bool) -> ListItem<'a> {
+    let Some(band) = game.world.scene_band(name) else {
+        return ListItem::new(name.to_string());
+    };
+    let status = if signed {
+        let act = game
+            .own_label
+            .as_ref()
+            .and_then(|label| label.acts.iter().find(|act| act.band == name));
+        let (releases, hits, earned) =
+            act.map_or((0, 0, 0), |act| (act.releases, act.hits, act.earned));
+        Span::styled(
+            format!(
+                "on the label · {} records, {} hits · earned {}",
+                releases,
+                hits,
+                format_money(earned as i32)
+            ),
+            Style::new().fg(Color::Green),
+        )
+    } else {
+        match game.signing_lock(band) {
+            Some(lock) => Span::styled(lock, Style::new().fg(Color::DarkGray)),
+            None => Span::raw(format!(
+                "advance {}",
+                format_money(Game::signing_advance(band) as i32)
+            )),
+        }
+    };
+    ListItem::new(Line::from(vec![
+        Span::styled(format!("{:<24}", band.name), Style::new().bold()),
+        Span::raw(format!(
+            "fame {:>3}  {:<12} ",
+            band.fame,
+            band.genre.to_string()
+        )),
+        status,
+    ]))
+}
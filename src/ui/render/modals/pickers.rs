@@ -10,9 +10,9 @@ use ratatui::{
 };
 
 use crate::data::format_money;
-use crate::game::PRESSING_TIERS;
-use crate::game::TourRig;
-use crate::game::music::{DistributionChannel, ReleaseType};
+use crate::game::music::{DistributionChannel, LiveFormat, ReleaseType};
+use crate::game::{PRESSING_TIERS, SETLIST_MAX_SONGS, SETLIST_MIN_SONGS, SetlistSong};
+use crate::game::{RouteQuote, TicketTier, TourLeg, TourRig, TourTerms};
 use crate::ui::app::{App, Screen};
 
 use super::super::{centered_rect, format_population};
@@ -20,24 +20,44 @@ pub(crate) fn draw_venue_picker_modal(frame: &mut Frame, app: &App) {
     let Screen::VenuePicker { selected } = app.screen else {
         return;
     };
-    let area = centered_rect(74, 50, frame.area());
+    let area = centered_rect(90, 60, frame.area());
     frame.render_widget(Clear, area);
 
+    let week = app.game.week;
+    let venues = &app.game.world.venues;
     let items: Vec<ListItem> = app
         .game
         .world
-        .venues
-        .iter()
-        .map(|venue| {
-            let locked = venue.prestige > app.game.band.fame.saturating_add(20);
-
-            let status = if locked {
+        .venue_ladder()
+        .into_iter()
+        .map(|index| {
+            let venue = &venues[index];
+            let locked = !app.game.venue_in_reach(venue);
+            let banned = venue.is_banned(week);
+
+            let status = if banned {
+                Span::styled(
+                    format!(" 🚫 BANNED until wk {}", venue.banned_until),
+                    Style::new().fg(Color::Red),
+                )
+            } else if locked {
                 Span::styled(" 🔒 LOCKED", Style::new().fg(Color::DarkGray))
+            } else if venue.has_invite(week) {
+                Span::styled(" 📅 INVITED BACK", Style::new().fg(Color::Yellow))
             } else {
                 Span::styled(" 🔓 UNLOCKED", Style::new().fg(Color::Green))
             };
+            let history = match venue.avg_reception() {
+                Some(avg) => format!(
+                    "{} show{}, avg {}",
+                    venue.shows_played,
+                    if venue.shows_played == 1 { "" } else { "s" },
+                    avg
+                ),
+                None => "never played".to_string(),
+            };
 
-            let style = if locked {
+            let style = if locked || banned {
                 Style::new().fg(Color::DarkGray)
             } else {
                 Style::new().fg(Color::White)
@@ -51,7 +71,8 @@ pub(crate) fn draw_venue_picker_modal(frame: &mut Frame, app: &App) {
                     format!("  Base Pay: {:<6}", format_money(venue.base_payment as i32)),
                     style,
                 ),
-                Span::raw("  "),
+                Span::styled(format!("  Promoter: {:<3}", venue.promoter), style),
+                Span::styled(format!("  {:<18}", history), style),
                 status,
             ]))
         })
@@ -73,11 +94,81 @@ pub(crate) fn draw_venue_picker_modal(frame: &mut Frame, app: &App) {
 /// rig-on-↑↓/length-on-←→ split (`draw_tour_booking_picker_modal`). The
 /// channel row is only shown while unsigned; the label decides both pressing
 /// and reach for a signed act, and never reaches this screen.
+/// The live-record screen: what's on the tapes, the best shows that make
+/// the cut, and the quality and mixing bill for each format.
+pub(crate) fn draw_live_release_modal(frame: &mut Frame, app: &App) {
+    let Screen::LiveRelease { format } = app.screen else {
+        return;
+    };
+    let area = centered_rect(70, 56, frame.area());
+    frame.render_widget(Clear, area);
+    let video = app.game.timeline.is_video_era();
+    let block = Block::bordered()
+        .title(" 🎚️ Live Record ")
+        .title_style(Style::new().fg(Color::Yellow).bold())
+        .title_bottom(if video {
+            " F album/film · Enter release · Esc close "
+        } else {
+            " Enter release · Esc close "
+        });
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut lines = Vec::new();
+    let formats: &[LiveFormat] = if video {
+        &[LiveFormat::Album, LiveFormat::ConcertFilm]
+    } else {
+        &[LiveFormat::Album]
+    };
+    let format_spans: Vec<Span> = formats
+        .iter()
+        .map(|&f| {
+            let style = if f == format {
+                Style::new().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::new().fg(Color::White)
+            };
+            Span::styled(format!(" {} ", f.label()), style)
+        })
+        .collect();
+    lines.push(Line::from(format_spans));
+    lines.push(Line::from(""));
+
+    if let Some(tapes) = &app.game.live_tapes {
+        let mut best: Vec<_> = tapes.rows.iter().collect();
+        best.sort_by_key(|row| std::cmp::Reverse(row.reception));
+        lines.push(Line::from(format!(
+            "{} shows on tape — the best of them:",
+            tapes.rows.len()
+        )));
+        for row in best.iter().take(3) {
+            lines.push(Line::styled(
+                format!("  {:<28} reception {}", row.venue_name, row.reception),
+                Style::new().fg(Color::Cyan),
+            ));
+        }
+        lines.push(Line::from(""));
+    }
+    match app.game.live_release_quality(format) {
+        Ok(quality) => {
+            lines.push(Line::from(format!("Quality       {}", quality)));
+            lines.push(Line::from(format!(
+                "Mixing        {}",
+                format_money(app.game.live_release_cost(format))
+            )));
+        }
+        Err(msg) => lines.push(Line::styled(msg, Style::new().fg(Color::Red))),
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
 pub(crate) fn draw_pressing_picker_modal(frame: &mut Frame, app: &App) {
     let Screen::PressingPicker {
         release_type,
         selected,
         channel,
+        live,
+        rerecord,
     } = app.screen
     else {
         return;
@@ -85,9 +176,11 @@ pub(crate) fn draw_pressing_picker_modal(frame: &mut Frame, app: &App) {
     let signed = app.game.band.current_deal().is_some();
     let fee = if signed { 0 } else { channel.fee() };
 
-    let kind = match release_type {
-        ReleaseType::Single => "Single",
-        ReleaseType::Album => "Album",
+    let kind = match (live, rerecord, release_type) {
+        (Some(format), _, _) => format.label(),
+        (None, Some(_), _) => "Re-recording",
+        (None, None, ReleaseType::Single) => "Single",
+        (None, None, ReleaseType::Album) => "Album",
     };
     let area = centered_rect(78, 56, frame.area());
     frame.render_widget(Clear, area);
@@ -100,7 +193,10 @@ pub(crate) fn draw_pressing_picker_modal(frame: &mut Frame, app: &App) {
     let [tiers_area, channel_area] =
         Layout::vertical([Constraint::Min(6), Constraint::Length(6)]).areas(inner);
 
-    let recording = app.game.recording_cost(&release_type);
+    let recording = match live {
+        Some(format) => app.game.live_release_cost(format),
+        None => app.game.recording_cost(&release_type),
+    };
     let items: Vec<ListItem> = PRESSING_TIERS
         .iter()
         .map(|(name, copies)| {
@@ -140,11 +236,10 @@ pub(crate) fn draw_pressing_picker_modal(frame: &mut Frame, app: &App) {
         return;
     }
 
-    let fame = app.game.band.fame;
     let channel_lines: Vec<Line> = DistributionChannel::ALL
         .iter()
         .map(|&c| {
-            let locked = !c.is_available(fame);
+            let locked = app.game.distribution_lock(c).is_some();
             let is_selected = c == channel;
             let mut style = if locked {
                 Style::new().fg(Color::DarkGray)
@@ -154,7 +249,9 @@ pub(crate) fn draw_pressing_picker_modal(frame: &mut Frame, app: &App) {
             if is_selected {
                 style = style.add_modifier(Modifier::REVERSED);
             }
-            let gate = if locked {
+            let gate = if c == DistributionChannel::OwnLabel && locked {
+                " 🔒 found it first".to_string()
+            } else if locked {
                 format!(" 🔒 needs fame {}", c.fame_gate())
             } else {
                 String::new()
@@ -282,13 +379,26 @@ pub(crate) fn draw_region_picker_modal(frame: &mut Frame, app: &App) {
     let sorted_regions = app.game.get_sorted_regions();
     let items: Vec<ListItem> = sorted_regions
         .iter()
+        .enumerate()
         .map(
-            |(country_key, region_key, region_name, population, economic_strength, fame_req)| {
+            |(
+                index,
+                (country_key, region_key, region_name, population, economic_strength, fame_req),
+            )| {
                 let locked = app.game.band.fame < *fame_req;
+                let stop = app
+                    .tour_route
+                    .iter()
+                    .position(|leg| leg.region_index == index);
                 let regional_fame_key = format!("{}:{}", country_key, region_key);
                 let regional_fame = *app.game.regional_fame.get(&regional_fame_key).unwrap_or(&0);
 
-                let status = if locked {
+                let status = if let Some(stop) = stop {
+                    Span::styled(
+                        format!(" 📍 Stop {} of the route", stop + 1),
+                        Style::new().fg(Color::Yellow),
+                    )
+                } else if locked {
                     Span::styled(
                         format!(" 🔒 Req Fame: {}", fame_req),
                         Style::new().fg(Color::DarkGray),
@@ -328,12 +438,24 @@ pub(crate) fn draw_region_picker_modal(frame: &mut Frame, app: &App) {
         )
         .collect();
 
+    let block = if app.tour_route.is_empty() {
+        Block::bordered()
+            .title(" 🚌 Select Region to Tour ")
+            .title_bottom(" Enter choose rig & length · Esc close ")
+    } else {
+        let route = app
+            .tour_route
+            .iter()
+            .filter_map(|leg| sorted_regions.get(leg.region_index))
+            .map(|(_, _, name, _, _, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(" → ");
+        Block::bordered()
+            .title(format!(" 🚌 Next stop after {route} "))
+            .title_bottom(" Enter choose length · Backspace drop last stop · Esc cancel tour ")
+    };
     let list = List::new(items)
-        .block(
-            Block::bordered()
-                .title(" 🚌 Select Region to Tour ")
-                .title_bottom(" Enter choose rig & length · Esc close "),
-        )
+        .block(block)
         .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
     let mut state = ListState::default().with_selected(Some(selected));
     frame.render_stateful_widget(list, area, &mut state);
@@ -360,18 +482,24 @@ pub(crate) fn draw_tour_booking_picker_modal(frame: &mut Frame, app: &App) {
         .map(|(_, _, name, _, _, _)| name.clone())
         .unwrap_or_else(|| "Unknown region".to_string());
 
-    let area = centered_rect(84, 66, frame.area());
+    let area = centered_rect(84, 80, frame.area());
     frame.render_widget(Clear, area);
 
-    let block = Block::bordered()
-        .title(format!(" 🎫 Book a Tour of {region_name} "))
-        .title_bottom(" ↑↓ rig · ←→ length · Enter book · Esc back ");
+    let title = if app.tour_route.is_empty() {
+        format!(" 🎫 Book a Tour of {region_name} ")
+    } else {
+        format!(" 🎫 Add {region_name} to the Tour ")
+    };
+    let block = Block::bordered().title(title).title_bottom(
+        " ↑↓ rig · ←→ length · T tickets · M merch · R tape · O opener · S setlist · A add a stop · Enter book · Esc back ",
+    );
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let [rig_area, weeks_area, quote_area] = Layout::vertical([
+    let [rig_area, weeks_area, terms_area, quote_area] = Layout::vertical([
         Constraint::Length(6),
         Constraint::Length(3),
+        Constraint::Length(4),
         Constraint::Min(6),
     ])
     .areas(inner);
@@ -394,7 +522,15 @@ pub(crate) fn draw_tour_booking_picker_modal(frame: &mut Frame, app: &App) {
             ListItem::new(Line::from(vec![
                 Span::styled(format!("{:<17}", r.label()), style.bold()),
                 Span::styled(
-                    format!(" {:>7}/wk", format_money(r.cost_per_week() as i32)),
+                    format!(
+                        " {:>7}/wk{}",
+                        format_money(app.game.rig_cost_per_week(r) as i32),
+                        if app.game.rig_cost_per_week(r) < r.cost_per_week() {
+                            " (owned)"
+                        } else {
+                            ""
+                        }
+                    ),
                     style,
                 ),
                 Span::styled(format!("  cap ×{:.1}", r.capacity_mult()), style),
@@ -438,49 +574,334 @@ pub(crate) fn draw_tour_booking_picker_modal(frame: &mut Frame, app: &App) {
         weeks_area,
     );
 
-    let quote_lines: Vec<Line> = match app.game.quote_tour(region_index, rig, weeks) {
-        Ok(quote) => {
-            let affordable = app.game.player.can_afford(quote.cost);
-            vec![
-                Line::styled("Quote", Style::new().bold()),
-                Line::from(format!(
-                    "  {} of {}, {} week{}: {} shows",
-                    quote.rig.label(),
-                    quote.region_name,
-                    quote.weeks,
-                    if quote.weeks == 1 { "" } else { "s" },
-                    quote.shows
-                )),
-                Line::from(format!("  Cost: {}", format_money(quote.cost))),
-                Line::from(format!(
-                    "  Projected gross: {} – {}",
-                    format_money(quote.gross_low as i32),
-                    format_money(quote.gross_high as i32)
-                )),
-                Line::from(format!(
-                    "  Fame gain: +{}   Regional fame gain: +{}–{}",
-                    quote.fame_gain, quote.regional_fame_gain_min, quote.regional_fame_gain_max
-                )),
-                if affordable {
-                    Line::styled("  Ready to book.", Style::new().fg(Color::Green))
+    let terms = &app.tour_terms;
+    let mut ticket_spans: Vec<Span> = vec![Span::raw("Tickets ")];
+    ticket_spans.extend(TicketTier::ALL.iter().map(|&tier| {
+        let style = if tier == terms.tickets {
+            Style::new().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::new().fg(Color::White)
+        };
+        Span::styled(
+            format!(" {} (×{:.2}) ", tier.label(), tier.price_mult()),
+            style,
+        )
+    }));
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(ticket_spans),
+            Line::from(format!(
+                "Merch order {} · {} already in stock · {}",
+                terms.merch_order,
+                app.game.merch_stock,
+                if terms.recorded {
+                    "taping every show"
                 } else {
-                    Line::styled(
-                        format!(
-                            "  Not enough cash — you have {}.",
-                            format_money(app.game.player.money)
-                        ),
-                        Style::new().fg(Color::Red),
-                    )
+                    "not taping"
+                }
+            )),
+            Line::from(match terms.opener.as_deref() {
+                Some(name) => match app.game.world.scene_band(name) {
+                    Some(opener) => format!(
+                        "Opener {} — fame {}, heat {}, asks {:.0}% of the gross",
+                        opener.name,
+                        opener.fame,
+                        opener.heat(),
+                        opener.opener_fee_share() * 100.0
+                    ),
+                    None => format!("Opener {name}"),
                 },
-            ]
+                None if app.game.opener_candidates().is_empty() => String::new(),
+                None => "No opener".to_string(),
+            }),
+            Line::from(setlist_summary(app)),
+        ]),
+        terms_area,
+    );
+
+    let mut legs = app.tour_route.clone();
+    legs.push(TourLeg {
+        region_index,
+        weeks,
+    });
+    let quote_lines: Vec<Line> = if legs.len() > 1 {
+        route_quote_lines(app, &legs, rig, terms.clone())
+    } else {
+        match app.game.quote_route(&legs, rig, terms.clone()) {
+            Ok(route) => {
+                let affordable = app.game.player.can_afford(route.cost);
+                let quote = &route.stops[0];
+                vec![
+                    Line::styled("Quote", Style::new().bold()),
+                    Line::from(format!(
+                        "  {} of {}, {} week{}: {} shows",
+                        quote.rig.label(),
+                        quote.region_name,
+                        quote.weeks,
+                        if quote.weeks == 1 { "" } else { "s" },
+                        quote.shows
+                    )),
+                    Line::from(cost_line(&route)),
+                    Line::from(format!(
+                        "  Projected gross: {} – {}",
+                        format_money(quote.gross_low as i32),
+                        format_money(quote.gross_high as i32)
+                    )),
+                    Line::from(format!(
+                        "  Fame gain: +{}   Regional fame gain: +{}–{}",
+                        quote.fame_gain, quote.regional_fame_gain_min, quote.regional_fame_gain_max
+                    )),
+                    if affordable {
+                        Line::styled("  Ready to book.", Style::new().fg(Color::Green))
+                    } else {
+                        Line::styled(
+                            format!(
+                                "  Not enough cash — you have {}.",
+                                format_money(app.game.player.money)
+                            ),
+                            Style::new().fg(Color::Red),
+                        )
+                    },
+                ]
+            }
+            Err(msg) => vec![
+                Line::styled("Quote", Style::new().bold()),
+                Line::styled(format!("  {msg}"), Style::new().fg(Color::Red)),
+            ],
         }
-        Err(msg) => vec![
-            Line::styled("Quote", Style::new().bold()),
-            Line::styled(format!("  {msg}"), Style::new().fg(Color::Red)),
-        ],
     };
     frame.render_widget(
         Paragraph::new(quote_lines).wrap(Wrap { trim: false }),
         quote_area,
     );
 }
+
+/// The setlist in a line: its length and what it does to every show.
+fn setlist_summary(app: &App) -> String {
+    let setlist = &app.tour_terms.setlist;
+    if setlist.is_empty() {
+        return "Setlist: the usual set".to_string();
+    }
+    format!(
+        "Setlist: {} song{}{} · reception {:+.1} a show",
+        setlist.len(),
+        if setlist.len() == 1 { "" } else { "s" },
+        if setlist.len() < SETLIST_MIN_SONGS {
+            " (too short)"
+        } else {
+            ""
+        },
+        app.game.setlist_reception_shift(setlist)
+    )
+}
+
+/// The setlist picker: the whole catalogue, each song marked with its place
+/// in the set, released records with what the crowd knows of them and
+/// unreleased songs with their songwriting.
+pub(crate) fn draw_setlist_picker_modal(frame: &mut Frame, app: &App) {
+    let Screen::SetlistPicker { selected, .. } = app.screen else {
+        return;
+    };
+    let area = centered_rect(70, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::bordered()
+        .title(" 🎼 Setlist ")
+        .title_bottom(" ↑↓ move · Space add/remove · C usual set · Enter/Esc back ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [summary_area, list_area] =
+        Layout::vertical([Constraint::Length(2), Constraint::Min(3)]).areas(inner);
+    frame.render_widget(
+        Paragraph::new(format!(
+            "{} of up to {} songs — a full night is {}+",
+            app.tour_terms.setlist.len(),
+            SETLIST_MAX_SONGS,
+            SETLIST_MIN_SONGS
+        ))
+        .wrap(Wrap { trim: false }),
+        summary_area,
+    );
+
+    let band = &app.game.band;
+    let items: Vec<ListItem> = app
+        .game
+        .setlist_catalogue()
+        .into_iter()
+        .map(|(song, name)| {
+            let place = match app
+                .tour_terms
+                .setlist
+                .iter()
+                .position(|&played| played == song)
+            {
+                Some(position) => format!("{:>2}.", position + 1),
+                None => "   ".to_string(),
+            };
+            let detail = match song {
+                SetlistSong::Release(id) => band
+                    .singles_released
+                    .iter()
+                    .chain(&band.albums_released)
+                    .find(|release| release.id == id)
+                    .map(|release| match release.peak_chart_position {
+                        Some(peak) => format!("peaked #{peak}, {} sold", release.copies_sold),
+                        None => format!("never charted, {} sold", release.copies_sold),
+                    })
+                    .unwrap_or_default(),
+                SetlistSong::Unreleased(id) => band
+                    .unreleased_songs
+                    .iter()
+                    .find(|unreleased| unreleased.id == id)
+                    .map(|unreleased| {
+                        format!("unreleased, songwriting {}", unreleased.songwriting_quality)
+                    })
+                    .unwrap_or_default(),
+            };
+            let style = if place.trim().is_empty() {
+                Style::new().fg(Color::Gray)
+            } else {
+                Style::new().fg(Color::White).bold()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{place} {:<28}", name), style),
+                Span::styled(detail, Style::new().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    if items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("Nothing to play yet — write some songs."),
+            list_area,
+        );
+        return;
+    }
+    let list = List::new(items).highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
+
+/// A single stop's cost, with what the merch order, the taping and the
+/// opener add to it.
+fn cost_line(route: &RouteQuote) -> String {
+    let extras: Vec<(i32, &str)> = [
+        (route.merch_cost, "merch"),
+        (route.recording_cost, "taping"),
+        (route.opener_cost, "opener"),
+    ]
+    .into_iter()
+    .filter(|(cost, _)| *cost > 0)
+    .collect();
+    match extras.as_slice() {
+        [] => format!("  Cost: {}", format_money(route.cost)),
+        [(cost, what)] => format!(
+            "  Cost: {} ({} of it {what})",
+            format_money(route.cost),
+            format_money(*cost)
+        ),
+        many => format!(
+            "  Cost: {} ({})",
+            format_money(route.cost),
+            many.iter()
+                .map(|(cost, what)| format!("{} {what}", format_money(*cost)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// The quote for a whole route: each stop on its own line, the travel legs
+/// between them, then the totals booking will charge.
+fn route_quote_lines<'a>(
+    app: &App,
+    legs: &[TourLeg],
+    rig: TourRig,
+    terms: TourTerms,
+) -> Vec<Line<'a>> {
+    let quote = match app.game.quote_route(legs, rig, terms) {
+        Ok(quote) => quote,
+        Err(msg) => {
+            return vec![
+                Line::styled("Route quote", Style::new().bold()),
+                Line::styled(format!("  {msg}"), Style::new().fg(Color::Red)),
+            ];
+        }
+    };
+    let mut lines = vec![Line::styled(
+        format!("Route quote — {}", quote.rig.label()),
+        Style::new().bold(),
+    )];
+    for (i, stop) in quote.stops.iter().enumerate() {
+        lines.push(Line::from(format!(
+            "  {}. {}, {} week{}: {} shows, {}, gross {} – {}, regional fame +{}–{}",
+            i + 1,
+            stop.region_name,
+            stop.weeks,
+            if stop.weeks == 1 { "" } else { "s" },
+            stop.shows,
+            format_money(stop.cost),
+            format_money(stop.gross_low as i32),
+            format_money(stop.gross_high as i32),
+            stop.regional_fame_gain_min,
+            stop.regional_fame_gain_max
+        )));
+        if let Some(leg) = quote.travel.get(i) {
+            lines.push(Line::styled(
+                format!(
+                    "     {} {} → {}: {}",
+                    if leg.international {
+                        "✈️"
+                    } else {
+                        "🛣️"
+                    },
+                    leg.from,
+                    leg.to,
+                    format_money(leg.cost)
+                ),
+                Style::new().fg(Color::Cyan),
+            ));
+        }
+    }
+    if quote.merch_cost > 0 {
+        lines.push(Line::from(format!(
+            "  Merch: {} items, {}",
+            quote.terms.merch_order,
+            format_money(quote.merch_cost)
+        )));
+    }
+    if quote.recording_cost > 0 {
+        lines.push(Line::from(format!(
+            "  Taping: {} shows, {}",
+            quote.shows,
+            format_money(quote.recording_cost)
+        )));
+    }
+    if let Some(opener) = &quote.terms.opener {
+        lines.push(Line::from(format!(
+            "  Opener: {}, {}",
+            opener,
+            format_money(quote.opener_cost)
+        )));
+    }
+    lines.push(Line::from(format!(
+        "  Total: {} weeks, {} shows, cost {}, projected gross {} – {}, fame +{}",
+        quote.weeks,
+        quote.shows,
+        format_money(quote.cost),
+        format_money(quote.gross_low as i32),
+        format_money(quote.gross_high as i32),
+        quote.fame_gain
+    )));
+    lines.push(if app.game.player.can_afford(quote.cost) {
+        Line::styled("  Ready to book.", Style::new().fg(Color::Green))
+    } else {
+        Line::styled(
+            format!(
+                "  Not enough cash — you have {}.",
+                format_money(app.game.player.money)
+            ),
+            Style::new().fg(Color::Red),
+        )
+    });
+    lines
+}
@@ -72,8 +72,8 @@ pub(crate) fn draw_tour_report_modal(frame: &mut Frame, app: &App) {
     frame.render_widget(
         Paragraph::new(Line::styled(
             format!(
-                "  {:<6}{:<34}{:<14}{:<11}{:<13}{}",
-                "Week", "Venue (city)", "Verdict", "Reception", "Sold/Cap", "Take"
+                "  {:<6}{:<34}{:<14}{:<11}{:<13}{:<10}{}",
+                "Week", "Venue (city)", "Verdict", "Reception", "Sold/Cap", "Take", "Merch"
             ),
             Style::new().fg(Color::Cyan).bold(),
         )),
@@ -94,7 +94,11 @@ pub(crate) fn draw_tour_report_modal(frame: &mut Frame, app: &App) {
                     "{:<13}",
                     format!("{}/{}", row.attendance, row.capacity)
                 )),
-                Span::styled(format_money(row.take as i32), Style::new().fg(Color::Green)),
+                Span::styled(
+                    format!("{:<10}", format_money(row.take as i32)),
+                    Style::new().fg(Color::Green),
+                ),
+                Span::styled(format_money(row.merch as i32), Style::new().fg(Color::Cyan)),
             ]))
         })
         .collect();
@@ -121,8 +125,9 @@ pub(crate) fn draw_tour_report_modal(frame: &mut Frame, app: &App) {
         )),
         verdict_line,
         Line::from(format!(
-            "  Total gross {}  ·  Fame gained +{}",
+            "  Total gross {}  ·  Merch {}  ·  Fame gained +{}",
             format_money(report.total_gross as i32),
+            format_money(report.total_merch as i32),
             report.fame_gained
         )),
     ];
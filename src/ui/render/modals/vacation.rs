@@ -0,0 +1,73 @@
+//! Vacation picker — cheap weekend to world cruise, with each trip's price,
+//! length, and what it does for the four bars (FUTURE §9.3). The longer
+//! trips say plainly that the public forgets you while you're gone.
+
+use ratatui::{
+    Frame,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState},
+};
+
+use crate::data::format_money;
+use crate::game::Vacation;
+use crate::ui::app::{App, Screen};
+
+use super::super::centered_rect;
+
+pub(crate) fn draw_vacation_picker_modal(frame: &mut Frame, app: &App) {
+    let Screen::VacationPicker { selected } = app.screen else {
+        return;
+    };
+    let area = centered_rect(86, 50, frame.area());
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = Vacation::ALL
+        .iter()
+        .map(|&trip| {
+            let affordable = app.game.player.can_afford(trip.cost() as i32);
+            let style = if affordable {
+                Style::new().fg(Color::White)
+            } else {
+                Style::new().fg(Color::DarkGray)
+            };
+            let weeks = trip.weeks();
+            let mut effects = vec![
+                format!("stress -{}", trip.stress_relief()),
+                format!("health +{}", trip.health_gain()),
+                format!("happy +{}", trip.happiness_gain()),
+                format!("creative +{}", trip.creativity_gain()),
+            ];
+            if trip.addiction_relief() > 0 {
+                effects.push(format!("addiction -{}", trip.addiction_relief()));
+            }
+
+            let mut spans = vec![
+                Span::styled(format!("{:<22}", trip.label()), style.bold()),
+                Span::styled(format!("{:>8}", format_money(trip.cost() as i32)), style),
+                Span::styled(
+                    format!("  {:>2} wk{}", weeks, if weeks == 1 { " " } else { "s" }),
+                    style,
+                ),
+                Span::styled(format!("  {}", effects.join(", ")), style),
+            ];
+            if !affordable {
+                spans.push(Span::styled(
+                    "  🔒 can't afford",
+                    Style::new().fg(Color::Red),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .title(" 🧳 Get Away ")
+                .title_bottom(" Enter book · Esc close · fame fades while you're away "),
+        )
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}